@@ -689,8 +689,10 @@ fn generate_readme_signals() {
     let groups: &[(&str, &[HeuristicLanguage])] = &[
         ("rust",       &[HeuristicLanguage::Rust,   HeuristicLanguage::RustCst]),
         ("python",     &[HeuristicLanguage::Python, HeuristicLanguage::PythonCst]),
-        ("javascript", &[HeuristicLanguage::Js,     HeuristicLanguage::JsCst]),
+        ("javascript", &[HeuristicLanguage::Js,     HeuristicLanguage::JsCst, HeuristicLanguage::Jsx]),
         ("go",         &[HeuristicLanguage::Go,     HeuristicLanguage::GoCst]),
+        ("csharp",     &[HeuristicLanguage::CSharp, HeuristicLanguage::CSharpCst]),
+        ("hcl",        &[HeuristicLanguage::Hcl]),
     ];
 
     let mut rows: Vec<String> = vec![
@@ -0,0 +1,296 @@
+//! Analysis of source files inside `.tar`, `.tar.gz` / `.tgz`, and `.zip`
+//! archives, e.g. a downloaded release or a vendored dependency, without
+//! extracting them to disk.
+//!
+//! Reading archives needs the `archive` feature; without it an archive
+//! argument is rejected with a hint to rebuild.
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use vibecheck_core::ignore_rules::IgnoreRules;
+use vibecheck_core::language::detect_language;
+use vibecheck_core::report::Report;
+use vibecheck_core::{ConfigOverrides, ExtensionFilter};
+
+/// Archive formats recognised by file name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ArchiveKind {
+    Tar,
+    TarGz,
+    Zip,
+}
+
+fn archive_kind(path: &Path) -> Option<ArchiveKind> {
+    let name = path.file_name()?.to_str()?.to_ascii_lowercase();
+    if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+        Some(ArchiveKind::TarGz)
+    } else if name.ends_with(".tar") {
+        Some(ArchiveKind::Tar)
+    } else if name.ends_with(".zip") {
+        Some(ArchiveKind::Zip)
+    } else {
+        None
+    }
+}
+
+/// Whether a CLI path argument names an archive file.
+pub fn is_archive(path: &Path) -> bool {
+    path.is_file() && archive_kind(path).is_some()
+}
+
+/// Caps on decompressed bytes read from one archive, so a small archive
+/// cannot expand into gigabytes of memory.
+#[derive(Debug, Clone, Copy)]
+struct SizeLimits {
+    /// Largest single entry.
+    entry: u64,
+    /// Sum of every analyzed entry.
+    total: u64,
+}
+
+const SIZE_LIMITS: SizeLimits = SizeLimits { entry: 16 << 20, total: 512 << 20 };
+
+/// Analyze every supported source file in the archive at `path`.
+///
+/// Entries are filtered like a directory scan: `ignore` is matched against
+/// the in-archive path (e.g. `pkg/src/lib.rs`) and `exts` against its
+/// extension.  Reports carry `<archive>/<entry>` as their file path and
+/// come back sorted by it.  Entries that are not valid UTF-8 are skipped
+/// with a warning.
+///
+/// Each entry is analyzed as soon as it is read.  An entry over 16 MiB, or
+/// more than 512 MiB decompressed in total, is an error.
+pub fn analyze_archive(
+    path: &Path,
+    ignore: &dyn IgnoreRules,
+    exts: &ExtensionFilter,
+    symbols: bool,
+    overrides: &ConfigOverrides,
+) -> Result<Vec<Report>> {
+    analyze_archive_within(path, ignore, exts, symbols, overrides, SIZE_LIMITS)
+}
+
+fn analyze_archive_within(
+    path: &Path,
+    ignore: &dyn IgnoreRules,
+    exts: &ExtensionFilter,
+    symbols: bool,
+    overrides: &ConfigOverrides,
+    limits: SizeLimits,
+) -> Result<Vec<Report>> {
+    let Some(kind) = archive_kind(path) else {
+        anyhow::bail!("{} is not a .tar, .tar.gz, .tgz, or .zip archive", path.display());
+    };
+    let wanted = |entry: &Path| exts.allows_path(entry) && !ignore.is_ignored(entry);
+    let mut reports = Vec::new();
+    let mut total = 0;
+    for_each_source(path, kind, &wanted, &mut |entry, reader| {
+        let file = path.join(&entry);
+        let mut bytes = Vec::new();
+        reader.take(limits.entry + 1).read_to_end(&mut bytes)?;
+        total += bytes.len() as u64;
+        if bytes.len() as u64 > limits.entry {
+            anyhow::bail!("{} is larger than the {} byte per-entry limit", file.display(), limits.entry);
+        }
+        if total > limits.total {
+            anyhow::bail!("{} holds more than {} bytes of source", path.display(), limits.total);
+        }
+        let Ok(source) = String::from_utf8(bytes) else {
+            eprintln!("vibecheck: warning: skipping {}: not valid UTF-8", file.display());
+            return Ok(());
+        };
+        let report = match detect_language(&entry) {
+            Some(language) if symbols => {
+                vibecheck_core::analyze_with_language(&source, &file, language, overrides)?
+            }
+            _ => vibecheck_core::analyze_source(&source, &file, overrides),
+        };
+        reports.push(report);
+        Ok(())
+    })?;
+    reports.sort_by(|a, b| a.metadata.file_path.cmp(&b.metadata.file_path));
+    Ok(reports)
+}
+
+/// Call `visit` with the in-archive path and a reader over the contents of
+/// each regular-file entry for which `wanted` holds, in archive order.
+#[cfg(feature = "archive")]
+fn for_each_source(
+    path: &Path,
+    kind: ArchiveKind,
+    wanted: &dyn Fn(&Path) -> bool,
+    visit: &mut dyn FnMut(PathBuf, &mut dyn Read) -> Result<()>,
+) -> Result<()> {
+    use std::fs::File;
+
+    use anyhow::Context;
+
+    let file = File::open(path).with_context(|| format!("cannot open {}", path.display()))?;
+    match kind {
+        ArchiveKind::Zip => {
+            let mut zip = zip::ZipArchive::new(file)
+                .with_context(|| format!("cannot read zip archive {}", path.display()))?;
+            for i in 0..zip.len() {
+                let mut entry = zip.by_index(i)?;
+                // `enclosed_name` rejects absolute and `..` paths.
+                let Some(name) = entry.enclosed_name().filter(|_| entry.is_file()) else {
+                    continue;
+                };
+                if wanted(&name) {
+                    visit(name, &mut entry)?;
+                }
+            }
+        }
+        ArchiveKind::Tar | ArchiveKind::TarGz => {
+            let reader: Box<dyn Read> = if kind == ArchiveKind::TarGz {
+                Box::new(flate2::read::GzDecoder::new(file))
+            } else {
+                Box::new(file)
+            };
+            let mut tar = tar::Archive::new(reader);
+            let entries = tar
+                .entries()
+                .with_context(|| format!("cannot read tar archive {}", path.display()))?;
+            for entry in entries {
+                let mut entry = entry?;
+                if !entry.header().entry_type().is_file() {
+                    continue;
+                }
+                let name = entry.path()?.into_owned();
+                if wanted(&name) {
+                    visit(name, &mut entry)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "archive"))]
+fn for_each_source(
+    path: &Path,
+    _kind: ArchiveKind,
+    _wanted: &dyn Fn(&Path) -> bool,
+    _visit: &mut dyn FnMut(PathBuf, &mut dyn Read) -> Result<()>,
+) -> Result<()> {
+    anyhow::bail!(
+        "cannot read {}: vibecheck was built without the `archive` feature \
+         (reinstall with `cargo install vibecheck-cli --features archive`)",
+        path.display()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vibecheck_core::ignore_rules::PatternIgnore;
+
+    #[test]
+    fn archive_kind_from_file_name() {
+        assert_eq!(archive_kind(Path::new("release-1.0.tar.gz")), Some(ArchiveKind::TarGz));
+        assert_eq!(archive_kind(Path::new("dl/pkg.TGZ")), Some(ArchiveKind::TarGz));
+        assert_eq!(archive_kind(Path::new("pkg.tar")), Some(ArchiveKind::Tar));
+        assert_eq!(archive_kind(Path::new("pkg.zip")), Some(ArchiveKind::Zip));
+        assert_eq!(archive_kind(Path::new("src/lib.rs")), None);
+        assert!(!is_archive(Path::new("/nonexistent/pkg.zip")));
+    }
+
+    #[cfg(not(feature = "archive"))]
+    #[test]
+    fn archives_need_the_feature() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("pkg.zip");
+        std::fs::write(&path, b"PK").unwrap();
+        let exts = ExtensionFilter::default();
+        let err = analyze_archive(&path, &PatternIgnore(vec![]), &exts, false, &Default::default()).unwrap_err();
+        assert!(err.to_string().contains("--features archive"), "{err}");
+    }
+
+    #[cfg(feature = "archive")]
+    const LIB_RS: &str = "/// Adds two numbers together.\nfn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n";
+
+    #[cfg(feature = "archive")]
+    #[test]
+    fn tar_gz_entries_are_filtered_and_analyzed() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("pkg.tar.gz");
+        let gz = flate2::write::GzEncoder::new(std::fs::File::create(&path).unwrap(), Default::default());
+        let mut tar = tar::Builder::new(gz);
+        for (name, body) in [
+            ("pkg/src/lib.rs", LIB_RS),
+            ("pkg/README.md", "# pkg\n"),
+            ("pkg/vendor/dep.py", "def f():\n    return 1\n"),
+            ("pkg/app.py", "def g():\n    return 2\n"),
+        ] {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(body.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            tar.append_data(&mut header, name, body.as_bytes()).unwrap();
+        }
+        tar.into_inner().unwrap().finish().unwrap();
+
+        assert!(is_archive(&path));
+        let ignore = PatternIgnore(vec!["vendor/".into()]);
+        let reports =
+            analyze_archive(&path, &ignore, &ExtensionFilter::default(), false, &Default::default()).unwrap();
+        let files: Vec<_> = reports.iter().filter_map(|r| r.metadata.file_path.clone()).collect();
+        assert_eq!(files, [path.join("pkg/app.py"), path.join("pkg/src/lib.rs")]);
+        assert!(reports[1].metadata.lines_of_code > 0);
+    }
+
+    #[cfg(feature = "archive")]
+    #[test]
+    fn oversized_entries_and_archives_are_rejected() {
+        use std::io::Write;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("pkg.zip");
+        let mut zip = zip::ZipWriter::new(std::fs::File::create(&path).unwrap());
+        let options = zip::write::SimpleFileOptions::default();
+        for name in ["a.rs", "b.rs"] {
+            zip.start_file(name, options).unwrap();
+            zip.write_all(LIB_RS.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap();
+
+        let analyze = |entry: u64, total: u64| {
+            let limits = SizeLimits { entry, total };
+            let exts = ExtensionFilter::default();
+            analyze_archive_within(&path, &PatternIgnore(vec![]), &exts, false, &Default::default(), limits)
+        };
+        let size = LIB_RS.len() as u64;
+        assert_eq!(analyze(size, 2 * size).unwrap().len(), 2);
+        let err = analyze(size - 1, 2 * size).unwrap_err();
+        assert!(err.to_string().contains("per-entry limit"), "{err}");
+        let err = analyze(size, 2 * size - 1).unwrap_err();
+        assert!(err.to_string().contains("bytes of source"), "{err}");
+    }
+
+    #[cfg(feature = "archive")]
+    #[test]
+    fn zip_entries_are_analyzed_with_symbols() {
+        use std::io::Write;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("pkg.zip");
+        let mut zip = zip::ZipWriter::new(std::fs::File::create(&path).unwrap());
+        let options = zip::write::SimpleFileOptions::default();
+        zip.add_directory("src/", options).unwrap();
+        zip.start_file("src/lib.rs", options).unwrap();
+        zip.write_all(LIB_RS.as_bytes()).unwrap();
+        zip.start_file("src/bad.rs", options).unwrap();
+        zip.write_all(&[0xff, 0xfe]).unwrap();
+        zip.finish().unwrap();
+
+        let reports =
+            analyze_archive(&path, &PatternIgnore(vec![]), &ExtensionFilter::default(), true, &Default::default())
+                .unwrap();
+        assert_eq!(reports.len(), 1, "non-UTF-8 entries are skipped");
+        assert_eq!(reports[0].metadata.file_path.as_deref(), Some(path.join("src/lib.rs").as_path()));
+        assert!(reports[0].symbol_reports.as_ref().is_some_and(|s| !s.is_empty()));
+    }
+}
@@ -1,40 +1,46 @@
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use walkdir::WalkDir;
 
-use vibecheck_core::ignore_rules::{IgnoreConfig, IgnoreRules};
-use vibecheck_core::output::OutputFormat;
+use vibecheck_core::ignore_rules::{FamilyAssertions, IgnoreRules};
+use vibecheck_core::language::detect_language;
 use vibecheck_core::report::{ModelFamily, Report};
+use vibecheck_core::{ConfigOverrides, ExtensionFilter, SUPPORTED_EXTS};
 
-use crate::output;
+use crate::output::{self, GroupBy, OutputFormat};
 
 /// Collect all supported source files under `path`, respecting `ignore`.
 ///
 /// When `path` is a single file it is returned directly (no filtering
 /// applied).  When it is a directory the tree is walked, skipping any entry
-/// for which `ignore` returns `true`.
-pub fn collect_files(path: &PathBuf, ignore: &dyn IgnoreRules) -> Result<Vec<PathBuf>> {
+/// for which `ignore` returns `true` or whose extension `exts` rejects; with
+/// `recursive` unset only the directory's immediate files are collected.
+pub fn collect_files(
+    path: &PathBuf,
+    ignore: &dyn IgnoreRules,
+    recursive: bool,
+    exts: &ExtensionFilter,
+) -> Result<Vec<PathBuf>> {
     if path.is_file() {
         return Ok(vec![path.clone()]);
     }
 
-    let supported_exts = ["rs", "py", "js", "ts", "jsx", "tsx", "go"];
+    let mut walker = WalkDir::new(path);
+    if !recursive {
+        walker = walker.max_depth(1);
+    }
     let mut files = Vec::new();
-    for entry in WalkDir::new(path)
+    for entry in walker
         .into_iter()
         .filter_entry(|e| !ignore.is_ignored_dir(e.path()))
         .filter_map(|e| e.ok())
         .filter(|e| e.file_type().is_file())
         .filter(|e| !ignore.is_ignored(e.path()))
     {
-        let p = entry.path();
-        if p.extension()
-            .and_then(|e| e.to_str())
-            .map(|e| supported_exts.contains(&e))
-            .unwrap_or(false)
-        {
-            files.push(p.to_path_buf());
+        if exts.allows_path(entry.path()) {
+            files.push(entry.path().to_path_buf());
         }
     }
     files.sort();
@@ -42,24 +48,39 @@ pub fn collect_files(path: &PathBuf, ignore: &dyn IgnoreRules) -> Result<Vec<Pat
 }
 
 pub fn parse_format(s: &str) -> Result<OutputFormat> {
+    OutputFormat::from_name(s).ok_or_else(|| {
+        let names: Vec<_> = OutputFormat::ALL.iter().map(|f| f.name()).collect();
+        let (last, rest) = names.split_last().expect("at least one format");
+        anyhow::anyhow!("unknown format: {s} (expected {}, or {last})", rest.join(", "))
+    })
+}
+
+/// Clap value parser for `--include-ext` / `--exclude-ext`: accept only
+/// extensions vibecheck can analyze, with or without a leading dot.
+pub fn parse_ext(s: &str) -> std::result::Result<String, String> {
+    let ext = s.trim().trim_start_matches('.');
+    if SUPPORTED_EXTS.contains(&ext) {
+        Ok(ext.to_string())
+    } else {
+        Err(format!("unsupported extension `{s}` (expected one of: {})", SUPPORTED_EXTS.join(", ")))
+    }
+}
+
+/// Parse a `--group-by` value (`none`, `family`, or `dir`).
+pub fn parse_group_by(s: &str) -> Result<GroupBy, String> {
     match s {
-        "pretty" => Ok(OutputFormat::Pretty),
-        "text" => Ok(OutputFormat::Text),
-        "json" => Ok(OutputFormat::Json),
-        other => anyhow::bail!("unknown format: {other} (expected pretty, text, or json)"),
+        "none" => Ok(GroupBy::None),
+        "family" => Ok(GroupBy::Family),
+        "dir" => Ok(GroupBy::Dir),
+        other => Err(format!("unknown grouping: {other} (expected none, family, or dir)")),
     }
 }
 
 pub fn parse_families(names: &[String]) -> Result<Vec<ModelFamily>> {
     names
         .iter()
-        .map(|s| match s.to_lowercase().as_str() {
-            "claude" => Ok(ModelFamily::Claude),
-            "gpt" => Ok(ModelFamily::Gpt),
-            "gemini" => Ok(ModelFamily::Gemini),
-            "copilot" => Ok(ModelFamily::Copilot),
-            "human" => Ok(ModelFamily::Human),
-            other => anyhow::bail!("unknown family: {other}"),
+        .map(|s| {
+            ModelFamily::from_name(s).ok_or_else(|| anyhow::anyhow!("unknown family: {s}"))
         })
         .collect()
 }
@@ -67,11 +88,484 @@ pub fn parse_families(names: &[String]) -> Result<Vec<ModelFamily>> {
 pub fn format_report(report: &Report, fmt: OutputFormat) -> String {
     match fmt {
         OutputFormat::Json => output::format_json(report),
+        OutputFormat::Jsonl => output::format_json_compact(report),
+        OutputFormat::Yaml => output::format_yaml(report),
         OutputFormat::Text => output::format_text(report),
         OutputFormat::Pretty => output::format_pretty(report, &vibecheck_core::colors::DefaultTheme),
     }
 }
 
+/// Options for [`run`], mirroring the `analyze` CLI flags.
+pub struct AnalyzeOptions<'a> {
+    pub format: &'a str,
+    pub no_cache: bool,
+    /// Analyze files that are not valid UTF-8 with the invalid bytes replaced.
+    pub lossy: bool,
+    pub symbols: bool,
+    /// List where line-specific signals fired under each text/pretty report.
+    pub verbose: bool,
+    pub assert_family: Option<Vec<String>>,
+    /// Exit 1 if any report is [degraded](vibecheck_core::report::ReportMetadata::is_degraded).
+    pub fail_on_parse_error: bool,
+    /// Settings such as `--config` and `--profile` applied on top of each
+    /// file's config.
+    pub overrides: &'a ConfigOverrides,
+    pub cache_dir: Option<&'a Path>,
+    /// Analyze staged blobs from the git index instead of files on disk.
+    pub staged: bool,
+    /// Analyze the tree at this git revision instead of files on disk.
+    pub git_ref: Option<&'a str>,
+    /// Analyze exactly the files listed in this file (`-` for stdin)
+    /// instead of walking `path`.
+    pub input_list: Option<&'a Path>,
+    /// Only analyze files directly inside a directory `path`.
+    pub no_recurse: bool,
+    /// Section text/pretty output by family or directory.
+    pub group_by: GroupBy,
+    /// Print raw per-family scores and contributing signals to stderr.
+    pub debug_scores: bool,
+    /// Print a JSON array of verdict-only reports instead of `format`.
+    pub minimal: bool,
+    /// Render each report through this template instead of `format`.
+    pub template: Option<&'a str>,
+    /// Print nothing but `--assert-family` failures and a one-line summary,
+    /// on stderr.
+    pub quiet: bool,
+    /// Which file extensions a directory walk picks up.
+    pub exts: ExtensionFilter,
+    /// Write results to this file instead of stdout.
+    pub output: Option<&'a Path>,
+}
+
+pub fn run(path: &PathBuf, opts: AnalyzeOptions<'_>) -> Result<()> {
+    let AnalyzeOptions {
+        format,
+        no_cache,
+        lossy,
+        symbols,
+        verbose,
+        assert_family,
+        fail_on_parse_error,
+        overrides,
+        cache_dir,
+        staged,
+        git_ref,
+        input_list,
+        no_recurse,
+        group_by,
+        debug_scores,
+        minimal,
+        template,
+        quiet,
+        exts,
+        output,
+    } = opts;
+    let fmt = parse_format(format)?;
+    if let Some(template) = template {
+        output::render_template(template, &[]).context("invalid --template")?;
+    }
+    let allowed_families = assert_family
+        .as_ref()
+        .map(|f| parse_families(f))
+        .transpose()?;
+
+    let config = overrides.load_config(path);
+    let ignore: &dyn IgnoreRules = &config;
+    let mut out = output::open_output(output)?;
+    // `--assert-family` replaces the config's `[assert]` section outright.
+    let assertions = config.family_assertions().filter(|_| allowed_families.is_none());
+    // Plain JSON output over files from disk is printed as each file is
+    // analyzed rather than once the whole scan is done.
+    let streams = !quiet && !minimal && template.is_none();
+    let mut streamed = false;
+    let mode = FileMode { no_cache, lossy, symbols, cache_dir, overrides };
+
+    let reports: Vec<Report> = if let Some(list) = input_list {
+        let files = read_input_list(list, &exts, quiet)?;
+        if files.is_empty() {
+            if !quiet {
+                eprintln!("No source files in the input list.");
+            }
+            return out.finish();
+        }
+        let stream = stream_format(fmt, files.len(), symbols).filter(|_| streams);
+        streamed = stream.is_some();
+        analyze_paths(&files, mode, stream, &mut out)?
+    } else if crate::remote::is_url(path) {
+        let url = path.to_string_lossy();
+        vec![crate::remote::analyze_url(&url, cache_dir, no_cache, symbols, overrides)?]
+    } else if crate::archive::is_archive(path) {
+        crate::archive::analyze_archive(path, ignore, &exts, symbols, overrides)?
+    } else if staged {
+        let mut sources = crate::commands::staged::staged_sources(path, ignore)
+            .context("failed to read staged files")?;
+        sources.retain(|(file, _)| exts.allows_path(file));
+        if sources.is_empty() {
+            if !quiet {
+                eprintln!("No staged source files to check.");
+            }
+            return out.finish();
+        }
+        sources
+            .iter()
+            .map(|(file, content)| vibecheck_core::analyze_source(content, file, overrides))
+            .collect()
+    } else if let Some(rev) = git_ref {
+        let mut sources = crate::commands::staged::ref_sources(path, rev, ignore)
+            .with_context(|| format!("failed to read files at {rev}"))?;
+        sources.retain(|(file, _)| exts.allows_path(file));
+        if sources.is_empty() {
+            if !quiet {
+                eprintln!("No source files under {} at {rev}.", path.display());
+            }
+            return out.finish();
+        }
+        sources
+            .iter()
+            .map(|(file, content)| match detect_language(file) {
+                Some(language) if symbols => {
+                    vibecheck_core::analyze_with_language(content, file, language, overrides)
+                }
+                _ => Ok(vibecheck_core::analyze_source(content, file, overrides)),
+            })
+            .collect::<Result<_, _>>()?
+    } else {
+        let files = collect_files(path, ignore, !no_recurse, &exts).context("failed to collect files")?;
+        if files.is_empty() {
+            anyhow::bail!("no supported source files found in {}", path.display());
+        }
+        let stream = stream_format(fmt, files.len(), symbols).filter(|_| streams);
+        streamed = stream.is_some();
+        analyze_paths(&files, mode, stream, &mut out)?
+    };
+
+    if quiet || streamed {
+        // Only assertion failures are printed, below.
+    } else if minimal {
+        writeln!(out, "{}", output::format_minimal_json(&reports))?;
+    } else if let Some(template) = template {
+        let rendered = output::render_template(template, &reports).context("failed to render --template")?;
+        write!(out, "{rendered}")?;
+    } else {
+        print_reports(&mut out, &reports, fmt, symbols, verbose, group_by)?;
+    }
+    // Results are complete; only diagnostics (on stderr) and the exit code
+    // remain.  The gates below may exit, so persist the lookup counters now.
+    out.finish()?;
+    vibecheck_core::cache::Cache::flush_lookups();
+    if debug_scores {
+        for report in &reports {
+            eprintln!("{}", output::format_debug_scores(report));
+        }
+    }
+
+    if fail_on_parse_error {
+        let degraded = parse_failures(&reports);
+        if !degraded.is_empty() {
+            if quiet {
+                for line in &degraded {
+                    eprintln!("{line}");
+                }
+                eprintln!("vibecheck: {} of {} files failed --fail-on-parse-error", degraded.len(), reports.len());
+            } else {
+                eprintln!("\n--- VIBECHECK FAILED: unreliable parses ---");
+                for line in &degraded {
+                    eprintln!("  {line}");
+                }
+            }
+            std::process::exit(1);
+        }
+    }
+
+    let gate = match (&allowed_families, assertions) {
+        (Some(_), _) => "--assert-family",
+        (None, Some(_)) => "[assert] in .vibecheck",
+        (None, None) => return Ok(()),
+    };
+    let allowed_for = |r: &Report| expected_families(r, allowed_families.as_deref(), assertions);
+    if quiet {
+        let cwd = std::env::current_dir()?;
+        let failures = crate::commands::pre_commit::violations(&reports, allowed_for, &cwd);
+        for line in &failures {
+            eprintln!("{line}");
+        }
+        if !failures.is_empty() {
+            eprintln!("vibecheck: {} of {} files failed {gate}", failures.len(), reports.len());
+            std::process::exit(1);
+        }
+    } else {
+        let mut failures = Vec::new();
+        for report in &reports {
+            if report.metadata.generated || report.metadata.signal_count == 0 {
+                continue;
+            }
+            if let Some(allowed) = allowed_for(report).filter(|a| !a.contains(&report.attribution.primary)) {
+                failures.push((report, allowed));
+            }
+        }
+        if !failures.is_empty() {
+            eprintln!("\n--- VIBECHECK FAILED ---");
+            for (report, allowed) in &failures {
+                let path = report
+                    .metadata
+                    .file_path
+                    .as_ref()
+                    .map(|p| p.display().to_string())
+                    .unwrap_or_else(|| "<stdin>".into());
+                eprintln!(
+                    "  {} — detected as {} ({:.0}%), expected one of: {}",
+                    path,
+                    report.attribution.primary,
+                    report.attribution.confidence * 100.0,
+                    allowed.iter().map(|f| f.to_string()).collect::<Vec<_>>().join(", "),
+                );
+            }
+            std::process::exit(1);
+        } else {
+            eprintln!("\nAll files passed the vibe check.");
+        }
+    }
+
+    Ok(())
+}
+
+/// The families `report` must be attributed to: the `--assert-family` list
+/// when given, else whatever the `[assert]` section says for its path.
+/// `None` leaves the report unchecked.
+fn expected_families<'a>(
+    report: &Report,
+    flag: Option<&'a [ModelFamily]>,
+    assertions: Option<&'a FamilyAssertions>,
+) -> Option<&'a [ModelFamily]> {
+    flag.or_else(|| assertions?.allowed_for(report.metadata.file_path.as_deref()?))
+}
+
+/// One line per degraded report, naming the file and what went wrong.
+fn parse_failures(reports: &[Report]) -> Vec<String> {
+    reports
+        .iter()
+        .filter(|r| r.metadata.is_degraded())
+        .map(|r| {
+            let path = r
+                .metadata
+                .file_path
+                .as_ref()
+                .map(|p| p.display().to_string())
+                .unwrap_or_else(|| "<stdin>".into());
+            if r.metadata.timed_out {
+                format!("{path} — parsing timed out")
+            } else {
+                format!("{path} — {} parse errors", r.metadata.parse_errors)
+            }
+        })
+        .collect()
+}
+
+/// The format in which a run over `count` files prints each report as soon
+/// as it is analyzed: JSON Lines always, and a JSON array of reports once
+/// there are several files (a single file prints as a bare object, and
+/// `--symbols` JSON is one flat array of symbols).
+fn stream_format(fmt: OutputFormat, count: usize, symbols: bool) -> Option<OutputFormat> {
+    match fmt {
+        OutputFormat::Jsonl => Some(fmt),
+        OutputFormat::Json if count > 1 && !symbols => Some(fmt),
+        _ => None,
+    }
+}
+
+/// Read an `--input-list`: one path per line from `list` (`-` is stdin),
+/// blank lines skipped.  Paths `exts` rejects are dropped silently; paths
+/// that are not files (typically deleted in a `git diff --name-only`) are
+/// dropped with a warning unless `quiet`.
+fn read_input_list(list: &Path, exts: &ExtensionFilter, quiet: bool) -> Result<Vec<PathBuf>> {
+    let text = if list == Path::new("-") {
+        std::io::read_to_string(std::io::stdin()).context("failed to read the input list from stdin")?
+    } else {
+        std::fs::read_to_string(list).with_context(|| format!("failed to read input list {}", list.display()))?
+    };
+    Ok(parse_input_list(&text, exts)
+        .into_iter()
+        .filter(|file| {
+            let exists = file.is_file();
+            if !exists && !quiet {
+                eprintln!("vibecheck: warning: {} is not a file; skipping", file.display());
+            }
+            exists
+        })
+        .collect())
+}
+
+/// The analyzable paths in an `--input-list`, in order, without duplicates.
+fn parse_input_list(text: &str, exts: &ExtensionFilter) -> Vec<PathBuf> {
+    let mut seen = std::collections::HashSet::new();
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(PathBuf::from)
+        .filter(|path| exts.allows_path(path) && seen.insert(path.clone()))
+        .collect()
+}
+
+/// How [`analyze_paths`] reads and analyzes each file from disk.
+#[derive(Clone, Copy)]
+struct FileMode<'a> {
+    /// Bypass the content-addressed cache.
+    no_cache: bool,
+    /// Decode invalid UTF-8 lossily instead of failing.
+    lossy: bool,
+    /// Include per-symbol reports.
+    symbols: bool,
+    cache_dir: Option<&'a Path>,
+    overrides: &'a ConfigOverrides,
+}
+
+/// Analyze each of `files` from disk as `mode` says.
+///
+/// With `stream` (see [`stream_format`]), each report is also written to
+/// `out` in that format as soon as it is ready.
+fn analyze_paths(
+    files: &[PathBuf],
+    mode: FileMode<'_>,
+    stream: Option<OutputFormat>,
+    out: &mut dyn Write,
+) -> Result<Vec<Report>> {
+    let FileMode { no_cache, lossy, symbols, cache_dir, overrides } = mode;
+    let analyze = |f: &Path| -> Result<Report> {
+        Ok(match (symbols, no_cache) {
+            (true, true) => vibecheck_core::analyze_file_symbols_no_cache_with_overrides(f, overrides)?,
+            (true, false) => vibecheck_core::analyze_file_symbols_with_cache_dir(f, cache_dir, overrides)?,
+            (false, true) if lossy => vibecheck_core::analyze_file_lossy_no_cache(f, overrides)?,
+            (false, false) if lossy => vibecheck_core::analyze_file_lossy(f, cache_dir, overrides)?,
+            (false, true) => hint_lossy(vibecheck_core::analyze_file_no_cache_with_overrides(f, overrides), f)?,
+            (false, false) => {
+                hint_lossy(vibecheck_core::analyze_file_with_cache_dir(f, cache_dir, overrides), f)?
+            }
+        })
+    };
+    let (mut array, mut jsonl) = match stream {
+        Some(OutputFormat::Json) => (Some(output::JsonArrayWriter::new(out)), None),
+        Some(OutputFormat::Jsonl) => (None, Some(out)),
+        _ => (None, None),
+    };
+    let mut reports = Vec::with_capacity(files.len());
+    for file in files {
+        let report = analyze(file).context("failed to analyze files")?;
+        if let Some(array) = &mut array {
+            array.push(&report)?;
+        } else if let Some(out) = &mut jsonl {
+            writeln!(out, "{}", output::format_json_compact(&report))?;
+            out.flush()?;
+        }
+        reports.push(report);
+    }
+    if let Some(array) = array {
+        array.finish()?;
+    }
+    Ok(reports)
+}
+
+/// Point at `--lossy` when a strict read of `file` failed on invalid UTF-8.
+fn hint_lossy(result: std::io::Result<Report>, file: &Path) -> Result<Report> {
+    result.map_err(|e| {
+        let invalid = e.kind() == std::io::ErrorKind::InvalidData;
+        let err = anyhow::Error::from(e);
+        if invalid {
+            err.context(format!("{} is not valid UTF-8 (--lossy analyzes it anyway)", file.display()))
+        } else {
+            err
+        }
+    })
+}
+
+fn print_reports(
+    out: &mut dyn Write,
+    reports: &[Report],
+    fmt: OutputFormat,
+    symbols: bool,
+    verbose: bool,
+    group_by: GroupBy,
+) -> Result<()> {
+    if fmt == OutputFormat::Jsonl {
+        // One compact object per line; symbol reports are already inside.
+        for report in reports {
+            writeln!(out, "{}", format_report(report, fmt))?;
+        }
+    } else if fmt == OutputFormat::Json && symbols {
+        // A flat array of symbol spans and verdicts for editor gutters.
+        writeln!(out, "{}", output::format_symbols_json(reports))?;
+    } else if fmt == OutputFormat::Json && reports.len() > 1 {
+        let json = serde_json::to_string_pretty(reports)?;
+        writeln!(out, "{json}")?;
+    } else if fmt == OutputFormat::Yaml {
+        // YAML is one document: a sequence for multi-file runs, and symbol
+        // reports are already part of each report's structure.
+        match reports {
+            [report] => write!(out, "{}", output::format_yaml(report))?,
+            _ => write!(out, "{}", output::format_yaml_sequence(reports))?,
+        }
+    } else if group_by != GroupBy::None && fmt != OutputFormat::Json {
+        for (title, members) in output::group_reports(reports, group_by) {
+            writeln!(
+                out,
+                "{}\n",
+                output::format_group_header(&title, &members, fmt == OutputFormat::Pretty)
+            )?;
+            for report in members {
+                print_report(out, report, fmt, symbols, verbose)?;
+            }
+        }
+    } else {
+        for report in reports {
+            print_report(out, report, fmt, symbols, verbose)?;
+        }
+    }
+    if reports.len() > 1 && matches!(fmt, OutputFormat::Pretty | OutputFormat::Text) {
+        let all: Vec<&Report> = reports.iter().collect();
+        writeln!(
+            out,
+            "{}",
+            output::format_group_header("Overall", &all, fmt == OutputFormat::Pretty)
+        )?;
+    }
+    Ok(())
+}
+
+fn print_report(
+    out: &mut dyn Write,
+    report: &Report,
+    fmt: OutputFormat,
+    symbols: bool,
+    verbose: bool,
+) -> Result<()> {
+    writeln!(out, "{}", format_report(report, fmt))?;
+    if verbose && matches!(fmt, OutputFormat::Pretty | OutputFormat::Text) {
+        let locations = output::format_signal_locations(report);
+        if !locations.is_empty() {
+            writeln!(out, "{locations}")?;
+        }
+    }
+    if !symbols {
+        return Ok(());
+    }
+    if let Some(ref sym_reports) = report.symbol_reports {
+        if !sym_reports.is_empty() {
+            writeln!(out, "  Symbol-level attribution:")?;
+            for sr in sym_reports {
+                writeln!(
+                    out,
+                    "    {:>4}–{:<4}  {:<40}  {} ({:.0}%)",
+                    sr.metadata.start_line,
+                    sr.metadata.end_line,
+                    format!("{}  [{}]", sr.metadata.name, sr.metadata.kind),
+                    sr.attribution.primary,
+                    sr.attribution.confidence * 100.0,
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,9 +586,49 @@ mod tests {
         assert_eq!(parse_format("json").unwrap(), OutputFormat::Json);
     }
 
+    #[test]
+    fn parse_format_yaml() {
+        assert_eq!(parse_format("yaml").unwrap(), OutputFormat::Yaml);
+    }
+
     #[test]
     fn parse_format_unknown_is_error() {
-        assert!(parse_format("csv").is_err());
+        let err = parse_format("csv").unwrap_err();
+        assert_eq!(err.to_string(), "unknown format: csv (expected pretty, text, json, jsonl, or yaml)");
+    }
+
+    #[test]
+    fn every_core_format_is_parsed_and_rendered() {
+        // The CLI's `OutputFormat` is the core enum, so both expose the same
+        // variants; each must be reachable from `--format` and render.
+        let core: &[vibecheck_core::output::OutputFormat] = output::OutputFormat::ALL;
+        let report = vibecheck_core::analyze("fn main() {}\n");
+        for &format in core {
+            assert_eq!(parse_format(format.name()).unwrap(), format);
+            assert!(!format_report(&report, format).is_empty(), "{format:?}");
+        }
+    }
+
+    #[test]
+    fn parse_group_by_values() {
+        assert_eq!(parse_group_by("none").unwrap(), GroupBy::None);
+        assert_eq!(parse_group_by("family").unwrap(), GroupBy::Family);
+        assert_eq!(parse_group_by("dir").unwrap(), GroupBy::Dir);
+        assert!(parse_group_by("signal").is_err());
+    }
+
+    #[test]
+    fn parse_failures_name_degraded_files() {
+        let clean = vibecheck_core::analyze_source("fn main() {}\n", Path::new("ok.rs"), &Default::default());
+        let broken = vibecheck_core::analyze_source("fn main() {\n    let x = ;\n}\n", Path::new("bad.rs"), &Default::default());
+        let mut slow = clean.clone();
+        slow.metadata.timed_out = true;
+        slow.metadata.file_path = None;
+
+        let lines = parse_failures(&[clean, broken, slow]);
+        assert_eq!(lines.len(), 2, "{lines:?}");
+        assert!(lines[0].starts_with("bad.rs — ") && lines[0].ends_with("parse errors"), "{lines:?}");
+        assert_eq!(lines[1], "<stdin> — parsing timed out");
     }
 
     #[test]
@@ -122,7 +656,7 @@ mod tests {
         let fixture = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
             .join("../vibecheck-core/tests/fixtures/lru_cache/claude.rs");
         let ignore = PatternIgnore(vec![]);
-        let files = collect_files(&fixture, &ignore).unwrap();
+        let files = collect_files(&fixture, &ignore, true, &ExtensionFilter::default()).unwrap();
         assert_eq!(files.len(), 1);
         assert!(files[0].ends_with("claude.rs"));
     }
@@ -132,7 +666,7 @@ mod tests {
         let fixture_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
             .join("../vibecheck-core/tests/fixtures/lru_cache");
         let ignore = PatternIgnore(vec![]);
-        let files = collect_files(&fixture_dir, &ignore).unwrap();
+        let files = collect_files(&fixture_dir, &ignore, true, &ExtensionFilter::default()).unwrap();
         assert!(files.len() >= 20, "should find all fixture files; got {}", files.len());
         for f in &files {
             let ext = f.extension().unwrap().to_str().unwrap();
@@ -148,7 +682,7 @@ mod tests {
         let fixture_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
             .join("../vibecheck-core/tests/fixtures/lru_cache");
         let ignore = PatternIgnore(vec!["claude".into()]);
-        let files = collect_files(&fixture_dir, &ignore).unwrap();
+        let files = collect_files(&fixture_dir, &ignore, true, &ExtensionFilter::default()).unwrap();
         for f in &files {
             assert!(
                 !f.to_string_lossy().contains("claude"),
@@ -158,6 +692,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn collect_files_non_recursive_skips_subdirs() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("sub")).unwrap();
+        std::fs::write(dir.path().join("top.rs"), "fn a() {}\n").unwrap();
+        std::fs::write(dir.path().join("sub/nested.rs"), "fn b() {}\n").unwrap();
+        let ignore = PatternIgnore(vec![]);
+        let root = dir.path().to_path_buf();
+
+        let all = ExtensionFilter::default();
+
+        let shallow = collect_files(&root, &ignore, false, &all).unwrap();
+        assert_eq!(shallow, vec![root.join("top.rs")]);
+        assert_eq!(collect_files(&root, &ignore, true, &all).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn collect_files_honours_extension_filter() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("a.rs"), "fn a() {}\n").unwrap();
+        std::fs::write(dir.path().join("b.py"), "def b(): pass\n").unwrap();
+        std::fs::write(dir.path().join("c.go"), "package c\n").unwrap();
+        let ignore = PatternIgnore(vec![]);
+        let root = dir.path().to_path_buf();
+
+        let only_rs = ExtensionFilter::new(Some(vec!["rs".into()]), vec![]);
+        assert_eq!(collect_files(&root, &ignore, true, &only_rs).unwrap(), vec![root.join("a.rs")]);
+
+        let no_go = ExtensionFilter::new(None, vec!["go".into()]);
+        assert_eq!(
+            collect_files(&root, &ignore, true, &no_go).unwrap(),
+            vec![root.join("a.rs"), root.join("b.py")]
+        );
+    }
+
+    #[test]
+    fn parse_ext_accepts_supported_only() {
+        assert_eq!(parse_ext(".rs"), Ok("rs".to_string()));
+        assert_eq!(parse_ext("tsx"), Ok("tsx".to_string()));
+        assert!(parse_ext("md").is_err());
+    }
+
     #[test]
     fn format_report_text_contains_verdict() {
         let report = vibecheck_core::analyze("fn main() { println!(\"hello\"); }");
@@ -165,6 +741,28 @@ mod tests {
         assert!(output.contains("Verdict:"), "text output should have Verdict");
     }
 
+    #[test]
+    fn input_list_keeps_supported_paths_in_order_once() {
+        let text = "src/b.rs\n\n  src/a.py \nREADME.md\nsrc/b.rs\n";
+        assert_eq!(
+            parse_input_list(text, &ExtensionFilter::default()),
+            [PathBuf::from("src/b.rs"), PathBuf::from("src/a.py")]
+        );
+        let only_rust = ExtensionFilter::new(Some(vec!["rs".into()]), vec![]);
+        assert_eq!(parse_input_list(text, &only_rust), [PathBuf::from("src/b.rs")]);
+    }
+
+    #[test]
+    fn input_list_skips_missing_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("lib.rs");
+        std::fs::write(&file, "fn main() {}\n").unwrap();
+        let list = dir.path().join("changed.txt");
+        std::fs::write(&list, format!("{}\n{}\n", file.display(), dir.path().join("gone.rs").display())).unwrap();
+        assert_eq!(read_input_list(&list, &ExtensionFilter::default(), true).unwrap(), [file]);
+        assert!(read_input_list(&dir.path().join("nope.txt"), &ExtensionFilter::default(), true).is_err());
+    }
+
     #[test]
     fn format_report_json_is_valid() {
         let report = vibecheck_core::analyze("fn main() {}");
@@ -172,123 +770,48 @@ mod tests {
         let _: serde_json::Value = serde_json::from_str(&output).expect("should be valid JSON");
     }
 
+    #[test]
+    fn json_streams_only_for_multi_file_report_arrays() {
+        assert_eq!(stream_format(OutputFormat::Jsonl, 1, true), Some(OutputFormat::Jsonl));
+        assert_eq!(stream_format(OutputFormat::Json, 2, false), Some(OutputFormat::Json));
+        assert_eq!(stream_format(OutputFormat::Json, 1, false), None, "one file is a bare object");
+        assert_eq!(stream_format(OutputFormat::Json, 2, true), None, "symbols are one flat array");
+        assert_eq!(stream_format(OutputFormat::Pretty, 2, false), None);
+    }
+
+    #[test]
+    fn format_report_jsonl_is_a_single_line() {
+        assert_eq!(parse_format("jsonl").unwrap(), OutputFormat::Jsonl);
+        let report = vibecheck_core::analyze("fn main() {}");
+        let output = format_report(&report, OutputFormat::Jsonl);
+        assert!(!output.contains('\n'));
+        let _: serde_json::Value = serde_json::from_str(&output).expect("should be valid JSON");
+    }
+
     #[test]
     fn format_report_pretty_contains_verdict() {
         let report = vibecheck_core::analyze("fn main() { println!(\"hello\"); }");
         let output = format_report(&report, OutputFormat::Pretty);
         assert!(output.contains("Verdict:"), "pretty output should have Verdict");
     }
-}
-
-pub fn run(
-    path: &PathBuf,
-    format: &str,
-    no_cache: bool,
-    symbols: bool,
-    assert_family: Option<Vec<String>>,
-    ignore_file: Option<&PathBuf>,
-) -> Result<()> {
-    let fmt = parse_format(format)?;
-    let allowed_families = assert_family
-        .as_ref()
-        .map(|f| parse_families(f))
-        .transpose()?;
-
-    let ignore: Box<dyn IgnoreRules> = match ignore_file {
-        Some(f) => Box::new(IgnoreConfig::from_file(f)?),
-        None => Box::new(IgnoreConfig::load(path)),
-    };
-
-    let files = collect_files(path, ignore.as_ref()).context("failed to collect files")?;
 
-    if files.is_empty() {
-        anyhow::bail!("no supported source files found in {}", path.display());
-    }
-
-    let reports: Vec<Report> = if symbols {
-        let symbol_fn: fn(&std::path::Path) -> anyhow::Result<Report> = if no_cache {
-            vibecheck_core::analyze_file_symbols_no_cache
-        } else {
-            vibecheck_core::analyze_file_symbols
-        };
-        files
-            .iter()
-            .map(|f| symbol_fn(f).map_err(|e| std::io::Error::other(e.to_string())))
-            .collect::<std::io::Result<Vec<_>>>()
-            .context("failed to analyze files")?
-    } else {
-        let analyze_fn: fn(&std::path::Path) -> std::io::Result<Report> = if no_cache {
-            vibecheck_core::analyze_file_no_cache
-        } else {
-            vibecheck_core::analyze_file
-        };
-        files
-            .iter()
-            .map(|f| analyze_fn(f))
-            .collect::<std::io::Result<Vec<_>>>()
-            .context("failed to analyze files")?
-    };
-
-    if fmt == OutputFormat::Json && reports.len() > 1 {
-        let json = serde_json::to_string_pretty(&reports)?;
-        println!("{json}");
-    } else if symbols {
-        for report in &reports {
-            println!("{}", format_report(report, fmt));
-            if let Some(ref sym_reports) = report.symbol_reports {
-                if !sym_reports.is_empty() {
-                    println!("  Symbol-level attribution:");
-                    for sr in sym_reports {
-                        println!(
-                            "    {:>4}–{:<4}  {:<40}  {} ({:.0}%)",
-                            sr.metadata.start_line,
-                            sr.metadata.end_line,
-                            format!("{}  [{}]", sr.metadata.name, sr.metadata.kind),
-                            sr.attribution.primary,
-                            sr.attribution.confidence * 100.0,
-                        );
-                    }
-                }
-            }
-        }
-    } else {
-        for report in &reports {
-            println!("{}", format_report(report, fmt));
-        }
-    }
+    #[test]
+    fn expected_families_prefer_the_flag_over_assert_config() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join(".vibecheck"),
+            "[assert]\nallowed_families = [\"human\"]\n[[assert.override]]\npaths = [\"gen/\"]\nallowed_families = [\"gpt\"]\n",
+        )
+        .unwrap();
+        let config = vibecheck_core::ignore_rules::IgnoreConfig::load(dir.path());
+        let assertions = config.family_assertions();
+        let report_at = |rel: &str| vibecheck_core::analyze_source("fn main() {}\n", &dir.path().join(rel), &Default::default());
 
-    if let Some(ref allowed) = allowed_families {
-        let mut failures = Vec::new();
-        for report in &reports {
-            if report.metadata.signal_count == 0 {
-                continue;
-            }
-            if !allowed.contains(&report.attribution.primary) {
-                failures.push(report);
-            }
-        }
-        if !failures.is_empty() {
-            eprintln!("\n--- VIBECHECK FAILED ---");
-            for report in &failures {
-                let path = report
-                    .metadata
-                    .file_path
-                    .as_ref()
-                    .map(|p| p.display().to_string())
-                    .unwrap_or_else(|| "<stdin>".into());
-                eprintln!(
-                    "  {} — detected as {} ({:.0}%), expected one of: {}",
-                    path,
-                    report.attribution.primary,
-                    report.attribution.confidence * 100.0,
-                    allowed.iter().map(|f| f.to_string()).collect::<Vec<_>>().join(", "),
-                );
-            }
-            std::process::exit(1);
-        } else {
-            eprintln!("\nAll files passed the vibe check.");
-        }
+        let generated = report_at("gen/a.rs");
+        assert_eq!(expected_families(&generated, None, assertions), Some(&[ModelFamily::Gpt][..]));
+        assert_eq!(expected_families(&report_at("src/a.rs"), None, assertions), Some(&[ModelFamily::Human][..]));
+        let flag = [ModelFamily::Claude];
+        assert_eq!(expected_families(&generated, Some(&flag), assertions), Some(&flag[..]));
+        assert_eq!(expected_families(&vibecheck_core::analyze("fn main() {}"), None, assertions), None, "no path");
     }
-
-    Ok(())
 }
@@ -0,0 +1,143 @@
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+
+use vibecheck_core::pipeline::Pipeline;
+use vibecheck_core::ConfigOverrides;
+
+/// Read JSON Lines requests from stdin and stream one result line per
+/// request to stdout.
+///
+/// The `.vibecheck` config is loaded once (from `overrides`, i.e.
+/// `--config`, or discovered from the current directory) and shared by
+/// every request.
+pub fn run(overrides: &ConfigOverrides) -> Result<()> {
+    let config = overrides.load_config(&std::env::current_dir()?);
+    let pipeline = vibecheck_core::pipeline_from_config(&config);
+    let stdin = std::io::stdin();
+    let stdout = std::io::stdout();
+    process(stdin.lock(), stdout.lock(), &pipeline)
+}
+
+/// Analyze each `{"id", "lang", "source"}` line of `input`, writing
+/// `{"id", "report"}` (or `{"id", "error"}`) to `output` as it goes.
+///
+/// A malformed request produces an error line rather than aborting the
+/// stream; blank lines are skipped.  Output is flushed after every line so
+/// consumers see results as soon as they are ready.
+fn process<R: BufRead, W: Write>(input: R, mut output: W, pipeline: &Pipeline) -> Result<()> {
+    for line in input.lines() {
+        let line = line.context("failed to read batch input")?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        // Report is written with its own Serialize impl (not via `Value`)
+        // so score keys keep their stable family order.
+        match analyze_line(&line, pipeline) {
+            Ok((id, report)) => writeln!(
+                output,
+                r#"{{"id":{id},"report":{}}}"#,
+                serde_json::to_string(&report)?
+            )?,
+            Err((id, error)) => writeln!(output, "{}", json!({ "id": id, "error": error }))?,
+        }
+        output.flush()?;
+    }
+    Ok(())
+}
+
+type LineResult = std::result::Result<(Value, vibecheck_core::report::Report), (Value, String)>;
+
+fn analyze_line(line: &str, pipeline: &Pipeline) -> LineResult {
+    let request: Value =
+        serde_json::from_str(line).map_err(|e| (Value::Null, format!("invalid JSON: {e}")))?;
+    let id = request.get("id").cloned().unwrap_or(Value::Null);
+    let field = |name: &str| {
+        request
+            .get(name)
+            .and_then(Value::as_str)
+            .ok_or_else(|| (id.clone(), format!("missing string field `{name}`")))
+    };
+    let lang = field("lang")?;
+    let source = field("source")?;
+    let ext = extension_for(lang)
+        .ok_or_else(|| (id.clone(), format!("unsupported lang: {lang}")))?;
+
+    // The path only selects the language; it is not part of the result.
+    let mut report = pipeline.run(source, Some(PathBuf::from(format!("snippet.{ext}"))));
+    report.metadata.file_path = None;
+    Ok((id, report))
+}
+
+/// File extension that makes the pipeline treat a snippet as `lang`.
+pub(crate) fn extension_for(lang: &str) -> Option<&'static str> {
+    match lang.to_ascii_lowercase().as_str() {
+        "rust" | "rs" => Some("rs"),
+        "python" | "py" => Some("py"),
+        "javascript" | "js" => Some("js"),
+        "typescript" | "ts" => Some("ts"),
+        "jsx" => Some("jsx"),
+        "tsx" => Some("tsx"),
+        "go" | "golang" => Some("go"),
+        "csharp" | "c#" | "cs" => Some("cs"),
+        "hcl" | "terraform" | "tf" => Some("tf"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_batch(input: &str) -> Vec<Value> {
+        let mut out = Vec::new();
+        process(input.as_bytes(), &mut out, &Pipeline::with_defaults()).unwrap();
+        String::from_utf8(out)
+            .unwrap()
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn one_result_line_per_request_in_order() {
+        let input = concat!(
+            r#"{"id": "a", "lang": "rust", "source": "fn main() {}"}"#, "\n",
+            "\n",
+            r#"{"id": 7, "lang": "python", "source": "def f():\n    return 1\n"}"#, "\n",
+        );
+        let results = run_batch(input);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0]["id"], "a");
+        assert_eq!(results[1]["id"], 7);
+        assert!(results[0]["report"]["attribution"]["primary"].is_string());
+        assert!(results[1]["report"]["metadata"]["file_path"].is_null());
+    }
+
+    #[test]
+    fn bad_requests_yield_error_lines_without_stopping() {
+        let input = concat!(
+            "not json\n",
+            r#"{"id": "x", "lang": "cobol", "source": ""}"#, "\n",
+            r#"{"id": "y", "lang": "go"}"#, "\n",
+            r#"{"id": "z", "lang": "go", "source": "package main"}"#, "\n",
+        );
+        let results = run_batch(input);
+        assert_eq!(results.len(), 4);
+        assert!(results[0]["error"].as_str().unwrap().starts_with("invalid JSON"));
+        assert_eq!(results[1]["error"], "unsupported lang: cobol");
+        assert_eq!(results[2]["error"], "missing string field `source`");
+        assert!(results[3]["report"].is_object());
+    }
+
+    #[test]
+    fn extension_for_accepts_aliases() {
+        assert_eq!(extension_for("Rust"), Some("rs"));
+        assert_eq!(extension_for("c#"), Some("cs"));
+        assert_eq!(extension_for("terraform"), Some("tf"));
+        assert_eq!(extension_for("tsx"), Some("tsx"));
+        assert_eq!(extension_for("cobol"), None);
+    }
+}
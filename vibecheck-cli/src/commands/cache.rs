@@ -0,0 +1,144 @@
+use std::io::Write;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use anyhow::{Context, Result};
+use serde_json::json;
+
+use vibecheck_core::cache::{Cache, CacheStats};
+use vibecheck_core::ConfigOverrides;
+
+/// Print [`Cache::stats`] for the cache at `cache_dir`, or the one the
+/// `.vibecheck` config (from `overrides`, or discovered from the current
+/// directory) and environment resolve to.
+///
+/// A cache directory that does not exist yet is reported as empty rather
+/// than created.
+pub fn stats(
+    cache_dir: Option<&Path>,
+    overrides: &ConfigOverrides,
+    format: &str,
+    output: Option<&Path>,
+) -> Result<()> {
+    let config = overrides.load_config(&std::env::current_dir()?);
+    let dir = Cache::resolve_path(cache_dir.or(config.cache_dir()));
+    let stats = read_stats(&dir)?;
+
+    let mut out = crate::output::open_output(output)?;
+    if format == "json" {
+        writeln!(out, "{}", serde_json::to_string_pretty(&stats_json(&dir, &stats, SystemTime::now()))?)?;
+    } else {
+        write!(out, "{}", format_stats(&dir, &stats, SystemTime::now()))?;
+    }
+    out.finish()
+}
+
+/// Stats of the cache at `dir`, or empty stats when none has been created
+/// there yet.
+pub(crate) fn read_stats(dir: &Path) -> Result<CacheStats> {
+    if !dir.join("cache.redb").exists() {
+        return Ok(CacheStats::default());
+    }
+    let cache = Cache::open(dir)
+        .map_err(|e| anyhow::anyhow!("{e}"))
+        .with_context(|| format!("cannot open cache at {}", dir.display()))?;
+    cache.stats().context("cannot read cache stats")
+}
+
+fn age_secs(oldest: Option<SystemTime>, now: SystemTime) -> Option<u64> {
+    oldest.map(|t| now.duration_since(t).unwrap_or(Duration::ZERO).as_secs())
+}
+
+fn stats_json(dir: &Path, stats: &CacheStats, now: SystemTime) -> serde_json::Value {
+    json!({
+        "path": dir,
+        "entries": stats.entries,
+        "size_on_disk": stats.size_on_disk,
+        "oldest_entry_age_secs": age_secs(stats.oldest_entry, now),
+        "hits": stats.lifetime_hits,
+        "misses": stats.lifetime_misses,
+    })
+}
+
+fn format_stats(dir: &Path, stats: &CacheStats, now: SystemTime) -> String {
+    let lookups = stats.lifetime_hits + stats.lifetime_misses;
+    let hit_rate = if lookups > 0 {
+        format!(" ({:.1}% hit rate)", stats.lifetime_hits as f64 / lookups as f64 * 100.0)
+    } else {
+        String::new()
+    };
+    format!(
+        "Cache:        {}\n\
+         Entries:      {}\n\
+         Size on disk: {}\n\
+         Oldest entry: {}\n\
+         Lookups:      {} hits, {} misses{hit_rate}\n",
+        dir.display(),
+        stats.entries,
+        stats.size_on_disk.map_or_else(|| "-".into(), format_size),
+        age_secs(stats.oldest_entry, now).map_or_else(|| "-".into(), |s| format!("{} ago", format_age(s))),
+        stats.lifetime_hits,
+        stats.lifetime_misses,
+    )
+}
+
+/// `512 B`, `4.2 KiB`, `13.0 MiB`, …
+pub(crate) fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+/// Largest whole unit of an age: `45s`, `12m`, `5h`, `3d`.
+fn format_age(secs: u64) -> String {
+    match secs {
+        s if s < 60 => format!("{s}s"),
+        s if s < 3600 => format!("{}m", s / 60),
+        s if s < 86_400 => format!("{}h", s / 3600),
+        s => format!("{}d", s / 86_400),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sizes_and_ages_use_the_largest_unit() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(4300), "4.2 KiB");
+        assert_eq!(format_size(13 * 1024 * 1024), "13.0 MiB");
+        assert_eq!(format_age(45), "45s");
+        assert_eq!(format_age(3 * 86_400 + 5), "3d");
+    }
+
+    #[test]
+    fn format_stats_lists_every_field() {
+        let now = SystemTime::now();
+        let stats = CacheStats {
+            entries: 12,
+            size_on_disk: Some(2048),
+            oldest_entry: Some(now - Duration::from_secs(7200)),
+            lifetime_hits: 3,
+            lifetime_misses: 1,
+            ..CacheStats::default()
+        };
+        let text = format_stats(Path::new("/tmp/vc"), &stats, now);
+        assert!(text.contains("Entries:      12\n"), "{text}");
+        assert!(text.contains("2.0 KiB"), "{text}");
+        assert!(text.contains("2h ago"), "{text}");
+        assert!(text.contains("3 hits, 1 misses (75.0% hit rate)"), "{text}");
+
+        let empty = format_stats(Path::new("/tmp/vc"), &CacheStats::default(), now);
+        assert!(empty.contains("Oldest entry: -\n") && !empty.contains("hit rate"), "{empty}");
+        assert_eq!(stats_json(Path::new("/tmp/vc"), &stats, now)["oldest_entry_age_secs"], 7200);
+    }
+}
@@ -0,0 +1,127 @@
+//! `vibecheck doctor`: explain which config, cache, and repository a run
+//! from a given directory would use, and what in the `.vibecheck` file is
+//! being silently ignored.
+
+use std::fmt::Write as _;
+use std::path::Path;
+
+use anyhow::Result;
+
+use vibecheck_core::cache::Cache;
+use vibecheck_core::ignore_rules::{config_problems, find_config_file, find_git_root, IgnoreConfig};
+use vibecheck_core::ConfigOverrides;
+
+use super::cache::{format_size, read_stats};
+
+/// Print the diagnosis for a run against `path`, with `overrides`
+/// (`--config`, `--profile`) and `cache_dir` (`--cache-dir`) as a real run
+/// would get them.
+///
+/// Problems are reported, not returned as errors: the command succeeds as
+/// long as it could look.
+pub fn run(
+    path: &Path,
+    overrides: &ConfigOverrides,
+    cache_dir: Option<&Path>,
+) -> Result<()> {
+    let path = std::path::absolute(path)?;
+    print!("{}", diagnose(&path, overrides, cache_dir));
+    Ok(())
+}
+
+fn diagnose(
+    path: &Path,
+    overrides: &ConfigOverrides,
+    cache_dir: Option<&Path>,
+) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "vibecheck {}", env!("CARGO_PKG_VERSION"));
+
+    let (file, origin) = match &overrides.config_file {
+        Some(f) => (Some(f.clone()), "from --config"),
+        None => (find_config_file(path), "discovered"),
+    };
+    match &file {
+        Some(f) => {
+            let _ = writeln!(out, "Config:       {} ({origin})", f.display());
+            let problems = match std::fs::read_to_string(f) {
+                Ok(text) => config_problems(&text),
+                Err(e) => vec![format!("cannot read: {e}")],
+            };
+            if problems.is_empty() {
+                let _ = writeln!(out, "              parsed cleanly");
+            }
+            for problem in problems {
+                let _ = writeln!(out, "  problem:    {problem}");
+            }
+        }
+        None => {
+            let _ = writeln!(out, "Config:       none found; using defaults");
+        }
+    }
+
+    let config = overrides.apply(match &overrides.config_file {
+        Some(f) => IgnoreConfig::from_file(f).unwrap_or_else(|_| IgnoreConfig::load(path)),
+        None => IgnoreConfig::load(path),
+    });
+    let _ = writeln!(out, "Profile:      {}", config.profile().unwrap_or("default"));
+
+    match find_git_root(path) {
+        Some(root) => {
+            let _ = writeln!(out, "Git repo:     {}", root.display());
+        }
+        None => {
+            let _ = writeln!(out, "Git repo:     not detected (history and --staged are unavailable)");
+        }
+    }
+
+    let dir = Cache::resolve_path(cache_dir.or(config.cache_dir()));
+    let cache = match read_stats(&dir) {
+        Ok(stats) if stats.size_on_disk.is_none() => "not created yet".to_string(),
+        Ok(stats) => format!(
+            "{} entries, {}",
+            stats.entries,
+            stats.size_on_disk.map_or_else(|| "-".into(), format_size)
+        ),
+        Err(e) => format!("unreadable: {e:#}"),
+    };
+    let _ = writeln!(out, "Cache:        {} ({cache})", dir.display());
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_config_problems_git_root_and_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join(".git")).unwrap();
+        std::fs::write(
+            dir.path().join(".vibecheck"),
+            "profile = \"strict\"\n[heuristics]\n\"rust.made_up\" = 2.0\n",
+        )
+        .unwrap();
+        let cache = dir.path().join("cache");
+
+        let text = diagnose(dir.path(), &Default::default(), Some(&cache));
+        assert!(text.contains(&format!("Config:       {} (discovered)", dir.path().join(".vibecheck").display())), "{text}");
+        assert!(text.contains("unknown signal ID `rust.made_up`"), "{text}");
+        assert!(text.contains("Profile:      strict"), "{text}");
+        assert!(text.contains(&format!("Git repo:     {}\n", dir.path().display())), "{text}");
+        assert!(text.contains("(not created yet)"), "{text}");
+
+        std::fs::write(dir.path().join(".vibecheck"), "profile = \"lenient\"\n").unwrap();
+        assert!(diagnose(dir.path(), &Default::default(), Some(&cache)).contains("parsed cleanly"));
+    }
+
+    #[test]
+    fn explicit_config_is_checked_even_when_unreadable() {
+        let dir = tempfile::tempdir().unwrap();
+        let missing = dir.path().join("missing.toml");
+        let overrides = ConfigOverrides { config_file: Some(missing), ..Default::default() };
+        let text = diagnose(dir.path(), &overrides, Some(dir.path()));
+        assert!(text.contains("(from --config)"), "{text}");
+        assert!(text.contains("problem:    cannot read"), "{text}");
+    }
+}
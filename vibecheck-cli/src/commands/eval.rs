@@ -0,0 +1,261 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde_json::json;
+
+use vibecheck_core::eval::Evaluation;
+use vibecheck_core::report::{ModelFamily, Report};
+use vibecheck_core::{ConfigOverrides, ExtensionFilter};
+
+use crate::commands::analyze::collect_files;
+
+/// Analyze every labeled file under `corpus` and print per-family
+/// precision, recall, and F1 plus a confusion matrix, as a table or
+/// (`format == "json"`) a JSON object.
+///
+/// A file's true family is its name (`claude.rs`) or, failing that, the
+/// nearest enclosing directory named after a family (`gpt/lru.py`).
+/// Files without a label are skipped and counted.
+///
+/// With `record`, each labeled file is also added to the corpus store at
+/// that path under its true family, with the signals that fired in it.
+pub fn run(
+    corpus: &PathBuf,
+    no_cache: bool,
+    cache_dir: Option<&Path>,
+    overrides: &ConfigOverrides,
+    format: &str,
+    output: Option<&Path>,
+    record: Option<&Path>,
+) -> Result<()> {
+    let store = record.map(open_store).transpose()?;
+    let ignore = overrides.load_config(corpus);
+    let files = collect_files(corpus, &ignore, true, &ExtensionFilter::default())
+        .context("failed to collect files")?;
+
+    let mut eval = Evaluation::new();
+    let mut unlabeled = 0;
+    for file in &files {
+        let Some(truth) = label(file, corpus) else {
+            unlabeled += 1;
+            continue;
+        };
+        let report = if no_cache {
+            vibecheck_core::analyze_file_no_cache_with_overrides(file, overrides)
+        } else {
+            vibecheck_core::analyze_file_with_cache_dir(file, cache_dir, overrides)
+        }
+        .with_context(|| format!("failed to analyze {}", file.display()))?;
+        if let Some(store) = &store {
+            record_labeled(store, file, &report, truth)?;
+        }
+        eval.record(truth, report.attribution.primary);
+    }
+    if eval.total() == 0 {
+        anyhow::bail!(
+            "no labeled source files found in {} (name files or directories after a family, e.g. claude.rs or human/)",
+            corpus.display()
+        );
+    }
+
+    let mut out = crate::output::open_output(output)?;
+    if format == "json" {
+        writeln!(out, "{}", serde_json::to_string_pretty(&eval_json(&eval, unlabeled))?)?;
+    } else {
+        write!(out, "{}", format_eval(&eval, unlabeled))?;
+    }
+    out.finish()
+}
+
+#[cfg(feature = "corpus")]
+use vibecheck_core::store::Store;
+
+/// Stand-in for the corpus store, which needs the `corpus` feature.
+#[cfg(not(feature = "corpus"))]
+enum Store {}
+
+#[cfg(feature = "corpus")]
+fn open_store(path: &Path) -> Result<Store> {
+    Store::open(path).with_context(|| format!("cannot open corpus store {}", path.display()))
+}
+
+#[cfg(not(feature = "corpus"))]
+fn open_store(path: &Path) -> Result<Store> {
+    anyhow::bail!(
+        "cannot record to {}: vibecheck was built without the `corpus` feature \
+         (reinstall with `cargo install vibecheck-cli --features corpus`)",
+        path.display()
+    )
+}
+
+/// Add `file`, whose true family is `truth`, to the corpus in `store`.
+#[cfg(feature = "corpus")]
+fn record_labeled(store: &Store, file: &Path, report: &Report, truth: ModelFamily) -> Result<()> {
+    let content = std::fs::read(file).with_context(|| format!("cannot read {}", file.display()))?;
+    store
+        .insert_labeled(&content, report, truth)
+        .with_context(|| format!("cannot record {} in the corpus store", file.display()))
+}
+
+#[cfg(not(feature = "corpus"))]
+fn record_labeled(store: &Store, _file: &Path, _report: &Report, _truth: ModelFamily) -> Result<()> {
+    match *store {}
+}
+
+/// The true family of `file` in the corpus rooted at `root`: the file stem
+/// if it names a family, else the nearest ancestor directory (below `root`,
+/// or `root` itself) that does.
+fn label(file: &Path, root: &Path) -> Option<ModelFamily> {
+    let stem = file.file_stem().and_then(|s| s.to_str());
+    let dirs = file
+        .ancestors()
+        .skip(1)
+        .take_while(|d| d.starts_with(root))
+        .filter_map(|d| d.file_name().and_then(|n| n.to_str()));
+    stem.into_iter().chain(dirs).find_map(ModelFamily::from_name)
+}
+
+fn eval_json(eval: &Evaluation, unlabeled: usize) -> serde_json::Value {
+    let families = eval.families();
+    let scores: Vec<_> = eval
+        .scores()
+        .iter()
+        .map(|s| {
+            json!({
+                "family": s.family,
+                "precision": s.precision,
+                "recall": s.recall,
+                "f1": s.f1,
+                "support": s.support,
+            })
+        })
+        .collect();
+    let confusion: serde_json::Map<_, _> = families
+        .iter()
+        .map(|&truth| {
+            let row: serde_json::Map<_, _> =
+                families.iter().map(|&p| (p.name().to_string(), json!(eval.count(truth, p)))).collect();
+            (truth.name().to_string(), row.into())
+        })
+        .collect();
+    json!({
+        "files": eval.total(),
+        "unlabeled": unlabeled,
+        "accuracy": eval.accuracy(),
+        "weighted_f1": eval.weighted_f1(),
+        "families": scores,
+        "confusion": confusion,
+    })
+}
+
+fn format_eval(eval: &Evaluation, unlabeled: usize) -> String {
+    let mut out = format!("{:<8}  {:>9}  {:>6}  {:>6}  {:>7}\n", "FAMILY", "PRECISION", "RECALL", "F1", "SUPPORT");
+    for s in eval.scores() {
+        out.push_str(&format!(
+            "{:<8}  {:>9.2}  {:>6.2}  {:>6.2}  {:>7}\n",
+            s.family.to_string(),
+            s.precision,
+            s.recall,
+            s.f1,
+            s.support,
+        ));
+    }
+
+    let families = eval.families();
+    out.push_str("\nCONFUSION (rows: true family, columns: predicted)\n");
+    out.push_str(&format!("{:<8}", ""));
+    for p in &families {
+        out.push_str(&format!("  {:>7}", p.to_string()));
+    }
+    out.push('\n');
+    for &truth in &families {
+        out.push_str(&format!("{:<8}", truth.to_string()));
+        for &p in &families {
+            out.push_str(&format!("  {:>7}", eval.count(truth, p)));
+        }
+        out.push('\n');
+    }
+
+    out.push_str(&format!(
+        "\nweighted F1 {:.3}, accuracy {:.1}% over {} files",
+        eval.weighted_f1(),
+        eval.accuracy() * 100.0,
+        eval.total()
+    ));
+    if unlabeled > 0 {
+        out.push_str(&format!(" ({unlabeled} unlabeled skipped)"));
+    }
+    out.push('\n');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labels_come_from_file_name_then_nearest_directory() {
+        let root = Path::new("/corpus");
+        assert_eq!(label(Path::new("/corpus/lru/claude.rs"), root), Some(ModelFamily::Claude));
+        assert_eq!(label(Path::new("/corpus/human/gpt/lru.py"), root), Some(ModelFamily::Gpt));
+        assert_eq!(label(Path::new("/corpus/human/src/lru.py"), root), Some(ModelFamily::Human));
+        assert_eq!(label(Path::new("/corpus/misc/lru.py"), root), None);
+        assert_eq!(label(Path::new("/gemini/corpus/lru.py"), root), None, "directories above the corpus");
+        assert_eq!(label(Path::new("/corpus/lru.py"), Path::new("/corpus")), None);
+    }
+
+    #[test]
+    fn table_and_json_cover_scores_and_confusion() {
+        let mut eval = Evaluation::new();
+        eval.record(ModelFamily::Claude, ModelFamily::Claude);
+        eval.record(ModelFamily::Human, ModelFamily::Claude);
+        let text = format_eval(&eval, 2);
+        assert!(text.contains("Claude         0.50    1.00    0.67        1\n"), "{text}");
+        assert!(text.contains("Human           1        0\n"), "{text}");
+        assert!(text.contains("accuracy 50.0% over 2 files (2 unlabeled skipped)"), "{text}");
+
+        let json = eval_json(&eval, 2);
+        assert_eq!(json["confusion"]["human"]["claude"], 1);
+        assert_eq!(json["families"][1]["support"], 1);
+        assert_eq!(json["unlabeled"], 2);
+    }
+
+    #[test]
+    fn evaluates_the_fixture_corpus() {
+        let corpus = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../vibecheck-core/tests/fixtures");
+        let empty = tempfile::tempdir().unwrap();
+        let report = empty.path().join("artifacts/eval.json");
+        run(&corpus, true, None, &Default::default(), "json", Some(&report), None).unwrap();
+        let text = std::fs::read_to_string(&report).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert!(json["accuracy"].is_number(), "{json}");
+        std::fs::remove_dir_all(empty.path().join("artifacts")).unwrap();
+        let err = run(&empty.path().to_path_buf(), true, None, &Default::default(), "table", None, None).unwrap_err();
+        assert!(err.to_string().contains("no labeled source files"), "{err}");
+    }
+
+    #[cfg(feature = "corpus")]
+    #[test]
+    fn record_builds_a_corpus_store() {
+        let corpus = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../vibecheck-core/tests/fixtures");
+        let dir = tempfile::tempdir().unwrap();
+        let db = dir.path().join("corpus.db");
+        let report = dir.path().join("eval.json");
+        run(&corpus, true, None, &Default::default(), "json", Some(&report), Some(&db)).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&report).unwrap()).unwrap();
+
+        let counts = Store::open(&db).unwrap().signal_family_counts().unwrap();
+        assert_eq!(counts.families.values().sum::<usize>() as u64, json["files"].as_u64().unwrap());
+        assert!(!counts.signals.is_empty());
+    }
+
+    #[cfg(not(feature = "corpus"))]
+    #[test]
+    fn record_needs_the_feature() {
+        let corpus = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../vibecheck-core/tests/fixtures");
+        let db = Path::new("corpus.db");
+        let err = run(&corpus, true, None, &Default::default(), "table", None, Some(db)).unwrap_err();
+        assert!(err.to_string().contains("--features corpus"), "{err}");
+    }
+}
@@ -0,0 +1,17 @@
+use std::collections::HashMap;
+
+// Word counting:
+// - splits on whitespace
+// - lowercases each word
+// - ignores empty tokens
+pub fn word_counts(text: &str) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for word in text.split_whitespace() {
+        let key = word.to_lowercase();
+        if key.is_empty() {
+            continue;
+        }
+        *counts.entry(key).or_insert(0) += 1;
+    }
+    counts
+}
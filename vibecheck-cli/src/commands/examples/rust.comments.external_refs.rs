@@ -0,0 +1,8 @@
+use std::collections::HashMap;
+
+// ask @dmiller before touching cfg parsing, still flaky on windows
+// workaround for #418 until upstream ships a fix
+#[allow(dead_code)]
+struct Cfg {
+    m: HashMap<String, String>,
+}
@@ -0,0 +1,17 @@
+
+pub fn process_items(config: &Config) -> Vec<String> {
+    // Step 1: Create a vector to hold the result.
+    let mut result: Vec<String> = Vec::new();
+
+    // Step 2: Iterate over each item in the config.
+    for item in config.items.iter() {
+        // Step 3: Here's where we build the output string.
+        let output: String = format!("{}={}", item.name, item.value);
+
+        // Step 4: Let's add the output to the result.
+        result.push(output);
+    }
+
+    // Step 5: Let's return the result.
+    result
+}
@@ -0,0 +1,10 @@
+
+fn get(c: &Cfg, k: &str) -> String {
+    // HACK: callers should check first, fix later
+    // TODO: this is dumb, use Option
+    if !c.m.contains_key(k) {
+        panic!("missing key {}", k);
+    }
+    let v = c.m.get(k).unwrap();
+    v.to_string() + ""
+}
@@ -0,0 +1,26 @@
+
+/// Parse a reading such as `21.5C`, returning a descriptive error when
+/// the input is malformed.
+pub fn parse_temperature_reading(raw_reading: &str) -> Result<Temperature, String> {
+    // This is important: we trim first, because sensors often pad readings.
+    let trimmed_reading = raw_reading.trim();
+    let numeric_portion = match trimmed_reading.strip_suffix('C') {
+        Some(numeric_portion) => numeric_portion,
+        None => return Err(format!("Failed to find the Celsius suffix in {trimmed_reading}")),
+    };
+    let degrees_celsius: f64 = match numeric_portion.parse() {
+        Ok(degrees_celsius) => degrees_celsius,
+        Err(error) => return Err(format!("Unable to parse the reading {numeric_portion}: {error}")),
+    };
+    let is_plausible = degrees_celsius > -273.15;
+    if !is_plausible {
+        return Err(format!("Failed to accept a reading below absolute zero: {degrees_celsius}"));
+    }
+    Ok(Temperature::from(degrees_celsius))
+}
+
+/// Parse every reading, stopping at the first malformed one.
+pub fn parse_all_temperature_readings(raw_readings: &[&str]) -> Result<Vec<Temperature>, String> {
+    // Notice how collecting into a Result short-circuits on the first error.
+    raw_readings.iter().map(|raw_reading| parse_temperature_reading(raw_reading)).collect()
+}
@@ -0,0 +1,15 @@
+
+#[allow(unused)]
+fn load(p: &str) -> Cfg {
+    let s = std::fs::read_to_string(p).unwrap();
+    let mut m = HashMap::new();
+    for l in s.lines() {
+        let mut it = l.splitn(2, '=');
+        let k = it.next().unwrap();
+        let v = it.next().unwrap();
+        m.insert(k.trim().to_string(), v.trim().to_string());
+    }
+    // let dbg = m.len();
+    // println!("loaded {} keys", dbg);
+    Cfg { m }
+}
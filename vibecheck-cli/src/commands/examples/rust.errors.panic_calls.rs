@@ -0,0 +1,11 @@
+
+fn port(c: &Cfg) -> u16 {
+    let s = get(c, "port");
+    let n: u16 = s.parse().unwrap();
+    if n == 0 {
+        panic!("port 0??");
+    }
+    let _x = n;
+    let _y = "unused";
+    n
+}
@@ -0,0 +1,19 @@
+/// A temperature reading converted between scales on demand.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Temperature {
+    degrees_celsius: f64,
+}
+
+impl From<f64> for Temperature {
+    /// Interpret a bare number as degrees Celsius.
+    fn from(degrees_celsius: f64) -> Self {
+        Self { degrees_celsius }
+    }
+}
+
+impl From<Temperature> for f64 {
+    /// Extract the reading in degrees Celsius.
+    fn from(temperature: Temperature) -> Self {
+        temperature.degrees_celsius
+    }
+}
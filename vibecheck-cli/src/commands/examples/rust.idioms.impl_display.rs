@@ -0,0 +1,7 @@
+
+impl std::fmt::Display for Temperature {
+    /// Render the reading with one decimal place, e.g. `21.5°C`.
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(formatter, "{:.1}°C", self.degrees_celsius)
+    }
+}
@@ -0,0 +1,25 @@
+
+/// Return the average of every reading above freezing, or `None` when
+/// there are none.
+pub fn average_above_freezing(readings: &[Temperature]) -> Option<f64> {
+    // Note that we filter before counting so the average only covers the
+    // readings that actually contributed to the sum.
+    let positive_readings: Vec<f64> = readings
+        .iter()
+        .map(|reading| reading.degrees_celsius)
+        .filter(|degrees| *degrees > 0.0)
+        .collect();
+    let reading_count = positive_readings.iter().count();
+    let total_degrees: f64 = positive_readings.iter().copied().sum();
+    let has_readings = reading_count > 0;
+    has_readings.then(|| total_degrees / reading_count as f64)
+}
+
+/// Return the name of every sensor whose latest reading is above `limit`.
+pub fn sensors_exceeding_limit(sensor_readings: &[(String, Temperature)], limit: f64) -> Vec<String> {
+    sensor_readings
+        .iter()
+        .filter(|(_, reading)| reading.degrees_celsius > limit)
+        .map(|(sensor_name, _)| sensor_name.clone())
+        .collect()
+}
@@ -0,0 +1,12 @@
+
+pub trait Processor {
+    fn process(&self, input: &str) -> String;
+}
+
+pub trait Validator {
+    fn validate(&self, input: &str) -> bool;
+}
+
+pub trait Formatter {
+    fn format(&self, input: &str) -> String;
+}
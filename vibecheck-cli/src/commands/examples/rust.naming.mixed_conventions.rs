@@ -0,0 +1,22 @@
+pub struct Cart {
+    pub items: Vec<(String, u32)>,
+}
+
+impl Cart {
+    pub fn new() -> Cart {
+        Cart { items: Vec::new() }
+    }
+
+    pub fn addItem(&mut self, itemName: &str, price: u32) {
+        let item_name = itemName.to_string();
+        self.items.push((item_name, price));
+    }
+
+    pub fn total(&self) -> u32 {
+        let mut totalPrice = 0;
+        for item in &self.items {
+            let unit_price = item.1;
+            totalPrice += unit_price;
+        }
+        totalPrice
+    }
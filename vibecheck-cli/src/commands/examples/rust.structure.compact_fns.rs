@@ -0,0 +1,23 @@
+
+pub fn longest_word(text: &str) -> Option<&str> {
+    let mut longest = None;
+    let mut size = 0;
+    for word in text.split_whitespace() {
+        if word.len() > size {
+            size = word.len();
+            longest = Some(word);
+        }
+    }
+    longest
+}
+
+pub fn unique_words(text: &str) -> usize {
+    let counts = word_counts(text);
+    let mut total = 0;
+    for (_, n) in counts.iter() {
+        if *n == 1 {
+            total += 1;
+        }
+    }
+    total
+}
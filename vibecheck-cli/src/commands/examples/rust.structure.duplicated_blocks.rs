@@ -0,0 +1,31 @@
+
+    pub fn average(&self) -> u32 {
+        let mut sum = 0;
+        let mut n = 0;
+        for item in &self.items {
+            sum += item.1;
+            n += 1;
+        }
+        sum / n
+    }
+
+    pub fn summary(&self) -> String {
+        let mut sum = 0;
+        let mut n = 0;
+        for item in &self.items {
+            sum += item.1;
+            n += 1;
+        }
+        format!("{} items, {} total", n, sum)
+    }
+
+    pub fn report(&self) -> String {
+        let mut sum = 0;
+        let mut n = 0;
+        for item in &self.items {
+            sum += item.1;
+            n += 1;
+        }
+        format!("avg {}", sum / n)
+    }
+}
@@ -0,0 +1,21 @@
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Item {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    pub items: Vec<Item>,
+    pub data: HashMap<String, String>,
+    pub mode: Mode,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Mode {
+    #[default]
+    Fast,
+    Safe,
+}
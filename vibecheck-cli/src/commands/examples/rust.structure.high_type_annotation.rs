@@ -0,0 +1,17 @@
+
+pub fn build_data(config: &Config) -> HashMap<String, String> {
+    // Create a new HashMap to store the data.
+    let mut data: HashMap<String, String> = HashMap::new();
+    let count: usize = config.items.len();
+    let prefix: String = String::from("item");
+
+    // Loop through the items and insert them into the map.
+    for index in 0..count {
+        let key: String = format!("{}_{}", prefix, index);
+        let value: String = config.items[index].value.clone();
+        data.insert(key, value);
+    }
+
+    // Return the data.
+    data
+}
@@ -0,0 +1,23 @@
+
+pub fn label_count(count: usize) -> &'static str {
+    match count {
+        0 => "none",
+        1 => "one",
+        2..=9 => "some",
+        10..=99 => "many",
+        _ => "lots",
+    }
+}
+
+pub fn top_word(counts: &HashMap<String, usize>) -> Option<String> {
+    let mut best = None;
+    let mut best_n = 0;
+    for (word, n) in counts {
+        let better = if *n > best_n { true } else { false };
+        if better {
+            best = Some(word.clone());
+            best_n = *n;
+        }
+    }
+    best
+}
@@ -0,0 +1,16 @@
+
+    pub fn count(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+
+    pub fn first(&self) -> String {
+        self.items.first().unwrap().0.clone()
+    }
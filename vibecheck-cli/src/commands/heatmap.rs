@@ -0,0 +1,43 @@
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use vibecheck_core::colors::DefaultTheme;
+use vibecheck_core::ConfigOverrides;
+
+use crate::output;
+
+/// Attribute each line of `path` over windows of `window` lines and print
+/// the source with a per-line family gutter (`pretty`, colored, or `text`),
+/// or (`format == "json"`) a `[{line, family, score}]` array.
+pub fn run(path: &Path, window: usize, format: &str, overrides: &ConfigOverrides) -> Result<()> {
+    if window == 0 {
+        bail!("--window must be at least 1");
+    }
+    let lines = vibecheck_core::analyze_file_heatmap(path, window, overrides)
+        .with_context(|| format!("failed to analyze {}", path.display()))?;
+    match format {
+        "json" => println!("{}", serde_json::to_string_pretty(&lines)?),
+        "pretty" | "text" => {
+            let source = std::fs::read_to_string(path)?;
+            let theme = (format == "pretty").then_some(&DefaultTheme as _);
+            print!("{}", output::format_heatmap(&source, &lines, theme));
+        }
+        other => bail!("unknown format: {other} (expected pretty, text, or json)"),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_empty_windows_and_unknown_formats() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("lib.rs");
+        std::fs::write(&file, "fn main() {}\n").unwrap();
+        assert!(run(&file, 0, "json", &Default::default()).unwrap_err().to_string().contains("--window"));
+        assert!(run(&file, 20, "csv", &Default::default()).unwrap_err().to_string().contains("unknown format: csv"));
+        run(&file, 20, "text", &Default::default()).unwrap();
+    }
+}
@@ -1,5 +1,15 @@
-use anyhow::Result;
-use vibecheck_core::heuristics::{all_heuristics, signal_ids};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use serde_json::json;
+use vibecheck_core::heuristics::{all_heuristics, signal_ids, PROFILES};
+use vibecheck_core::ignore_rules::IgnoreConfig;
+use vibecheck_core::pipeline::Pipeline;
+use vibecheck_core::report::{ModelFamily, Report};
+use vibecheck_core::{ConfigOverrides, ExtensionFilter};
+
+use crate::commands::analyze::collect_files;
 
 pub fn run(format: &str) -> Result<()> {
     match format {
@@ -9,6 +19,16 @@ pub fn run(format: &str) -> Result<()> {
     Ok(())
 }
 
+/// Clap value parser for `--profile`: accept only built-in profile names.
+pub fn parse_profile(s: &str) -> std::result::Result<String, String> {
+    if PROFILES.iter().any(|p| p.name == s) {
+        Ok(s.to_string())
+    } else {
+        let names: Vec<_> = PROFILES.iter().map(|p| p.name).collect();
+        Err(format!("unknown profile `{s}` (expected one of: {})", names.join(", ")))
+    }
+}
+
 fn print_table() {
     // Group by language then analyzer
     let col_widths = (8usize, 10usize, 38usize, 6usize, 7usize);
@@ -58,6 +78,10 @@ fn print_table() {
 }
 
 fn print_toml() {
+    let names: Vec<_> = PROFILES.iter().map(|p| p.name).collect();
+    println!("# Weight profile ({}); must precede any [section].", names.join(", "));
+    println!("# profile = \"balanced\"");
+    println!();
     println!("[heuristics]");
     println!("# Adjust signal weights (0.0 = disabled).");
     println!("# Uncomment and edit lines to override defaults.");
@@ -83,6 +107,287 @@ fn print_toml() {
     }
 }
 
+/// The signal catalogue as a JSON array of
+/// `{"id", "language", "analyzer", "family", "weight", "description"}`
+/// objects, in catalogue order.
+pub fn catalogue() -> serde_json::Value {
+    all_heuristics()
+        .iter()
+        .map(|h| {
+            serde_json::json!({
+                "id": h.id,
+                "language": h.language.to_string(),
+                "analyzer": h.analyzer,
+                "family": h.family,
+                "weight": h.default_weight,
+                "description": h.description,
+            })
+        })
+        .collect()
+}
+
+/// A Rust fragment written to trip one signal, for `--examples`.
+struct Example {
+    signal: &'static str,
+    source: &'static str,
+}
+
+/// Fragments that the example file for a family is assembled from, grouped
+/// by family in catalogue order; each one's source is `examples/<signal>.rs`.
+/// Whole-file signals (`zero_unwrap`, `no_todo`, comment density) need no
+/// fragment of their own: they follow from the rest of the file.
+const EXAMPLES: &[Example] = &[
+    // ── Claude ──
+    Example {
+        signal: "rust.idioms.from_into_impls",
+        source: include_str!("examples/rust.idioms.from_into_impls.rs"),
+    },
+    Example {
+        signal: "rust.idioms.impl_display",
+        source: include_str!("examples/rust.idioms.impl_display.rs"),
+    },
+    Example {
+        signal: "rust.idioms.iterator_chains",
+        source: include_str!("examples/rust.idioms.iterator_chains.rs"),
+    },
+    Example {
+        signal: "rust.errors.ai_phrasing",
+        source: include_str!("examples/rust.errors.ai_phrasing.rs"),
+    },
+    // ── GPT ──
+    Example {
+        signal: "rust.structure.heavy_derive",
+        source: include_str!("examples/rust.structure.heavy_derive.rs"),
+    },
+    Example {
+        signal: "rust.idioms.many_traits",
+        source: include_str!("examples/rust.idioms.many_traits.rs"),
+    },
+    Example {
+        signal: "rust.comments.step_numbered",
+        source: include_str!("examples/rust.comments.step_numbered.rs"),
+    },
+    Example {
+        signal: "rust.structure.high_type_annotation",
+        source: include_str!("examples/rust.structure.high_type_annotation.rs"),
+    },
+    // ── Gemini ──
+    Example {
+        signal: "rust.comments.bullet_style",
+        source: include_str!("examples/rust.comments.bullet_style.rs"),
+    },
+    Example {
+        signal: "rust.structure.ternary_heavy",
+        source: include_str!("examples/rust.structure.ternary_heavy.rs"),
+    },
+    Example {
+        signal: "rust.structure.compact_fns",
+        source: include_str!("examples/rust.structure.compact_fns.rs"),
+    },
+    // ── Copilot ──
+    Example {
+        signal: "rust.naming.mixed_conventions",
+        source: include_str!("examples/rust.naming.mixed_conventions.rs"),
+    },
+    Example {
+        signal: "rust.structure.very_short_fns",
+        source: include_str!("examples/rust.structure.very_short_fns.rs"),
+    },
+    Example {
+        signal: "rust.structure.duplicated_blocks",
+        source: include_str!("examples/rust.structure.duplicated_blocks.rs"),
+    },
+    // ── Human ──
+    Example {
+        signal: "rust.comments.external_refs",
+        source: include_str!("examples/rust.comments.external_refs.rs"),
+    },
+    Example {
+        signal: "rust.errors.many_unwraps",
+        source: include_str!("examples/rust.errors.many_unwraps.rs"),
+    },
+    Example {
+        signal: "rust.comments.terse_markers",
+        source: include_str!("examples/rust.comments.terse_markers.rs"),
+    },
+    Example {
+        signal: "rust.errors.panic_calls",
+        source: include_str!("examples/rust.errors.panic_calls.rs"),
+    },
+];
+
+/// Assemble the example Rust file for `family` from [`EXAMPLES`], along
+/// with the signals its fragments target.  `None` for a family without
+/// fragments (custom families).
+fn example_for(family: ModelFamily) -> Option<(String, Vec<&'static str>)> {
+    let catalogue = all_heuristics();
+    let fragments: Vec<&Example> = EXAMPLES
+        .iter()
+        .filter(|e| catalogue.iter().any(|h| h.id == e.signal && h.family == family))
+        .collect();
+    if fragments.is_empty() {
+        return None;
+    }
+    let source = fragments.iter().map(|e| e.source).collect();
+    Some((source, fragments.iter().map(|e| e.signal).collect()))
+}
+
+/// Print the example file for `family`.  With `check`, analyze it with
+/// the default weights and report the verdict and which targeted signals
+/// fired.
+pub fn run_examples(family: &str, check: bool) -> Result<()> {
+    let Some(family) = ModelFamily::from_name(family) else {
+        bail!("unknown family: {family}");
+    };
+    let Some((source, targets)) = example_for(family) else {
+        bail!("no example fragments for {family}; examples cover the built-in families");
+    };
+    print!("{source}");
+    if check {
+        let report = Pipeline::with_defaults().run(&source, Some(PathBuf::from("example.rs")));
+        print!("{}", format_check(&report, &targets));
+    }
+    Ok(())
+}
+
+fn format_check(report: &Report, targets: &[&str]) -> String {
+    let attribution = &report.attribution;
+    let mut out = format!(
+        "\n// ── analysis ──\n// verdict: {} ({:.0}%)\n",
+        attribution.primary,
+        attribution.confidence * 100.0
+    );
+    for target in targets {
+        let mark = if report.signals.iter().any(|s| s.id == *target) { "fired " } else { "missed" };
+        out.push_str(&format!("// {mark}  {target}\n"));
+    }
+    out
+}
+
+/// One file analyzed under the current and the proposed config.
+#[derive(Debug)]
+struct Comparison {
+    path: PathBuf,
+    before: (ModelFamily, f64),
+    after: (ModelFamily, f64),
+}
+
+/// Analyze every supported file under `sample` under the current config
+/// (`overrides`, else the `.vibecheck` discovered for `sample`) and under
+/// `proposed`, then print which files' primary family would change and how
+/// many files each family gains or loses, as a table or (`format ==
+/// "json"`) a JSON object.
+///
+/// Files are picked with the current config's ignore rules, so both runs
+/// see the same set.  `overrides` (e.g. `--profile`) apply to the current
+/// config only, so the proposal is compared as written.
+pub fn run_diff(
+    proposed: &Path,
+    sample: &Path,
+    overrides: &ConfigOverrides,
+    format: &str,
+) -> Result<()> {
+    let comparisons = compare(proposed, sample, overrides)?;
+    if format == "json" {
+        println!("{}", serde_json::to_string_pretty(&diff_json(&comparisons))?);
+    } else {
+        print!("{}", format_diff(&comparisons));
+    }
+    Ok(())
+}
+
+fn compare(
+    proposed: &Path,
+    sample: &Path,
+    overrides: &ConfigOverrides,
+) -> Result<Vec<Comparison>> {
+    let before = overrides.load_config(sample);
+    let after = IgnoreConfig::from_file(proposed)?;
+    let files = collect_files(&sample.to_path_buf(), &before, true, &ExtensionFilter::default())
+        .context("failed to collect files")?;
+    if files.is_empty() {
+        bail!("no supported source files found in {}", sample.display());
+    }
+
+    let (before, after) = (vibecheck_core::pipeline_from_config(&before), vibecheck_core::pipeline_from_config(&after));
+    let mut comparisons = Vec::with_capacity(files.len());
+    for file in files {
+        let source = std::fs::read_to_string(&file).with_context(|| format!("failed to read {}", file.display()))?;
+        let verdict = |pipeline: &Pipeline| {
+            let a = pipeline.run(&source, Some(file.clone())).attribution;
+            (a.primary, a.confidence)
+        };
+        comparisons.push(Comparison { before: verdict(&before), after: verdict(&after), path: file });
+    }
+    Ok(comparisons)
+}
+
+/// Files attributed to each family before and after, in
+/// [`ModelFamily::all`] order then by name, for families with any.
+fn family_counts(comparisons: &[Comparison]) -> Vec<(ModelFamily, usize, usize)> {
+    let mut counts: BTreeMap<(usize, &str), (ModelFamily, usize, usize)> = BTreeMap::new();
+    for c in comparisons {
+        for (family, is_after) in [(c.before.0, false), (c.after.0, true)] {
+            let order = ModelFamily::all().iter().position(|f| *f == family).unwrap_or(usize::MAX);
+            let entry = counts.entry((order, family.name())).or_insert((family, 0, 0));
+            if is_after {
+                entry.2 += 1;
+            } else {
+                entry.1 += 1;
+            }
+        }
+    }
+    counts.into_values().collect()
+}
+
+fn changed(comparisons: &[Comparison]) -> impl Iterator<Item = &Comparison> {
+    comparisons.iter().filter(|c| c.before.0 != c.after.0)
+}
+
+fn format_diff(comparisons: &[Comparison]) -> String {
+    let mut out = String::new();
+    let changed: Vec<&Comparison> = changed(comparisons).collect();
+    if !changed.is_empty() {
+        out.push_str(&format!("{:<48}  {:<16}  {}\n", "FILE", "BEFORE", "AFTER"));
+        let verdict = |(family, confidence): (ModelFamily, f64)| format!("{family} ({:.0}%)", confidence * 100.0);
+        for c in &changed {
+            let before = verdict(c.before);
+            out.push_str(&format!("{:<48}  {before:<16}  {}\n", c.path.display(), verdict(c.after)));
+        }
+        out.push('\n');
+    }
+    for (family, before, after) in family_counts(comparisons) {
+        let delta = after as i64 - before as i64;
+        let delta = if delta == 0 { String::new() } else { format!("  ({delta:+})") };
+        let line = format!("{:<8}  {before:>4} → {after:<4}{delta}", family.to_string());
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+    out.push_str(&format!(
+        "\n{} of {} files change primary family\n",
+        changed.len(),
+        comparisons.len()
+    ));
+    out
+}
+
+fn diff_json(comparisons: &[Comparison]) -> serde_json::Value {
+    let changed: Vec<_> = changed(comparisons)
+        .map(|c| {
+            json!({
+                "path": c.path,
+                "before": { "family": c.before.0, "confidence": c.before.1 },
+                "after": { "family": c.after.0, "confidence": c.after.1 },
+            })
+        })
+        .collect();
+    let families: serde_json::Map<_, _> = family_counts(comparisons)
+        .into_iter()
+        .map(|(family, before, after)| (family.name().to_string(), json!({ "before": before, "after": after })))
+        .collect();
+    json!({ "files": comparisons.len(), "changed": changed, "families": families })
+}
+
 // Suppress dead_code lint — signal_ids is referenced at compile time for completeness checks.
 #[allow(dead_code)]
 const _ALL_IDS_REFERENCED: () = {
@@ -109,6 +414,69 @@ mod tests {
         run("anything").unwrap();
     }
 
+    #[test]
+    fn parse_profile_accepts_builtin_names_only() {
+        assert_eq!(parse_profile("strict"), Ok("strict".to_string()));
+        assert!(parse_profile("lenient").is_ok());
+        assert!(parse_profile("paranoid").unwrap_err().contains("strict, balanced, lenient"));
+    }
+
+    #[test]
+    fn catalogue_lists_every_signal() {
+        let catalogue = catalogue();
+        let entries = catalogue.as_array().unwrap();
+        assert_eq!(entries.len(), all_heuristics().len());
+        assert_eq!(entries[0]["id"], all_heuristics()[0].id);
+        assert!(entries[0]["family"].is_string());
+        assert!(entries[0]["weight"].is_number());
+    }
+
+    #[test]
+    fn examples_trip_their_signals_and_attribute_to_their_family() {
+        for &family in ModelFamily::all() {
+            let (source, targets) = example_for(family).unwrap();
+            let report = Pipeline::with_defaults().run(&source, Some(PathBuf::from("example.rs")));
+            assert_eq!(report.attribution.primary, family, "{family} example");
+            let check = format_check(&report, &targets);
+            assert!(!check.contains("missed"), "{family}: {check}");
+        }
+        assert!(EXAMPLES.iter().all(|e| all_heuristics().iter().any(|h| h.id == e.signal)));
+        assert!(run_examples("cobol", false).unwrap_err().to_string().contains("unknown family"));
+    }
+
+    #[test]
+    fn diff_reports_files_whose_primary_family_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let (source, _) = example_for(ModelFamily::Gpt).unwrap();
+        std::fs::write(dir.path().join("example.rs"), source).unwrap();
+        let zeroed: String = all_heuristics()
+            .iter()
+            .filter(|h| h.family == ModelFamily::Gpt)
+            .map(|h| format!("\"{}\" = 0.0\n", h.id))
+            .collect();
+        let proposed = dir.path().join("proposed.toml");
+        std::fs::write(&proposed, format!("[heuristics]\n{zeroed}")).unwrap();
+
+        let current = ConfigOverrides { config_file: Some(proposed.clone()), ..Default::default() };
+        let unchanged = compare(&proposed, dir.path(), &current).unwrap();
+        assert_eq!(changed(&unchanged).count(), 0);
+        assert!(format_diff(&unchanged).ends_with("\n0 of 1 files change primary family\n"));
+
+        let comparisons = compare(&proposed, dir.path(), &Default::default()).unwrap();
+        assert_eq!(comparisons[0].before.0, ModelFamily::Gpt);
+        assert_ne!(comparisons[0].after.0, ModelFamily::Gpt);
+        let table = format_diff(&comparisons);
+        assert!(table.contains("GPT          1 → 0     (-1)\n"), "{table}");
+        assert!(table.ends_with("\n1 of 1 files change primary family\n"), "{table}");
+        let json = diff_json(&comparisons);
+        assert_eq!((&json["families"]["gpt"]["before"], &json["families"]["gpt"]["after"]), (&1.into(), &0.into()));
+        assert_eq!(json["changed"][0]["before"]["family"], "gpt");
+
+        let empty = tempfile::tempdir().unwrap();
+        let err = compare(&proposed, empty.path(), &Default::default()).unwrap_err();
+        assert!(err.to_string().contains("no supported source files"), "{err}");
+    }
+
     #[test]
     fn all_heuristics_nonempty() {
         assert!(!all_heuristics().is_empty());
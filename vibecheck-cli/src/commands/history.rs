@@ -1,14 +1,145 @@
+use std::collections::HashMap;
 use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use anyhow::{Context, Result};
-use git2::{Repository, Sort};
+use git2::{Oid, Repository, Sort};
+use serde::Serialize;
 
-use vibecheck_core::report::ModelFamily;
+use vibecheck_core::ignore_rules::IgnoreRules;
+use vibecheck_core::report::{aggregate_attribution, AggregateAttribution, ModelFamily, Report};
+use vibecheck_core::{ConfigOverrides, ExtensionFilter};
 
 const DEFAULT_LIMIT: usize = 20;
 
-pub fn run(path: &Path, limit: Option<usize>) -> Result<()> {
+/// Half-life of the recency weighting behind the TREND column: a commit
+/// this much older than the row counts half as much.
+const TREND_HALF_LIFE_DAYS: f64 = 30.0;
+
+/// How far the recency-weighted AI share must move from the plain mean
+/// before a row reports a trend rather than `steady`.
+const TREND_THRESHOLD: f64 = 0.05;
+
+/// Which way attribution is drifting: whether recent commits look more or
+/// less AI-written than the history as a whole.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Trend {
+    TowardAi,
+    TowardHuman,
+    Steady,
+}
+
+impl Trend {
+    fn label(self) -> &'static str {
+        match self {
+            Trend::TowardAi => "↑ AI",
+            Trend::TowardHuman => "↓ human",
+            Trend::Steady => "steady",
+        }
+    }
+}
+
+/// One row of the attribution timeline, newest commit first.
+#[derive(Debug, Serialize)]
+pub struct HistoryEntry {
+    /// Full commit hash.
+    pub commit: String,
+    /// Commit date as `YYYY-MM-DD`.
+    pub date: String,
+    pub family: ModelFamily,
+    pub confidence: f64,
+    /// Change relative to the previous row, as shown in the CHANGE column.
+    pub change: String,
+    /// For a directory, how many source files the line-weighted aggregate
+    /// covered at this commit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files: Option<usize>,
+    /// Exponentially decayed mean of the AI share (see [`ai_share`]) over
+    /// this commit and every older row, weighting commits by age with a
+    /// [`TREND_HALF_LIFE_DAYS`] half-life.
+    pub recent_ai_share: f64,
+    /// `recent_ai_share` against the unweighted mean of the same rows.
+    pub trend: Trend,
+}
+
+pub fn run(
+    path: &Path,
+    limit: Option<usize>,
+    since: Option<Duration>,
+    overrides: &ConfigOverrides,
+    format: &str,
+) -> Result<()> {
+    let ignore = overrides.load_config(path);
+    let (label, entries) = collect(path, limit, since, &ignore, overrides)?;
+    match format {
+        "json" => println!("{}", serde_json::to_string_pretty(&entries)?),
+        _ => print_table(&label, &entries),
+    }
+    Ok(())
+}
+
+fn print_table(label: &str, entries: &[HistoryEntry]) {
+    print!("{}", format_table(label, entries));
+}
+
+fn format_table(label: &str, entries: &[HistoryEntry]) -> String {
+    let is_dir = entries.iter().any(|e| e.files.is_some());
+    let mut out = format!("Attribution history for {}\n\n", label);
+    out.push_str(&format!(
+        "{:<10}  {:<12}  {:<8}  {:<6}  {}{:<8}  CHANGE\n",
+        "COMMIT",
+        "DATE",
+        "FAMILY",
+        "CONF",
+        if is_dir { "FILES  " } else { "" },
+        "TREND",
+    ));
+    out.push_str(&format!("{}\n", "─".repeat(if is_dir { 79 } else { 72 })));
+
+    for entry in entries {
+        let files = entry.files.map(|n| format!("{n:>5}  ")).unwrap_or_default();
+        out.push_str(&format!(
+            "{:<10}  {:<12}  {:<8}  {:>5.0}%  {}{:<8}  {}\n",
+            &entry.commit[..8],
+            entry.date,
+            entry.family.to_string(),
+            entry.confidence * 100.0,
+            files,
+            entry.trend.label(),
+            entry.change,
+        ));
+    }
+
+    if entries.is_empty() {
+        out.push_str(&format!("(no commits found that touched {})\n", label));
+    }
+    out
+}
+
+/// Replay the history of `path` and return its display label plus one
+/// entry per commit that touched it.
+///
+/// For a directory, each entry is the line-weighted aggregate (see
+/// [`aggregate_attribution`]) of every source file under it at that commit,
+/// skipping files `ignore` excludes.  Commits that left the directory
+/// untouched are skipped, and only blobs not seen at a later commit are
+/// analyzed.
+pub fn collect(
+    path: &Path,
+    limit: Option<usize>,
+    since: Option<Duration>,
+    ignore: &dyn IgnoreRules,
+    overrides: &ConfigOverrides,
+) -> Result<(String, Vec<HistoryEntry>)> {
     let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    // Commits older than this Unix timestamp end the walk.
+    let cutoff = since.map(|d| {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        now.saturating_sub(d).as_secs() as i64
+    });
 
     let repo = Repository::discover(path)
         .context("not inside a git repository (or no .git found)")?;
@@ -16,11 +147,12 @@ pub fn run(path: &Path, limit: Option<usize>) -> Result<()> {
     let workdir = repo
         .workdir()
         .context("bare repositories are not supported")?;
+    let workdir = workdir.canonicalize().unwrap_or_else(|_| workdir.to_path_buf());
 
     let relative = path
         .canonicalize()
         .unwrap_or_else(|_| path.to_path_buf())
-        .strip_prefix(workdir.canonicalize().unwrap_or_else(|_| workdir.to_path_buf()))
+        .strip_prefix(&workdir)
         .context("path is not inside the repository work tree")?
         .to_path_buf();
 
@@ -35,43 +167,40 @@ pub fn run(path: &Path, limit: Option<usize>) -> Result<()> {
     } else {
         relative.display().to_string()
     };
-    println!("Attribution history for {}\n", label);
-    println!(
-        "{:<10}  {:<12}  {:<8}  {:<6}  CHANGE",
-        "COMMIT", "DATE", "FAMILY", "CONF"
-    );
-    println!("{}", "─".repeat(62));
 
     let mut prev_family: Option<ModelFamily> = None;
     let mut prev_conf: Option<f64> = None;
-    let mut shown = 0;
+    let mut entries = Vec::new();
+    // Commit time and AI share per entry, for the trend pass.
+    let mut points = Vec::new();
+    // Directory mode: reports by blob id, so unchanged files are analyzed once.
+    let mut analyzed: HashMap<Oid, Report> = HashMap::new();
 
     for oid_result in revwalk {
-        if shown >= limit {
+        if entries.len() >= limit {
             break;
         }
         let oid = oid_result?;
         let commit = repo.find_commit(oid)?;
+        // The walk is time-sorted, so the first commit past the cutoff ends it.
+        if cutoff.is_some_and(|c| commit.time().seconds() < c) {
+            break;
+        }
         let tree = commit.tree()?;
 
-        let (family, conf) = if is_dir {
-            // Aggregate attribution across all source files in the subtree.
-            let subtree = if relative.as_os_str().is_empty() {
-                // Root directory — use the commit tree directly.
-                tree
-            } else {
-                match tree.get_path(&relative) {
-                    Ok(entry) => match repo.find_tree(entry.id()) {
-                        Ok(t) => t,
-                        Err(_) => continue,
-                    },
-                    Err(_) => continue, // dir didn't exist in this commit
-                }
+        let (family, conf, files) = if is_dir {
+            let Some(subtree_id) = subtree_id(&tree, &relative) else {
+                continue; // dir didn't exist in this commit
             };
-
-            match aggregate_tree(&repo, &subtree) {
-                Some(result) => result,
-                None => continue, // no analysable source files
+            // Skip commits that changed nothing under the directory.
+            let parent_subtree = commit.parents().next().and_then(|p| subtree_id_of(&p, &relative));
+            if parent_subtree == Some(subtree_id) {
+                continue;
+            }
+            let subtree = repo.find_tree(subtree_id)?;
+            match aggregate_tree(&repo, &subtree, &workdir.join(&relative), ignore, overrides, &mut analyzed) {
+                Some(agg) => (agg.family, agg.share, Some(agg.files)),
+                None => continue, // no attributable source files
             }
         } else {
             // Single file — fetch the blob and analyse it.
@@ -88,7 +217,7 @@ pub fn run(path: &Path, limit: Option<usize>) -> Result<()> {
                 Err(_) => continue,
             };
             let report = vibecheck_core::analyze(content);
-            (report.attribution.primary, report.attribution.confidence)
+            (report.attribution.primary, report.attribution.confidence, None)
         };
 
         let ts = commit.time().seconds();
@@ -111,102 +240,280 @@ pub fn run(path: &Path, limit: Option<usize>) -> Result<()> {
             _ => "—".to_string(),
         };
 
-        let short_hash = &oid.to_string()[..8];
-        println!(
-            "{:<10}  {:<12}  {:<8}  {:>5.0}%  {}",
-            short_hash,
+        entries.push(HistoryEntry {
+            commit: oid.to_string(),
             date,
-            family.to_string(),
-            conf * 100.0,
+            family,
+            confidence: conf,
             change,
-        );
+            files,
+            recent_ai_share: 0.0,
+            trend: Trend::Steady,
+        });
+        points.push((ts, ai_share(family, conf)));
 
         prev_family = Some(family);
         prev_conf = Some(conf);
-        shown += 1;
     }
 
-    if shown == 0 {
-        println!("(no commits found that touched {})", label);
+    for (entry, (recent, trend)) in entries.iter_mut().zip(trends(&points)) {
+        entry.recent_ai_share = recent;
+        entry.trend = trend;
     }
+    Ok((label, entries))
+}
 
-    Ok(())
+/// How AI-written a row looks, from 0 (certainly human) to 1: the
+/// confidence for an AI family, its complement for [`ModelFamily::Human`].
+fn ai_share(family: ModelFamily, confidence: f64) -> f64 {
+    if family == ModelFamily::Human {
+        1.0 - confidence
+    } else {
+        confidence
+    }
 }
 
-/// Walk `tree` recursively, analyse every source blob, and return the
-/// line-weighted dominant family + confidence. Returns `None` if no
-/// supported source files are found in the tree.
-fn aggregate_tree(repo: &Repository, tree: &git2::Tree) -> Option<(ModelFamily, f64)> {
-    use std::collections::HashMap;
+/// The recency-weighted AI share and trend for each of `points`
+/// (`(commit time, AI share)`, newest first).
+///
+/// Each row looks at itself and every older row, weighting a commit
+/// `age` seconds older by `0.5^(age / half-life)`.  When the weighted mean
+/// sits more than [`TREND_THRESHOLD`] above the plain mean, recent commits
+/// are pulling the file toward AI; below it, toward human.
+fn trends(points: &[(i64, f64)]) -> Vec<(f64, Trend)> {
+    let half_life = TREND_HALF_LIFE_DAYS * 86400.0;
+    (0..points.len())
+        .map(|i| {
+            let window = &points[i..];
+            let now = window[0].0;
+            let (mut weighted, mut total_weight) = (0.0, 0.0);
+            for &(ts, share) in window {
+                let weight = 0.5f64.powf((now - ts).max(0) as f64 / half_life);
+                weighted += weight * share;
+                total_weight += weight;
+            }
+            let recent = weighted / total_weight;
+            let mean = window.iter().map(|&(_, s)| s).sum::<f64>() / window.len() as f64;
+            let trend = if recent - mean > TREND_THRESHOLD {
+                Trend::TowardAi
+            } else if mean - recent > TREND_THRESHOLD {
+                Trend::TowardHuman
+            } else {
+                Trend::Steady
+            };
+            (recent, trend)
+        })
+        .collect()
+}
 
-    let mut total_lines = 0usize;
-    let mut family_scores: HashMap<ModelFamily, f64> = HashMap::new();
+/// The tree id of the directory `relative` (empty for the root) in `tree`.
+fn subtree_id(tree: &git2::Tree, relative: &Path) -> Option<Oid> {
+    if relative.as_os_str().is_empty() {
+        return Some(tree.id());
+    }
+    let entry = tree.get_path(relative).ok()?;
+    (entry.kind() == Some(git2::ObjectType::Tree)).then(|| entry.id())
+}
 
-    tree.walk(git2::TreeWalkMode::PreOrder, |_root, entry| {
-        if entry.kind() != Some(git2::ObjectType::Blob) {
-            return git2::TreeWalkResult::Ok;
-        }
-        let name = entry.name().unwrap_or("");
-        if !is_source_file(name) {
+fn subtree_id_of(commit: &git2::Commit, relative: &Path) -> Option<Oid> {
+    subtree_id(&commit.tree().ok()?, relative)
+}
+
+/// Analyse every source blob under `tree` (checked out at `dir`) and
+/// aggregate them line-weighted.  Reports are memoized by blob id in
+/// `analyzed`.  Returns `None` if no file has enough signal to attribute.
+fn aggregate_tree(
+    repo: &Repository,
+    tree: &git2::Tree,
+    dir: &Path,
+    ignore: &dyn IgnoreRules,
+    overrides: &ConfigOverrides,
+    analyzed: &mut HashMap<Oid, Report>,
+) -> Option<AggregateAttribution> {
+    let exts = ExtensionFilter::default();
+    let mut blobs = Vec::new();
+    tree.walk(git2::TreeWalkMode::PreOrder, |root, entry| {
+        let Some(name) = entry.name() else {
             return git2::TreeWalkResult::Ok;
-        }
-        let blob = match repo.find_blob(entry.id()) {
-            Ok(b) => b,
-            Err(_) => return git2::TreeWalkResult::Ok,
-        };
-        let content = match std::str::from_utf8(blob.content()) {
-            Ok(s) => s,
-            Err(_) => return git2::TreeWalkResult::Ok,
         };
-        let report = vibecheck_core::analyze(content);
-        let lines = content.lines().count().max(1);
-        total_lines += lines;
-        *family_scores
-            .entry(report.attribution.primary)
-            .or_insert(0.0) += lines as f64 * report.attribution.confidence;
+        let path = dir.join(root).join(name);
+        if entry.kind() == Some(git2::ObjectType::Blob) && exts.allows_path(&path) && !ignore.is_ignored(&path) {
+            blobs.push((path, entry.id()));
+        }
         git2::TreeWalkResult::Ok
     })
     .ok()?;
 
-    if total_lines == 0 {
-        return None;
+    for (path, id) in &blobs {
+        if analyzed.contains_key(id) {
+            continue;
+        }
+        let Ok(blob) = repo.find_blob(*id) else { continue };
+        let Ok(content) = std::str::from_utf8(blob.content()) else { continue };
+        analyzed.insert(*id, vibecheck_core::analyze_source(content, path, overrides));
     }
-    let total = total_lines as f64;
-    family_scores
-        .into_iter()
-        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
-        .map(|(family, score)| (family, score / total))
+    aggregate_attribution(blobs.iter().filter_map(|(_, id)| analyzed.get(id)))
+}
+
+/// Parse a human duration such as `90m`, `36h`, `7d`, or `2w`.
+///
+/// Used as the clap value parser for `history --since`.
+pub fn parse_duration(s: &str) -> std::result::Result<Duration, String> {
+    let s = s.trim();
+    let split = s
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or_else(|| format!("missing unit in `{s}` (expected s, m, h, d, or w)"))?;
+    let (num, unit) = s.split_at(split);
+    let n: u64 = num
+        .parse()
+        .map_err(|_| format!("invalid duration `{s}`: expected a number followed by a unit"))?;
+    let secs_per_unit = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 3600,
+        "d" => 86400,
+        "w" => 7 * 86400,
+        _ => return Err(format!("unknown unit `{unit}` in `{s}` (expected s, m, h, d, or w)")),
+    };
+    let secs = n
+        .checked_mul(secs_per_unit)
+        .ok_or_else(|| format!("duration `{s}` is too large"))?;
+    Ok(Duration::from_secs(secs))
 }
 
-/// Return `true` for file extensions vibecheck can analyse.
-fn is_source_file(name: &str) -> bool {
-    matches!(
-        std::path::Path::new(name)
-            .extension()
-            .and_then(|e| e.to_str()),
-        Some("rs" | "py" | "js" | "ts" | "go")
-    )
+/// Format a Unix timestamp as `YYYY-MM-DD`.
+fn format_date(unix_secs: i64) -> String {
+    // Hand-rolled to avoid a chrono dependency.
+    let secs = unix_secs as u64;
+    let days_since_epoch = secs / 86400;
+
+    // Gregorian calendar algorithm.
+    let z = days_since_epoch + 719468;
+    let era = z / 146097;
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let y = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = if m <= 2 { y + 1 } else { y };
+
+    format!("{y:04}-{m:02}-{d:02}")
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use vibecheck_core::ignore_rules::PatternIgnore;
 
     #[test]
-    fn is_source_file_supported_extensions() {
-        assert!(is_source_file("main.rs"));
-        assert!(is_source_file("script.py"));
-        assert!(is_source_file("app.js"));
-        assert!(is_source_file("types.ts"));
-        assert!(is_source_file("server.go"));
+    fn parse_duration_units() {
+        assert_eq!(parse_duration("45s"), Ok(Duration::from_secs(45)));
+        assert_eq!(parse_duration("90m"), Ok(Duration::from_secs(90 * 60)));
+        assert_eq!(parse_duration("36h"), Ok(Duration::from_secs(36 * 3600)));
+        assert_eq!(parse_duration("7d"), Ok(Duration::from_secs(7 * 86400)));
+        assert_eq!(parse_duration("2w"), Ok(Duration::from_secs(14 * 86400)));
     }
 
     #[test]
-    fn is_source_file_unsupported() {
-        assert!(!is_source_file("README.md"));
-        assert!(!is_source_file("Cargo.toml"));
-        assert!(!is_source_file("image.png"));
-        assert!(!is_source_file("noextension"));
+    fn parse_duration_rejects_malformed() {
+        assert!(parse_duration("7").is_err());
+        assert!(parse_duration("d").is_err());
+        assert!(parse_duration("7y").is_err());
+        assert!(parse_duration("").is_err());
+    }
+
+    #[test]
+    fn parse_duration_rejects_overflow() {
+        assert!(parse_duration("99999999999999999w").is_err());
+        assert!(parse_duration("99999999999999999999s").is_err());
+    }
+
+    #[test]
+    fn collect_returns_serializable_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        let file = dir.path().join("lib.rs");
+        std::fs::write(&file, "fn main() {\n    println!(\"hi\");\n}\n").unwrap();
+
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("lib.rs")).unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let sig = git2::Signature::new("t", "t@example.com", &git2::Time::new(1772150400, 0)).unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "init", &tree, &[]).unwrap();
+
+        let (label, entries) = collect(&file, None, None, &PatternIgnore(vec![]), &Default::default()).unwrap();
+        assert_eq!(label, "lib.rs");
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].date, "2026-02-27");
+        assert_eq!(entries[0].commit.len(), 40);
+
+        let json = serde_json::to_value(&entries).unwrap();
+        let row = &json[0];
+        for key in ["commit", "date", "family", "confidence", "change", "recent_ai_share", "trend"] {
+            assert!(row.get(key).is_some(), "missing `{key}` in {row}");
+        }
+    }
+
+    fn commit_all(repo: &Repository, message: &str, time: i64) {
+        let mut index = repo.index().unwrap();
+        index.add_all(["*"], git2::IndexAddOption::DEFAULT, None).unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let sig = git2::Signature::new("t", "t@example.com", &git2::Time::new(time, 0)).unwrap();
+        let parents: Vec<_> = repo.head().ok().and_then(|h| h.peel_to_commit().ok()).into_iter().collect();
+        let parents: Vec<_> = parents.iter().collect();
+        repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents).unwrap();
+    }
+
+    #[test]
+    fn directory_history_aggregates_commits_that_touch_it() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("../vibecheck-core/tests/fixtures/lru_cache");
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        let src = dir.path().join("src");
+        std::fs::create_dir(&src).unwrap();
+
+        std::fs::copy(fixtures.join("claude.rs"), src.join("cache.rs")).unwrap();
+        commit_all(&repo, "add cache", 1772150400);
+        std::fs::write(dir.path().join("NOTES.txt"), "outside src\n").unwrap();
+        commit_all(&repo, "notes", 1772236800);
+        std::fs::copy(fixtures.join("human.py"), src.join("lru.py")).unwrap();
+        std::fs::copy(fixtures.join("gpt.py"), src.join("skipped.py")).unwrap();
+        commit_all(&repo, "add python", 1772323200);
+
+        let ignore = PatternIgnore(vec!["skipped.py".into()]);
+        let (label, entries) = collect(&src, None, None, &ignore, &Default::default()).unwrap();
+        assert_eq!(label, "src");
+        assert_eq!(entries.len(), 2, "the NOTES.txt commit is skipped");
+        assert_eq!((entries[0].date.as_str(), entries[0].files), ("2026-03-01", Some(2)));
+        assert_eq!((entries[1].date.as_str(), entries[1].files), ("2026-02-27", Some(1)));
+
+        let table = format_table(&label, &entries);
+        assert!(table.contains("FILES  TREND     CHANGE"), "{table}");
+        assert!(table.contains("      2  "), "{table}");
+        let json = serde_json::to_value(&entries).unwrap();
+        assert_eq!(json[1]["files"], 1);
+    }
+
+    #[test]
+    fn trend_follows_the_recency_weighted_ai_share() {
+        const DAY: i64 = 86400;
+        // Newest first: three recent AI-looking commits after a long human stretch.
+        let rising = [(200 * DAY, 0.9), (190 * DAY, 0.9), (180 * DAY, 0.8), (60 * DAY, 0.2), (0, 0.1)];
+        let rows = trends(&rising);
+        assert_eq!(rows[0].1, Trend::TowardAi);
+        assert!(rows[0].0 > 0.8, "{rows:?}");
+        assert_eq!(rows[3].1, Trend::Steady, "two-commit window, {rows:?}");
+        assert_eq!(rows[4], (0.1, Trend::Steady), "a single commit has no trend");
+
+        let falling: Vec<_> = rising.iter().map(|&(t, s)| (t, 1.0 - s)).collect();
+        assert_eq!(trends(&falling)[0].1, Trend::TowardHuman);
+
+        let same_day = [(DAY, 0.9), (DAY, 0.1)];
+        assert_eq!(trends(&same_day)[0], (0.5, Trend::Steady));
+        assert!((ai_share(ModelFamily::Human, 0.8) - 0.2).abs() < 1e-9);
+        assert_eq!(ai_share(ModelFamily::Gpt, 0.8), 0.8);
     }
 
     #[test]
@@ -226,24 +533,3 @@ mod tests {
         assert_eq!(format_date(1709208000), "2024-02-29");
     }
 }
-
-/// Format a Unix timestamp as `YYYY-MM-DD`.
-fn format_date(unix_secs: i64) -> String {
-    // Hand-rolled to avoid a chrono dependency.
-    let secs = unix_secs as u64;
-    let days_since_epoch = secs / 86400;
-
-    // Gregorian calendar algorithm.
-    let z = days_since_epoch + 719468;
-    let era = z / 146097;
-    let doe = z - era * 146097;
-    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
-    let y = yoe + era * 400;
-    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
-    let mp = (5 * doy + 2) / 153;
-    let d = doy - (153 * mp + 2) / 5 + 1;
-    let m = if mp < 10 { mp + 3 } else { mp - 9 };
-    let y = if m <= 2 { y + 1 } else { y };
-
-    format!("{y:04}-{m:02}-{d:02}")
-}
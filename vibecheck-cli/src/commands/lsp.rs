@@ -0,0 +1,387 @@
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+use serde_json::{json, Value};
+
+use vibecheck_core::language::Language;
+use vibecheck_core::report::{ModelFamily, Report};
+use vibecheck_core::ConfigOverrides;
+
+/// Run a minimal Language Server over stdio.
+///
+/// Documents are analyzed in memory on `didOpen` and `didSave`; each result
+/// is published as `textDocument/publishDiagnostics` plus a
+/// `vibecheck/report` notification carrying the full report.
+pub fn run(overrides: ConfigOverrides) -> Result<()> {
+    let stdin = std::io::stdin();
+    let stdout = std::io::stdout();
+    Server { overrides, ..Server::default() }.serve(stdin.lock(), stdout.lock())
+}
+
+/// An open document: its latest full text and LSP language identifier.
+struct Document {
+    text: String,
+    language_id: String,
+}
+
+#[derive(Default)]
+struct Server {
+    documents: HashMap<String, Document>,
+    shutdown_requested: bool,
+    /// Applied on top of each document's discovered config.
+    overrides: ConfigOverrides,
+}
+
+impl Server {
+    /// Handle messages until `exit` or end of input.
+    fn serve<R: BufRead, W: Write>(&mut self, mut input: R, mut output: W) -> Result<()> {
+        while let Some(message) = read_message(&mut input)? {
+            let method = message.get("method").and_then(Value::as_str).unwrap_or("");
+            if method == "exit" {
+                break;
+            }
+            let params = message.get("params").cloned().unwrap_or(Value::Null);
+            for reply in self.handle(method, message.get("id").cloned(), &params) {
+                write_message(&mut output, &reply)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Messages to send in response to one incoming request or notification.
+    fn handle(&mut self, method: &str, id: Option<Value>, params: &Value) -> Vec<Value> {
+        match method {
+            "initialize" => vec![response(id, initialize_result())],
+            "shutdown" => {
+                self.shutdown_requested = true;
+                vec![response(id, Value::Null)]
+            }
+            _ if self.shutdown_requested && id.is_some() => {
+                vec![error_response(id, -32600, "server is shutting down")]
+            }
+            "textDocument/didOpen" => {
+                let doc = &params["textDocument"];
+                let (Some(uri), Some(text)) = (doc["uri"].as_str(), doc["text"].as_str()) else {
+                    return vec![];
+                };
+                let language_id = doc["languageId"].as_str().unwrap_or("").to_string();
+                self.documents.insert(
+                    uri.to_string(),
+                    Document { text: text.to_string(), language_id },
+                );
+                self.analyze(uri)
+            }
+            "textDocument/didChange" => {
+                // Full sync: the last change holds the whole document.
+                let uri = params["textDocument"]["uri"].as_str().unwrap_or("");
+                let text = params["contentChanges"]
+                    .as_array()
+                    .and_then(|changes| changes.last())
+                    .and_then(|c| c["text"].as_str());
+                if let (Some(doc), Some(text)) = (self.documents.get_mut(uri), text) {
+                    doc.text = text.to_string();
+                }
+                vec![]
+            }
+            "textDocument/didSave" => {
+                let uri = params["textDocument"]["uri"].as_str().unwrap_or("");
+                if let (Some(doc), Some(text)) =
+                    (self.documents.get_mut(uri), params["text"].as_str())
+                {
+                    doc.text = text.to_string();
+                }
+                self.analyze(uri)
+            }
+            "textDocument/didClose" => {
+                let uri = params["textDocument"]["uri"].as_str().unwrap_or("");
+                self.documents.remove(uri);
+                vec![publish_diagnostics(uri, vec![])]
+            }
+            _ if id.is_some() => {
+                vec![error_response(id, -32601, &format!("method not found: {method}"))]
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Analyze the open document at `uri` and build the notifications to send.
+    fn analyze(&self, uri: &str) -> Vec<Value> {
+        let Some(doc) = self.documents.get(uri) else {
+            return vec![];
+        };
+        let path = uri_to_path(uri);
+        let language = language_for(&doc.language_id)
+            .or_else(|| vibecheck_core::language::detect_language(&path));
+        let Some(language) = language else {
+            return vec![];
+        };
+        match vibecheck_core::analyze_with_language(&doc.text, &path, language, &self.overrides) {
+            Ok(report) => vec![
+                publish_diagnostics(uri, diagnostics(&report)),
+                json!({
+                    "jsonrpc": "2.0",
+                    "method": "vibecheck/report",
+                    "params": { "uri": uri, "report": report },
+                }),
+            ],
+            Err(e) => vec![json!({
+                "jsonrpc": "2.0",
+                "method": "window/logMessage",
+                "params": { "type": 1, "message": format!("vibecheck: {e}") },
+            })],
+        }
+    }
+}
+
+fn initialize_result() -> Value {
+    json!({
+        "capabilities": {
+            "textDocumentSync": {
+                "openClose": true,
+                "change": 1,
+                "save": { "includeText": true },
+            },
+        },
+        "serverInfo": { "name": "vibecheck", "version": env!("CARGO_PKG_VERSION") },
+    })
+}
+
+/// One file-level diagnostic on the first line, plus one per symbol whose
+/// primary attribution is not Human, anchored at the symbol's first line.
+fn diagnostics(report: &Report) -> Vec<Value> {
+    const INFORMATION: u8 = 3;
+    const HINT: u8 = 4;
+
+    let attr = &report.attribution;
+    let mut out = vec![diagnostic(
+        0,
+        INFORMATION,
+        format!(
+            "{} ({:.0}% confidence, {} signals)",
+            attr.primary,
+            attr.confidence * 100.0,
+            report.signals.len()
+        ),
+    )];
+    for sym in report.symbol_reports.iter().flatten() {
+        if sym.attribution.primary == ModelFamily::Human {
+            continue;
+        }
+        out.push(diagnostic(
+            sym.metadata.start_line.saturating_sub(1),
+            HINT,
+            format!(
+                "{}: {} ({:.0}%)",
+                sym.metadata.display_name(40),
+                sym.attribution.primary,
+                sym.attribution.confidence * 100.0
+            ),
+        ));
+    }
+    out
+}
+
+fn diagnostic(line: usize, severity: u8, message: String) -> Value {
+    json!({
+        "range": {
+            "start": { "line": line, "character": 0 },
+            "end": { "line": line, "character": 0 },
+        },
+        "severity": severity,
+        "source": "vibecheck",
+        "message": message,
+    })
+}
+
+fn publish_diagnostics(uri: &str, diagnostics: Vec<Value>) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/publishDiagnostics",
+        "params": { "uri": uri, "diagnostics": diagnostics },
+    })
+}
+
+fn response(id: Option<Value>, result: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "result": result })
+}
+
+fn error_response(id: Option<Value>, code: i64, message: &str) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+/// Map an LSP `languageId` to the language vibecheck analyzes it as.
+fn language_for(language_id: &str) -> Option<Language> {
+    match language_id {
+        "rust" => Some(Language::Rust),
+        "python" => Some(Language::Python),
+        "javascript" | "javascriptreact" | "typescript" | "typescriptreact" => {
+            Some(Language::JavaScript)
+        }
+        "go" => Some(Language::Go),
+        "csharp" => Some(Language::CSharp),
+        "terraform" | "hcl" => Some(Language::Hcl),
+        _ => None,
+    }
+}
+
+/// Filesystem path for a `file://` URI (percent-decoded).  Other schemes
+/// yield their raw text as a path, which still selects a language by
+/// extension and falls back to config discovery from the current directory.
+fn uri_to_path(uri: &str) -> PathBuf {
+    let Some(rest) = uri.strip_prefix("file://") else {
+        return PathBuf::from(uri);
+    };
+    let bytes = rest.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes.get(i + 1..i + 3).and_then(|h| std::str::from_utf8(h).ok());
+        match (bytes[i], hex.and_then(|h| u8::from_str_radix(h, 16).ok())) {
+            (b'%', Some(b)) => {
+                decoded.push(b);
+                i += 3;
+            }
+            (b, _) => {
+                decoded.push(b);
+                i += 1;
+            }
+        }
+    }
+    let path = String::from_utf8_lossy(&decoded).into_owned();
+    // `file:///C:/x` → `C:/x` on Windows.
+    match path.as_bytes() {
+        [b'/', drive, b':', ..] if cfg!(windows) && drive.is_ascii_alphabetic() => {
+            PathBuf::from(&path[1..])
+        }
+        _ => PathBuf::from(path),
+    }
+}
+
+/// Read one `Content-Length`-framed JSON-RPC message; `None` at end of input.
+fn read_message<R: BufRead>(input: &mut R) -> Result<Option<Value>> {
+    let mut length = None;
+    loop {
+        let mut header = String::new();
+        if input.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("Content-Length") {
+                length = Some(value.trim().parse::<usize>().context("bad Content-Length")?);
+            }
+        }
+    }
+    let Some(length) = length else {
+        bail!("LSP message without Content-Length header");
+    };
+    let mut body = vec![0; length];
+    input.read_exact(&mut body)?;
+    Ok(Some(serde_json::from_slice(&body).context("invalid JSON-RPC message")?))
+}
+
+fn write_message<W: Write>(output: &mut W, message: &Value) -> Result<()> {
+    let body = serde_json::to_string(message)?;
+    write!(output, "Content-Length: {}\r\n\r\n{body}", body.len())?;
+    output.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(message: Value) -> String {
+        let body = message.to_string();
+        format!("Content-Length: {}\r\n\r\n{body}", body.len())
+    }
+
+    fn session(messages: Vec<Value>) -> Vec<Value> {
+        let input: String = messages.into_iter().map(frame).collect();
+        let mut output = Vec::new();
+        Server::default().serve(input.as_bytes(), &mut output).unwrap();
+        let mut reader = output.as_slice();
+        std::iter::from_fn(|| read_message(&mut reader).unwrap()).collect()
+    }
+
+    const SOURCE: &str = "def greet(name):\n    return f\"hi {name}\"\n";
+
+    #[test]
+    fn initialize_open_and_shutdown() {
+        let replies = session(vec![
+            json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {} }),
+            json!({ "jsonrpc": "2.0", "method": "initialized", "params": {} }),
+            json!({ "jsonrpc": "2.0", "method": "textDocument/didOpen", "params": {
+                "textDocument": {
+                    "uri": "untitled:Untitled-1", "languageId": "python",
+                    "version": 1, "text": SOURCE,
+                },
+            }}),
+            json!({ "jsonrpc": "2.0", "id": 2, "method": "shutdown" }),
+            json!({ "jsonrpc": "2.0", "method": "exit" }),
+        ]);
+        assert_eq!(replies.len(), 4, "{replies:#?}");
+        assert_eq!(replies[0]["id"], 1);
+        assert_eq!(replies[0]["result"]["capabilities"]["textDocumentSync"]["change"], 1);
+
+        assert_eq!(replies[1]["method"], "textDocument/publishDiagnostics");
+        let diags = replies[1]["params"]["diagnostics"].as_array().unwrap();
+        assert_eq!(diags[0]["source"], "vibecheck");
+
+        assert_eq!(replies[2]["method"], "vibecheck/report");
+        let symbols = replies[2]["params"]["report"]["symbol_reports"].as_array().unwrap();
+        assert_eq!(symbols[0]["metadata"]["name"], "greet");
+
+        assert_eq!(replies[3]["id"], 2);
+        assert!(replies[3]["result"].is_null());
+    }
+
+    #[test]
+    fn save_reanalyzes_latest_text_and_close_clears() {
+        let uri = "file:///tmp/vibecheck-lsp-test/app.py";
+        let replies = session(vec![
+            json!({ "jsonrpc": "2.0", "method": "textDocument/didOpen", "params": {
+                "textDocument": { "uri": uri, "languageId": "python", "version": 1, "text": "" },
+            }}),
+            json!({ "jsonrpc": "2.0", "method": "textDocument/didChange", "params": {
+                "textDocument": { "uri": uri, "version": 2 },
+                "contentChanges": [{ "text": SOURCE }],
+            }}),
+            json!({ "jsonrpc": "2.0", "method": "textDocument/didSave", "params": {
+                "textDocument": { "uri": uri },
+            }}),
+            json!({ "jsonrpc": "2.0", "method": "textDocument/didClose", "params": {
+                "textDocument": { "uri": uri },
+            }}),
+        ]);
+        // open → 2, change → 0, save → 2, close → 1
+        assert_eq!(replies.len(), 5, "{replies:#?}");
+        let saved = &replies[3]["params"]["report"];
+        assert_eq!(saved["metadata"]["lines_of_code"], 2);
+        assert_eq!(replies[4]["params"]["diagnostics"], json!([]));
+    }
+
+    #[test]
+    fn unknown_requests_get_method_not_found() {
+        let replies = session(vec![
+            json!({ "jsonrpc": "2.0", "id": 7, "method": "textDocument/hover", "params": {} }),
+            json!({ "jsonrpc": "2.0", "method": "$/cancelRequest", "params": { "id": 1 } }),
+        ]);
+        assert_eq!(replies.len(), 1);
+        assert_eq!(replies[0]["error"]["code"], -32601);
+    }
+
+    #[test]
+    fn uri_to_path_decodes_file_uris() {
+        assert_eq!(
+            uri_to_path("file:///home/me/my%20project/lib.rs"),
+            PathBuf::from("/home/me/my project/lib.rs")
+        );
+        assert_eq!(uri_to_path("untitled:Untitled-1"), PathBuf::from("untitled:Untitled-1"));
+    }
+}
@@ -1,5 +1,16 @@
 pub mod analyze;
+pub mod batch;
+pub mod cache;
+pub mod doctor;
+pub mod eval;
+pub mod heatmap;
 pub mod heuristics;
 pub mod history;
+pub mod lsp;
+pub mod pre_commit;
+pub mod rank;
+pub mod serve;
+pub mod signals_freq;
+pub mod staged;
 pub mod tui;
 pub mod watch;
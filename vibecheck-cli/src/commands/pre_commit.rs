@@ -0,0 +1,107 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use vibecheck_core::report::{ModelFamily, Report};
+use vibecheck_core::ConfigOverrides;
+
+use crate::commands::analyze::parse_families;
+
+/// Check the staged contents of `files` (as passed by the pre-commit
+/// framework) against `assert_family`, printing one line per offending file
+/// and exiting 1 if there are any.  Passing runs print nothing.
+pub fn run(
+    files: &[PathBuf],
+    assert_family: &[String],
+    overrides: &ConfigOverrides,
+) -> Result<()> {
+    let allowed = parse_families(assert_family)?;
+    let cwd = std::env::current_dir()?;
+    let ignore = overrides.load_config(&cwd);
+    let sources = crate::commands::staged::staged_blobs(&cwd, files, &ignore)
+        .context("failed to read staged files")?;
+
+    let reports: Vec<Report> = sources
+        .iter()
+        .map(|(file, content)| vibecheck_core::analyze_source(content, file, overrides))
+        .collect();
+    let failures = violations(&reports, |_| Some(&allowed[..]), &cwd);
+    for line in &failures {
+        println!("{line}");
+    }
+    if !failures.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// One `path: family (NN%), expected a, b` line per report whose primary
+/// family is not in `allowed_for(report)`; reports it returns `None` for are
+/// not checked.  Generated files and reports without signals never fail.
+/// Paths are shown relative to `root` when possible.
+pub(crate) fn violations<'a>(
+    reports: &[Report],
+    allowed_for: impl Fn(&Report) -> Option<&'a [ModelFamily]>,
+    root: &Path,
+) -> Vec<String> {
+    reports
+        .iter()
+        .filter(|r| !r.metadata.generated && r.metadata.signal_count > 0)
+        .filter_map(|r| {
+            let allowed = allowed_for(r).filter(|a| !a.contains(&r.attribution.primary))?;
+            Some((r, allowed))
+        })
+        .map(|(r, allowed)| {
+            let expected = allowed.iter().map(|f| f.to_string()).collect::<Vec<_>>().join(", ");
+            let path = r
+                .metadata
+                .file_path
+                .as_deref()
+                .map(|p| p.strip_prefix(root).unwrap_or(p).display().to_string())
+                .unwrap_or_else(|| "<stdin>".into());
+            format!(
+                "{path}: {} ({:.0}%), expected {expected}",
+                r.attribution.primary,
+                r.attribution.confidence * 100.0,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report_at(path: &str, source: &str) -> Report {
+        vibecheck_core::analyze_source(source, Path::new(path), &Default::default())
+    }
+
+    #[test]
+    fn violations_lists_only_disallowed_files() {
+        let source = "/// Adds two numbers together.\n/// Returns the sum.\nfn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n".repeat(4);
+        let report = report_at("/repo/src/lib.rs", &source);
+        let family = report.attribution.primary;
+        let other = *ModelFamily::all().iter().find(|f| **f != family).unwrap();
+
+        let (family, other) = ([family], [other]);
+        assert!(violations(std::slice::from_ref(&report), |_| Some(&family[..]), Path::new("/repo")).is_empty());
+        let lines = violations(&[report], |_| Some(&other[..]), Path::new("/repo"));
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].starts_with(&format!("src/lib.rs: {} (", family[0])), "{}", lines[0]);
+        assert!(lines[0].ends_with(&format!("expected {}", other[0])), "{}", lines[0]);
+    }
+
+    #[test]
+    fn generated_files_pass() {
+        let source = "/// Adds two numbers together.\nfn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n".repeat(4);
+        let report = report_at("/repo/gen.rs", &format!("// @generated by build.rs\n{source}"));
+        assert!(report.metadata.generated);
+        assert!(violations(&[report], |_| Some(&[][..]), Path::new("/repo")).is_empty());
+    }
+
+    #[test]
+    fn reports_without_signals_pass() {
+        let report = report_at("/repo/empty.rs", "");
+        assert!(violations(&[report], |_| Some(&[ModelFamily::Human][..]), Path::new("/repo")).is_empty());
+    }
+}
@@ -0,0 +1,192 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde_json::json;
+
+use vibecheck_core::report::{ModelFamily, Report};
+use vibecheck_core::{ConfigOverrides, ExtensionFilter};
+
+use crate::commands::analyze::collect_files;
+
+/// One file's place in the ranking.
+#[derive(Debug)]
+struct Ranked<'a> {
+    report: &'a Report,
+    /// The family whose score ranks the file.
+    family: ModelFamily,
+    score: f64,
+}
+
+/// Options for [`run`], mirroring the `rank` CLI flags.
+pub struct RankOptions<'a> {
+    /// How many files to list.
+    pub limit: usize,
+    /// Rank by this family's score instead of the highest non-human one.
+    pub family: Option<&'a str>,
+    pub no_cache: bool,
+    pub cache_dir: Option<&'a Path>,
+    pub overrides: &'a ConfigOverrides,
+    /// `table` or `json`.
+    pub format: &'a str,
+    /// Write results to this file instead of stdout.
+    pub output: Option<&'a Path>,
+}
+
+/// Analyze every supported file under `path` and print the `limit` most
+/// likely AI-generated ones, as a table or (`format == "json"`) a JSON
+/// array.
+///
+/// A file's score is its highest non-human family score, or with `family`
+/// that family's score.  Generated files and files too small to attribute
+/// are left out.
+pub fn run(path: &PathBuf, opts: RankOptions<'_>) -> Result<()> {
+    let RankOptions { limit, family, no_cache, cache_dir, overrides, format, output } = opts;
+    let family = family
+        .map(|f| ModelFamily::from_name(f).ok_or_else(|| anyhow::anyhow!("unknown family: {f}")))
+        .transpose()?;
+    let ignore = overrides.load_config(path);
+    let files = collect_files(path, &ignore, true, &ExtensionFilter::default())
+        .context("failed to collect files")?;
+    if files.is_empty() {
+        anyhow::bail!("no supported source files found in {}", path.display());
+    }
+    let reports = files
+        .iter()
+        .map(|f| {
+            if no_cache {
+                vibecheck_core::analyze_file_no_cache_with_overrides(f, overrides)
+            } else {
+                vibecheck_core::analyze_file_with_cache_dir(f, cache_dir, overrides)
+            }
+        })
+        .collect::<std::io::Result<Vec<Report>>>()
+        .context("failed to analyze files")?;
+
+    let ranked = rank(&reports, family, limit);
+    let mut out = crate::output::open_output(output)?;
+    if format == "json" {
+        let rows: Vec<_> = ranked
+            .iter()
+            .map(|r| {
+                json!({
+                    "path": r.report.metadata.file_path,
+                    "family": r.family,
+                    "score": r.score,
+                    "primary": r.report.attribution.primary,
+                    "confidence": r.report.attribution.confidence,
+                })
+            })
+            .collect();
+        writeln!(out, "{}", serde_json::to_string_pretty(&rows)?)?;
+    } else {
+        write!(out, "{}", format_table(&ranked, reports.len()))?;
+    }
+    out.finish()
+}
+
+/// The top `limit` of `reports` by score (see [`run`]), highest first and
+/// then by path.
+fn rank(reports: &[Report], family: Option<ModelFamily>, limit: usize) -> Vec<Ranked<'_>> {
+    let mut ranked: Vec<Ranked> = reports
+        .iter()
+        .filter(|r| !r.metadata.generated && r.metadata.signal_count > 0)
+        .filter_map(|report| {
+            let scores = &report.attribution.scores;
+            let (family, score) = match family {
+                Some(f) => (f, scores.get(&f).copied().unwrap_or(0.0)),
+                None => scores
+                    .iter()
+                    .filter(|(f, _)| **f != ModelFamily::Human)
+                    .map(|(f, s)| (*f, *s))
+                    .max_by(|a, b| a.1.total_cmp(&b.1).then_with(|| b.0.name().cmp(a.0.name())))?,
+            };
+            Some(Ranked { report, family, score })
+        })
+        .collect();
+    ranked.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.report.metadata.file_path.cmp(&b.report.metadata.file_path))
+    });
+    ranked.truncate(limit);
+    ranked
+}
+
+fn format_table(ranked: &[Ranked], total_files: usize) -> String {
+    let mut out = format!("{:>4}  {:>6}  {:<8}  {:<8}  {}\n", "RANK", "SCORE", "FAMILY", "PRIMARY", "FILE");
+    for (i, r) in ranked.iter().enumerate() {
+        out.push_str(&format!(
+            "{:>4}  {:>5.1}%  {:<8}  {:<8}  {}\n",
+            i + 1,
+            r.score * 100.0,
+            r.family.to_string(),
+            r.report.attribution.primary.to_string(),
+            r.report.metadata.file_path.as_deref().unwrap_or(Path::new("<stdin>")).display(),
+        ));
+    }
+    out.push_str(&format!("\ntop {} of {total_files} files\n", ranked.len()));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report_at(path: &str, scores: &[(ModelFamily, f64)]) -> Report {
+        let mut report = vibecheck_core::analyze("");
+        report.metadata.file_path = Some(PathBuf::from(path));
+        report.metadata.signal_count = 1;
+        report.attribution.scores = scores.iter().copied().collect();
+        report.attribution.primary = scores.iter().max_by(|a, b| a.1.total_cmp(&b.1)).unwrap().0;
+        report
+    }
+
+    #[test]
+    fn ranks_by_top_ai_score_or_one_family() {
+        use ModelFamily::{Claude, Gpt, Human};
+        let mut generated = report_at("gen.rs", &[(Claude, 0.99)]);
+        generated.metadata.generated = true;
+        let reports = [
+            report_at("a.rs", &[(Claude, 0.3), (Gpt, 0.2), (Human, 0.5)]),
+            report_at("b.rs", &[(Claude, 0.1), (Gpt, 0.6), (Human, 0.3)]),
+            report_at("c.rs", &[(Claude, 0.4), (Human, 0.6)]),
+            generated,
+        ];
+        let order = |ranked: Vec<Ranked>| -> Vec<(String, ModelFamily)> {
+            ranked
+                .iter()
+                .map(|r| (r.report.metadata.file_path.as_ref().unwrap().display().to_string(), r.family))
+                .collect()
+        };
+        assert_eq!(
+            order(rank(&reports, None, 10)),
+            [("b.rs".into(), Gpt), ("c.rs".into(), Claude), ("a.rs".into(), Claude)]
+        );
+        assert_eq!(order(rank(&reports, Some(Claude), 2)), [("c.rs".into(), Claude), ("a.rs".into(), Claude)]);
+
+        let table = format_table(&rank(&reports, Some(Gpt), 1), reports.len());
+        assert!(table.contains("   1   60.0%  GPT       GPT       b.rs\n"), "{table}");
+        assert!(table.ends_with("top 1 of 4 files\n"), "{table}");
+    }
+
+    #[test]
+    fn run_rejects_unknown_family_and_empty_directories() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().to_path_buf();
+        let overrides = Default::default();
+        let opts = |family| RankOptions {
+            limit: 10,
+            family,
+            no_cache: true,
+            cache_dir: None,
+            overrides: &overrides,
+            format: "table",
+            output: None,
+        };
+        let err = run(&path, opts(Some("deepseek"))).unwrap_err();
+        assert!(err.to_string().contains("unknown family: deepseek"), "{err}");
+        let err = run(&path, opts(None)).unwrap_err();
+        assert!(err.to_string().contains("no supported source files"), "{err}");
+    }
+}
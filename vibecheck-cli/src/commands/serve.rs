@@ -0,0 +1,223 @@
+//! `vibecheck serve`: a small HTTP API over the in-memory analyzer, so
+//! services in other languages can integrate without FFI.
+//!
+//! Endpoints:
+//!
+//! - `POST /analyze` with `{"source", "lang"}` returns the JSON [`Report`].
+//! - `POST /analyze-symbols` takes the same body and returns the report with
+//!   `symbol_reports` filled in.
+//! - `GET /heuristics` returns the signal catalogue.
+//!
+//! Errors come back as `{"error": "..."}` with a 4xx status.  Nothing is
+//! read from or written to disk per request.  Serving needs the `server`
+//! feature; without it the command fails with a hint to rebuild.
+//!
+//! [`Report`]: vibecheck_core::report::Report
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use serde::Deserialize;
+use serde_json::json;
+
+use vibecheck_core::pipeline::Pipeline;
+use vibecheck_core::ConfigOverrides;
+
+use super::batch::extension_for;
+
+/// Serve the API on `addr` (e.g. `127.0.0.1:7878`) until the process is
+/// killed.
+///
+/// The `.vibecheck` config is loaded once (from `overrides`, i.e.
+/// `--config`, or discovered from the current directory) and shared by
+/// every request.
+pub fn run(addr: &str, overrides: &ConfigOverrides) -> Result<()> {
+    let config = overrides.load_config(&std::env::current_dir()?);
+    let pipeline = vibecheck_core::pipeline_from_config(&config);
+    listen(addr, &|method, url, body| handle(method, url, body, &pipeline))
+}
+
+/// `{"source", "lang"}` body of the analyze endpoints.
+#[derive(Deserialize)]
+struct AnalyzeRequest {
+    source: String,
+    lang: String,
+}
+
+/// Route one request, returning the status code and JSON response body.
+fn handle(method: &str, url: &str, body: &[u8], pipeline: &Pipeline) -> (u16, String) {
+    let path = url.split('?').next().unwrap_or(url);
+    match (method, path) {
+        ("POST", "/analyze") => analyze(body, pipeline, false),
+        ("POST", "/analyze-symbols") => analyze(body, pipeline, true),
+        ("GET", "/heuristics") => (200, super::heuristics::catalogue().to_string()),
+        (_, "/analyze" | "/analyze-symbols" | "/heuristics") => {
+            error(405, format!("method {method} not allowed on {path}"))
+        }
+        _ => error(404, format!("no such endpoint: {path}")),
+    }
+}
+
+fn analyze(body: &[u8], pipeline: &Pipeline, symbols: bool) -> (u16, String) {
+    let request: AnalyzeRequest = match serde_json::from_slice(body) {
+        Ok(r) => r,
+        Err(e) => return error(400, format!("invalid request: {e}")),
+    };
+    let Some(ext) = extension_for(&request.lang) else {
+        return error(400, format!("unsupported lang: {}", request.lang));
+    };
+
+    // The path only selects the language; it is not part of the result.
+    let path = PathBuf::from(format!("snippet.{ext}"));
+    let mut report = pipeline.run(&request.source, Some(path.clone()));
+    if symbols {
+        match pipeline.run_symbols(request.source.as_bytes(), &path) {
+            Ok(symbol_reports) => report.symbol_reports = Some(symbol_reports),
+            Err(e) => return error(422, format!("symbol analysis failed: {e}")),
+        }
+    }
+    report.metadata.file_path = None;
+    // Report is written with its own Serialize impl (not via `Value`) so
+    // score keys keep their stable family order.
+    match serde_json::to_string(&report) {
+        Ok(json) => (200, json),
+        Err(e) => error(500, format!("cannot serialize report: {e}")),
+    }
+}
+
+fn error(status: u16, message: String) -> (u16, String) {
+    (status, json!({ "error": message }).to_string())
+}
+
+/// Answers `(method, url, body)` with a status code and JSON body.
+type Handler<'a> = dyn Fn(&str, &str, &[u8]) -> (u16, String) + 'a;
+
+/// Accept connections on `addr` forever, answering each request with
+/// `handler(method, url, body)`.
+#[cfg(feature = "server")]
+fn listen(addr: &str, handler: &Handler) -> Result<()> {
+    let server = tiny_http::Server::http(addr)
+        .map_err(|e| anyhow::anyhow!("cannot listen on {addr}: {e}"))?;
+    eprintln!("vibecheck: listening on http://{}", server.server_addr());
+    serve(server, handler);
+    Ok(())
+}
+
+/// Answer every request `server` receives with `handler`.
+#[cfg(feature = "server")]
+fn serve(server: tiny_http::Server, handler: &Handler) {
+    use std::io::Read;
+
+    /// Largest request body accepted, in bytes.
+    const MAX_BODY_BYTES: u64 = 8 * 1024 * 1024;
+
+    let content_type = tiny_http::Header::from_bytes("Content-Type", "application/json")
+        .expect("static header is valid");
+
+    for mut request in server.incoming_requests() {
+        let mut body = Vec::new();
+        let (status, json) = match request.as_reader().take(MAX_BODY_BYTES + 1).read_to_end(&mut body) {
+            Err(e) => error(400, format!("cannot read request body: {e}")),
+            Ok(_) if body.len() as u64 > MAX_BODY_BYTES => {
+                error(413, format!("request body exceeds {MAX_BODY_BYTES} bytes"))
+            }
+            Ok(_) => handler(request.method().as_str(), request.url(), &body),
+        };
+        let response = tiny_http::Response::from_string(json)
+            .with_status_code(status)
+            .with_header(content_type.clone());
+        if let Err(e) = request.respond(response) {
+            eprintln!("vibecheck: warning: failed to send response: {e}");
+        }
+    }
+}
+
+#[cfg(not(feature = "server"))]
+fn listen(addr: &str, _handler: &Handler) -> Result<()> {
+    anyhow::bail!(
+        "cannot serve on {addr}: vibecheck was built without the `server` feature \
+         (reinstall with `cargo install vibecheck-cli --features server`)"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    fn call(method: &str, url: &str, body: &str) -> (u16, Value) {
+        let (status, json) = handle(method, url, body.as_bytes(), &Pipeline::with_defaults());
+        (status, serde_json::from_str(&json).unwrap())
+    }
+
+    const SOURCE: &str = "/// Adds two numbers together.\nfn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n";
+
+    #[test]
+    fn analyze_returns_a_report() {
+        let body = json!({ "source": SOURCE, "lang": "rust" }).to_string();
+        let (status, report) = call("POST", "/analyze", &body);
+        assert_eq!(status, 200);
+        assert!(report["attribution"]["primary"].is_string());
+        assert!(report["metadata"]["file_path"].is_null());
+        assert!(report["symbol_reports"].is_null());
+    }
+
+    #[test]
+    fn analyze_symbols_fills_symbol_reports() {
+        let body = json!({ "source": SOURCE, "lang": "rs" }).to_string();
+        let (status, report) = call("POST", "/analyze-symbols", &body);
+        assert_eq!(status, 200);
+        assert_eq!(report["symbol_reports"][0]["metadata"]["name"], "add");
+    }
+
+    #[test]
+    fn heuristics_returns_the_catalogue() {
+        let (status, catalogue) = call("GET", "/heuristics?x=1", "");
+        assert_eq!(status, 200);
+        assert!(!catalogue.as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn bad_requests_get_error_bodies() {
+        assert_eq!(call("POST", "/analyze", "not json").0, 400);
+        let (status, body) = call("POST", "/analyze", r#"{"source": "", "lang": "cobol"}"#);
+        assert_eq!((status, body["error"].as_str()), (400, Some("unsupported lang: cobol")));
+        assert_eq!(call("GET", "/analyze", "").0, 405);
+        assert_eq!(call("GET", "/nope", "").0, 404);
+    }
+
+    #[cfg(not(feature = "server"))]
+    #[test]
+    fn serving_needs_the_feature() {
+        let err = run("127.0.0.1:0", &Default::default()).unwrap_err();
+        assert!(err.to_string().contains("--features server"), "{err}");
+    }
+
+    #[cfg(feature = "server")]
+    #[test]
+    fn serves_over_http() {
+        use std::io::{Read, Write};
+
+        // Bind before spawning so no other test can take the port between.
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let addr = server.server_addr().to_ip().unwrap();
+        std::thread::spawn(move || {
+            let pipeline = Pipeline::with_defaults();
+            serve(server, &|method, url, body| handle(method, url, body, &pipeline));
+        });
+
+        let body = json!({ "source": SOURCE, "lang": "rust" }).to_string();
+        let mut stream = std::net::TcpStream::connect(addr).unwrap();
+        write!(
+            stream,
+            "POST /analyze HTTP/1.1\r\nHost: x\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+        assert!(response.contains("application/json"));
+        assert!(response.contains(r#""attribution""#));
+    }
+}
@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde_json::json;
+
+use vibecheck_core::report::{ModelFamily, Report};
+use vibecheck_core::{ConfigOverrides, ExtensionFilter};
+
+use crate::commands::analyze::collect_files;
+
+/// How often one signal fired across a set of reports.
+#[derive(Debug, PartialEq)]
+struct SignalFrequency {
+    id: String,
+    family: ModelFamily,
+    /// Total firings; a signal may fire more than once per file.
+    fired: usize,
+    /// Files it fired in at least once.
+    files: usize,
+}
+
+/// Analyze every supported file under `path` and print how often each
+/// signal fired, noisiest first, as a table or (`format == "json"`) a
+/// JSON array.
+pub fn run(
+    path: &PathBuf,
+    no_cache: bool,
+    cache_dir: Option<&Path>,
+    overrides: &ConfigOverrides,
+    format: &str,
+    output: Option<&Path>,
+) -> Result<()> {
+    let ignore = overrides.load_config(path);
+    let files = collect_files(path, &ignore, true, &ExtensionFilter::default())
+        .context("failed to collect files")?;
+    if files.is_empty() {
+        anyhow::bail!("no supported source files found in {}", path.display());
+    }
+    let reports = files
+        .iter()
+        .map(|f| {
+            if no_cache {
+                vibecheck_core::analyze_file_no_cache_with_overrides(f, overrides)
+            } else {
+                vibecheck_core::analyze_file_with_cache_dir(f, cache_dir, overrides)
+            }
+        })
+        .collect::<std::io::Result<Vec<Report>>>()
+        .context("failed to analyze files")?;
+
+    let frequencies = tally(&reports);
+    let mut out = crate::output::open_output(output)?;
+    if format == "json" {
+        let rows: Vec<_> = frequencies
+            .iter()
+            .map(|f| json!({ "id": f.id, "family": f.family, "fired": f.fired, "files": f.files }))
+            .collect();
+        writeln!(out, "{}", serde_json::to_string_pretty(&rows)?)?;
+    } else {
+        write!(out, "{}", format_table(&frequencies, reports.len()))?;
+    }
+    out.finish()
+}
+
+/// Tally signal IDs over `reports`, sorted by firing count (descending),
+/// then file count, then ID.  Signals without an ID (from cache entries
+/// that predate IDs) are skipped.
+fn tally(reports: &[Report]) -> Vec<SignalFrequency> {
+    let mut by_id: HashMap<&str, SignalFrequency> = HashMap::new();
+    for report in reports {
+        let mut seen = std::collections::HashSet::new();
+        for signal in report.signals.iter().filter(|s| !s.id.is_empty()) {
+            let entry = by_id.entry(&signal.id).or_insert_with(|| SignalFrequency {
+                id: signal.id.clone(),
+                family: signal.family,
+                fired: 0,
+                files: 0,
+            });
+            entry.fired += 1;
+            if seen.insert(&signal.id) {
+                entry.files += 1;
+            }
+        }
+    }
+    let mut frequencies: Vec<SignalFrequency> = by_id.into_values().collect();
+    frequencies.sort_by(|a, b| {
+        b.fired
+            .cmp(&a.fired)
+            .then(b.files.cmp(&a.files))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    frequencies
+}
+
+fn format_table(frequencies: &[SignalFrequency], total_files: usize) -> String {
+    let mut out = format!(
+        "{:<48}  {:<8}  {:>6}  {:>6}  {:>6}\n",
+        "SIGNAL ID", "FAMILY", "FIRED", "FILES", "%FILES"
+    );
+    for f in frequencies {
+        out.push_str(&format!(
+            "{:<48}  {:<8}  {:>6}  {:>6}  {:>5.0}%\n",
+            f.id,
+            f.family.to_string(),
+            f.fired,
+            f.files,
+            f.files as f64 / total_files as f64 * 100.0,
+        ));
+    }
+    out.push_str(&format!("\n{} signals fired across {total_files} files\n", frequencies.len()));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vibecheck_core::report::Signal;
+
+    fn report_with(ids: &[&str]) -> Report {
+        let mut report = vibecheck_core::analyze("");
+        report.signals = ids
+            .iter()
+            .map(|id| Signal::new(id, "test", "", ModelFamily::Gpt, 1.0))
+            .collect();
+        report
+    }
+
+    #[test]
+    fn tally_counts_firings_and_files() {
+        let reports = [report_with(&["a", "b", "b"]), report_with(&["b", "c"]), report_with(&["a", ""])];
+        let counts: Vec<_> = tally(&reports).into_iter().map(|f| (f.id, f.fired, f.files)).collect();
+        assert_eq!(
+            counts,
+            [("b".to_string(), 3, 2), ("a".to_string(), 2, 2), ("c".to_string(), 1, 1)]
+        );
+    }
+
+    #[test]
+    fn table_shows_share_of_files() {
+        let table = format_table(&tally(&[report_with(&["a"]), report_with(&[])]), 2);
+        assert!(table.lines().nth(1).unwrap().ends_with("50%"), "{table}");
+        assert!(table.ends_with("1 signals fired across 2 files\n"), "{table}");
+    }
+
+    #[test]
+    fn run_rejects_directories_without_sources() {
+        let dir = tempfile::tempdir().unwrap();
+        let err = run(&dir.path().to_path_buf(), true, None, &Default::default(), "table", None).unwrap_err();
+        assert!(err.to_string().contains("no supported source files"), "{err}");
+    }
+}
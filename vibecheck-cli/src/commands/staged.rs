@@ -0,0 +1,271 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use git2::{Delta, Repository};
+
+use vibecheck_core::ignore_rules::IgnoreRules;
+use vibecheck_core::ExtensionFilter;
+
+
+/// Collect the staged content of every supported source file under `path`.
+///
+/// Compares the index against `HEAD` (or an empty tree before the first
+/// commit) and reads each added/modified file's blob straight from the
+/// object store, so unstaged working-tree edits are not seen.  Returns
+/// `(absolute path, staged content)` pairs sorted by path; deleted and
+/// non-UTF-8 files are skipped.
+pub fn staged_sources(path: &Path, ignore: &dyn IgnoreRules) -> Result<Vec<(PathBuf, String)>> {
+    let repo = Repository::discover(path)
+        .context("not inside a git repository (or no .git found)")?;
+    let workdir = repo
+        .workdir()
+        .context("bare repositories are not supported")?
+        .to_path_buf();
+    let workdir = workdir.canonicalize().unwrap_or(workdir);
+    let scope = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+
+    let head_tree = match repo.head() {
+        Ok(head) => Some(head.peel_to_tree()?),
+        Err(_) => None, // unborn branch — everything in the index is new
+    };
+    let diff = repo.diff_tree_to_index(head_tree.as_ref(), None, None)?;
+
+    let mut sources = Vec::new();
+    for delta in diff.deltas() {
+        if !matches!(
+            delta.status(),
+            Delta::Added | Delta::Modified | Delta::Renamed | Delta::Copied | Delta::Typechange
+        ) {
+            continue;
+        }
+        let new_file = delta.new_file();
+        let Some(relative) = new_file.path() else {
+            continue;
+        };
+        let abs = workdir.join(relative);
+        if !abs.starts_with(&scope) || !ExtensionFilter::default().allows_path(&abs) || ignore.is_ignored(&abs) {
+            continue;
+        }
+        let blob = repo.find_blob(new_file.id())?;
+        if let Ok(content) = std::str::from_utf8(blob.content()) {
+            sources.push((abs, content.to_string()));
+        }
+    }
+    sources.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(sources)
+}
+
+/// Read the staged content of each of `files` straight from the git index.
+///
+/// Relative `files` are resolved against `cwd`, which the pre-commit
+/// framework sets to the repository root.  Files that are not in the index,
+/// unsupported, ignored, or not UTF-8 are skipped.  Returns
+/// `(absolute path, staged content)` pairs in argument order.
+pub fn staged_blobs(
+    cwd: &Path,
+    files: &[PathBuf],
+    ignore: &dyn IgnoreRules,
+) -> Result<Vec<(PathBuf, String)>> {
+    let repo = Repository::discover(cwd)
+        .context("not inside a git repository (or no .git found)")?;
+    let workdir = repo
+        .workdir()
+        .context("bare repositories are not supported")?
+        .to_path_buf();
+    let workdir = workdir.canonicalize().unwrap_or(workdir);
+    let index = repo.index()?;
+
+    let mut sources = Vec::new();
+    for file in files {
+        let abs = cwd.join(file);
+        let abs = abs.canonicalize().unwrap_or(abs);
+        let Ok(relative) = abs.strip_prefix(&workdir) else {
+            continue;
+        };
+        if !ExtensionFilter::default().allows_path(&abs) || ignore.is_ignored(&abs) {
+            continue;
+        }
+        let Some(entry) = index.get_path(relative, 0) else {
+            continue;
+        };
+        let blob = repo.find_blob(entry.id)?;
+        if let Ok(content) = std::str::from_utf8(blob.content()) {
+            sources.push((abs, content.to_string()));
+        }
+    }
+    Ok(sources)
+}
+
+/// Collect every supported source file under `path` as of revision `rev`
+/// (a branch, tag, hash, or any other `git rev-parse` spec).
+///
+/// Blobs are read straight from the object store, so nothing is checked
+/// out and the working tree may differ freely, or lack `path` entirely.
+/// Returns `(absolute path, content at rev)` pairs sorted by path; ignored
+/// and non-UTF-8 files are skipped.
+pub fn ref_sources(path: &Path, rev: &str, ignore: &dyn IgnoreRules) -> Result<Vec<(PathBuf, String)>> {
+    // Canonicalize the part of `path` that still exists on disk.
+    let abs = std::path::absolute(path)?;
+    let (existing, scope) = abs
+        .ancestors()
+        .find_map(|a| {
+            let canonical = a.canonicalize().ok()?;
+            let scope = canonical.join(abs.strip_prefix(a).ok()?);
+            Some((canonical, scope))
+        })
+        .unwrap_or_else(|| (abs.clone(), abs.clone()));
+    let repo = Repository::discover(&existing)
+        .context("not inside a git repository (or no .git found)")?;
+    let workdir = repo
+        .workdir()
+        .context("bare repositories are not supported")?
+        .to_path_buf();
+    let workdir = workdir.canonicalize().unwrap_or(workdir);
+    let tree = repo
+        .revparse_single(rev)
+        .and_then(|object| object.peel_to_tree())
+        .with_context(|| format!("cannot resolve {rev} to a tree"))?;
+
+    let mut sources = Vec::new();
+    tree.walk(git2::TreeWalkMode::PreOrder, |root, entry| {
+        let Some(name) = entry.name() else {
+            return git2::TreeWalkResult::Skip;
+        };
+        let abs = workdir.join(root).join(name);
+        match entry.kind() {
+            // Only descend into directories on the way to (or inside) `path`.
+            Some(git2::ObjectType::Tree) if !abs.starts_with(&scope) && !scope.starts_with(&abs) => {
+                return git2::TreeWalkResult::Skip;
+            }
+            Some(git2::ObjectType::Blob) => {}
+            _ => return git2::TreeWalkResult::Ok,
+        }
+        if !abs.starts_with(&scope) || !ExtensionFilter::default().allows_path(&abs) || ignore.is_ignored(&abs) {
+            return git2::TreeWalkResult::Ok;
+        }
+        if let Ok(blob) = repo.find_blob(entry.id()) {
+            if let Ok(content) = std::str::from_utf8(blob.content()) {
+                sources.push((abs, content.to_string()));
+            }
+        }
+        git2::TreeWalkResult::Ok
+    })?;
+    sources.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(sources)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vibecheck_core::ignore_rules::PatternIgnore;
+
+    fn commit_all(repo: &Repository, message: &str) {
+        let mut index = repo.index().unwrap();
+        index.add_all(["*"], git2::IndexAddOption::DEFAULT, None).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let sig = git2::Signature::now("test", "test@example.com").unwrap();
+        let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+        let parents: Vec<_> = parent.iter().collect();
+        repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents).unwrap();
+    }
+
+    fn stage(repo: &Repository, file: &str) {
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new(file)).unwrap();
+        index.write().unwrap();
+    }
+
+    #[test]
+    fn reads_staged_content_not_working_tree() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        std::fs::write(dir.path().join("lib.rs"), "fn committed() {}\n").unwrap();
+        std::fs::write(dir.path().join("other.rs"), "fn untouched() {}\n").unwrap();
+        commit_all(&repo, "initial");
+
+        std::fs::write(dir.path().join("lib.rs"), "fn staged() {}\n").unwrap();
+        stage(&repo, "lib.rs");
+        std::fs::write(dir.path().join("lib.rs"), "fn unstaged() {}\n").unwrap();
+
+        let sources = staged_sources(dir.path(), &PatternIgnore(vec![])).unwrap();
+        assert_eq!(sources.len(), 1, "only the staged file: {sources:?}");
+        assert!(sources[0].0.ends_with("lib.rs"));
+        assert_eq!(sources[0].1, "fn staged() {}\n");
+    }
+
+    #[test]
+    fn works_before_first_commit_and_skips_unsupported_and_ignored() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        std::fs::write(dir.path().join("main.py"), "print('hi')\n").unwrap();
+        std::fs::write(dir.path().join("notes.md"), "# notes\n").unwrap();
+        std::fs::write(dir.path().join("generated.rs"), "fn g() {}\n").unwrap();
+        for f in ["main.py", "notes.md", "generated.rs"] {
+            stage(&repo, f);
+        }
+
+        let ignore = PatternIgnore(vec!["generated".into()]);
+        let sources = staged_sources(dir.path(), &ignore).unwrap();
+        let names: Vec<_> = sources
+            .iter()
+            .map(|(p, _)| p.file_name().unwrap().to_str().unwrap())
+            .collect();
+        assert_eq!(names, vec!["main.py"]);
+    }
+
+    #[test]
+    fn staged_blobs_reads_index_for_listed_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        std::fs::write(dir.path().join("a.rs"), "fn staged() {}\n").unwrap();
+        std::fs::write(dir.path().join("b.rs"), "fn b() {}\n").unwrap();
+        std::fs::write(dir.path().join("notes.md"), "# notes\n").unwrap();
+        stage(&repo, "a.rs");
+        stage(&repo, "notes.md");
+        std::fs::write(dir.path().join("a.rs"), "fn unstaged() {}\n").unwrap();
+
+        let files: Vec<PathBuf> = ["a.rs", "b.rs", "notes.md"].iter().map(PathBuf::from).collect();
+        let sources = staged_blobs(dir.path(), &files, &PatternIgnore(vec![])).unwrap();
+        assert_eq!(sources.len(), 1, "only the staged source file: {sources:?}");
+        assert!(sources[0].0.ends_with("a.rs"));
+        assert_eq!(sources[0].1, "fn staged() {}\n");
+    }
+
+    #[test]
+    fn ref_sources_reads_the_tree_at_a_revision() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        std::fs::create_dir_all(dir.path().join("src/nested")).unwrap();
+        std::fs::create_dir(dir.path().join("docs")).unwrap();
+        std::fs::write(dir.path().join("src/lib.rs"), "fn old() {}\n").unwrap();
+        std::fs::write(dir.path().join("src/nested/gone.py"), "x = 1\n").unwrap();
+        std::fs::write(dir.path().join("docs/example.go"), "package main\n").unwrap();
+        commit_all(&repo, "initial");
+        let first = repo.head().unwrap().peel_to_commit().unwrap().id().to_string();
+
+        std::fs::write(dir.path().join("src/lib.rs"), "fn new() {}\n").unwrap();
+        std::fs::remove_dir_all(dir.path().join("src/nested")).unwrap();
+        let mut index = repo.index().unwrap();
+        index.remove_dir(Path::new("src/nested"), 0).unwrap();
+        index.write().unwrap();
+        commit_all(&repo, "second");
+        std::fs::write(dir.path().join("src/lib.rs"), "fn uncommitted() {}\n").unwrap();
+
+        let ignore = PatternIgnore(vec![]);
+        let head = ref_sources(&dir.path().join("src"), "HEAD", &ignore).unwrap();
+        assert_eq!(head.len(), 1, "{head:?}");
+        assert_eq!(head[0].1, "fn new() {}\n");
+
+        let old = ref_sources(&dir.path().join("src"), &first[..8], &ignore).unwrap();
+        let names: Vec<_> = old.iter().map(|(p, _)| p.strip_prefix(dir.path().canonicalize().unwrap()).unwrap()).collect();
+        assert_eq!(names, [Path::new("src/lib.rs"), Path::new("src/nested/gone.py")]);
+        assert_eq!(old[0].1, "fn old() {}\n");
+
+        let nested = ref_sources(&dir.path().join("src/nested"), "HEAD~1", &ignore).unwrap();
+        assert_eq!(nested.len(), 1, "a path deleted from the working tree still resolves");
+
+        let err = ref_sources(dir.path(), "no-such-branch", &ignore).unwrap_err();
+        assert!(err.to_string().contains("cannot resolve no-such-branch"), "{err}");
+    }
+}
@@ -18,8 +18,8 @@ use ratatui::{
     Frame, Terminal,
 };
 
-use vibecheck_core::ignore_rules::{IgnoreConfig, IgnoreRules};
 use vibecheck_core::report::{ModelFamily, Report, SymbolReport};
+use vibecheck_core::{ConfigOverrides, DirOptions};
 
 // ---------------------------------------------------------------------------
 // Data model
@@ -96,6 +96,10 @@ struct App {
     history_loading: bool,
     /// Receives the loaded history from the background thread.
     history_rx: Option<mpsc::Receiver<Vec<HistoryEntry>>>,
+    /// Explicit cache directory (`--cache-dir`), if any.
+    cache_dir: Option<PathBuf>,
+    /// Applied on top of each file's discovered config.
+    overrides: ConfigOverrides,
 }
 
 impl App {
@@ -122,16 +126,17 @@ impl App {
             history_cursor: 0,
             history_loading: false,
             history_rx: None,
+            cache_dir: None,
+            overrides: ConfigOverrides::default(),
         }
     }
 
-    fn new(all: Vec<FlatEntry>) -> Self {
+    fn new(all: Vec<FlatEntry>, cache_dir: Option<PathBuf>, overrides: ConfigOverrides) -> Self {
         let mut list_state = ListState::default();
         list_state.select(Some(0));
-        let detail = all
-            .first()
-            .filter(|e| !e.is_dir)
-            .and_then(|e| vibecheck_core::analyze_file_symbols(&e.path).ok());
+        let detail = all.first().filter(|e| !e.is_dir).and_then(|e| {
+            vibecheck_core::analyze_file_symbols_with_cache_dir(&e.path, cache_dir.as_deref(), &overrides).ok()
+        });
         App {
             all,
             collapsed: HashSet::new(),
@@ -151,6 +156,8 @@ impl App {
             history_cursor: 0,
             history_loading: false,
             history_rx: None,
+            cache_dir,
+            overrides,
         }
     }
 
@@ -208,10 +215,10 @@ impl App {
 
     fn refresh_detail(&mut self) {
         let visible = self.visible();
-        self.detail = visible
-            .get(self.selected)
-            .filter(|e| !e.is_dir)
-            .and_then(|e| vibecheck_core::analyze_file_symbols(&e.path).ok());
+        self.detail = visible.get(self.selected).filter(|e| !e.is_dir).and_then(|e| {
+            vibecheck_core::analyze_file_symbols_with_cache_dir(&e.path, self.cache_dir.as_deref(), &self.overrides)
+                .ok()
+        });
         self.detail_scroll = 0;
         self.detail_scroll_x = 0;
         // Close the history panel when navigating to a different file.
@@ -434,27 +441,18 @@ fn aggregate_dir(dir: &Path, reports: &BTreeMap<&Path, &Report>) -> (ModelFamily
 }
 
 fn name_to_family(name: &str) -> ModelFamily {
-    match name.to_lowercase().as_str() {
-        "claude" => ModelFamily::Claude,
-        "gpt" => ModelFamily::Gpt,
-        "gemini" => ModelFamily::Gemini,
-        "copilot" => ModelFamily::Copilot,
-        _ => ModelFamily::Human,
-    }
+    ModelFamily::from_name(name).unwrap_or(ModelFamily::Human)
 }
 
 // ---------------------------------------------------------------------------
 // Rendering
 // ---------------------------------------------------------------------------
 
+/// The family's [`ModelFamily::rgb`], shared with the SVG and terminal
+/// renderers (and recolored by `[colors]` in `.vibecheck`).
 fn family_color(family: ModelFamily) -> Color {
-    match family {
-        ModelFamily::Claude  => Color::Rgb(210, 168, 255), // #d2a8ff
-        ModelFamily::Gpt     => Color::Rgb(126, 231, 135), // #7ee787
-        ModelFamily::Gemini  => Color::Rgb(121, 192, 255), // #79c0ff
-        ModelFamily::Copilot => Color::Rgb( 57, 197, 207), // #39c5cf
-        ModelFamily::Human   => Color::Rgb(227, 179,  65), // #e3b341
-    }
+    let (r, g, b) = family.rgb();
+    Color::Rgb(r, g, b)
 }
 
 fn family_abbrev(family: ModelFamily) -> &'static str {
@@ -587,7 +585,7 @@ fn render_symbol_lines(symbols: &[SymbolReport]) -> Vec<Line<'static>> {
         let color = family_color(sym.attribution.primary);
         let kind_label = sym.metadata.kind_label();
         let name = sym.metadata.display_name(22);
-        lines.push(Line::from(vec![
+        let mut spans = vec![
             Span::styled(
                 format!("  {:<8}", kind_label),
                 Style::default().fg(Color::DarkGray),
@@ -599,7 +597,20 @@ fn render_symbol_lines(symbols: &[SymbolReport]) -> Vec<Line<'static>> {
                 format!("{} {:.0}%", family_abbrev(sym.attribution.primary), sym.attribution.confidence * 100.0),
                 Style::default().fg(color),
             ),
-        ]));
+        ];
+        // Reports cached before complexity was recorded deserialize as zeros.
+        if sym.metadata.loc > 0 {
+            spans.push(Span::styled(
+                format!(
+                    "  cc {} · depth {} · {} loc",
+                    sym.metadata.cyclomatic_complexity,
+                    sym.metadata.max_nesting_depth,
+                    sym.metadata.loc,
+                ),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        lines.push(Line::from(spans));
     }
     lines
 }
@@ -962,21 +973,27 @@ fn git_history_for_file(path: &Path) -> anyhow::Result<Vec<HistoryEntry>> {
 // Entry point
 // ---------------------------------------------------------------------------
 
-pub fn run(path: &Path, ignore_file: Option<&PathBuf>) -> Result<()> {
-    let ignore: Box<dyn IgnoreRules> = match ignore_file {
-        Some(f) => Box::new(IgnoreConfig::from_file(f)?),
-        None => Box::new(IgnoreConfig::load(path)),
-    };
+pub fn run(
+    path: &Path,
+    overrides: &ConfigOverrides,
+    cache_dir: Option<&Path>,
+) -> Result<()> {
+    let ignore = overrides.load_config(path);
 
     // Analyze all files up front (cache-backed, so fast on repeat runs).
     eprintln!("Analyzing {}…", path.display());
-    let reports = vibecheck_core::analyze_directory_with(path, true, ignore.as_ref())?;
+    let options = DirOptions {
+        cache_dir: cache_dir.map(Path::to_path_buf),
+        overrides: overrides.clone(),
+        ..DirOptions::default()
+    };
+    let reports = vibecheck_core::analyze_directory_with_options(path, true, &ignore, &options)?;
     if reports.is_empty() {
         anyhow::bail!("no supported source files found in {}", path.display());
     }
 
     let flat = build_flat_tree(path, &reports);
-    let mut app = App::new(flat);
+    let mut app = App::new(flat, options.cache_dir, options.overrides);
 
     // Set up terminal.
     enable_raw_mode()?;
@@ -1101,7 +1118,17 @@ mod tests {
         Report {
             attribution: Attribution { primary: family, confidence, scores },
             signals: vec![],
-            metadata: ReportMetadata { file_path: None, lines_of_code: loc, signal_count: 0 },
+            metadata: ReportMetadata {
+                file_path: None,
+                lines_of_code: loc,
+                signal_count: 0,
+                analyzer_version: String::new(),
+                generated: false,
+                timed_out: false,
+                parse_errors: 0,
+                language: None,
+                lossy: false,
+            },
             symbol_reports: None,
         }
     }
@@ -1113,6 +1140,7 @@ mod tests {
                 kind: kind.to_string(),
                 start_line: 1,
                 end_line: 10,
+                ..Default::default()
             },
             attribution: Attribution {
                 primary: family,
@@ -1258,6 +1286,19 @@ mod tests {
         assert_eq!(render_symbol_lines(&syms).len(), 5);
     }
 
+    #[test]
+    fn symbol_lines_show_complexity_when_known() {
+        let mut sym = make_sym("f", "function", ModelFamily::Claude, 0.5);
+        let row = format!("{:?}", render_symbol_lines(std::slice::from_ref(&sym))[2]);
+        assert!(!row.contains("cc "), "no complexity for legacy cache entries: {row}");
+
+        sym.metadata.cyclomatic_complexity = 4;
+        sym.metadata.max_nesting_depth = 3;
+        sym.metadata.loc = 12;
+        let row = format!("{:?}", render_symbol_lines(&[sym])[2]);
+        assert!(row.contains("cc 4 · depth 3 · 12 loc"), "row: {row}");
+    }
+
     // -------------------------------------------------------------------------
     // name_to_family
     // -------------------------------------------------------------------------
@@ -1277,6 +1318,16 @@ mod tests {
         assert_eq!(name_to_family(""),        ModelFamily::Human);
     }
 
+    #[test]
+    fn name_to_family_resolves_registered_custom() {
+        let family = ModelFamily::custom("tui-internal-model");
+        assert_eq!(name_to_family("tui-internal-model"), family);
+        assert_eq!(family_color(family), {
+            let (r, g, b) = vibecheck_core::colors::CUSTOM_FAMILY_RGB;
+            Color::Rgb(r, g, b)
+        });
+    }
+
     // -------------------------------------------------------------------------
     // family_abbrev / family_color — smoke tests
     // -------------------------------------------------------------------------
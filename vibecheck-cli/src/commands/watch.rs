@@ -1,27 +1,35 @@
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::process::Command;
 use std::sync::mpsc;
 use std::time::{Duration, Instant};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
+use globset::{Glob, GlobSet, GlobSetBuilder};
 use notify::{Config, RecommendedWatcher, RecursiveMode, Watcher};
 
-use vibecheck_core::ignore_rules::{IgnoreConfig, IgnoreRules};
-use vibecheck_core::output::OutputFormat;
+use vibecheck_core::ignore_rules::IgnoreRules;
+use vibecheck_core::report::{Attribution, ModelFamily};
+use vibecheck_core::{ConfigOverrides, ExtensionFilter};
 
 use crate::commands::analyze::format_report;
+use crate::output::OutputFormat;
 
 const DEBOUNCE: Duration = Duration::from_millis(300);
 /// Minimum gap between two analyses of the same file. Prevents re-analysis
 /// from late-arriving OS events (kernel batching, atomic-rename sequences).
 const COOLDOWN: Duration = Duration::from_secs(2);
-const SUPPORTED_EXTS: &[&str] = &["rs", "py", "js", "ts", "jsx", "tsx", "go"];
 
-pub fn run(path: &Path, no_cache: bool, ignore_file: Option<&PathBuf>) -> Result<()> {
-    let ignore: Box<dyn IgnoreRules> = match ignore_file {
-        Some(f) => Box::new(IgnoreConfig::from_file(f)?),
-        None => Box::new(IgnoreConfig::load(path)),
-    };
+pub fn run(
+    path: &Path,
+    no_cache: bool,
+    overrides: &ConfigOverrides,
+    cache_dir: Option<&Path>,
+    run_cmd: Option<&str>,
+    watch_globs: &[String],
+) -> Result<()> {
+    let ignore = overrides.load_config(path);
+    let globs = build_glob_set(watch_globs)?;
 
     let (tx, rx) = mpsc::channel();
     let mut watcher = RecommendedWatcher::new(tx, Config::default())?;
@@ -29,12 +37,18 @@ pub fn run(path: &Path, no_cache: bool, ignore_file: Option<&PathBuf>) -> Result
 
     let abs = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
     println!("Watching {} — Ctrl+C to stop\n", abs.display());
+    let exts = ExtensionFilter::default();
+    let wanted = |p: &Path| {
+        exts.allows_path(p) && !ignore.is_ignored(p) && matches_globs(globs.as_ref(), &abs, p)
+    };
 
     // Debounce: collect events for DEBOUNCE duration, then process unique paths.
     let mut pending: HashSet<PathBuf> = HashSet::new();
     let mut deadline: Option<Instant> = None;
     // Per-file cooldown: skip re-analysis if the file was analyzed < COOLDOWN ago.
     let mut last_analyzed: HashMap<PathBuf, Instant> = HashMap::new();
+    // Last primary family seen per file, so `--run` only fires on a flip.
+    let mut last_family: HashMap<PathBuf, ModelFamily> = HashMap::new();
 
     loop {
         // Block for up to DEBOUNCE, collecting events.
@@ -45,7 +59,7 @@ pub fn run(path: &Path, no_cache: bool, ignore_file: Option<&PathBuf>) -> Result
         match rx.recv_timeout(timeout) {
             Ok(Ok(event)) => {
                 for p in event.paths {
-                    if is_supported(&p) && !ignore.is_ignored(&p) {
+                    if wanted(&p) {
                         pending.insert(p);
                         deadline.get_or_insert_with(|| Instant::now() + DEBOUNCE);
                     }
@@ -70,7 +84,15 @@ pub fn run(path: &Path, no_cache: bool, ignore_file: Option<&PathBuf>) -> Result
                     continue;
                 }
                 last_analyzed.insert(p.clone(), now);
-                analyze_and_print(p, no_cache);
+                let Some(attribution) = analyze_and_print(p, no_cache, cache_dir, overrides) else {
+                    continue;
+                };
+                let previous = last_family.insert(p.clone(), attribution.primary);
+                if let Some(cmd) = run_cmd {
+                    if attribution_changed(previous, attribution.primary) {
+                        run_hook(cmd, p, &attribution);
+                    }
+                }
             }
             // Drain events that accumulated during analysis. Keep any for
             // *different* files (user saved a second file while the first was
@@ -78,7 +100,7 @@ pub fn run(path: &Path, no_cache: bool, ignore_file: Option<&PathBuf>) -> Result
             let just_ran: HashSet<&PathBuf> = paths.iter().collect();
             while let Ok(Ok(event)) = rx.try_recv() {
                 for p in event.paths {
-                    if is_supported(&p) && !ignore.is_ignored(&p) && !just_ran.contains(&p) {
+                    if wanted(&p) && !just_ran.contains(&p) {
                         pending.insert(p);
                     }
                 }
@@ -94,53 +116,123 @@ pub fn run(path: &Path, no_cache: bool, ignore_file: Option<&PathBuf>) -> Result
     Ok(())
 }
 
-fn is_supported(path: &Path) -> bool {
-    path.extension()
-        .and_then(|e| e.to_str())
-        .map(|e| SUPPORTED_EXTS.contains(&e))
-        .unwrap_or(false)
+/// Compile `--watch-glob` patterns; `None` when none were given, meaning
+/// every supported file is watched.
+fn build_glob_set(patterns: &[String]) -> Result<Option<GlobSet>> {
+    if patterns.is_empty() {
+        return Ok(None);
+    }
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        builder.add(Glob::new(pattern).with_context(|| format!("invalid --watch-glob `{pattern}`"))?);
+    }
+    Ok(Some(builder.build()?))
+}
+
+/// Whether `path` matches `globs`, tried relative to the watched `root`
+/// (notify reports absolute paths) and then as given.
+fn matches_globs(globs: Option<&GlobSet>, root: &Path, path: &Path) -> bool {
+    let Some(globs) = globs else {
+        return true;
+    };
+    path.strip_prefix(root).is_ok_and(|rel| globs.is_match(rel)) || globs.is_match(path)
 }
 
-fn analyze_and_print(path: &Path, no_cache: bool) {
+/// Analyze `path` and print the report. Returns the attribution on success.
+fn analyze_and_print(
+    path: &Path,
+    no_cache: bool,
+    cache_dir: Option<&Path>,
+    overrides: &ConfigOverrides,
+) -> Option<Attribution> {
     let now = chrono_now();
-    let analyze: fn(&Path) -> std::io::Result<vibecheck_core::report::Report> = if no_cache {
-        vibecheck_core::analyze_file_no_cache
+    let result = if no_cache {
+        vibecheck_core::analyze_file_no_cache_with_overrides(path, overrides)
     } else {
-        vibecheck_core::analyze_file
+        vibecheck_core::analyze_file_with_cache_dir(path, cache_dir, overrides)
     };
 
-    match analyze(path) {
+    match result {
         Ok(report) => {
             println!("[{now}] {}", path.display());
             print!("{}", format_report(&report, OutputFormat::Pretty));
+            Some(report.attribution)
         }
         Err(e) => {
             eprintln!("[{now}] {} — error: {e}", path.display());
+            None
+        }
+    }
+}
+
+/// The first analysis of a file only records a baseline; the hook fires when
+/// a later analysis lands on a different primary family.
+fn attribution_changed(previous: Option<ModelFamily>, current: ModelFamily) -> bool {
+    previous.is_some_and(|p| p != current)
+}
+
+/// Build the `--run` command with the attribution exported as env vars.
+fn hook_command(cmd: &str, path: &Path, attribution: &Attribution) -> Command {
+    let mut command = if cfg!(windows) {
+        let mut c = Command::new("cmd");
+        c.arg("/C");
+        c
+    } else {
+        let mut c = Command::new("sh");
+        c.arg("-c");
+        c
+    };
+    command
+        .arg(cmd)
+        .env("VIBECHECK_PATH", path)
+        .env("VIBECHECK_FAMILY", attribution.primary.to_string().to_lowercase())
+        .env("VIBECHECK_CONFIDENCE", format!("{:.2}", attribution.confidence));
+    command
+}
+
+fn run_hook(cmd: &str, path: &Path, attribution: &Attribution) {
+    match hook_command(cmd, path, attribution).status() {
+        Ok(status) if !status.success() => {
+            eprintln!("--run command exited with {status}");
         }
+        Ok(_) => {}
+        Err(e) => eprintln!("--run command failed to start: {e}"),
     }
 }
 
+fn chrono_now() -> String {
+    // Use std time to avoid adding the chrono dep.
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let h = (secs % 86400) / 3600;
+    let m = (secs % 3600) / 60;
+    let s = secs % 60;
+    format!("{h:02}:{m:02}:{s:02}")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn is_supported_known_extensions() {
-        assert!(is_supported(Path::new("main.rs")));
-        assert!(is_supported(Path::new("script.py")));
-        assert!(is_supported(Path::new("app.js")));
-        assert!(is_supported(Path::new("types.ts")));
-        assert!(is_supported(Path::new("component.jsx")));
-        assert!(is_supported(Path::new("component.tsx")));
-        assert!(is_supported(Path::new("server.go")));
+    fn watch_globs_match_relative_to_root() {
+        let root = Path::new("/repo");
+        let globs = build_glob_set(&["src/**/*.rs".into(), "*.py".into()]).unwrap();
+        assert!(matches_globs(globs.as_ref(), root, Path::new("/repo/src/a/lib.rs")));
+        assert!(matches_globs(globs.as_ref(), root, Path::new("/repo/tools/gen.py")));
+        assert!(!matches_globs(globs.as_ref(), root, Path::new("/repo/target/out.rs")));
+        assert!(!matches_globs(globs.as_ref(), root, Path::new("/repo/tests/fixtures/x.rs")));
     }
 
     #[test]
-    fn is_supported_rejects_unsupported() {
-        assert!(!is_supported(Path::new("README.md")));
-        assert!(!is_supported(Path::new("Cargo.toml")));
-        assert!(!is_supported(Path::new("image.png")));
-        assert!(!is_supported(Path::new("noext")));
+    fn no_watch_globs_match_everything() {
+        let globs = build_glob_set(&[]).unwrap();
+        assert!(globs.is_none());
+        assert!(matches_globs(globs.as_ref(), Path::new("/repo"), Path::new("/repo/target/out.rs")));
+        assert!(build_glob_set(&["src/[".into()]).is_err());
     }
 
     #[test]
@@ -152,22 +244,27 @@ mod tests {
     }
 
     #[test]
-    fn supported_exts_includes_all_expected() {
-        assert_eq!(SUPPORTED_EXTS.len(), 7);
-        assert!(SUPPORTED_EXTS.contains(&"rs"));
-        assert!(SUPPORTED_EXTS.contains(&"tsx"));
+    fn attribution_changed_only_on_family_flip() {
+        assert!(!attribution_changed(None, ModelFamily::Claude));
+        assert!(!attribution_changed(Some(ModelFamily::Claude), ModelFamily::Claude));
+        assert!(attribution_changed(Some(ModelFamily::Human), ModelFamily::Claude));
     }
-}
 
-fn chrono_now() -> String {
-    // Use std time to avoid adding the chrono dep.
-    use std::time::{SystemTime, UNIX_EPOCH};
-    let secs = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs();
-    let h = (secs % 86400) / 3600;
-    let m = (secs % 3600) / 60;
-    let s = secs % 60;
-    format!("{h:02}:{m:02}:{s:02}")
+    #[cfg(unix)]
+    #[test]
+    fn hook_command_exports_attribution_env() {
+        let attribution = Attribution {
+            primary: ModelFamily::Gpt,
+            confidence: 0.8234,
+            scores: HashMap::new(),
+        };
+        let output = hook_command(
+            "echo \"$VIBECHECK_PATH $VIBECHECK_FAMILY $VIBECHECK_CONFIDENCE\"",
+            Path::new("src/main.rs"),
+            &attribution,
+        )
+        .output()
+        .unwrap();
+        assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "src/main.rs gpt 0.82");
+    }
 }
@@ -1,12 +1,14 @@
 #![deny(dead_code)]
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::{Args, Parser, Subcommand};
 
+mod archive;
 mod commands;
 mod output;
+mod remote;
 
 // ---------------------------------------------------------------------------
 // CLI definition
@@ -29,35 +31,111 @@ mod output;
                   vibecheck src/ --format json         Analyze a directory as JSON\n  \
                   vibecheck src/ --assert-family human  CI gate: fail if AI-generated\n  \
                   vibecheck analyze --symbols src/lib.rs  Symbol-level attribution\n  \
+                  vibecheck src/ --profile strict      Weight AI tells more heavily\n  \
                   vibecheck heuristics --format toml   Dump signal weights as TOML",
+    // The shared flags below apply to a top-level path, like the rest.
+    mut_arg("no_cache", |a| a.requires("path")),
+    mut_arg("cache_dir", |a| a.requires("path")),
+    mut_arg("output", |a| a.requires("path")),
 )]
 struct Cli {
     #[command(subcommand)]
     command: Option<Command>,
 
-    /// File or directory to analyze (shorthand for `vibecheck analyze <path>`).
+    /// File, directory, or http(s) URL to analyze (shorthand for
+    /// `vibecheck analyze <path>`).
     path: Option<PathBuf>,
 
-    /// Output format: pretty (colored), text (plain), or json (machine-readable).
+    /// Output format: pretty (colored), text (plain), json, jsonl (one compact
+    /// JSON object per line), or yaml (machine-readable).
     #[arg(long, default_value = "pretty", requires = "path")]
     format: String,
 
     /// Exit 1 if any file is NOT attributed to one of these families.
-    /// Comma-separated, e.g. `--assert-family claude,gpt`
+    /// Comma-separated, e.g. `--assert-family claude,gpt`.  Replaces the
+    /// `[assert]` section of `.vibecheck` for this run.
     #[arg(long, value_delimiter = ',', requires = "path")]
     assert_family: Option<Vec<String>>,
 
-    /// Skip the content-addressed cache (always re-analyze).
+    /// Exit 1 if any file hit tree-sitter parse errors or the parse timeout,
+    /// i.e. its verdict rests on degraded syntax-tree signals.
     #[arg(long, requires = "path")]
-    no_cache: bool,
+    fail_on_parse_error: bool,
+
+    #[command(flatten)]
+    cache: CacheArgs,
+
+    /// Analyze files that are not valid UTF-8 instead of failing on them,
+    /// replacing each invalid byte sequence with U+FFFD. Such reports are
+    /// marked `lossy`.
+    #[arg(long, requires = "path", conflicts_with = "symbols")]
+    lossy: bool,
 
     /// Perform symbol-level analysis and show per-function attribution.
+    /// With `--format json`, print a flat array of symbol spans and verdicts
+    /// instead of the full reports.
     #[arg(long, requires = "path")]
     symbols: bool,
 
-    /// Path to a `.vibecheck` config file (default: auto-discovered from project root).
+    /// Under each text/pretty report, list where line-specific signals
+    /// fired (line:column) with the offending source line.
+    #[arg(short, long, requires = "path")]
+    verbose: bool,
+
+    /// Analyze only the files directly inside the given directory; do not
+    /// descend into subdirectories.
     #[arg(long, requires = "path")]
-    ignore_file: Option<PathBuf>,
+    no_recurse: bool,
+
+    /// Section text/pretty output: none, family (one section per primary
+    /// family), or dir (one per directory). JSON and YAML are unaffected.
+    #[arg(long, default_value = "none", requires = "path", value_parser = commands::analyze::parse_group_by)]
+    group_by: output::GroupBy,
+
+    /// Print each file's raw per-family weight sums (before normalization)
+    /// and contributing signals to stderr.
+    #[arg(long, requires = "path")]
+    debug_scores: bool,
+
+    /// Print only a JSON array of `{path, family, confidence}` verdicts,
+    /// without signals or scores.
+    #[arg(long, requires = "path", conflicts_with_all = ["format", "group_by"])]
+    minimal: bool,
+
+    /// Render each report through this Jinja template instead of `--format`,
+    /// one line per file, e.g. `'{{ path }}: {{ family }} {{ confidence }}'`.
+    #[arg(long, requires = "path", conflicts_with_all = ["format", "group_by", "minimal"])]
+    template: Option<String>,
+
+    /// Print nothing on success; with `--assert-family`, print only the
+    /// failing files and a one-line summary to stderr. Exit codes are
+    /// unchanged.
+    #[arg(long, requires = "path", conflicts_with_all = ["format", "group_by", "minimal", "template"])]
+    quiet: bool,
+
+    /// Only analyze files with these extensions when walking a directory.
+    /// Comma-separated, e.g. `--include-ext rs,py`
+    #[arg(long, value_delimiter = ',', requires = "path", value_parser = commands::analyze::parse_ext)]
+    include_ext: Option<Vec<String>>,
+
+    /// Skip files with these extensions when walking a directory.
+    /// Comma-separated, e.g. `--exclude-ext go,cs`
+    #[arg(long, value_delimiter = ',', requires = "path", value_parser = commands::analyze::parse_ext)]
+    exclude_ext: Vec<String>,
+
+    #[command(flatten)]
+    output: OutputArgs,
+
+    /// Explicit `.vibecheck`-format TOML config, used for every section
+    /// (`[ignore]`, `[heuristics]`, `[cache]`, …) instead of searching upward
+    /// from the analyzed path. Handy in CI when the config lives elsewhere.
+    #[arg(long, global = true, visible_alias = "ignore-file")]
+    config: Option<PathBuf>,
+
+    /// Built-in weight profile: strict, balanced, or lenient. Overrides the
+    /// `.vibecheck` `profile` key; explicit `[heuristics]` weights still win.
+    #[arg(long, global = true, value_parser = commands::heuristics::parse_profile)]
+    profile: Option<String>,
 }
 
 #[derive(Subcommand)]
@@ -65,14 +143,20 @@ enum Command {
     /// Analyze a file or directory for AI-generated code.
     #[command(
         long_about = "Analyze source files for AI-generated code patterns and attribute each \
-                      file to a model family. Supports Rust, Python, JavaScript, and Go.\n\n\
+                      file to a model family. Supports Rust, Python, JavaScript, Go, and C#.\n\n\
                       By default, results are cached by file content hash (SHA-256). Use \
-                      --no-cache to force re-analysis. Use --symbols for per-function attribution.",
+                      --no-cache to force re-analysis. Use --symbols for per-function attribution. \
+                      Use --staged to check only the contents staged in the git index, \
+                      --ref to check the tree at another revision without checking it out, or \
+                      --input-list to check exactly the files another tool lists.",
         after_help = "EXAMPLES:\n  \
                       vibecheck analyze src/main.rs\n  \
                       vibecheck analyze src/ --format json\n  \
                       vibecheck analyze src/ --assert-family human --no-cache\n  \
-                      vibecheck analyze --symbols src/lib.rs",
+                      vibecheck analyze --symbols src/lib.rs\n  \
+                      vibecheck analyze --staged --assert-family human\n  \
+                      vibecheck analyze src/ --ref main\n  \
+                      git diff --name-only | vibecheck analyze --input-list -",
     )]
     Analyze(AnalyzeArgs),
 
@@ -98,18 +182,27 @@ enum Command {
         long_about = "Monitor a file or directory for changes using OS file-system events \
                       (inotify/kqueue/FSEvents). On each save, re-analyze the changed file \
                       and print the updated attribution to stdout. Uses a 300ms debounce \
-                      and 2s per-file cooldown.",
+                      and 2s per-file cooldown. With --run, executes a shell command whenever \
+                      a file's primary family differs from its previous analysis.",
+        after_help = "EXAMPLES:\n  \
+                      vibecheck watch src/\n  \
+                      vibecheck watch src/ --run 'notify-send \"$VIBECHECK_PATH → $VIBECHECK_FAMILY\"'",
     )]
     Watch(WatchArgs),
 
     /// Walk git history and show per-commit attribution over time.
     #[command(
-        long_about = "Replay git history for a file and show how attribution changed over \
-                      commits. Reads blobs directly from the git object store (no working-tree \
-                      checkout). Prints a table: COMMIT | DATE | FAMILY | CONFIDENCE | CHANGE.",
+        long_about = "Replay git history for a file or directory and show how attribution \
+                      changed over commits. Reads blobs directly from the git object store (no \
+                      working-tree checkout). For a directory, each row is the line-weighted \
+                      aggregate of its source files at commits that changed something under it. \
+                      Prints a table: COMMIT | DATE | FAMILY | CONFIDENCE | CHANGE (plus FILES for \
+                      a directory), or a JSON array of the same rows with --format json.",
         after_help = "EXAMPLES:\n  \
                       vibecheck history src/pipeline.rs\n  \
-                      vibecheck history src/lib.rs --limit 5",
+                      vibecheck history src/lib.rs --limit 5\n  \
+                      vibecheck history src/ --since 2w\n  \
+                      vibecheck history src/lib.rs --format json",
     )]
     History(HistoryArgs),
 
@@ -117,39 +210,347 @@ enum Command {
     #[command(
         long_about = "Display the full catalogue of detection heuristics. Each signal has a \
                       stable ID, weight, and target model family. Use --format toml to generate \
-                      a block ready to paste into your .vibecheck config for weight overrides.",
+                      a block ready to paste into your .vibecheck config for weight overrides. \
+                      --examples <family> instead prints a small Rust file assembled from \
+                      fragments that each trip one of that family's signals; --check appends \
+                      its verdict and which targeted signals fired. --diff <config> analyzes a \
+                      sample directory under both the current config and the proposed one and \
+                      lists the files whose primary family would change.",
         after_help = "EXAMPLES:\n  \
                       vibecheck heuristics\n  \
-                      vibecheck heuristics --format toml",
+                      vibecheck heuristics --format toml\n  \
+                      vibecheck heuristics --examples gpt --check\n  \
+                      vibecheck heuristics --diff proposed.toml --sample src/",
     )]
     Heuristics(HeuristicsArgs),
+
+    /// Count how often each signal fires across a directory.
+    #[command(
+        name = "signals-freq",
+        long_about = "Analyze every supported file under a directory and list each signal that \
+                      fired with its total firing count and the number (and share) of files it \
+                      appeared in, noisiest first. Signals that fire almost everywhere carry \
+                      little information; use this to decide which weights to tune.",
+        after_help = "EXAMPLES:\n  \
+                      vibecheck signals-freq src/\n  \
+                      vibecheck signals-freq corpus/ --format json",
+    )]
+    SignalsFreq(SignalsFreqArgs),
+
+    /// List the files most likely to be AI-generated, highest first.
+    #[command(
+        long_about = "Analyze every supported file under a directory and list the ones most \
+                      likely to be AI-generated, ranked by their highest non-human family score \
+                      (or, with --family, by that family's score). Generated files and files too \
+                      small to attribute are left out. Use it to decide where to look first.",
+        after_help = "EXAMPLES:\n  \
+                      vibecheck rank src/\n  \
+                      vibecheck rank src/ --limit 5 --family gpt\n  \
+                      vibecheck rank src/ --format json",
+    )]
+    Rank(RankArgs),
+
+    /// Attribute a file line by line and show it as a heatmap.
+    #[command(
+        long_about = "Analyze overlapping windows of a file (--window lines each, a quarter \
+                      window apart) and label every line with the family whose averaged score \
+                      is highest across the windows covering it. Prints the source with a \
+                      colored family gutter, or a JSON array of {line, family, score} with \
+                      --format json. Finer than --symbols; useful for spotting an AI-inserted \
+                      block inside a human-written file. Lines whose windows are too small to \
+                      attribute are left blank.",
+        after_help = "EXAMPLES:\n  \
+                      vibecheck heatmap src/lib.rs\n  \
+                      vibecheck heatmap src/lib.rs --window 40\n  \
+                      vibecheck heatmap src/lib.rs --format json",
+    )]
+    Heatmap(HeatmapArgs),
+
+    /// Score attribution against a labeled corpus.
+    #[command(
+        long_about = "Analyze every file under a labeled corpus and report precision, recall, \
+                      and F1 per family, the support-weighted F1, and a confusion matrix of \
+                      true against predicted family. A file's true family is its name \
+                      (claude.rs) or the nearest directory named after a family (gpt/lru.py); \
+                      unlabeled files are skipped. Run it before and after changing weights to \
+                      see whether attribution improved. With --record, the labeled files also \
+                      build a corpus store that `[corpus] store` in .vibecheck turns into \
+                      weight priors.",
+        after_help = "EXAMPLES:\n  \
+                      vibecheck eval vibecheck-core/tests/fixtures\n  \
+                      vibecheck eval corpus/ --config tuned.toml --no-cache\n  \
+                      vibecheck eval corpus/ --format json | jq .weighted_f1\n  \
+                      vibecheck eval corpus/ --record corpus.db",
+    )]
+    Eval(EvalArgs),
+
+    /// Analyze JSON Lines snippets from stdin, streaming results to stdout.
+    #[command(
+        long_about = "Read one JSON object per line from stdin, each with an `id`, a `lang` \
+                      (rust, python, javascript, typescript, jsx, tsx, go, csharp), and the \
+                      `source` to analyze. Writes one line per request: `{\"id\", \"report\"}`, \
+                      or `{\"id\", \"error\"}` for a malformed request. Lines are processed \
+                      and flushed one at a time, so input of any size streams.",
+        after_help = "EXAMPLES:\n  \
+                      echo '{\"id\":\"1\",\"lang\":\"rust\",\"source\":\"fn main() {}\"}' | vibecheck batch\n  \
+                      vibecheck batch < snippets.jsonl > results.jsonl",
+    )]
+    Batch,
+
+    /// Run a Language Server over stdio for editor integration.
+    #[command(
+        long_about = "Speak the Language Server Protocol over stdin/stdout. Documents are \
+                      analyzed in memory when opened and saved; the file attribution and \
+                      every non-human symbol are published as diagnostics, and the full \
+                      report (with per-symbol breakdown) is sent as a `vibecheck/report` \
+                      notification for clients that want to render it themselves.",
+        after_help = "EXAMPLES:\n  \
+                      vibecheck lsp    Configure your editor to launch this command",
+    )]
+    Lsp,
+
+    /// Serve the analyzer over HTTP for services in other languages.
+    #[command(
+        long_about = "Run a small HTTP server exposing the in-memory analyzer. `POST /analyze` \
+                      takes `{\"source\", \"lang\"}` and returns the JSON report; \
+                      `POST /analyze-symbols` returns the same report with per-symbol results; \
+                      `GET /heuristics` returns the signal catalogue. Requests never touch the \
+                      filesystem. Requires the `server` feature.",
+        after_help = "EXAMPLES:\n  \
+                      vibecheck serve\n  \
+                      vibecheck serve --addr 0.0.0.0:8080\n  \
+                      curl -d '{\"lang\":\"rust\",\"source\":\"fn main() {}\"}' localhost:7878/analyze",
+    )]
+    Serve(ServeArgs),
+
+    /// Inspect the analysis cache.
+    #[command(subcommand)]
+    Cache(CacheCommand),
+
+    /// Diagnose config discovery, config problems, and the cache location.
+    #[command(
+        long_about = "Explain what a run from PATH would pick up: which `.vibecheck` file is \
+                      discovered (or the --config file), anything in it that is silently \
+                      ignored (invalid TOML, unknown sections, unknown signal IDs in \
+                      [heuristics], bad profile, [size_buckets], or [colors] values), the \
+                      active weight profile, the enclosing git repository, and the cache \
+                      directory with its size.",
+        after_help = "EXAMPLES:\n  \
+                      vibecheck doctor\n  \
+                      vibecheck doctor src/ --config ci/.vibecheck",
+    )]
+    Doctor(DoctorArgs),
+
+    /// Check staged files passed by the pre-commit framework.
+    #[command(
+        name = "pre-commit",
+        long_about = "Analyze the staged (index) contents of the given files and exit 1 if any \
+                      is attributed to a family outside --assert-family. Prints one line per \
+                      offending file and nothing on success. Unsupported and ignored files are \
+                      skipped, so the hook can receive every staged path.",
+        after_help = "EXAMPLES:\n  \
+                      vibecheck pre-commit --assert-family human src/lib.rs src/main.rs\n\n\
+                      .pre-commit-config.yaml:\n  \
+                      - repo: https://github.com/o-k-a-y/vibecheck\n    \
+                        rev: v0.6.0\n    \
+                        hooks:\n      \
+                          - id: vibecheck\n        \
+                            args: [--assert-family, human]",
+    )]
+    PreCommit(PreCommitArgs),
+}
+
+#[derive(Subcommand)]
+enum CacheCommand {
+    /// Show entry count, size on disk, oldest entry age, and hit/miss counts.
+    #[command(
+        long_about = "Report on the analysis cache: how many entries it holds, the size of \
+                      its database file, how long ago the oldest entry was written, and how \
+                      many report lookups hit or missed across every run that used it. \
+                      Useful before deciding whether to clear it.",
+        after_help = "EXAMPLES:\n  \
+                      vibecheck cache stats\n  \
+                      vibecheck cache stats --cache-dir /tmp/vc --format json",
+    )]
+    Stats(CacheStatsArgs),
 }
 
+/// `--no-cache` and `--cache-dir`, for commands that analyze through the
+/// cache.
 #[derive(Args)]
-struct AnalyzeArgs {
-    /// File or directory to analyze.
+struct CacheArgs {
+    /// Skip the content-addressed cache (always re-analyze).
+    #[arg(long)]
+    no_cache: bool,
+
+    #[command(flatten)]
+    dir: CacheDirArgs,
+}
+
+/// `--cache-dir`, for commands that use the cache.
+#[derive(Args)]
+struct CacheDirArgs {
+    /// Cache directory (default: `[cache] dir` from `.vibecheck`, then
+    /// `VIBECHECK_CACHE_DIR`, then the platform cache dir).
+    #[arg(long)]
+    cache_dir: Option<PathBuf>,
+}
+
+/// `--output`, for commands that print a report.
+#[derive(Args)]
+struct OutputArgs {
+    /// Write results to FILE instead of stdout, creating parent directories
+    /// as needed (e.g. a JSON report kept as a CI artifact). The file is
+    /// replaced only once the run succeeds.
+    #[arg(short, long, value_name = "FILE")]
+    output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct CacheStatsArgs {
+    #[command(flatten)]
+    cache: CacheDirArgs,
+
+    /// Output format: `text` (default) or `json`.
+    #[arg(long, default_value = "text")]
+    format: String,
+
+    #[command(flatten)]
+    output: OutputArgs,
+}
+
+#[derive(Args)]
+struct DoctorArgs {
+    /// Directory (or file) to diagnose a run from.
+    #[arg(default_value = ".")]
     path: PathBuf,
 
-    /// Output format: pretty (colored), text (plain), or json (machine-readable).
+    #[command(flatten)]
+    cache: CacheDirArgs,
+}
+
+#[derive(Args)]
+struct ServeArgs {
+    /// Address to listen on.
+    #[arg(long, default_value = "127.0.0.1:7878", value_name = "HOST:PORT")]
+    addr: String,
+}
+
+#[derive(Args)]
+struct PreCommitArgs {
+    /// Files to check, as passed by pre-commit.
+    files: Vec<PathBuf>,
+
+    /// Families a file may be attributed to. Comma-separated, e.g.
+    /// `--assert-family human,claude`
+    #[arg(long, value_delimiter = ',', required = true)]
+    assert_family: Vec<String>,
+}
+
+#[derive(Args)]
+struct AnalyzeArgs {
+    /// File or directory to analyze (with `--staged` or `--ref`, limits the
+    /// check to files under this path; defaults to the whole repository).
+    #[arg(required_unless_present_any = ["staged", "git_ref", "input_list"])]
+    path: Option<PathBuf>,
+
+    /// Output format: pretty (colored), text (plain), json, jsonl (one compact
+    /// JSON object per line), or yaml (machine-readable).
     #[arg(long, default_value = "pretty")]
     format: String,
 
     /// Exit 1 if any file is NOT attributed to one of these families.
-    /// Comma-separated, e.g. `--assert-family claude,gpt,human`
+    /// Comma-separated, e.g. `--assert-family claude,gpt,human`.  Replaces the
+    /// `[assert]` section of `.vibecheck` for this run.
     #[arg(long, value_delimiter = ',')]
     assert_family: Option<Vec<String>>,
 
-    /// Skip the content-addressed cache (always re-analyze).
+    /// Exit 1 if any file hit tree-sitter parse errors or the parse timeout,
+    /// i.e. its verdict rests on degraded syntax-tree signals.
     #[arg(long)]
-    no_cache: bool,
+    fail_on_parse_error: bool,
+
+    #[command(flatten)]
+    cache: CacheArgs,
+
+    /// Analyze files that are not valid UTF-8 instead of failing on them,
+    /// replacing each invalid byte sequence with U+FFFD. Such reports are
+    /// marked `lossy`. Applies to files read from disk.
+    #[arg(long, conflicts_with_all = ["symbols", "staged", "git_ref"])]
+    lossy: bool,
 
     /// Perform symbol-level analysis (per-function/method attribution).
+    /// With `--format json`, print a flat array of symbol spans and verdicts
+    /// instead of the full reports.
     #[arg(long)]
     symbols: bool,
 
-    /// Path to a `.vibecheck` config file (default: auto-discovered from project root).
+    /// Under each text/pretty report, list where line-specific signals
+    /// fired (line:column) with the offending source line.
+    #[arg(short, long)]
+    verbose: bool,
+
+    /// Analyze only files staged in the git index, reading their staged
+    /// contents rather than the working tree. Intended for pre-commit hooks.
+    #[arg(long, conflicts_with = "symbols")]
+    staged: bool,
+
+    /// Analyze the files as of this git revision (branch, tag, or commit),
+    /// reading them from the object store without checking anything out.
+    #[arg(long = "ref", value_name = "REV", conflicts_with_all = ["staged", "no_recurse"])]
+    git_ref: Option<String>,
+
+    /// Analyze only the files directly inside the given directory; do not
+    /// descend into subdirectories.
+    #[arg(long, conflicts_with = "staged")]
+    no_recurse: bool,
+
+    /// Analyze exactly the files listed in FILE, one path per line (`-` reads
+    /// stdin), without walking any directory, e.g.
+    /// `git diff --name-only | vibecheck analyze --input-list -`.
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["path", "staged", "git_ref", "no_recurse"])]
+    input_list: Option<PathBuf>,
+
+    /// Section text/pretty output: none, family (one section per primary
+    /// family), or dir (one per directory). JSON and YAML are unaffected.
+    #[arg(long, default_value = "none", value_parser = commands::analyze::parse_group_by)]
+    group_by: output::GroupBy,
+
+    /// Print each file's raw per-family weight sums (before normalization)
+    /// and contributing signals to stderr.
     #[arg(long)]
-    ignore_file: Option<PathBuf>,
+    debug_scores: bool,
+
+    /// Print only a JSON array of `{path, family, confidence}` verdicts,
+    /// without signals or scores.
+    #[arg(long, conflicts_with_all = ["format", "group_by"])]
+    minimal: bool,
+
+    /// Render each report through this Jinja template instead of `--format`,
+    /// one line per file, e.g. `'{{ path }}: {{ family }} {{ confidence }}'`.
+    #[arg(long, conflicts_with_all = ["format", "group_by", "minimal"])]
+    template: Option<String>,
+
+    /// Print nothing on success; with `--assert-family`, print only the
+    /// failing files and a one-line summary to stderr. Exit codes are
+    /// unchanged.
+    #[arg(long, conflicts_with_all = ["format", "group_by", "minimal", "template"])]
+    quiet: bool,
+
+    /// Only analyze files with these extensions when walking a directory or
+    /// reading `--input-list`.
+    /// Comma-separated, e.g. `--include-ext rs,py`
+    #[arg(long, value_delimiter = ',', value_parser = commands::analyze::parse_ext)]
+    include_ext: Option<Vec<String>>,
+
+    /// Skip files with these extensions when walking a directory or reading
+    /// `--input-list`.
+    /// Comma-separated, e.g. `--exclude-ext go,cs`
+    #[arg(long, value_delimiter = ',', value_parser = commands::analyze::parse_ext)]
+    exclude_ext: Vec<String>,
+
+    #[command(flatten)]
+    output: OutputArgs,
 }
 
 #[derive(Args)]
@@ -157,9 +558,8 @@ struct TuiArgs {
     /// Directory to browse.
     path: PathBuf,
 
-    /// Path to a `.vibecheck` config file (default: auto-discovered from project root).
-    #[arg(long)]
-    ignore_file: Option<PathBuf>,
+    #[command(flatten)]
+    cache: CacheDirArgs,
 }
 
 #[derive(Args)]
@@ -167,23 +567,116 @@ struct WatchArgs {
     /// File or directory to watch.
     path: PathBuf,
 
-    /// Skip the cache (always re-analyze on each change).
-    #[arg(long)]
-    no_cache: bool,
+    #[command(flatten)]
+    cache: CacheArgs,
 
-    /// Path to a `.vibecheck` config file (default: auto-discovered from project root).
-    #[arg(long)]
-    ignore_file: Option<PathBuf>,
+    /// Shell command to run when a file's primary family changes. Receives
+    /// `VIBECHECK_PATH`, `VIBECHECK_FAMILY`, and `VIBECHECK_CONFIDENCE`.
+    #[arg(long, value_name = "SHELL_CMD")]
+    run: Option<String>,
+
+    /// Only re-analyze changed files matching this glob, relative to the
+    /// watched path (repeatable), e.g. `--watch-glob 'src/**/*.rs'`.
+    /// Applied on top of the ignore rules.
+    #[arg(long, value_name = "PATTERN")]
+    watch_glob: Vec<String>,
 }
 
 #[derive(Args)]
 struct HistoryArgs {
-    /// File whose git history to replay.
+    /// File or directory whose git history to replay.
     path: PathBuf,
 
     /// Maximum number of commits to show (default: 20).
     #[arg(long, short = 'n', default_value = "20")]
     limit: usize,
+
+    /// Only show commits newer than this age, e.g. `7d` or `2w`. Combines
+    /// with `--limit`; whichever is reached first stops the walk.
+    #[arg(long, value_parser = commands::history::parse_duration)]
+    since: Option<std::time::Duration>,
+
+    /// Output format: `table` (default) or `json`.
+    #[arg(long, default_value = "table")]
+    format: String,
+}
+
+#[derive(Args)]
+struct SignalsFreqArgs {
+    /// Directory (or file) to analyze.
+    path: PathBuf,
+
+    /// Output format: `table` (default) or `json`.
+    #[arg(long, default_value = "table")]
+    format: String,
+
+    #[command(flatten)]
+    cache: CacheArgs,
+
+    #[command(flatten)]
+    output: OutputArgs,
+}
+
+#[derive(Args)]
+struct RankArgs {
+    /// Directory (or file) to analyze.
+    path: PathBuf,
+
+    /// Maximum number of files to list (default: 20).
+    #[arg(long, short = 'n', default_value = "20")]
+    limit: usize,
+
+    /// Rank by this family's score instead of the highest non-human one.
+    #[arg(long, value_name = "FAMILY")]
+    family: Option<String>,
+
+    /// Output format: `table` (default) or `json`.
+    #[arg(long, default_value = "table")]
+    format: String,
+
+    #[command(flatten)]
+    cache: CacheArgs,
+
+    #[command(flatten)]
+    output: OutputArgs,
+}
+
+#[derive(Args)]
+struct HeatmapArgs {
+    /// File to attribute.
+    path: PathBuf,
+
+    /// Lines analyzed together in each window (default: 20).  Larger
+    /// windows are steadier, smaller ones sharper.
+    #[arg(long, default_value_t = vibecheck_core::pipeline::DEFAULT_HEATMAP_WINDOW)]
+    window: usize,
+
+    /// Output format: pretty (default), text (no colors), or json.
+    #[arg(long, default_value = "pretty")]
+    format: String,
+}
+
+#[derive(Args)]
+struct EvalArgs {
+    /// Corpus directory whose files or directories are named after their
+    /// true family.
+    corpus: PathBuf,
+
+    /// Output format: `table` (default) or `json`.
+    #[arg(long, default_value = "table")]
+    format: String,
+
+    #[command(flatten)]
+    cache: CacheArgs,
+
+    #[command(flatten)]
+    output: OutputArgs,
+
+    /// Also add each labeled file, with the signals that fired in it, to
+    /// the corpus store DB, for `[corpus]` weight priors.  Needs the
+    /// `corpus` feature.
+    #[arg(long, value_name = "DB")]
+    record: Option<PathBuf>,
 }
 
 #[derive(Args)]
@@ -191,12 +684,226 @@ struct HeuristicsArgs {
     /// Output format: `table` (default) or `toml`.
     #[arg(long, default_value = "table")]
     format: String,
+
+    /// Print a small Rust file written to trip FAMILY's signals instead of
+    /// the catalogue.
+    #[arg(long, value_name = "FAMILY")]
+    examples: Option<String>,
+
+    /// With --examples, also analyze the example and list which of its
+    /// targeted signals fired.
+    #[arg(long, requires = "examples")]
+    check: bool,
+
+    /// Analyze --sample under the current config and under CONFIG and list
+    /// the files whose primary family would change.
+    #[arg(long, value_name = "CONFIG", conflicts_with = "examples")]
+    diff: Option<PathBuf>,
+
+    /// Directory analyzed by --diff.
+    #[arg(long, value_name = "DIR", default_value = ".", requires = "diff")]
+    sample: PathBuf,
 }
 
 // ---------------------------------------------------------------------------
 // Dispatch
 // ---------------------------------------------------------------------------
 
+impl Cli {
+    /// The file, directory, or corpus the command analyzes, if it takes one.
+    fn target_path(&self) -> Option<&Path> {
+        match &self.command {
+            Some(Command::Analyze(a)) => a.path.as_deref(),
+            Some(Command::Tui(a)) => Some(&a.path),
+            Some(Command::Watch(a)) => Some(&a.path),
+            Some(Command::History(a)) => Some(&a.path),
+            Some(Command::SignalsFreq(a)) => Some(&a.path),
+            Some(Command::Rank(a)) => Some(&a.path),
+            Some(Command::Heatmap(a)) => Some(&a.path),
+            Some(Command::Eval(a)) => Some(&a.corpus),
+            Some(Command::Doctor(a)) => Some(&a.path),
+            Some(_) => None,
+            None => self.path.as_deref(),
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let overrides =
+        vibecheck_core::ConfigOverrides { config_file: cli.config.clone(), profile: cli.profile.clone() };
+    let start = match cli.target_path() {
+        Some(path) => path.to_path_buf(),
+        None => std::env::current_dir()?,
+    };
+    let doctor = matches!(cli.command, Some(Command::Doctor(_)));
+    let config = match &cli.config {
+        // `doctor` reports a broken --config file itself instead of failing.
+        Some(config) if doctor => vibecheck_core::ignore_rules::IgnoreConfig::from_file(config)
+            .unwrap_or_else(|_| vibecheck_core::ignore_rules::IgnoreConfig::load(&start)),
+        // Fail fast on a bad path; `overrides` hands the file to every
+        // per-file analysis instead of searching upward.
+        Some(config) => vibecheck_core::ignore_rules::IgnoreConfig::from_file(config)
+            .with_context(|| format!("failed to load config {}", config.display()))?,
+        None => vibecheck_core::ignore_rules::IgnoreConfig::load(&start),
+    };
+    // Run-wide settings come from `--config` when given, else from the
+    // `.vibecheck` governing the analyzed path (the current directory for
+    // commands without one).  `[colors]` then applies to every renderer for
+    // the rest of the run; nested configs further down do not change it.
+    vibecheck_core::colors::set_family_colors(config.family_colors());
+    // Loading a config never prints; report its problems once here.
+    // `doctor` lists them itself.
+    if !doctor {
+        for problem in config.diagnostics() {
+            eprintln!("vibecheck: warning: {problem}");
+        }
+    }
+
+    let result = match cli.command {
+        Some(Command::Analyze(a)) => commands::analyze::run(
+            &a.path.unwrap_or_else(|| PathBuf::from(".")),
+            commands::analyze::AnalyzeOptions {
+                format: &a.format,
+                no_cache: a.cache.no_cache,
+                lossy: a.lossy,
+                symbols: a.symbols,
+                verbose: a.verbose,
+                assert_family: a.assert_family,
+                fail_on_parse_error: a.fail_on_parse_error,
+                overrides: &overrides,
+                cache_dir: a.cache.dir.cache_dir.as_deref(),
+                staged: a.staged,
+                git_ref: a.git_ref.as_deref(),
+                input_list: a.input_list.as_deref(),
+                no_recurse: a.no_recurse,
+                group_by: a.group_by,
+                debug_scores: a.debug_scores,
+                minimal: a.minimal,
+                template: a.template.as_deref(),
+                quiet: a.quiet,
+                exts: vibecheck_core::ExtensionFilter::new(a.include_ext, a.exclude_ext),
+                output: a.output.output.as_deref(),
+            },
+        ),
+
+        Some(Command::Tui(a)) => {
+            commands::tui::run(&a.path, &overrides, a.cache.cache_dir.as_deref())
+        }
+
+        Some(Command::Watch(a)) => commands::watch::run(
+            &a.path,
+            a.cache.no_cache,
+            &overrides,
+            a.cache.dir.cache_dir.as_deref(),
+            a.run.as_deref(),
+            &a.watch_glob,
+        ),
+
+        Some(Command::History(a)) => {
+            commands::history::run(&a.path, Some(a.limit), a.since, &overrides, &a.format)
+        }
+
+        Some(Command::Heuristics(a)) => match (&a.examples, &a.diff) {
+            (Some(family), _) => commands::heuristics::run_examples(family, a.check),
+            (None, Some(proposed)) => commands::heuristics::run_diff(
+                proposed,
+                &a.sample,
+                &overrides,
+                &a.format,
+            ),
+            (None, None) => commands::heuristics::run(&a.format),
+        },
+
+        Some(Command::SignalsFreq(a)) => commands::signals_freq::run(
+            &a.path,
+            a.cache.no_cache,
+            a.cache.dir.cache_dir.as_deref(),
+            &overrides,
+            &a.format,
+            a.output.output.as_deref(),
+        ),
+
+        Some(Command::Rank(a)) => commands::rank::run(
+            &a.path,
+            commands::rank::RankOptions {
+                limit: a.limit,
+                family: a.family.as_deref(),
+                no_cache: a.cache.no_cache,
+                cache_dir: a.cache.dir.cache_dir.as_deref(),
+                overrides: &overrides,
+                format: &a.format,
+                output: a.output.output.as_deref(),
+            },
+        ),
+
+        Some(Command::Heatmap(a)) => commands::heatmap::run(&a.path, a.window, &a.format, &overrides),
+
+        Some(Command::Eval(a)) => commands::eval::run(
+            &a.corpus,
+            a.cache.no_cache,
+            a.cache.dir.cache_dir.as_deref(),
+            &overrides,
+            &a.format,
+            a.output.output.as_deref(),
+            a.record.as_deref(),
+        ),
+
+        Some(Command::Batch) => commands::batch::run(&overrides),
+
+        Some(Command::Lsp) => commands::lsp::run(overrides),
+
+        Some(Command::Serve(a)) => commands::serve::run(&a.addr, &overrides),
+
+        Some(Command::Cache(CacheCommand::Stats(a))) => {
+            let output = a.output.output.as_deref();
+            commands::cache::stats(a.cache.cache_dir.as_deref(), &overrides, &a.format, output)
+        }
+
+        Some(Command::Doctor(a)) => {
+            commands::doctor::run(&a.path, &overrides, a.cache.cache_dir.as_deref())
+        }
+
+        Some(Command::PreCommit(a)) => {
+            commands::pre_commit::run(&a.files, &a.assert_family, &overrides)
+        }
+
+        None => match cli.path {
+            Some(path) => commands::analyze::run(
+                &path,
+                commands::analyze::AnalyzeOptions {
+                    format: &cli.format,
+                    no_cache: cli.cache.no_cache,
+                    lossy: cli.lossy,
+                    symbols: cli.symbols,
+                    verbose: cli.verbose,
+                    assert_family: cli.assert_family,
+                    fail_on_parse_error: cli.fail_on_parse_error,
+                    overrides: &overrides,
+                    cache_dir: cli.cache.dir.cache_dir.as_deref(),
+                    staged: false,
+                    git_ref: None,
+                    input_list: None,
+                    no_recurse: cli.no_recurse,
+                    group_by: cli.group_by,
+                    debug_scores: cli.debug_scores,
+                    minimal: cli.minimal,
+                    template: cli.template.as_deref(),
+                    quiet: cli.quiet,
+                    exts: vibecheck_core::ExtensionFilter::new(cli.include_ext, cli.exclude_ext),
+                    output: cli.output.output.as_deref(),
+                },
+            ),
+            None => {
+                let cwd = std::env::current_dir()?;
+                commands::tui::run(&cwd, &overrides, None)
+            }
+        },
+    };
+    vibecheck_core::cache::Cache::flush_lookups();
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -217,51 +924,378 @@ mod tests {
     }
 
     #[test]
-    fn cli_has_all_subcommands() {
-        let cmd = Cli::command();
-        let names: Vec<_> = cmd.get_subcommands().map(|s| s.get_name().to_string()).collect();
-        assert!(names.contains(&"analyze".to_string()));
-        assert!(names.contains(&"tui".to_string()));
-        assert!(names.contains(&"watch".to_string()));
-        assert!(names.contains(&"history".to_string()));
-        assert!(names.contains(&"heuristics".to_string()));
+    fn cache_dir_flag_parses_on_caching_commands() {
+        for args in [
+            &["vibecheck", "src", "--cache-dir", "/tmp/vc"][..],
+            &["vibecheck", "analyze", "src", "--cache-dir", "/tmp/vc"][..],
+            &["vibecheck", "tui", "src", "--cache-dir", "/tmp/vc"][..],
+            &["vibecheck", "watch", "src", "--cache-dir", "/tmp/vc"][..],
+        ] {
+            assert!(Cli::try_parse_from(args).is_ok(), "failed to parse {args:?}");
+        }
     }
-}
 
-fn main() -> Result<()> {
-    let cli = Cli::parse();
+    #[test]
+    fn watch_run_flag_parses() {
+        let cli = Cli::try_parse_from(["vibecheck", "watch", "src", "--run", "echo hi"]).unwrap();
+        match cli.command {
+            Some(Command::Watch(a)) => assert_eq!(a.run.as_deref(), Some("echo hi")),
+            _ => panic!("expected watch subcommand"),
+        }
+    }
 
-    match cli.command {
-        Some(Command::Analyze(a)) => commands::analyze::run(
-            &a.path,
-            &a.format,
-            a.no_cache,
-            a.symbols,
-            a.assert_family,
-            a.ignore_file.as_ref(),
-        ),
+    #[test]
+    fn watch_glob_is_repeatable() {
+        let cli = Cli::try_parse_from([
+            "vibecheck", "watch", "src", "--watch-glob", "src/**/*.rs", "--watch-glob", "*.py",
+        ])
+        .unwrap();
+        match cli.command {
+            Some(Command::Watch(a)) => assert_eq!(a.watch_glob, ["src/**/*.rs", "*.py"]),
+            _ => panic!("expected watch subcommand"),
+        }
+    }
 
-        Some(Command::Tui(a)) => commands::tui::run(&a.path, a.ignore_file.as_ref()),
+    #[test]
+    fn history_since_flag_parses_duration() {
+        let cli = Cli::try_parse_from(["vibecheck", "history", "src", "--since", "2w"]).unwrap();
+        match cli.command {
+            Some(Command::History(a)) => {
+                assert_eq!(a.since, Some(std::time::Duration::from_secs(14 * 86400)));
+            }
+            _ => panic!("expected history subcommand"),
+        }
+        assert!(Cli::try_parse_from(["vibecheck", "history", "src", "--since", "soon"]).is_err());
+    }
 
-        Some(Command::Watch(a)) => commands::watch::run(&a.path, a.no_cache, a.ignore_file.as_ref()),
+    #[test]
+    fn history_format_defaults_to_table() {
+        let cli = Cli::try_parse_from(["vibecheck", "history", "src"]).unwrap();
+        match cli.command {
+            Some(Command::History(a)) => assert_eq!(a.format, "table"),
+            _ => panic!("expected history subcommand"),
+        }
+        let cli = Cli::try_parse_from(["vibecheck", "history", "src", "--format", "json"]).unwrap();
+        match cli.command {
+            Some(Command::History(a)) => assert_eq!(a.format, "json"),
+            _ => panic!("expected history subcommand"),
+        }
+    }
 
-        Some(Command::History(a)) => commands::history::run(&a.path, Some(a.limit)),
+    #[test]
+    fn analyze_no_recurse_flag() {
+        let cli = Cli::try_parse_from(["vibecheck", "analyze", "src/", "--no-recurse"]).unwrap();
+        match cli.command {
+            Some(Command::Analyze(a)) => assert!(a.no_recurse),
+            _ => panic!("expected analyze subcommand"),
+        }
+        let cli = Cli::try_parse_from(["vibecheck", "src/", "--no-recurse"]).unwrap();
+        assert!(cli.no_recurse);
+        assert!(Cli::try_parse_from(["vibecheck", "analyze", "--staged", "--no-recurse"]).is_err());
+    }
 
-        Some(Command::Heuristics(a)) => commands::heuristics::run(&a.format),
+    #[test]
+    fn config_flag_is_global_with_ignore_file_alias() {
+        let cli = Cli::try_parse_from(["vibecheck", "analyze", "src/", "--config", "ci/vibecheck.toml"]).unwrap();
+        assert_eq!(cli.config, Some(PathBuf::from("ci/vibecheck.toml")));
+        let cli = Cli::try_parse_from(["vibecheck", "src/", "--ignore-file", ".vibecheck"]).unwrap();
+        assert_eq!(cli.config, Some(PathBuf::from(".vibecheck")));
+        let cli = Cli::try_parse_from(["vibecheck", "batch", "--config", "ci/vibecheck.toml"]).unwrap();
+        assert!(matches!(cli.command, Some(Command::Batch)));
+        assert!(cli.config.is_some());
+    }
 
-        None => match cli.path {
-            Some(path) => commands::analyze::run(
-                &path,
-                &cli.format,
-                cli.no_cache,
-                cli.symbols,
-                cli.assert_family,
-                cli.ignore_file.as_ref(),
-            ),
-            None => {
-                let cwd = std::env::current_dir()?;
-                commands::tui::run(&cwd, None)
+    #[test]
+    fn signals_freq_subcommand() {
+        match Cli::try_parse_from(["vibecheck", "signals-freq", "src/", "--format", "json"]).unwrap().command {
+            Some(Command::SignalsFreq(a)) => {
+                assert_eq!(a.path, PathBuf::from("src/"));
+                assert_eq!(a.format, "json");
+                assert!(!a.cache.no_cache);
             }
-        },
+            _ => panic!("expected signals-freq subcommand"),
+        }
+    }
+
+    #[test]
+    fn rank_subcommand() {
+        match Cli::try_parse_from(["vibecheck", "rank", "src/", "-n", "5", "--family", "gpt"]).unwrap().command {
+            Some(Command::Rank(a)) => {
+                assert_eq!(a.path, PathBuf::from("src/"));
+                assert_eq!((a.limit, a.family.as_deref()), (5, Some("gpt")));
+                assert_eq!(a.format, "table");
+            }
+            _ => panic!("expected rank subcommand"),
+        }
+    }
+
+    #[test]
+    fn heatmap_subcommand() {
+        match Cli::try_parse_from(["vibecheck", "heatmap", "src/lib.rs", "--window", "40"]).unwrap().command {
+            Some(Command::Heatmap(a)) => {
+                assert_eq!((a.path, a.window), (PathBuf::from("src/lib.rs"), 40));
+                assert_eq!(a.format, "pretty");
+            }
+            _ => panic!("expected heatmap subcommand"),
+        }
+        assert!(Cli::try_parse_from(["vibecheck", "heatmap"]).is_err());
+    }
+
+    #[test]
+    fn eval_subcommand() {
+        match Cli::try_parse_from(["vibecheck", "eval", "corpus/", "--no-cache"]).unwrap().command {
+            Some(Command::Eval(a)) => {
+                assert_eq!(a.corpus, PathBuf::from("corpus/"));
+                assert_eq!(a.format, "table");
+                assert!(a.cache.no_cache);
+            }
+            _ => panic!("expected eval subcommand"),
+        }
+        assert!(Cli::try_parse_from(["vibecheck", "eval"]).is_err());
+    }
+
+    #[test]
+    fn cache_stats_subcommand() {
+        match Cli::try_parse_from(["vibecheck", "cache", "stats", "--cache-dir", "/tmp/vc"]).unwrap().command {
+            Some(Command::Cache(CacheCommand::Stats(a))) => {
+                assert_eq!(a.cache.cache_dir, Some(PathBuf::from("/tmp/vc")));
+                assert_eq!(a.format, "text");
+            }
+            _ => panic!("expected cache stats subcommand"),
+        }
+        assert!(Cli::try_parse_from(["vibecheck", "cache"]).is_err());
+    }
+
+    #[test]
+    fn doctor_subcommand() {
+        match Cli::try_parse_from(["vibecheck", "doctor"]).unwrap().command {
+            Some(Command::Doctor(a)) => {
+                assert_eq!(a.path, PathBuf::from("."));
+                assert!(a.cache.cache_dir.is_none());
+            }
+            _ => panic!("expected doctor subcommand"),
+        }
+        match Cli::try_parse_from(["vibecheck", "--config", "c.toml", "doctor", "src/"]).unwrap().command {
+            Some(Command::Doctor(a)) => assert_eq!(a.path, PathBuf::from("src/")),
+            _ => panic!("expected doctor subcommand"),
+        }
+    }
+
+    #[test]
+    fn serve_addr_defaults_to_localhost() {
+        match Cli::try_parse_from(["vibecheck", "serve"]).unwrap().command {
+            Some(Command::Serve(a)) => assert_eq!(a.addr, "127.0.0.1:7878"),
+            _ => panic!("expected serve subcommand"),
+        }
+        match Cli::try_parse_from(["vibecheck", "serve", "--addr", "0.0.0.0:8080"]).unwrap().command {
+            Some(Command::Serve(a)) => assert_eq!(a.addr, "0.0.0.0:8080"),
+            _ => panic!("expected serve subcommand"),
+        }
+    }
+
+    #[test]
+    fn heuristics_check_requires_examples() {
+        let cli = Cli::try_parse_from(["vibecheck", "heuristics", "--examples", "gpt", "--check"]).unwrap();
+        match cli.command {
+            Some(Command::Heuristics(a)) => assert_eq!((a.examples.as_deref(), a.check), (Some("gpt"), true)),
+            _ => panic!("expected the heuristics subcommand"),
+        }
+        assert!(Cli::try_parse_from(["vibecheck", "heuristics", "--check"]).is_err());
+    }
+
+    #[test]
+    fn heuristics_diff_takes_a_sample_directory() {
+        let cli = Cli::try_parse_from(["vibecheck", "heuristics", "--diff", "new.toml", "--sample", "src"]).unwrap();
+        match cli.command {
+            Some(Command::Heuristics(a)) => {
+                assert_eq!((a.diff, a.sample), (Some(PathBuf::from("new.toml")), PathBuf::from("src")))
+            }
+            _ => panic!("expected the heuristics subcommand"),
+        }
+        assert!(Cli::try_parse_from(["vibecheck", "heuristics", "--sample", "src"]).is_err());
+        assert!(Cli::try_parse_from(["vibecheck", "heuristics", "--diff", "a.toml", "--examples", "gpt"]).is_err());
+    }
+
+    #[test]
+    fn pre_commit_takes_files_and_requires_assert_family() {
+        let cli = Cli::try_parse_from([
+            "vibecheck", "pre-commit", "--assert-family", "human,claude", "a.rs", "b.py",
+        ])
+        .unwrap();
+        match cli.command {
+            Some(Command::PreCommit(a)) => {
+                assert_eq!(a.files, vec![PathBuf::from("a.rs"), PathBuf::from("b.py")]);
+                assert_eq!(a.assert_family, vec!["human".to_string(), "claude".to_string()]);
+            }
+            _ => panic!("expected pre-commit subcommand"),
+        }
+        assert!(Cli::try_parse_from(["vibecheck", "pre-commit", "a.rs"]).is_err());
+    }
+
+    #[test]
+    fn quiet_flag_conflicts_with_output_selection() {
+        let cli = Cli::try_parse_from(["vibecheck", "src/", "--quiet", "--assert-family", "human"]).unwrap();
+        assert!(cli.quiet);
+        match Cli::try_parse_from(["vibecheck", "analyze", "src/", "--quiet"]).unwrap().command {
+            Some(Command::Analyze(a)) => assert!(a.quiet),
+            _ => panic!("expected analyze subcommand"),
+        }
+        assert!(Cli::try_parse_from(["vibecheck", "src/", "--quiet", "--minimal"]).is_err());
+        assert!(Cli::try_parse_from(["vibecheck", "analyze", "src/", "--quiet", "--format", "json"]).is_err());
+    }
+
+    #[test]
+    fn verbose_flag_short_and_long() {
+        assert!(Cli::try_parse_from(["vibecheck", "src/", "-v"]).unwrap().verbose);
+        match Cli::try_parse_from(["vibecheck", "analyze", "src/", "--verbose"]).unwrap().command {
+            Some(Command::Analyze(a)) => assert!(a.verbose),
+            _ => panic!("expected analyze subcommand"),
+        }
+    }
+
+    #[test]
+    fn fail_on_parse_error_flag() {
+        assert!(Cli::try_parse_from(["vibecheck", "src/", "--fail-on-parse-error"]).unwrap().fail_on_parse_error);
+        match Cli::try_parse_from(["vibecheck", "analyze", "src/", "--fail-on-parse-error"]).unwrap().command {
+            Some(Command::Analyze(a)) => assert!(a.fail_on_parse_error),
+            _ => panic!("expected analyze subcommand"),
+        }
+    }
+
+    #[test]
+    fn lossy_flag() {
+        assert!(Cli::try_parse_from(["vibecheck", "src/", "--lossy"]).unwrap().lossy);
+        match Cli::try_parse_from(["vibecheck", "analyze", "src/", "--lossy"]).unwrap().command {
+            Some(Command::Analyze(a)) => assert!(a.lossy),
+            _ => panic!("expected analyze subcommand"),
+        }
+        assert!(Cli::try_parse_from(["vibecheck", "analyze", "src/", "--lossy", "--symbols"]).is_err());
+    }
+
+    #[test]
+    fn template_flag_replaces_format() {
+        let cli = Cli::try_parse_from(["vibecheck", "src/", "--template", "{{ path }}"]).unwrap();
+        assert_eq!(cli.template.as_deref(), Some("{{ path }}"));
+        match Cli::try_parse_from(["vibecheck", "analyze", "src/", "--template", "{{ family }}"]).unwrap().command {
+            Some(Command::Analyze(a)) => assert_eq!(a.template.as_deref(), Some("{{ family }}")),
+            _ => panic!("expected analyze subcommand"),
+        }
+        assert!(Cli::try_parse_from(["vibecheck", "src/", "--template", "x", "--format", "json"]).is_err());
+        assert!(Cli::try_parse_from(["vibecheck", "src/", "--template", "x", "--quiet"]).is_err());
+    }
+
+    #[test]
+    fn minimal_flag_conflicts_with_format() {
+        let cli = Cli::try_parse_from(["vibecheck", "src/", "--minimal"]).unwrap();
+        assert!(cli.minimal);
+        assert!(Cli::try_parse_from(["vibecheck", "src/", "--minimal", "--format", "text"]).is_err());
+        assert!(Cli::try_parse_from(["vibecheck", "analyze", "--minimal", "--format", "json"]).is_err());
+    }
+
+    #[test]
+    fn output_flag_parses_on_analyze_and_stats_commands() {
+        let cli = Cli::try_parse_from(["vibecheck", "src/", "--format", "json", "-o", "out/r.json"]).unwrap();
+        assert_eq!(cli.output.output, Some(PathBuf::from("out/r.json")));
+        assert!(Cli::try_parse_from(["vibecheck", "--output", "report.json"]).is_err(), "needs a path");
+        assert!(Cli::try_parse_from(["vibecheck", "--no-cache"]).is_err(), "needs a path");
+        assert!(Cli::try_parse_from(["vibecheck", "--cache-dir", "/tmp/vc"]).is_err(), "needs a path");
+        match Cli::try_parse_from(["vibecheck", "analyze", "src/", "--output", "a.sarif"]).unwrap().command {
+            Some(Command::Analyze(a)) => assert_eq!(a.output.output, Some(PathBuf::from("a.sarif"))),
+            _ => panic!("expected analyze subcommand"),
+        }
+        match Cli::try_parse_from(["vibecheck", "rank", "src/", "--output", "rank.txt"]).unwrap().command {
+            Some(Command::Rank(a)) => assert_eq!(a.output.output, Some(PathBuf::from("rank.txt"))),
+            _ => panic!("expected rank subcommand"),
+        }
+        match Cli::try_parse_from(["vibecheck", "cache", "stats", "-o", "cache.json"]).unwrap().command {
+            Some(Command::Cache(CacheCommand::Stats(a))) => {
+                assert_eq!(a.output.output, Some(PathBuf::from("cache.json")))
+            }
+            _ => panic!("expected cache stats subcommand"),
+        }
+    }
+
+    #[test]
+    fn extension_filter_flags_parse() {
+        let cli = Cli::try_parse_from(["vibecheck", "src/", "--include-ext", "rs,.py"]).unwrap();
+        assert_eq!(cli.include_ext, Some(vec!["rs".to_string(), "py".to_string()]));
+        let cli = Cli::try_parse_from(["vibecheck", "analyze", "src/", "--exclude-ext", "go"]).unwrap();
+        match cli.command {
+            Some(Command::Analyze(a)) => assert_eq!(a.exclude_ext, vec!["go".to_string()]),
+            _ => panic!("expected analyze subcommand"),
+        }
+        assert!(Cli::try_parse_from(["vibecheck", "src/", "--include-ext", "md"]).is_err());
+    }
+
+    #[test]
+    fn debug_scores_flag_parses() {
+        let cli = Cli::try_parse_from(["vibecheck", "src/", "--debug-scores"]).unwrap();
+        assert!(cli.debug_scores);
+        let cli = Cli::try_parse_from(["vibecheck", "analyze", "src/", "--debug-scores"]).unwrap();
+        assert!(matches!(cli.command, Some(Command::Analyze(a)) if a.debug_scores));
+    }
+
+    #[test]
+    fn group_by_flag_parses() {
+        let cli = Cli::try_parse_from(["vibecheck", "analyze", "src/", "--group-by", "family"]).unwrap();
+        match cli.command {
+            Some(Command::Analyze(a)) => assert_eq!(a.group_by, output::GroupBy::Family),
+            _ => panic!("expected analyze subcommand"),
+        }
+        let cli = Cli::try_parse_from(["vibecheck", "src/"]).unwrap();
+        assert_eq!(cli.group_by, output::GroupBy::None);
+        assert!(Cli::try_parse_from(["vibecheck", "src/", "--group-by", "size"]).is_err());
+    }
+
+    #[test]
+    fn profile_flag_is_global_and_validated() {
+        let cli = Cli::try_parse_from(["vibecheck", "src/", "--profile", "strict"]).unwrap();
+        assert_eq!(cli.profile.as_deref(), Some("strict"));
+        let cli = Cli::try_parse_from(["vibecheck", "watch", "src/", "--profile", "lenient"]).unwrap();
+        assert_eq!(cli.profile.as_deref(), Some("lenient"));
+        assert!(Cli::try_parse_from(["vibecheck", "src/", "--profile", "paranoid"]).is_err());
+    }
+
+    #[test]
+    fn analyze_staged_makes_path_optional() {
+        let cli = Cli::try_parse_from(["vibecheck", "analyze", "--staged"]).unwrap();
+        match cli.command {
+            Some(Command::Analyze(a)) => {
+                assert!(a.staged);
+                assert!(a.path.is_none());
+            }
+            _ => panic!("expected analyze subcommand"),
+        }
+        assert!(Cli::try_parse_from(["vibecheck", "analyze"]).is_err());
+        assert!(Cli::try_parse_from(["vibecheck", "analyze", "--staged", "--symbols"]).is_err());
+        assert!(Cli::try_parse_from(["vibecheck", "analyze", "--input-list", "-"]).is_ok());
+        assert!(Cli::try_parse_from(["vibecheck", "analyze", "src/", "--input-list", "-"]).is_err());
+        assert!(Cli::try_parse_from(["vibecheck", "analyze", "--staged", "--input-list", "-"]).is_err());
+    }
+
+    #[test]
+    fn analyze_ref_makes_path_optional() {
+        match Cli::try_parse_from(["vibecheck", "analyze", "src/", "--ref", "main"]).unwrap().command {
+            Some(Command::Analyze(a)) => {
+                assert_eq!(a.git_ref.as_deref(), Some("main"));
+                assert_eq!(a.path, Some(PathBuf::from("src/")));
+            }
+            _ => panic!("expected analyze subcommand"),
+        }
+        assert!(Cli::try_parse_from(["vibecheck", "analyze", "--ref", "v1.0"]).is_ok());
+        assert!(Cli::try_parse_from(["vibecheck", "analyze", "--ref", "main", "--staged"]).is_err());
+    }
+
+    #[test]
+    fn cli_has_all_subcommands() {
+        let cmd = Cli::command();
+        let names: Vec<_> = cmd.get_subcommands().map(|s| s.get_name().to_string()).collect();
+        assert!(names.contains(&"analyze".to_string()));
+        assert!(names.contains(&"tui".to_string()));
+        assert!(names.contains(&"watch".to_string()));
+        assert!(names.contains(&"history".to_string()));
+        assert!(names.contains(&"heuristics".to_string()));
+        assert!(names.contains(&"batch".to_string()));
+        assert!(names.contains(&"lsp".to_string()));
     }
 }
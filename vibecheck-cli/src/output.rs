@@ -1,6 +1,119 @@
-use colored::Colorize;
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+use colored::{ColoredString, Colorize};
 use vibecheck_core::colors::ColorTheme;
-use vibecheck_core::report::Report;
+use vibecheck_core::report::{aggregate_attribution, Attribution, LineAttribution, ModelFamily, Report};
+
+// Every format and the `OutputFormat` enum come from the core crate; this
+// module only adds terminal presentation (colors, grouping, templates).
+pub use vibecheck_core::output::*;
+
+/// How multi-file text/pretty output is sectioned (`--group-by`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GroupBy {
+    /// Flat, in path order.
+    #[default]
+    None,
+    /// One section per primary family.
+    Family,
+    /// One section per parent directory.
+    Dir,
+}
+
+/// Split `reports` into titled sections for `group_by`, keeping path order
+/// within each section.
+///
+/// Family sections follow [`ModelFamily::all`] order, followed by files
+/// lacking enough data to attribute and then generated files; directory
+/// sections are sorted by path.
+pub fn group_reports(reports: &[Report], group_by: GroupBy) -> Vec<(String, Vec<&Report>)> {
+    match group_by {
+        GroupBy::None => vec![(String::new(), reports.iter().collect())],
+        GroupBy::Family => {
+            let mut groups: Vec<(String, Vec<&Report>)> = ModelFamily::all()
+                .iter()
+                .map(|family| {
+                    let members = reports
+                        .iter()
+                        .filter(|r| r.attribution.has_sufficient_data() && r.attribution.primary == *family)
+                        .collect();
+                    (family.to_string(), members)
+                })
+                .collect();
+            groups.push((
+                "Insufficient data".into(),
+                reports
+                    .iter()
+                    .filter(|r| !r.metadata.generated && !r.attribution.has_sufficient_data())
+                    .collect(),
+            ));
+            groups.push(("Generated".into(), reports.iter().filter(|r| r.metadata.generated).collect()));
+            groups.retain(|(_, members)| !members.is_empty());
+            groups
+        }
+        GroupBy::Dir => {
+            let mut groups: BTreeMap<String, Vec<&Report>> = BTreeMap::new();
+            for report in reports {
+                let dir = report
+                    .metadata
+                    .file_path
+                    .as_deref()
+                    .and_then(Path::parent)
+                    .map(|p| p.display().to_string())
+                    .filter(|p| !p.is_empty())
+                    .unwrap_or_else(|| ".".into());
+                groups.entry(dir).or_default().push(report);
+            }
+            groups.into_iter().collect()
+        }
+    }
+}
+
+/// Section header with the group's subtotal and line-weighted attribution,
+/// e.g. `== src — 3 files, 412 lines · Claude 62% ± 8% ==`.  The
+/// attribution is omitted when no member has enough data.
+pub fn format_group_header(title: &str, members: &[&Report], colored: bool) -> String {
+    let files = members.len();
+    let lines: usize = members.iter().map(|r| r.metadata.lines_of_code).sum();
+    let attribution = aggregate_attribution(members.iter().copied())
+        .map(|a| format!(" · {} {:.0}% ± {:.0}%", a.family, a.share * 100.0, a.margin * 100.0))
+        .unwrap_or_default();
+    let header = format!(
+        "== {title} — {files} file{}, {lines} lines{attribution} ==",
+        if files == 1 { "" } else { "s" }
+    );
+    if colored {
+        header.bold().to_string()
+    } else {
+        header
+    }
+}
+
+/// Whether the terminal advertises 24-bit color through `COLORTERM`.
+fn truecolor_supported() -> bool {
+    std::env::var("COLORTERM").is_ok_and(|v| v == "truecolor" || v == "24bit")
+}
+
+/// Color a verdict: the theme's confidence gradient on truecolor terminals,
+/// the flat family color everywhere else.
+fn paint_verdict(
+    text: String,
+    attribution: &Attribution,
+    theme: &dyn ColorTheme,
+    truecolor: bool,
+) -> ColoredString {
+    let gradient = theme
+        .confidence_rgb(attribution.primary, attribution.confidence)
+        .filter(|_| truecolor);
+    match gradient {
+        Some((r, g, b)) => text.truecolor(r, g, b),
+        None => text.color(theme.terminal_color(attribution.primary)),
+    }
+}
 
 /// Format a report with terminal colors, using the supplied [`ColorTheme`].
 ///
@@ -13,8 +126,13 @@ pub fn format_pretty(report: &Report, theme: &dyn ColorTheme) -> String {
         out.push_str(&format!("{} {}\n", "File:".bold(), path.display()));
     }
 
-    if report.attribution.has_sufficient_data() {
-        let verdict_color = theme.terminal_color(report.attribution.primary);
+    if report.metadata.generated {
+        out.push_str(&format!(
+            "{} {}\n",
+            "Verdict:".bold(),
+            "Generated code (not analyzed)".dimmed()
+        ));
+    } else if report.attribution.has_sufficient_data() {
         let verdict_str = format!(
             "{} ({:.0}% confidence)",
             report.attribution.primary,
@@ -23,7 +141,7 @@ pub fn format_pretty(report: &Report, theme: &dyn ColorTheme) -> String {
         out.push_str(&format!(
             "{} {}\n",
             "Verdict:".bold(),
-            verdict_str.color(verdict_color).bold()
+            paint_verdict(verdict_str, &report.attribution, theme, truecolor_supported()).bold()
         ));
     } else {
         out.push_str(&format!(
@@ -32,6 +150,9 @@ pub fn format_pretty(report: &Report, theme: &dyn ColorTheme) -> String {
             "Insufficient data".dimmed()
         ));
     }
+    if let Some(language) = report.metadata.language {
+        out.push_str(&format!("{} {language} | ", "Language:".dimmed()));
+    }
     out.push_str(&format!(
         "{} {} | {} {}\n",
         "Lines:".dimmed(),
@@ -39,6 +160,15 @@ pub fn format_pretty(report: &Report, theme: &dyn ColorTheme) -> String {
         "Signals:".dimmed(),
         report.metadata.signal_count,
     ));
+    if report.metadata.timed_out {
+        out.push_str(&format!("{}\n", "Parsing timed out; syntax-tree signals skipped".yellow()));
+    } else if report.metadata.parse_errors > 0 {
+        let note = format!("{} parse errors; syntax-tree signals down-weighted", report.metadata.parse_errors);
+        out.push_str(&format!("{}\n", note.yellow()));
+    }
+    if report.metadata.lossy {
+        out.push_str(&format!("{}\n", "Invalid UTF-8 replaced before analysis".yellow()));
+    }
 
     out.push_str(&format!("\n{}\n", "Scores:".bold()));
     let mut sorted_scores: Vec<_> = report.attribution.scores.iter().collect();
@@ -79,13 +209,261 @@ pub fn format_pretty(report: &Report, theme: &dyn ColorTheme) -> String {
     out
 }
 
-pub use vibecheck_core::output::{format_json, format_text};
+/// `source` with a gutter giving each line's number and heatmap verdict,
+/// e.g. `   12  Claude   62% │ let x = 1;`, then how many lines each family
+/// took.  Lines missing from `lines` get an empty gutter.  With a `theme`
+/// the verdicts are colored (fading with score on truecolor terminals).
+pub fn format_heatmap(source: &str, lines: &[LineAttribution], theme: Option<&dyn ColorTheme>) -> String {
+    let truecolor = truecolor_supported();
+    let by_line: BTreeMap<usize, &LineAttribution> = lines.iter().map(|l| (l.line, l)).collect();
+    let mut out = String::new();
+    for (i, text) in source.lines().enumerate() {
+        let gutter = match by_line.get(&(i + 1)) {
+            Some(l) => {
+                let label = format!("{:<8} {:>3.0}%", l.family.to_string(), l.score * 100.0);
+                match theme {
+                    Some(theme) => {
+                        let attribution =
+                            Attribution { primary: l.family, confidence: l.score, scores: Default::default() };
+                        paint_verdict(label, &attribution, theme, truecolor).to_string()
+                    }
+                    None => label,
+                }
+            }
+            None => " ".repeat(13),
+        };
+        out.push_str(format!("{:>5}  {gutter} │ {text}", i + 1).trim_end());
+        out.push('\n');
+    }
+
+    let mut counts: Vec<(ModelFamily, usize)> = Vec::new();
+    for l in lines {
+        match counts.iter_mut().find(|(f, _)| *f == l.family) {
+            Some((_, n)) => *n += 1,
+            None => counts.push((l.family, 1)),
+        }
+    }
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.name().cmp(b.0.name())));
+    let mut summary: Vec<String> = counts.iter().map(|(f, n)| format!("{f} {n}")).collect();
+    let unattributed = source.lines().count().saturating_sub(lines.len());
+    if unattributed > 0 {
+        summary.push(format!("unattributed {unattributed}"));
+    }
+    out.push_str(&format!("\nlines: {}\n", summary.join(" · ")));
+    out
+}
+
+/// The variables a `--template` string sees for one report: `path`,
+/// `family`, `confidence`, `lines`, `language`, `signal_count`,
+/// `generated`, `scores` (family → score), and `signals` (each with `id`,
+/// `source`, `description`, `family`, `weight`, and `location`/`snippet`
+/// when known).  Families and languages use their JSON names, e.g.
+/// `claude`.
+pub fn template_context(report: &Report) -> serde_json::Value {
+    let path = report
+        .metadata
+        .file_path
+        .as_ref()
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|| "<stdin>".into());
+    let scores: BTreeMap<_, _> = report.attribution.scores.iter().map(|(f, s)| (f.name(), *s)).collect();
+    serde_json::json!({
+        "path": path,
+        "family": report.attribution.primary,
+        "confidence": report.attribution.confidence,
+        "lines": report.metadata.lines_of_code,
+        "language": report.metadata.language,
+        "signal_count": report.metadata.signal_count,
+        "generated": report.metadata.generated,
+        "scores": scores,
+        "signals": report.signals,
+    })
+}
+
+/// Render each report through `template` (Jinja syntax, e.g.
+/// `{{ path }}: {{ family }} {{ confidence }}`), one line per report.
+///
+/// Referencing a variable that isn't in [`template_context`] is an error
+/// rather than an empty string, so typos surface.  Passing no reports just
+/// checks that the template parses.
+pub fn render_template(template: &str, reports: &[Report]) -> Result<String, minijinja::Error> {
+    let mut env = minijinja::Environment::new();
+    env.set_undefined_behavior(minijinja::UndefinedBehavior::Strict);
+    env.add_template("template", template)?;
+    let tmpl = env.get_template("template")?;
+    let mut out = String::new();
+    for report in reports {
+        out.push_str(tmpl.render(template_context(report))?.trim_end_matches('\n'));
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+/// Where a command writes its results (`--output`); see [`open_output`].
+pub struct Output {
+    writer: Box<dyn Write>,
+    /// `(temporary file, destination)` when writing to a file.
+    file: Option<(PathBuf, PathBuf)>,
+}
+
+impl Output {
+    /// Flush the results and, when writing to a file, move them into place.
+    pub fn finish(mut self) -> anyhow::Result<()> {
+        self.writer.flush()?;
+        // Close the file before renaming it.
+        self.writer = Box::new(std::io::sink());
+        if let Some((tmp, path)) = &self.file {
+            std::fs::rename(tmp, path).with_context(|| format!("failed to write {}", path.display()))?;
+            self.file = None;
+        }
+        Ok(())
+    }
+}
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.writer.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+impl Drop for Output {
+    fn drop(&mut self) {
+        if let Some((tmp, _)) = &self.file {
+            self.writer = Box::new(std::io::sink());
+            let _ = std::fs::remove_file(tmp);
+        }
+    }
+}
+
+/// Open the destination for a command's results: a file at `path`, created
+/// along with any missing parent directories, or stdout.
+///
+/// A file is written through a temporary file beside it that
+/// [`Output::finish`] renames over `path`, so a run that fails part-way
+/// leaves any previous results untouched.  Writing to a file also turns
+/// colors off, so `pretty` output is saved without escape codes.
+pub fn open_output(path: Option<&Path>) -> anyhow::Result<Output> {
+    let Some(path) = path else {
+        return Ok(Output { writer: Box::new(std::io::stdout()), file: None });
+    };
+    let name = path.file_name().with_context(|| format!("{} is not a file path", path.display()))?;
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent).with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+    let tmp = path.with_file_name(format!(".{}.{}.tmp", name.to_string_lossy(), std::process::id()));
+    let file = std::fs::File::create(&tmp).with_context(|| format!("failed to create {}", tmp.display()))?;
+    colored::control::set_override(false);
+    Ok(Output { writer: Box::new(std::io::BufWriter::new(file)), file: Some((tmp, path.to_path_buf())) })
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use vibecheck_core::colors::DefaultTheme;
 
+    fn report_at(path: &str, source: &str) -> Report {
+        let mut report = vibecheck_core::analyze(source);
+        report.metadata.file_path = Some(path.into());
+        report
+    }
+
+    #[test]
+    fn group_by_family_sections_follow_family_order() {
+        let mut human = report_at("src/b.rs", "fn main() {}\n");
+        human.attribution.primary = ModelFamily::Human;
+        human.attribution.confidence = 0.6;
+        let mut claude = report_at("src/a.rs", "fn main() {}\n");
+        claude.attribution.primary = ModelFamily::Claude;
+        claude.attribution.confidence = 0.7;
+        let mut empty = report_at("src/c.rs", "");
+        empty.attribution.confidence = 0.0;
+        let reports = vec![human, claude, empty];
+
+        let groups = group_reports(&reports, GroupBy::Family);
+        let titles: Vec<_> = groups.iter().map(|(t, _)| t.as_str()).collect();
+        assert_eq!(titles, ["Claude", "Human", "Insufficient data"]);
+        assert_eq!(groups[0].1.len(), 1);
+    }
+
+    #[test]
+    fn template_renders_each_report_on_its_own_line() {
+        let mut claude = report_at("src/a.rs", "fn main() {}\n");
+        claude.attribution.primary = ModelFamily::Claude;
+        claude.attribution.confidence = 0.75;
+        let reports = vec![claude, report_at("src/b.rs", "")];
+
+        let out = render_template("{{ path }}: {{ family }} {{ confidence }}", &reports[..1]).unwrap();
+        assert_eq!(out, "src/a.rs: claude 0.75\n");
+
+        let out = render_template("{{ path }} {{ signals | length }}{% if generated %} gen{% endif %}\n", &reports)
+            .unwrap();
+        assert_eq!(out.lines().count(), 2, "{out}");
+        assert!(out.starts_with(&format!("src/a.rs {}\n", reports[0].signals.len())), "{out}");
+    }
+
+    #[test]
+    fn template_errors_on_bad_syntax_and_unknown_variables() {
+        assert!(render_template("{{ path ", &[]).is_err());
+        assert!(render_template("{{ famly }}", &[report_at("a.rs", "")]).is_err());
+        assert_eq!(render_template("{{ famly }}", &[]).unwrap(), "");
+    }
+
+    #[test]
+    fn group_by_dir_uses_parent_directories() {
+        let reports = vec![
+            report_at("src/b.rs", "fn b() {}\n"),
+            report_at("lib.rs", "fn l() {}\n"),
+            report_at("src/a.rs", "fn a() {}\n"),
+        ];
+        let groups = group_reports(&reports, GroupBy::Dir);
+        let titles: Vec<_> = groups.iter().map(|(t, m)| (t.as_str(), m.len())).collect();
+        assert_eq!(titles, [(".", 1), ("src", 2)]);
+    }
+
+    #[test]
+    fn group_header_includes_subtotal() {
+        let reports = [report_at("a.rs", "fn a() {}\nfn b() {}\n"), report_at("b.rs", "fn c() {}\n")];
+        let members: Vec<_> = reports.iter().collect();
+        assert_eq!(
+            format_group_header("Claude", &members, false),
+            "== Claude — 2 files, 3 lines =="
+        );
+    }
+
+    #[test]
+    fn group_header_includes_interval_when_attributed() {
+        let source = "/// Adds two numbers together.\n/// Returns the sum.\nfn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n".repeat(4);
+        let reports = [report_at("a.rs", &source), report_at("b.rs", &source)];
+        let members: Vec<_> = reports.iter().collect();
+        let header = format_group_header("src", &members, false);
+        let agg = aggregate_attribution(members.iter().copied()).expect("fixture should be attributed");
+        assert!(
+            header.ends_with(&format!("· {} {:.0}% ± 0% ==", agg.family, agg.share * 100.0)),
+            "{header}"
+        );
+    }
+
+    #[test]
+    fn verdict_gradient_only_on_truecolor() {
+        let attribution = |confidence| Attribution {
+            primary: ModelFamily::Gpt,
+            confidence,
+            scores: Default::default(),
+        };
+        let paint = |confidence, truecolor| {
+            paint_verdict("GPT".into(), &attribution(confidence), &DefaultTheme, truecolor).fgcolor
+        };
+        let rgb = |(r, g, b): (u8, u8, u8)| Some(colored::Color::TrueColor { r, g, b });
+        assert_eq!(paint(0.95, true), rgb(ModelFamily::Gpt.confidence_rgb(0.95)));
+        assert_ne!(paint(0.95, true), paint(0.55, true));
+        assert_eq!(paint(0.95, false), Some(colored::Color::Green));
+        assert_eq!(paint(0.55, false), paint(0.95, false));
+    }
+
     #[test]
     fn format_pretty_with_file_path() {
         let mut report = vibecheck_core::analyze("fn main() { println!(\"hello world\"); }");
@@ -130,4 +508,54 @@ mod tests {
             "should still show verdict line"
         );
     }
+
+    #[test]
+    fn format_heatmap_adds_a_verdict_gutter() {
+        let lines = [
+            LineAttribution { line: 1, family: ModelFamily::Human, score: 0.5 },
+            LineAttribution { line: 3, family: ModelFamily::Claude, score: 0.625 },
+        ];
+        let plain = format_heatmap("fn a() {}\n\nfn b() {}\n", &lines, None);
+        assert_eq!(
+            plain,
+            concat!(
+                "    1  Human     50% │ fn a() {}\n",
+                "    2                │\n",
+                "    3  Claude    62% │ fn b() {}\n",
+                "\nlines: Claude 1 · Human 1 · unattributed 1\n",
+            )
+        );
+        let colored = format_heatmap("fn a() {}\n", &lines[..1], Some(&DefaultTheme));
+        assert!(colored.contains("Human     50%"), "{colored}");
+    }
+
+    #[test]
+    fn open_output_creates_parent_directories() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("reports/ci/vibecheck.json");
+        let mut out = open_output(Some(&path)).unwrap();
+        writeln!(out, "[]").unwrap();
+        out.finish().unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "[]\n");
+    }
+
+    #[test]
+    fn output_replaces_the_file_only_when_finished() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("report.json");
+        std::fs::write(&path, "old\n").unwrap();
+        let mut out = open_output(Some(&path)).unwrap();
+        writeln!(out, "partial").unwrap();
+        out.flush().unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "old\n", "still being written");
+        drop(out);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "old\n", "abandoned");
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1, "temporary file removed");
+
+        let mut out = open_output(Some(&path)).unwrap();
+        writeln!(out, "new").unwrap();
+        out.finish().unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "new\n");
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
 }
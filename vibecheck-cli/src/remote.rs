@@ -0,0 +1,158 @@
+//! Analysis of source files fetched over HTTP(S), e.g.
+//! `vibecheck https://example.com/src/lib.rs`.
+//!
+//! Fetching needs the `remote` feature; without it a URL argument is
+//! rejected with a hint to rebuild.  Everything else — language inference
+//! from the URL and content-hash caching — is always compiled.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use vibecheck_core::cache::Cache;
+use vibecheck_core::language::{detect_language, Language};
+use vibecheck_core::report::Report;
+use vibecheck_core::ConfigOverrides;
+
+/// Whether a CLI path argument is really an `http://` or `https://` URL.
+pub fn is_url(arg: &Path) -> bool {
+    arg.to_str()
+        .is_some_and(|s| s.starts_with("http://") || s.starts_with("https://"))
+}
+
+/// The path component of `url`, without scheme, host, query, or fragment.
+fn url_path(url: &str) -> &str {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let path = rest.find('/').map_or("", |i| &rest[i..]);
+    path.split(['?', '#']).next().unwrap_or(path)
+}
+
+/// Infer the source language from the file extension in `url`'s path.
+fn language_for_url(url: &str) -> Result<Language> {
+    detect_language(Path::new(url_path(url))).with_context(|| {
+        format!("cannot infer a supported language from the extension of {url}")
+    })
+}
+
+/// Fetch `url` and analyze its body, using the content-addressed cache
+/// unless `no_cache` is set.
+pub fn analyze_url(
+    url: &str,
+    cache_dir: Option<&Path>,
+    no_cache: bool,
+    symbols: bool,
+    overrides: &ConfigOverrides,
+) -> Result<Report> {
+    let language = language_for_url(url)?;
+    let body = fetch(url)?;
+    let cache_path = (!no_cache).then(|| Cache::resolve_path(cache_dir));
+    analyze_body(url, language, body, cache_path.as_deref(), symbols, overrides)
+}
+
+/// Analyze an already-fetched `body` as `language`, caching by content hash
+/// in `cache_path` when given.  The report's file path is the URL.
+fn analyze_body(
+    url: &str,
+    language: Language,
+    body: Vec<u8>,
+    cache_path: Option<&Path>,
+    symbols: bool,
+    overrides: &ConfigOverrides,
+) -> Result<Report> {
+    let hash = vibecheck_core::cache_key(&body, Path::new(url), overrides);
+    let cache = cache_path.and_then(|p| Cache::open(p).ok());
+    if let Some(ref c) = cache {
+        if let Some(mut report) = c.get(&hash) {
+            let syms = if symbols { c.get_symbols(&hash) } else { None };
+            if !symbols || syms.is_some() {
+                report.metadata.file_path = Some(url.into());
+                report.symbol_reports = syms;
+                return Ok(report);
+            }
+        }
+    }
+
+    let source = String::from_utf8(body)
+        .map_err(|e| anyhow::anyhow!("response body of {url} is not valid UTF-8: {e}"))?;
+    let mut report = vibecheck_core::analyze_with_language(&source, Path::new(url), language, overrides)?;
+    let syms = report.symbol_reports.take().unwrap_or_default();
+    if let Some(ref c) = cache.filter(|_| !report.metadata.timed_out) {
+        let _ = c.put(&hash, &report);
+        let _ = c.put_symbols(&hash, &syms);
+    }
+    if symbols {
+        report.symbol_reports = Some(syms);
+    }
+    Ok(report)
+}
+
+#[cfg(feature = "remote")]
+fn fetch(url: &str) -> Result<Vec<u8>> {
+    let mut response = ureq::get(url).call().map_err(|e| match e {
+        ureq::Error::StatusCode(code) => anyhow::anyhow!("GET {url} returned HTTP {code}"),
+        e => anyhow::anyhow!("GET {url} failed: {e}"),
+    })?;
+    response
+        .body_mut()
+        .read_to_vec()
+        .with_context(|| format!("failed to read response body of {url}"))
+}
+
+#[cfg(not(feature = "remote"))]
+fn fetch(url: &str) -> Result<Vec<u8>> {
+    anyhow::bail!(
+        "cannot fetch {url}: vibecheck was built without the `remote` feature \
+         (reinstall with `cargo install vibecheck-cli --features remote`)"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_url_matches_http_schemes_only() {
+        assert!(is_url(Path::new("https://example.com/lib.rs")));
+        assert!(is_url(Path::new("http://example.com/lib.rs")));
+        assert!(!is_url(Path::new("src/lib.rs")));
+        assert!(!is_url(Path::new("ftp://example.com/lib.rs")));
+    }
+
+    #[test]
+    fn language_comes_from_url_path_extension() {
+        assert_eq!(
+            language_for_url("https://raw.example.com/o/r/main/src/app.py?token=abc#L10").unwrap(),
+            Language::Python
+        );
+        assert_eq!(url_path("https://example.com"), "");
+        assert!(language_for_url("https://example.com/README.md").is_err());
+        assert!(language_for_url("https://example.com/").is_err());
+    }
+
+    #[test]
+    fn analyze_body_rejects_non_utf8() {
+        let err = analyze_body("https://x.test/a.rs", Language::Rust, vec![0xff, 0xfe], None, false, &ConfigOverrides::default())
+            .unwrap_err();
+        assert!(err.to_string().contains("not valid UTF-8"), "{err}");
+    }
+
+    #[test]
+    fn analyze_body_caches_by_content_hash() {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let body = b"fn main() {\n    println!(\"hi\");\n}\n".to_vec();
+        let url = "https://x.test/main.rs";
+        let overrides = ConfigOverrides::default();
+        let first =
+            analyze_body(url, Language::Rust, body.clone(), Some(cache_dir.path()), true, &overrides).unwrap();
+        assert!(first.symbol_reports.is_some());
+        let hash = vibecheck_core::cache_key(&body, Path::new(url), &overrides);
+        assert!(Cache::open(cache_dir.path()).unwrap().get(&hash).is_some());
+
+        let other = "https://y.test/other.rs";
+        let second =
+            analyze_body(other, Language::Rust, body, Some(cache_dir.path()), false, &overrides).unwrap();
+        assert_eq!(second.metadata.file_path.as_deref(), Some(Path::new("https://y.test/other.rs")));
+        assert!(second.symbol_reports.is_none());
+        assert_eq!(second.attribution.primary, first.attribution.primary);
+    }
+}
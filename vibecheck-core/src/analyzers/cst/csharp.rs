@@ -0,0 +1,601 @@
+use std::collections::HashMap;
+
+use tree_sitter::{Node, Tree};
+
+use crate::analyzers::CstAnalyzer;
+use crate::language::Language;
+use crate::report::SymbolMetadata;
+
+pub struct CSharpCstAnalyzer;
+
+/// Keywords compared by [`super::signature_uniformity`].
+const SIGNATURE_MODIFIERS: &[&str] = &[
+    "public", "private", "protected", "internal", "static", "async", "override", "virtual",
+    "abstract", "sealed",
+];
+
+/// LINQ extension methods counted towards `linq_call_count`.
+const LINQ_METHODS: &[&str] = &[
+    "Where",
+    "Select",
+    "SelectMany",
+    "OrderBy",
+    "OrderByDescending",
+    "GroupBy",
+    "FirstOrDefault",
+    "SingleOrDefault",
+    "Any",
+    "All",
+    "ToList",
+    "ToDictionary",
+    "Aggregate",
+];
+
+const FN_KINDS: &[&str] = &[
+    "method_declaration",
+    "constructor_declaration",
+    "local_function_statement",
+];
+
+impl CstAnalyzer for CSharpCstAnalyzer {
+    fn name(&self) -> &str {
+        "csharp_cst"
+    }
+
+    fn target_language(&self) -> Language {
+        Language::CSharp
+    }
+
+    fn ts_language(&self) -> tree_sitter::Language {
+        tree_sitter_c_sharp::LANGUAGE.into()
+    }
+
+    fn extract_metrics(
+        &self,
+        tree: &Tree,
+        source: &str,
+    ) -> HashMap<String, f64> {
+        let mut metrics = HashMap::new();
+        let src_bytes = source.as_bytes();
+        let root = tree.root_node();
+
+        let public_members = collect_public_members(root, src_bytes);
+        if !public_members.is_empty() {
+            let documented = public_members
+                .iter()
+                .filter(|&&n| has_xml_doc_comment(n, src_bytes))
+                .count();
+            metrics.insert(
+                "doc_coverage_ratio".into(),
+                documented as f64 / public_members.len() as f64,
+            );
+        }
+
+        let methods = collect_nodes_of_kind(root, "method_declaration");
+        if methods.len() >= 3 {
+            let async_count = methods
+                .iter()
+                .filter(|&&m| has_modifier(m, src_bytes, "async"))
+                .count();
+            metrics.insert(
+                "async_method_ratio".into(),
+                async_count as f64 / methods.len() as f64,
+            );
+        }
+
+        let linq_calls = count_linq_calls(root, src_bytes)
+            + collect_nodes_of_kind(root, "query_expression").len();
+        metrics.insert("linq_call_count".into(), linq_calls as f64);
+
+        let nullable = collect_nodes_of_kind(root, "nullable_type").len();
+        metrics.insert("nullable_annotation_count".into(), nullable as f64);
+
+        let all_fns: Vec<Node<'_>> = FN_KINDS
+            .iter()
+            .flat_map(|k| collect_nodes_of_kind(root, k))
+            .collect();
+        if !all_fns.is_empty() {
+            let lengths: Vec<usize> = all_fns.iter().map(|&f| fn_line_count(f)).collect();
+            let avg_len = lengths.iter().sum::<usize>() as f64 / all_fns.len() as f64;
+            metrics.insert("avg_fn_length".into(), avg_len);
+
+            let complexities: Vec<usize> =
+                all_fns.iter().map(|&f| complexity_of_fn(f)).collect();
+            let avg_complexity =
+                complexities.iter().sum::<usize>() as f64 / all_fns.len() as f64;
+            metrics.insert("avg_complexity".into(), avg_complexity);
+        }
+
+        if let Some(u) = super::signature_uniformity(&all_fns, src_bytes, SIGNATURE_MODIFIERS) {
+            metrics.insert("signature_uniformity".into(), u);
+        }
+
+        // Explicitly private, or no access modifier (private by default).
+        let helpers: Vec<Node<'_>> = collect_nodes_of_kind(root, "method_declaration")
+            .into_iter()
+            .filter(|&m| {
+                ["public", "protected", "internal", "override"]
+                    .iter()
+                    .all(|modifier| !has_modifier(m, src_bytes, modifier))
+            })
+            .collect();
+        if let Some(r) =
+            super::single_use_helper_ratio(root, src_bytes, &helpers, &["invocation_expression"])
+        {
+            metrics.insert("single_use_helper_ratio".into(), r);
+        }
+
+        if let Some(r) = super::defensive_check_ratio(
+            &all_fns,
+            src_bytes,
+            |f| non_nullable_params(f, src_bytes),
+            |n| null_checked(n, src_bytes),
+        ) {
+            metrics.insert("defensive_check_ratio".into(), r);
+        }
+
+        if let Some(r) =
+            super::entry_validation_ratio(&all_fns, super::block_statements, |s| is_guard(s, src_bytes))
+        {
+            metrics.insert("entry_validation_ratio".into(), r);
+        }
+
+        if let Some(r) = super::doc_placement_consistency(&all_fns, |f| doc_placement(f, src_bytes)) {
+            metrics.insert("doc_placement_consistency".into(), r);
+        }
+
+        metrics
+    }
+
+    fn extract_symbols<'tree>(
+        &self,
+        tree: &'tree tree_sitter::Tree,
+        source: &[u8],
+    ) -> Vec<(SymbolMetadata, tree_sitter::Node<'tree>)> {
+        let root = tree.root_node();
+        let mut results = Vec::new();
+        let mut stack = vec![root];
+
+        while let Some(node) = stack.pop() {
+            let kind = match node.kind() {
+                "method_declaration" | "constructor_declaration" => Some("method"),
+                "local_function_statement" => Some("function"),
+                _ => None,
+            };
+            if let Some(kind) = kind {
+                if let Some(name) = node
+                    .child_by_field_name("name")
+                    .and_then(|n| n.utf8_text(source).ok())
+                {
+                    results.push((
+                        symbol(name, kind, node, source),
+                        node,
+                    ));
+                }
+            }
+            let mut cursor = node.walk();
+            for child in node.children(&mut cursor) {
+                stack.push(child);
+            }
+        }
+
+        results
+    }
+}
+
+fn collect_nodes_of_kind<'t>(root: Node<'t>, kind: &str) -> Vec<Node<'t>> {
+    let mut result = Vec::new();
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if node.kind() == kind {
+            result.push(node);
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            stack.push(child);
+        }
+    }
+    result
+}
+
+fn has_modifier(node: Node<'_>, src_bytes: &[u8], modifier: &str) -> bool {
+    let mut cursor = node.walk();
+    let found = node
+        .children(&mut cursor)
+        .any(|c| c.kind() == "modifier" && c.utf8_text(src_bytes).ok() == Some(modifier));
+    found
+}
+
+/// Public types, methods, constructors, and properties — the members XML doc
+/// comments are expected on.
+fn collect_public_members<'t>(root: Node<'t>, src_bytes: &[u8]) -> Vec<Node<'t>> {
+    let member_kinds = [
+        "class_declaration",
+        "interface_declaration",
+        "struct_declaration",
+        "record_declaration",
+        "method_declaration",
+        "constructor_declaration",
+        "property_declaration",
+    ];
+    let mut result = Vec::new();
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if member_kinds.contains(&node.kind()) && has_modifier(node, src_bytes, "public") {
+            result.push(node);
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            stack.push(child);
+        }
+    }
+    result
+}
+
+fn has_xml_doc_comment(node: Node<'_>, src_bytes: &[u8]) -> bool {
+    node.prev_named_sibling()
+        .filter(|n| n.kind() == "comment")
+        .and_then(|n| n.utf8_text(src_bytes).ok())
+        .is_some_and(|text| text.starts_with("///"))
+}
+
+/// Count method-syntax LINQ calls such as `.Where(...)` or `.Select(...)`.
+fn count_linq_calls(root: Node<'_>, src_bytes: &[u8]) -> usize {
+    collect_nodes_of_kind(root, "invocation_expression")
+        .into_iter()
+        .filter(|inv| {
+            inv.child_by_field_name("function")
+                .filter(|f| f.kind() == "member_access_expression")
+                .and_then(|f| f.child_by_field_name("name"))
+                .and_then(|n| n.utf8_text(src_bytes).ok())
+                .is_some_and(|name| LINQ_METHODS.contains(&name))
+        })
+        .count()
+}
+
+/// Built-in value types; they can never be null, so they are not counted.
+const VALUE_TYPES: &[&str] = &[
+    "bool", "byte", "sbyte", "char", "decimal", "double", "float", "int", "uint", "long", "ulong", "short",
+    "ushort", "nint", "nuint",
+];
+
+/// Whether `func` is documented by `///` XML doc comments directly above
+/// it, for [`super::doc_placement_consistency`].
+fn doc_placement(func: Node<'_>, src_bytes: &[u8]) -> Option<bool> {
+    match super::preceding_comment(func, &[]) {
+        Some((comment, adjacent)) => Some(adjacent && comment.utf8_text(src_bytes).ok()?.starts_with("///")),
+        None => super::body_opens_with_comment(func).then_some(false),
+    }
+}
+
+/// An `if` without `else` that only throws or returns, or a
+/// `ArgumentNullException.ThrowIfNull(x)`-style throw helper call.
+fn is_guard(statement: Node<'_>, src_bytes: &[u8]) -> bool {
+    match statement.kind() {
+        "if_statement" => {
+            statement.child_by_field_name("alternative").is_none()
+                && statement
+                    .child_by_field_name("consequence")
+                    .and_then(super::sole_statement)
+                    .is_some_and(|s| matches!(s.kind(), "throw_statement" | "return_statement"))
+        }
+        "expression_statement" => super::sole_statement(statement)
+            .filter(|s| s.kind() == "invocation_expression")
+            .and_then(|s| s.child_by_field_name("function"))
+            .and_then(|f| f.child_by_field_name("name"))
+            .and_then(|n| n.utf8_text(src_bytes).ok())
+            .is_some_and(|n| n.starts_with("ThrowIf")),
+        _ => false,
+    }
+}
+
+/// Parameters of `func` whose reference type is not annotated nullable
+/// (`string`, `Foo`, but not `string?` or `int`).  With nullable
+/// reference types on, the compiler already keeps null out of these.
+fn non_nullable_params<'t>(func: Node<'t>, src_bytes: &[u8]) -> Vec<Node<'t>> {
+    let Some(params) = func.child_by_field_name("parameters") else {
+        return Vec::new();
+    };
+    let mut cursor = params.walk();
+    params
+        .named_children(&mut cursor)
+        .filter(|p| p.kind() == "parameter")
+        .filter(|p| {
+            p.child_by_field_name("type").is_some_and(|t| {
+                t.kind() != "nullable_type"
+                    && !(t.kind() == "predefined_type" && t.utf8_text(src_bytes).is_ok_and(|t| VALUE_TYPES.contains(&t)))
+            })
+        })
+        .filter_map(|p| p.child_by_field_name("name"))
+        .collect()
+}
+
+/// The identifier in `x == null`, `x != null`, `x is null`, `x is not
+/// null`, `x ?? throw ...` or `ArgumentNullException.ThrowIfNull(x)`.
+fn null_checked<'t>(node: Node<'t>, src_bytes: &[u8]) -> Option<Node<'t>> {
+    match node.kind() {
+        "binary_expression" => super::compared_to_null(node, "null_literal").or_else(|| {
+            let left = node.child_by_field_name("left")?;
+            let rethrows = node.child_by_field_name("right")?.kind() == "throw_expression";
+            (rethrows && left.kind() == "identifier").then_some(left)
+        }),
+        "is_pattern_expression" => {
+            let pattern = node.child_by_field_name("pattern")?;
+            let is_null = matches!(pattern.utf8_text(src_bytes), Ok("null" | "not null"));
+            node.child_by_field_name("expression").filter(|e| is_null && e.kind() == "identifier")
+        }
+        "invocation_expression" => {
+            let function = node.child_by_field_name("function")?.utf8_text(src_bytes).ok()?;
+            if !function.ends_with("ThrowIfNull") {
+                return None;
+            }
+            let arg = node.child_by_field_name("arguments")?.named_child(0)?.named_child(0)?;
+            (arg.kind() == "identifier").then_some(arg)
+        }
+        _ => None,
+    }
+}
+
+fn fn_line_count(node: Node<'_>) -> usize {
+    let start = node.start_position().row;
+    let end = node.end_position().row;
+    (end - start) + 1
+}
+
+/// Symbol metadata for `node`, measured with this grammar's walkers.
+fn symbol(name: &str, kind: &str, node: Node<'_>, source: &[u8]) -> SymbolMetadata {
+    super::symbol_metadata(
+        name,
+        kind,
+        node,
+        source,
+        complexity_of_fn(node),
+        max_nesting_depth(node),
+    )
+}
+
+fn complexity_of_fn(root: Node<'_>) -> usize {
+    let decision_kinds = [
+        "if_statement",
+        "for_statement",
+        "foreach_statement",
+        "while_statement",
+        "do_statement",
+        "switch_statement",
+        "switch_expression",
+        "conditional_expression",
+        "catch_clause",
+    ];
+    let mut count = 0usize;
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if decision_kinds.contains(&node.kind()) {
+            count += 1;
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            if child != root && FN_KINDS.contains(&child.kind()) {
+                continue;
+            }
+            stack.push(child);
+        }
+    }
+    count
+}
+
+fn max_nesting_depth(root: Node<'_>) -> usize {
+    let nesting_kinds = [
+        "block",
+        "if_statement",
+        "for_statement",
+        "foreach_statement",
+        "while_statement",
+        "do_statement",
+        "switch_statement",
+        "try_statement",
+    ];
+    let mut stack = vec![(root, 0usize)];
+    let mut max_depth = 0usize;
+    while let Some((node, depth)) = stack.pop() {
+        let new_depth = if nesting_kinds.contains(&node.kind()) {
+            depth + 1
+        } else {
+            depth
+        };
+        max_depth = max_depth.max(new_depth);
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            if !FN_KINDS.contains(&child.kind()) {
+                stack.push((child, new_depth));
+            }
+        }
+    }
+    max_depth
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzers::CstAnalyzer;
+    use crate::report::SymbolMetadata;
+
+    fn parse_and_metrics(source: &str) -> HashMap<String, f64> {
+        let analyzer = CSharpCstAnalyzer;
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&analyzer.ts_language()).unwrap();
+        let tree = parser.parse(source, None).unwrap();
+        analyzer.extract_metrics(&tree, source)
+    }
+
+    fn parse_and_extract(source: &str) -> Vec<SymbolMetadata> {
+        let analyzer = CSharpCstAnalyzer;
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&analyzer.ts_language()).unwrap();
+        let tree = parser.parse(source, None).unwrap();
+        analyzer
+            .extract_symbols(&tree, source.as_bytes())
+            .into_iter()
+            .map(|(meta, _)| meta)
+            .collect()
+    }
+
+    const SERVICE: &str = r#"#nullable enable
+using System.Linq;
+
+namespace Shop
+{
+    /// <summary>Looks up products.</summary>
+    public class ProductService
+    {
+        /// <summary>Creates the service.</summary>
+        public ProductService(IRepository repo) { _repo = repo; }
+
+        /// <summary>Finds a product name by id.</summary>
+        public async Task<string?> FindNameAsync(int id)
+        {
+            var items = await _repo.LoadAsync();
+            return items.Where(i => i.Id == id).Select(i => i.Name).FirstOrDefault();
+        }
+
+        /// <summary>Returns expensive products.</summary>
+        public async Task<List<Product>> ExpensiveAsync(decimal? min)
+        {
+            var items = await _repo.LoadAsync();
+            var query = from i in items where i.Price > min select i;
+            return query.ToList();
+        }
+
+        /// <summary>Counts items.</summary>
+        public async Task<int> CountAsync(string? filter)
+        {
+            var items = await _repo.LoadAsync();
+            return items.Count;
+        }
+    }
+}
+"#;
+
+    #[test]
+    fn extract_methods_and_constructors() {
+        let syms = parse_and_extract(SERVICE);
+        assert!(syms.iter().any(|s| s.name == "FindNameAsync" && s.kind == "method"));
+        assert!(syms.iter().any(|s| s.name == "ProductService" && s.kind == "method"));
+        assert_eq!(syms.len(), 4);
+    }
+
+    #[test]
+    fn xml_doc_coverage_metrics() {
+        let m = parse_and_metrics(SERVICE);
+        assert_eq!(m["doc_coverage_ratio"], 1.0);
+
+        let undocumented = "public class A\n{\n    // plain comment\n    public void Run() {}\n}\n";
+        let m = parse_and_metrics(undocumented);
+        assert_eq!(m["doc_coverage_ratio"], 0.0);
+    }
+
+    #[test]
+    fn linq_async_and_nullable_metrics() {
+        let m = parse_and_metrics(SERVICE);
+        assert!(m["linq_call_count"] >= 4.0, "{m:?}");
+        assert_eq!(m["async_method_ratio"], 1.0);
+        assert_eq!(m["nullable_annotation_count"], 3.0);
+    }
+
+    #[test]
+    fn symbol_complexity_metrics() {
+        let syms = parse_and_extract(SERVICE);
+        let find = syms.iter().find(|s| s.name == "FindNameAsync").unwrap();
+        assert_eq!(find.cyclomatic_complexity, 1);
+        assert_eq!(find.max_nesting_depth, 1);
+        assert_eq!(find.loc, 5);
+
+        let branchy = "class A\n{\n    void Run(int[] xs)\n    {\n        foreach (var x in xs)\n        {\n            if (x > 0) { Log(x); }\n        }\n    }\n}\n";
+        let syms = parse_and_extract(branchy);
+        assert_eq!(syms[0].cyclomatic_complexity, 3);
+        assert_eq!(syms[0].max_nesting_depth, 5); // body > foreach > block > if > block
+    }
+
+    #[test]
+    fn defensive_check_ratio_uses_nullable_annotations() {
+        let source = r#"
+class Service {
+    public Service(Repo repo, string name, int size, Logger? logger) {
+        ArgumentNullException.ThrowIfNull(repo);
+        _name = name ?? throw new ArgumentNullException(nameof(name));
+        if (logger != null) { logger.Log(size); }
+    }
+    void Save(Item item, string path) {
+        if (item is null) return;
+    }
+}
+"#;
+        let metrics = parse_and_metrics(source);
+        // repo, name, item, path are non-nullable references; three are checked.
+        assert_eq!(metrics.get("defensive_check_ratio"), Some(&0.75));
+    }
+
+    #[test]
+    fn entry_validation_ratio_counts_leading_guard_clusters() {
+        let source = r#"
+class Service {
+    public Service(Repo repo, string name) {
+        ArgumentNullException.ThrowIfNull(repo);
+        ArgumentException.ThrowIfNullOrEmpty(name);
+        _repo = repo;
+        _name = name;
+    }
+    public void Save(Item item, int count) {
+        if (item == null) throw new ArgumentNullException(nameof(item));
+        // Negative counts are a caller bug.
+        if (count < 0) {
+            throw new ArgumentOutOfRangeException(nameof(count));
+        }
+        _repo.Add(item, count);
+    }
+    int Scale(int value, int factor) {
+        if (value == 0) return 0;
+        if (factor > 1) { value *= factor; }
+        return value;
+    }
+    int Total(int[] values) {
+        var result = 0;
+        foreach (var v in values) result += v;
+        return result;
+    }
+    int Double(int x) => x * 2;
+}
+"#;
+        assert_eq!(parse_and_metrics(source)["entry_validation_ratio"], 0.5);
+        let (head, _) = source.split_once("    int Total").unwrap();
+        assert!(!parse_and_metrics(&format!("{head}}}")).contains_key("entry_validation_ratio"));
+    }
+
+    #[test]
+    fn doc_placement_consistency_wants_xml_doc_comments() {
+        let source = r#"
+class Store {
+    /// <summary>Loads the store.</summary>
+    [Obsolete]
+    public void Load() {}
+
+    /// <summary>Saves the store.</summary>
+    public void Save() {}
+
+    /// <summary>Closes the store.</summary>
+    /// <param name="force">Skip flushing.</param>
+    public void Close(bool force) {}
+
+    // Opens the store.
+    public void Open() {}
+
+    void Seek() {
+        // Moves the cursor.
+        Move();
+    }
+
+    void Undocumented() {}
+}
+"#;
+        assert_eq!(parse_and_metrics(source)["doc_placement_consistency"], 0.6);
+    }
+}
@@ -74,6 +74,24 @@ impl CstAnalyzer for GoCstAnalyzer {
             metrics.insert("avg_nesting_depth".into(), avg_depth);
         }
 
+        if let Some(u) = super::signature_uniformity(&all_fns, src_bytes, &[]) {
+            metrics.insert("signature_uniformity".into(), u);
+        }
+
+        // Unexported: lower-case name, excluding the `main` / `init` entry points.
+        let helpers: Vec<Node> = all_fns
+            .iter()
+            .copied()
+            .filter(|&f| {
+                get_function_name(f, src_bytes).is_some_and(|n| {
+                    n.starts_with(|c: char| c.is_lowercase()) && n != "main" && n != "init"
+                })
+            })
+            .collect();
+        if let Some(r) = super::single_use_helper_ratio(root, src_bytes, &helpers, &["call_expression"]) {
+            metrics.insert("single_use_helper_ratio".into(), r);
+        }
+
         let identifiers = collect_identifiers(root, src_bytes);
         if identifiers.len() >= 10 {
             metrics.insert("identifier_entropy".into(), shannon_entropy(&identifiers));
@@ -90,6 +108,30 @@ impl CstAnalyzer for GoCstAnalyzer {
         let named_returns = count_named_returns(root, src_bytes);
         metrics.insert("named_return_count".into(), named_returns as f64);
 
+        if let Some(r) = super::boolean_prefix_ratio(root, src_bytes, |n| boolean_bindings(n, src_bytes)) {
+            metrics.insert("boolean_prefix_ratio".into(), r);
+        }
+
+        if let Some(r) = super::defensive_check_ratio(&all_fns, src_bytes, nilable_params, |n| {
+            (n.kind() == "binary_expression").then(|| super::compared_to_null(n, "nil")).flatten()
+        }) {
+            metrics.insert("defensive_check_ratio".into(), r);
+        }
+
+        if let Some(u) = error_wrap_uniformity(root, src_bytes) {
+            metrics.insert("error_wrap_uniformity".into(), u);
+        }
+
+        if let Some(r) =
+            super::entry_validation_ratio(&all_fns, super::block_statements, |s| is_guard(s, src_bytes))
+        {
+            metrics.insert("entry_validation_ratio".into(), r);
+        }
+
+        if let Some(r) = super::doc_placement_consistency(&all_fns, |f| doc_placement(f, src_bytes)) {
+            metrics.insert("doc_placement_consistency".into(), r);
+        }
+
         metrics
     }
 
@@ -112,12 +154,7 @@ impl CstAnalyzer for GoCstAnalyzer {
                     };
                     if let Some(name) = get_function_name(node, source) {
                         results.push((
-                            SymbolMetadata {
-                                name: name.to_string(),
-                                kind: kind.to_string(),
-                                start_line: node.start_position().row + 1,
-                                end_line: node.end_position().row + 1,
-                            },
+                            symbol(name, kind, node, source),
                             node,
                         ));
                     }
@@ -224,12 +261,84 @@ fn count_err_nil_checks(root: Node<'_>, src_bytes: &[u8]) -> usize {
     count
 }
 
+/// Whether `func` is documented by a godoc comment: `//` lines directly
+/// above it, opening with the function's name.  For
+/// [`super::doc_placement_consistency`].
+fn doc_placement(func: Node<'_>, src_bytes: &[u8]) -> Option<bool> {
+    match super::preceding_comment(func, &[]) {
+        Some((comment, adjacent)) => {
+            // The last line is the sibling; walk up to the first of the block.
+            let mut first = comment;
+            while let Some(prev) = first.prev_sibling().filter(|p| {
+                p.kind() == "comment" && p.end_position().row + 1 == first.start_position().row
+            }) {
+                first = prev;
+            }
+            let name = get_function_name(func, src_bytes)?;
+            let text = first.utf8_text(src_bytes).ok()?;
+            let names_func = text.strip_prefix("// ").is_some_and(|t| {
+                t.strip_prefix(name).is_some_and(|rest| rest.starts_with(' ') || rest.is_empty())
+            });
+            Some(adjacent && names_func)
+        }
+        None => super::body_opens_with_comment(func).then_some(false),
+    }
+}
+
+/// An `if` without `else` that only returns or panics.
+fn is_guard(statement: Node<'_>, src_bytes: &[u8]) -> bool {
+    statement.kind() == "if_statement"
+        && statement.child_by_field_name("alternative").is_none()
+        && statement
+            .child_by_field_name("consequence")
+            .and_then(super::sole_statement)
+            .is_some_and(|s| {
+                s.kind() == "return_statement"
+                    || (s.kind() == "call_expression"
+                        && s.child_by_field_name("function").and_then(|f| f.utf8_text(src_bytes).ok())
+                            == Some("panic"))
+            })
+}
+
+/// Parameters of `func` (not its receiver) declared as a pointer, slice
+/// or map.  Nil slices and maps already behave as empty ones, and callers
+/// rarely pass nil pointers, so guarding these is defensive.
+fn nilable_params(func: Node<'_>) -> Vec<Node<'_>> {
+    let Some(params) = func.child_by_field_name("parameters") else {
+        return Vec::new();
+    };
+    let mut cursor = params.walk();
+    params
+        .named_children(&mut cursor)
+        .filter(|p| {
+            p.child_by_field_name("type")
+                .is_some_and(|t| matches!(t.kind(), "pointer_type" | "slice_type" | "map_type"))
+        })
+        .flat_map(|p| {
+            let mut cursor = p.walk();
+            p.children_by_field_name("name", &mut cursor).collect::<Vec<_>>()
+        })
+        .collect()
+}
+
 fn fn_line_count(node: Node<'_>) -> usize {
     let start = node.start_position().row;
     let end = node.end_position().row;
     (end - start) + 1
 }
 
+/// Symbol metadata for `node`, measured with this grammar's walkers.
+fn symbol(name: &str, kind: &str, node: Node<'_>, source: &[u8]) -> SymbolMetadata {
+    super::symbol_metadata(
+        name,
+        kind,
+        node,
+        source,
+        complexity_of_fn(node),
+        max_nesting_depth(node),
+    )
+}
+
 fn complexity_of_fn(root: Node<'_>) -> usize {
     let decision_kinds = [
         "if_statement",
@@ -375,6 +484,92 @@ fn count_named_returns(root: Node<'_>, src_bytes: &[u8]) -> usize {
     count
 }
 
+/// Fewest `fmt.Errorf(…%w…)` sites for which [`error_wrap_uniformity`] is
+/// reported.
+const MIN_ERROR_WRAPS: usize = 5;
+
+/// Share of `fmt.Errorf` calls wrapping with `%w` whose message follows the
+/// most common template (see [`wrap_template`]).  `None` below
+/// [`MIN_ERROR_WRAPS`] such calls.
+fn error_wrap_uniformity(root: Node<'_>, src_bytes: &[u8]) -> Option<f64> {
+    let mut templates: HashMap<String, usize> = HashMap::new();
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if node.kind() == "call_expression"
+            && node.child_by_field_name("function").and_then(|f| f.utf8_text(src_bytes).ok()) == Some("fmt.Errorf")
+        {
+            let format = node.child_by_field_name("arguments").and_then(|args| args.named_child(0));
+            if let Some(text) = format
+                .filter(|f| matches!(f.kind(), "interpreted_string_literal" | "raw_string_literal"))
+                .and_then(|f| f.utf8_text(src_bytes).ok())
+            {
+                if let Some(template) = wrap_template(&text[1..text.len() - 1]) {
+                    *templates.entry(template).or_insert(0) += 1;
+                }
+            }
+        }
+        let mut cursor = node.walk();
+        stack.extend(node.children(&mut cursor));
+    }
+    let total: usize = templates.values().sum();
+    if total < MIN_ERROR_WRAPS {
+        return None;
+    }
+    Some(templates.values().copied().max().unwrap_or(0) as f64 / total as f64)
+}
+
+/// The shape of a wrapping message, or `None` if it has no `%w`: its
+/// lowercased first word plus everything from the punctuation before `%w`
+/// onward, so `"failed to open %s: %w"` and `"failed to decode header: %w"`
+/// share the template `failed … : %w` while `"open config: %w"` does not.
+fn wrap_template(message: &str) -> Option<String> {
+    let verb = message.find("%w")?;
+    let separator = message[..verb].trim_end_matches([':', ' ', '-', '(', '[', '=']).len();
+    let lead = message[..separator].split_whitespace().next().unwrap_or("").to_lowercase();
+    Some(format!("{lead} … {}", &message[separator..]))
+}
+
+/// Names `node` declares as `bool`, for [`super::boolean_prefix_ratio`]:
+/// `bool`-typed vars, consts, parameters and struct fields, names bound to
+/// `true`/`false` (including by `:=`), and functions returning `bool`.
+fn boolean_bindings<'t>(node: Node<'t>, src_bytes: &[u8]) -> Vec<Node<'t>> {
+    let is_bool_type = |t: Node<'_>| t.utf8_text(src_bytes) == Ok("bool");
+    // Pair each name with the value at the same position in `a, b = x, y`.
+    let bound_to_literals = |names: Vec<Node<'t>>, values: Option<Node<'t>>| -> Vec<Node<'t>> {
+        let Some(values) = values else { return Vec::new() };
+        let mut cursor = values.walk();
+        names
+            .into_iter()
+            .zip(values.named_children(&mut cursor))
+            .filter(|(_, v)| matches!(v.kind(), "true" | "false"))
+            .map(|(n, _)| n)
+            .collect()
+    };
+    let mut cursor = node.walk();
+    match node.kind() {
+        "var_spec" | "const_spec" | "parameter_declaration" | "field_declaration" => {
+            let names: Vec<Node> = node.children_by_field_name("name", &mut cursor).collect();
+            if node.child_by_field_name("type").is_some_and(is_bool_type) {
+                names
+            } else {
+                bound_to_literals(names, node.child_by_field_name("value"))
+            }
+        }
+        "short_var_declaration" => {
+            let names = node.child_by_field_name("left").map_or_else(Vec::new, |left| {
+                left.named_children(&mut cursor).filter(|n| n.kind() == "identifier").collect()
+            });
+            bound_to_literals(names, node.child_by_field_name("right"))
+        }
+        "function_declaration" | "method_declaration"
+            if node.child_by_field_name("result").is_some_and(is_bool_type) =>
+        {
+            node.child_by_field_name("name").into_iter().collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -458,6 +653,23 @@ func worker() {}
         assert!(m["goroutine_count"] >= 2.0);
     }
 
+    #[test]
+    fn boolean_prefix_ratio_uses_declared_types() {
+        let source = "package main
+type S struct {
+    IsOpen, HasData bool
+    count           int
+}
+func (s S) CanRetry() bool { return true }
+func run(shouldLog bool) {
+    done, n := false, 1
+    var isReady = true
+    _ = n
+}
+";
+        assert_eq!(parse_and_metrics(source)["boolean_prefix_ratio"], 5.0 / 6.0);
+    }
+
     #[test]
     fn err_nil_check_metrics() {
         let source = r#"package main
@@ -483,4 +695,137 @@ func Run() {
         let m = parse_and_metrics(source);
         assert!(m["err_nil_check_count"] >= 3.0);
     }
+
+    #[test]
+    fn defensive_check_ratio_counts_nil_guards_on_nilable_params() {
+        let source = r#"
+package store
+
+func (s *Store) Put(key string, value *Item, tags []string) error {
+	if value == nil {
+		return ErrNil
+	}
+	if nil == tags {
+		tags = []string{}
+	}
+	return nil
+}
+
+func Merge(a, b map[string]int, n int) {}
+"#;
+        let metrics = parse_and_metrics(source);
+        // value, tags, a, b are nilable (the receiver and key/n are not).
+        assert_eq!(metrics.get("defensive_check_ratio"), Some(&0.5));
+    }
+
+    #[test]
+    fn error_wrap_uniformity_compares_message_templates() {
+        let wraps = |messages: &[&str]| {
+            let body: String = messages
+                .iter()
+                .map(|m| format!("\tif err != nil {{\n\t\treturn fmt.Errorf(\"{m}\", err)\n\t}}\n"))
+                .collect();
+            parse_and_metrics(&format!("package app\n\nfunc run() error {{\n{body}\treturn nil\n}}\n"))
+        };
+        let uniform = wraps(&[
+            "failed to open %s: %w",
+            "failed to read config: %w",
+            "failed to decode header: %w",
+            "failed to connect: %w",
+            "failed to close: %w",
+        ]);
+        assert_eq!(uniform.get("error_wrap_uniformity"), Some(&1.0));
+
+        let varied = wraps(&[
+            "open %s: %w",
+            "reading config failed (%w)",
+            "failed to decode header: %w",
+            "%w",
+            "connect to %q: %w",
+            "no messages wrapped here",
+        ]);
+        assert_eq!(varied.get("error_wrap_uniformity"), Some(&0.2));
+        assert!(!wraps(&["failed to a: %w", "failed to b: %w"]).contains_key("error_wrap_uniformity"));
+    }
+
+    #[test]
+    fn entry_validation_ratio_counts_leading_guard_clusters() {
+        let source = r#"package app
+
+func Load(path string, mode int) (*File, error) {
+	if path == "" {
+		return nil, errors.New("path is required")
+	}
+	// Modes are validated here rather than by the caller.
+	if mode < 0 {
+		panic("negative mode")
+	}
+	return open(path, mode)
+}
+
+func (s *Store) Save(data []byte) error {
+	if s == nil {
+		return errNilStore
+	}
+	if len(data) == 0 {
+		return errEmpty
+	}
+	_, err := s.w.Write(data)
+	return err
+}
+
+func scale(values []int, factor int) []int {
+	if len(values) == 0 {
+		return nil
+	}
+	if factor > 1 {
+		values = multiply(values, factor)
+	}
+	return values
+}
+
+func total(values []int) int {
+	result := 0
+	for _, v := range values {
+		result += v
+	}
+	return result
+}
+
+func short() int { return 1 }
+"#;
+        assert_eq!(parse_and_metrics(source)["entry_validation_ratio"], 0.5);
+        let (head, _) = source.split_once("func total").unwrap();
+        assert!(!parse_and_metrics(head).contains_key("entry_validation_ratio"));
+    }
+
+    #[test]
+    fn doc_placement_consistency_wants_godoc_comments() {
+        let source = r#"package store
+
+// Load reads the file.
+// It never blocks.
+func Load() {}
+
+// Save writes the file.
+func (s *Store) Save() {}
+
+// Close closes the file.
+func Close() {}
+
+// opens the file
+func Open() {}
+
+/* Seek moves the cursor. */
+func Seek() {}
+
+func flush() {
+	// Writes pending data.
+	write()
+}
+
+func undocumented() {}
+"#;
+        assert_eq!(parse_and_metrics(source)["doc_placement_consistency"], 0.5);
+    }
 }
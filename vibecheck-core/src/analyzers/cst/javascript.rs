@@ -8,6 +8,9 @@ use crate::report::SymbolMetadata;
 
 pub struct JavaScriptCstAnalyzer;
 
+/// Keywords compared by [`super::signature_uniformity`].
+const SIGNATURE_MODIFIERS: &[&str] = &["async", "static", "get", "set"];
+
 impl CstAnalyzer for JavaScriptCstAnalyzer {
     fn name(&self) -> &str {
         "js_cst"
@@ -74,6 +77,15 @@ impl CstAnalyzer for JavaScriptCstAnalyzer {
             metrics.insert("avg_nesting_depth".into(), avg_depth);
         }
 
+        if let Some(u) = super::signature_uniformity(&all_fns, src_bytes, SIGNATURE_MODIFIERS) {
+            metrics.insert("signature_uniformity".into(), u);
+        }
+
+        let helpers = collect_private_helpers(root);
+        if let Some(r) = super::single_use_helper_ratio(root, src_bytes, &helpers, &["call_expression"]) {
+            metrics.insert("single_use_helper_ratio".into(), r);
+        }
+
         let identifiers = collect_identifiers(root, src_bytes);
         if identifiers.len() >= 10 {
             metrics.insert("identifier_entropy".into(), shannon_entropy(&identifiers));
@@ -95,6 +107,39 @@ impl CstAnalyzer for JavaScriptCstAnalyzer {
             );
         }
 
+        if let Some(r) = super::explicit_member_access_ratio(
+            root,
+            src_bytes,
+            is_this_member,
+            this_hoisted_bindings,
+            &["call_expression"],
+            "statement_block",
+        ) {
+            metrics.insert("explicit_member_access_ratio".into(), r);
+        }
+
+        if let Some(r) = super::boolean_prefix_ratio(root, src_bytes, boolean_bindings) {
+            metrics.insert("boolean_prefix_ratio".into(), r);
+        }
+
+        if has_jsx(root) {
+            extract_jsx_metrics(root, src_bytes, &mut metrics);
+        }
+
+        if let Some(r) = super::defensive_check_ratio(&all_fns, src_bytes, plain_params, |n| {
+            nullish_checked(n, src_bytes)
+        }) {
+            metrics.insert("defensive_check_ratio".into(), r);
+        }
+
+        if let Some(r) = super::entry_validation_ratio(&all_fns, super::block_statements, is_guard) {
+            metrics.insert("entry_validation_ratio".into(), r);
+        }
+
+        if let Some(r) = super::doc_placement_consistency(&all_fns, |f| doc_placement(f, src_bytes)) {
+            metrics.insert("doc_placement_consistency".into(), r);
+        }
+
         metrics
     }
 
@@ -116,12 +161,7 @@ impl CstAnalyzer for JavaScriptCstAnalyzer {
                         .and_then(|c| c.utf8_text(source).ok())
                     {
                         results.push((
-                            SymbolMetadata {
-                                name: name.to_string(),
-                                kind: "function".to_string(),
-                                start_line: node.start_position().row + 1,
-                                end_line: node.end_position().row + 1,
-                            },
+                            symbol(name, "function", node, source),
                             node,
                         ));
                     }
@@ -135,12 +175,7 @@ impl CstAnalyzer for JavaScriptCstAnalyzer {
                         .and_then(|c| c.utf8_text(source).ok())
                     {
                         results.push((
-                            SymbolMetadata {
-                                name: name.to_string(),
-                                kind: "method".to_string(),
-                                start_line: node.start_position().row + 1,
-                                end_line: node.end_position().row + 1,
-                            },
+                            symbol(name, "method", node, source),
                             node,
                         ));
                     }
@@ -161,12 +196,7 @@ impl CstAnalyzer for JavaScriptCstAnalyzer {
                             .and_then(|c| c.utf8_text(source).ok())
                         {
                             results.push((
-                                SymbolMetadata {
-                                    name: name.to_string(),
-                                    kind: "function".to_string(),
-                                    start_line: node.start_position().row + 1,
-                                    end_line: node.end_position().row + 1,
-                                },
+                                symbol(name, "function", node, source),
                                 node,
                             ));
                         }
@@ -222,6 +252,67 @@ fn count_then_calls(root: Node<'_>, src_bytes: &[u8]) -> usize {
     count
 }
 
+/// `this.prop`, the receiver member access counted by
+/// [`super::explicit_member_access_ratio`].
+fn is_this_member(node: Node<'_>) -> bool {
+    node.kind() == "member_expression"
+        && node.child_by_field_name("object").is_some_and(|o| o.kind() == "this")
+}
+
+/// Names `node` binds to `true`/`false`, for [`super::boolean_prefix_ratio`]:
+/// variables, `this.prop` assignments, class fields, and parameter
+/// defaults.  JavaScript has no declared types, so the literal is the only
+/// evidence a name holds a boolean.
+fn boolean_bindings(node: Node<'_>) -> Vec<Node<'_>> {
+    let (name, value) = match node.kind() {
+        "variable_declarator" => ("name", "value"),
+        "assignment_expression" | "assignment_pattern" => ("left", "right"),
+        "field_definition" => ("property", "value"),
+        _ => return Vec::new(),
+    };
+    if !node.child_by_field_name(value).is_some_and(|v| matches!(v.kind(), "true" | "false")) {
+        return Vec::new();
+    }
+    let name = node.child_by_field_name(name).map(|n| {
+        if is_this_member(n) {
+            n.child_by_field_name("property").unwrap_or(n)
+        } else {
+            n
+        }
+    });
+    name.filter(|n| matches!(n.kind(), "identifier" | "property_identifier"))
+        .into_iter()
+        .collect()
+}
+
+/// Names a declaration hoists out of `this`: `const x = this.x` or
+/// `const { a, b } = this`.
+fn this_hoisted_bindings(node: Node<'_>) -> Vec<Node<'_>> {
+    if node.kind() != "variable_declarator" {
+        return Vec::new();
+    }
+    let (Some(name), Some(value)) = (node.child_by_field_name("name"), node.child_by_field_name("value"))
+    else {
+        return Vec::new();
+    };
+    if name.kind() == "identifier" && is_this_member(value) {
+        return vec![name];
+    }
+    if name.kind() != "object_pattern" || value.kind() != "this" {
+        return Vec::new();
+    }
+    let mut names = Vec::new();
+    let mut stack = vec![name];
+    while let Some(n) = stack.pop() {
+        if matches!(n.kind(), "identifier" | "shorthand_property_identifier_pattern") {
+            names.push(n);
+        }
+        let mut cursor = n.walk();
+        stack.extend(n.children(&mut cursor));
+    }
+    names
+}
+
 fn collect_all_functions<'t>(root: Node<'t>) -> Vec<Node<'t>> {
     let fn_kinds = [
         "function_declaration",
@@ -243,12 +334,56 @@ fn collect_all_functions<'t>(root: Node<'t>) -> Vec<Node<'t>> {
     result
 }
 
+/// Named functions that aren't exported: `function` declarations and
+/// `const`/`let` declarators bound to a function or arrow function.
+fn collect_private_helpers<'t>(root: Node<'t>) -> Vec<Node<'t>> {
+    let exported = |decl: Node<'_>| decl.parent().is_some_and(|p| p.kind() == "export_statement");
+    let mut result = Vec::new();
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        match node.kind() {
+            "function_declaration" if !exported(node) => result.push(node),
+            "variable_declarator" => {
+                let is_fn = node
+                    .child_by_field_name("value")
+                    .is_some_and(|v| matches!(v.kind(), "arrow_function" | "function" | "function_expression"));
+                if is_fn && !node.parent().is_some_and(exported) {
+                    result.push(node);
+                }
+            }
+            _ => {}
+        }
+        let mut cursor = node.walk();
+        stack.extend(node.children(&mut cursor));
+    }
+    result
+}
+
 fn fn_line_count(node: Node<'_>) -> usize {
     let start = node.start_position().row;
     let end = node.end_position().row;
     (end - start) + 1
 }
 
+/// Symbol metadata for `node`, measured with this grammar's walkers.
+///
+/// For `const f = () => ...` the symbol spans the declarator, but the
+/// walkers skip nested functions, so complexity is measured on the value.
+fn symbol(name: &str, kind: &str, node: Node<'_>, source: &[u8]) -> SymbolMetadata {
+    let body = match node.kind() {
+        "variable_declarator" => node.child_by_field_name("value").unwrap_or(node),
+        _ => node,
+    };
+    super::symbol_metadata(
+        name,
+        kind,
+        node,
+        source,
+        complexity_of_fn(body),
+        max_nesting_depth(body),
+    )
+}
+
 fn complexity_of_fn(root: Node<'_>) -> usize {
     let decision_kinds = [
         "if_statement",
@@ -374,6 +509,70 @@ fn inline_comment_ratio(functions: &[Node<'_>], src_bytes: &[u8]) -> (usize, usi
     (comment_lines, code_lines)
 }
 
+/// Whether `func` is documented by a `/** */` JSDoc block directly above
+/// it (or above the `export`/`const` it is declared in), for
+/// [`super::doc_placement_consistency`].  Function expressions that are
+/// not bound to a name, such as callbacks, are skipped.
+fn doc_placement(func: Node<'_>, src_bytes: &[u8]) -> Option<bool> {
+    let mut anchor = func;
+    if matches!(func.kind(), "arrow_function" | "function") {
+        anchor = func.parent().filter(|p| p.kind() == "variable_declarator")?.parent()?;
+    }
+    if let Some(export) = anchor.parent().filter(|p| p.kind() == "export_statement") {
+        anchor = export;
+    }
+    match super::preceding_comment(anchor, &[]) {
+        Some((comment, adjacent)) => Some(adjacent && comment.utf8_text(src_bytes).ok()?.starts_with("/**")),
+        None => super::body_opens_with_comment(func).then_some(false),
+    }
+}
+
+/// An `if` without `else` that only throws or returns.
+fn is_guard(statement: Node<'_>) -> bool {
+    statement.kind() == "if_statement"
+        && statement.child_by_field_name("alternative").is_none()
+        && statement
+            .child_by_field_name("consequence")
+            .and_then(super::sole_statement)
+            .is_some_and(|s| matches!(s.kind(), "throw_statement" | "return_statement"))
+}
+
+/// Plain identifier parameters of `func`.  Without types every one of
+/// them could be null; those with a default, destructuring or rest
+/// parameters are skipped.
+fn plain_params(func: Node<'_>) -> Vec<Node<'_>> {
+    if let Some(param) = func.child_by_field_name("parameter") {
+        return vec![param].into_iter().filter(|p| p.kind() == "identifier").collect();
+    }
+    let Some(params) = func.child_by_field_name("parameters") else {
+        return Vec::new();
+    };
+    let mut cursor = params.walk();
+    params.named_children(&mut cursor).filter(|p| p.kind() == "identifier").collect()
+}
+
+/// The identifier compared against `null` or `undefined` (`x === null`,
+/// `x == undefined`, `typeof x === "undefined"`) or dereferenced with
+/// optional chaining (`x?.y`).
+fn nullish_checked<'t>(node: Node<'t>, src_bytes: &[u8]) -> Option<Node<'t>> {
+    match node.kind() {
+        "binary_expression" => super::compared_to_null(node, "null")
+            .or_else(|| super::compared_to_null(node, "undefined"))
+            .or_else(|| {
+                let left = node.child_by_field_name("left")?;
+                let right = node.child_by_field_name("right")?;
+                let typeof_undefined = left.kind() == "unary_expression"
+                    && left.child(0).is_some_and(|op| op.kind() == "typeof")
+                    && matches!(right.utf8_text(src_bytes), Ok("'undefined'" | "\"undefined\""));
+                left.child_by_field_name("argument").filter(|a| typeof_undefined && a.kind() == "identifier")
+            }),
+        "member_expression" => node
+            .child_by_field_name("object")
+            .filter(|o| o.kind() == "identifier" && node.child_by_field_name("optional_chain").is_some()),
+        _ => None,
+    }
+}
+
 fn count_template_literals(root: Node<'_>, _src_bytes: &[u8]) -> (usize, usize) {
     let mut template_count = 0usize;
     let mut string_count = 0usize;
@@ -397,6 +596,145 @@ fn count_template_literals(root: Node<'_>, _src_bytes: &[u8]) -> (usize, usize)
     (template_count, string_count)
 }
 
+fn has_jsx(root: Node<'_>) -> bool {
+    count_nodes_of_kind(root, "jsx_element") + count_nodes_of_kind(root, "jsx_self_closing_element")
+        > 0
+}
+
+/// React component metrics, only computed when the tree contains JSX.
+///
+/// The pipeline matches these against `jsx` heuristics for `.jsx` / `.tsx`
+/// files only, so plain `.js` files never emit component signals.
+fn extract_jsx_metrics(root: Node<'_>, src_bytes: &[u8], metrics: &mut HashMap<String, f64>) {
+    metrics.insert("jsx_hook_calls".into(), count_hook_calls(root, src_bytes) as f64);
+
+    let (functional, destructured) = count_functional_components(root, src_bytes);
+    let class = count_class_components(root, src_bytes);
+    let total = functional + class;
+    if total >= 2 {
+        metrics.insert(
+            "jsx_functional_component_ratio".into(),
+            functional as f64 / total as f64,
+        );
+    }
+    if functional >= 2 {
+        metrics.insert(
+            "jsx_prop_destructuring_ratio".into(),
+            destructured as f64 / functional as f64,
+        );
+    }
+}
+
+/// Count calls to React hooks — `useX(...)` or `React.useX(...)`.
+fn count_hook_calls(root: Node<'_>, src_bytes: &[u8]) -> usize {
+    let mut count = 0;
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if node.kind() == "call_expression" {
+            let callee = node.child_by_field_name("function").and_then(|func| match func.kind() {
+                "identifier" => Some(func),
+                "member_expression" => func.child_by_field_name("property"),
+                _ => None,
+            });
+            if let Some(name) = callee.and_then(|c| c.utf8_text(src_bytes).ok()) {
+                if is_hook_name(name) {
+                    count += 1;
+                }
+            }
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            stack.push(child);
+        }
+    }
+    count
+}
+
+fn is_hook_name(name: &str) -> bool {
+    name.strip_prefix("use")
+        .and_then(|rest| rest.chars().next())
+        .is_some_and(|c| c.is_ascii_uppercase())
+}
+
+fn is_component_name(name: &str) -> bool {
+    name.chars().next().is_some_and(|c| c.is_ascii_uppercase())
+}
+
+/// Count function components (capitalised functions that render JSX) and how
+/// many of them destructure their props in the parameter list.
+fn count_functional_components(root: Node<'_>, src_bytes: &[u8]) -> (usize, usize) {
+    let mut functional = 0;
+    let mut destructured = 0;
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        let candidate = match node.kind() {
+            "function_declaration" => Some((node.child_by_field_name("name"), node)),
+            "variable_declarator" => node.child_by_field_name("value").and_then(|value| {
+                matches!(value.kind(), "arrow_function" | "function_expression")
+                    .then(|| (node.child_by_field_name("name"), value))
+            }),
+            _ => None,
+        };
+        if let Some((Some(name), func)) = candidate {
+            let is_component = name
+                .utf8_text(src_bytes)
+                .is_ok_and(is_component_name)
+                && has_jsx(func);
+            if is_component {
+                functional += 1;
+                if first_param_is_object_pattern(func) {
+                    destructured += 1;
+                }
+            }
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            stack.push(child);
+        }
+    }
+    (functional, destructured)
+}
+
+fn first_param_is_object_pattern(func: Node<'_>) -> bool {
+    let Some(params) = func.child_by_field_name("parameters") else {
+        return false;
+    };
+    let mut cursor = params.walk();
+    let first = params.named_children(&mut cursor).next();
+    first.is_some_and(|p| match p.kind() {
+        "object_pattern" => true,
+        // `({ a, b } = {}) => ...`
+        "assignment_pattern" => p
+            .child_by_field_name("left")
+            .is_some_and(|l| l.kind() == "object_pattern"),
+        _ => false,
+    })
+}
+
+/// Count `class X extends Component` / `React.Component` / `PureComponent`.
+fn count_class_components(root: Node<'_>, src_bytes: &[u8]) -> usize {
+    let mut count = 0;
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if matches!(node.kind(), "class_declaration" | "class") {
+            let mut cursor = node.walk();
+            let extends_component = node
+                .children(&mut cursor)
+                .find(|c| c.kind() == "class_heritage")
+                .and_then(|h| h.utf8_text(src_bytes).ok())
+                .is_some_and(|text| text.contains("Component"));
+            if extends_component {
+                count += 1;
+            }
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            stack.push(child);
+        }
+    }
+    count
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -516,4 +854,178 @@ function process(user) {
         let m = parse_and_metrics(source);
         assert!(m["optional_chain_count"] >= 3.0);
     }
+
+    #[test]
+    fn jsx_component_metrics() {
+        let source = r#"
+import React, { useState, useEffect } from 'react';
+
+function Counter({ initial }) {
+    const [count, setCount] = useState(initial);
+    useEffect(() => { document.title = `${count}`; }, [count]);
+    return <button onClick={() => setCount(count + 1)}>{count}</button>;
+}
+
+const Label = ({ text }) => <span>{text}</span>;
+
+const Panel = (props) => {
+    const ref = React.useRef(null);
+    return <div ref={ref}>{props.children}</div>;
+};
+"#;
+        let m = parse_and_metrics(source);
+        assert_eq!(m["jsx_hook_calls"], 3.0);
+        assert_eq!(m["jsx_functional_component_ratio"], 1.0);
+        assert!((m["jsx_prop_destructuring_ratio"] - 2.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn jsx_class_components_lower_functional_ratio() {
+        let source = r#"
+class Header extends React.Component {
+    render() {
+        return <h1>{this.props.title}</h1>;
+    }
+}
+
+function Footer({ year }) {
+    return <footer>{year}</footer>;
+}
+"#;
+        let m = parse_and_metrics(source);
+        assert_eq!(m["jsx_functional_component_ratio"], 0.5);
+        assert!(!m.contains_key("jsx_prop_destructuring_ratio"));
+    }
+
+    #[test]
+    fn plain_js_has_no_jsx_metrics() {
+        let source = r#"
+function useThing() {
+    return useState(0);
+}
+"#;
+        let m = parse_and_metrics(source);
+        assert!(!m.contains_key("jsx_hook_calls"));
+    }
+
+    #[test]
+    fn arrow_function_symbol_complexity_measured_on_body() {
+        let source = "const pick = (a, b) => {\n  if (a) {\n    return a;\n  }\n  return b ? b : null;\n};\n";
+        let syms = parse_and_extract(source);
+        let pick = syms.iter().find(|s| s.name == "pick").unwrap();
+        assert_eq!(pick.cyclomatic_complexity, 3);
+        assert!(pick.max_nesting_depth >= 2, "{pick:?}");
+        assert_eq!(pick.loc, 6);
+    }
+
+    #[test]
+    fn boolean_prefix_ratio_uses_literal_initializers() {
+        let source = "class Conn {
+  isOpen = false;
+  constructor(shouldRetry = true) {
+    this.hasData = false;
+    this.count = 0;
+  }
+}
+let canRetry = true, done = false;
+let attempts = 0;
+";
+        assert_eq!(parse_and_metrics(source)["boolean_prefix_ratio"], 4.0 / 5.0);
+    }
+
+    #[test]
+    fn explicit_member_access_ratio_counts_hoisted_locals() {
+        let qualified = "class S {\n  total() {\n    return this.a + this.b + this.c + this.d + this.e;\n  }\n\
+                         \x20 scaled() {\n    return this.a * this.b * this.c * this.d * this.e * this.norm();\n  }\n}\n";
+        assert_eq!(parse_and_metrics(qualified)["explicit_member_access_ratio"], 1.0);
+
+        let hoisted = "class S {\n  total() {\n    const { a, b } = this;\n    const c = this.c;\n\
+                       \x20   return a + b + c + a + b + c + this.d + this.e;\n  }\n\
+                       \x20 scaled() {\n    return this.a * this.b * this.c * this.d * this.e * this.f * this.g;\n  }\n}\n";
+        assert_eq!(parse_and_metrics(hoisted)["explicit_member_access_ratio"], 10.0 / 16.0);
+    }
+
+    #[test]
+    fn defensive_check_ratio_counts_nullish_guards() {
+        let source = r#"
+function render(user, options, retries = 3) {
+  if (user === null || user === undefined) return "";
+  const theme = options?.theme;
+  return theme;
+}
+const label = (item) => typeof item === "undefined" ? "" : item.name;
+function plain(a, b) { return a + b; }
+"#;
+        let metrics = parse_and_metrics(source);
+        // user, options, item checked; a, b not; retries has a default.
+        assert_eq!(metrics.get("defensive_check_ratio"), Some(&0.6));
+    }
+
+    #[test]
+    fn entry_validation_ratio_counts_leading_guard_clusters() {
+        let source = r#"
+function load(path, mode) {
+  if (!path) throw new Error("path is required");
+  if (typeof mode !== "string") {
+    throw new TypeError("mode must be a string");
+  }
+  return read(path, mode);
+}
+const save = (data, target) => {
+  // Validate before writing.
+  if (data == null) return;
+  if (!target) {
+    throw new Error("target is required");
+  }
+  target.write(data);
+};
+function scale(values, factor) {
+  if (!values) return [];
+  if (factor > 1) {
+    values = values.map((v) => v * factor);
+  }
+  return values;
+}
+function total(values) {
+  let result = 0;
+  for (const v of values) result += v;
+  return result;
+}
+const double = (x) => x * 2;
+"#;
+        assert_eq!(parse_and_metrics(source)["entry_validation_ratio"], 0.5);
+        assert!(!parse_and_metrics("function f(a) {\n  if (!a) return;\n  a.run();\n  return a;\n}\n")
+            .contains_key("entry_validation_ratio"));
+    }
+
+    #[test]
+    fn doc_placement_consistency_wants_adjacent_jsdoc() {
+        let source = r#"
+/** Loads the file. */
+function load() {}
+
+/**
+ * Saves the file.
+ */
+export const save = () => {};
+
+class Store {
+  /** Closes the store. */
+  close() {}
+
+  // Opens the store.
+  open() {}
+}
+
+/* Not JSDoc. */
+export function seek() {
+  items.forEach((item) => {
+    // Callbacks are skipped.
+  });
+}
+
+function undocumented() {}
+"#;
+        assert_eq!(parse_and_metrics(source)["doc_placement_consistency"], 0.6);
+    }
 }
@@ -1,4 +1,478 @@
+pub mod csharp;
 pub mod go;
 pub mod javascript;
 pub mod python;
 pub mod rust;
+
+use tree_sitter::Node;
+
+use crate::report::SymbolMetadata;
+
+/// [`SymbolMetadata`] spanning the whole definition of `node` (see
+/// [`definition_start`]), with its per-symbol complexity.
+///
+/// `decisions` and `nesting` come from the calling analyzer's own walkers,
+/// since branch and block node kinds differ per grammar.
+pub(crate) fn symbol_metadata(
+    name: &str,
+    kind: &str,
+    node: Node<'_>,
+    source: &[u8],
+    decisions: usize,
+    nesting: usize,
+) -> SymbolMetadata {
+    let start = definition_start(node);
+    let loc = source
+        .get(start.start_byte()..node.end_byte())
+        .and_then(|bytes| std::str::from_utf8(bytes).ok())
+        .map(|text| text.lines().filter(|l| !l.trim().is_empty()).count())
+        .unwrap_or(0);
+    SymbolMetadata {
+        name: name.to_string(),
+        kind: kind.to_string(),
+        start_line: start.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        cyclomatic_complexity: decisions + 1,
+        max_nesting_depth: nesting,
+        loc,
+    }
+}
+
+/// The node a definition really starts at: `node` widened to its Python
+/// `decorated_definition`, JS `export_statement` or `const`/`let`/`var`
+/// declaration, then back over directly preceding Rust `#[...]`
+/// attributes.  Decorators and attributes are part of what an editor
+/// shows as the symbol.
+fn definition_start(node: Node<'_>) -> Node<'_> {
+    const WRAPPERS: &[&str] =
+        &["decorated_definition", "export_statement", "lexical_declaration", "variable_declaration"];
+    let mut start = node;
+    while let Some(parent) = start.parent().filter(|p| WRAPPERS.contains(&p.kind())) {
+        start = parent;
+    }
+    while let Some(prev) = start.prev_sibling().filter(|p| p.kind() == "attribute_item") {
+        start = prev;
+    }
+    start
+}
+
+/// Fewest functions for which [`signature_uniformity`] is reported.
+pub(crate) const MIN_SIGNATURES: usize = 6;
+
+/// How uniformly the function signatures in a file are styled, in `[0, 1]`.
+///
+/// Each signature (the function node's text up to its body) is reduced to a
+/// handful of formatting features: modifier keywords in order, whether it
+/// declares a return type, whether it spans lines, spacing after `,`, `(`
+/// and `:`, and the gap before the body.  For every feature the share of
+/// signatures agreeing with the most common value is taken (skipping
+/// signatures where the feature doesn't apply, such as comma spacing with
+/// one parameter), and the shares are averaged.  `None` below
+/// [`MIN_SIGNATURES`] functions with bodies.
+pub(crate) fn signature_uniformity(
+    functions: &[Node<'_>],
+    source: &[u8],
+    modifiers: &[&str],
+) -> Option<f64> {
+    let features: Vec<[Option<String>; 7]> = functions
+        .iter()
+        .filter_map(|f| {
+            let body = f.child_by_field_name("body")?;
+            let text = std::str::from_utf8(source.get(f.start_byte()..body.start_byte())?).ok()?;
+            Some(signature_features(text, modifiers))
+        })
+        .collect();
+    if features.len() < MIN_SIGNATURES {
+        return None;
+    }
+
+    let mut shares = Vec::new();
+    for i in 0..7 {
+        let mut counts: std::collections::HashMap<&str, usize> = std::collections::HashMap::new();
+        for f in &features {
+            if let Some(value) = &f[i] {
+                *counts.entry(value).or_insert(0) += 1;
+            }
+        }
+        let present: usize = counts.values().sum();
+        if present >= 2 {
+            let majority = counts.values().copied().max().unwrap_or(0);
+            shares.push(majority as f64 / present as f64);
+        }
+    }
+    Some(shares.iter().sum::<f64>() / shares.len() as f64)
+}
+
+/// Fewest private helpers for which [`single_use_helper_ratio`] is reported,
+/// so a file with one or two extracted functions is never judged.
+pub(crate) const MIN_PRIVATE_HELPERS: usize = 4;
+
+/// Share of `helpers` (private function nodes with a `name` field) that
+/// are called exactly once in the file.
+///
+/// Calls are counted over every `call_kinds` node by the trailing
+/// identifier of its `function` field, so `helper()`, `self.helper()` and
+/// `Self::helper()` all count toward `helper`.  Reused helpers (two or more
+/// calls) and uncalled ones (entry points, callbacks passed by name) are not
+/// single-use.  `None` below [`MIN_PRIVATE_HELPERS`] helpers.
+pub(crate) fn single_use_helper_ratio(
+    root: Node<'_>,
+    source: &[u8],
+    helpers: &[Node<'_>],
+    call_kinds: &[&str],
+) -> Option<f64> {
+    let names: Vec<&str> = helpers
+        .iter()
+        .filter_map(|f| f.child_by_field_name("name")?.utf8_text(source).ok())
+        .collect();
+    if names.len() < MIN_PRIVATE_HELPERS {
+        return None;
+    }
+
+    let mut calls: std::collections::HashMap<&str, usize> = std::collections::HashMap::new();
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if call_kinds.contains(&node.kind()) {
+            if let Some(callee) = node
+                .child_by_field_name("function")
+                .and_then(|f| f.utf8_text(source).ok())
+            {
+                // Drop generic arguments (`parse::<T>`, `Parse<T>`) first.
+                let callee = callee.split('<').next().unwrap_or(callee);
+                if let Some(name) = callee
+                    .rsplit(|c: char| !c.is_alphanumeric() && c != '_')
+                    .find(|s| !s.is_empty())
+                {
+                    *calls.entry(name).or_insert(0) += 1;
+                }
+            }
+        }
+        let mut cursor = node.walk();
+        stack.extend(node.children(&mut cursor));
+    }
+
+    let single_use = names.iter().filter(|n| calls.get(*n) == Some(&1)).count();
+    Some(single_use as f64 / names.len() as f64)
+}
+
+/// Fewest receiver field reads for which [`explicit_member_access_ratio`]
+/// is reported.
+pub(crate) const MIN_MEMBER_ACCESSES: usize = 10;
+
+/// Share of field reads on the method receiver (`self.x`, `this.x`) that
+/// are written out in full rather than through a local hoisted from it.
+///
+/// `is_receiver_access` picks out receiver member-access nodes; those that
+/// are the `function` of a `call_kinds` node are method calls, which can't
+/// be hoisted, and are skipped.  `hoisted_bindings` returns the identifier
+/// nodes a statement binds from the receiver (`let x = &self.x`,
+/// `const { a, b } = this`); every later use of such a name within the
+/// enclosing `scope_kind` node counts as an unqualified access.  `None`
+/// below [`MIN_MEMBER_ACCESSES`] qualified reads.
+pub(crate) fn explicit_member_access_ratio<'t>(
+    root: Node<'t>,
+    source: &[u8],
+    is_receiver_access: impl Fn(Node<'t>) -> bool,
+    hoisted_bindings: impl Fn(Node<'t>) -> Vec<Node<'t>>,
+    call_kinds: &[&str],
+    scope_kind: &str,
+) -> Option<f64> {
+    let mut qualified = 0usize;
+    let mut bindings = Vec::new();
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if is_receiver_access(node) {
+            let called = node.parent().is_some_and(|p| {
+                call_kinds.contains(&p.kind()) && p.child_by_field_name("function") == Some(node)
+            });
+            if !called {
+                qualified += 1;
+            }
+        }
+        bindings.extend(hoisted_bindings(node));
+        let mut cursor = node.walk();
+        stack.extend(node.children(&mut cursor));
+    }
+    if qualified < MIN_MEMBER_ACCESSES {
+        return None;
+    }
+
+    let mut unqualified = 0usize;
+    for binding in bindings {
+        let Ok(name) = binding.utf8_text(source) else { continue };
+        let mut scope = binding;
+        while scope.kind() != scope_kind {
+            match scope.parent() {
+                Some(parent) => scope = parent,
+                None => break,
+            }
+        }
+        let mut stack = vec![scope];
+        while let Some(node) = stack.pop() {
+            // Python's `obj.attr` names the attribute with an identifier too.
+            let is_attribute_name = node
+                .parent()
+                .is_some_and(|p| p.child_by_field_name("attribute") == Some(node));
+            if node.kind() == "identifier"
+                && !is_attribute_name
+                && node.start_byte() >= binding.end_byte()
+                && node.utf8_text(source) == Ok(name)
+            {
+                unqualified += 1;
+            }
+            let mut cursor = node.walk();
+            stack.extend(node.children(&mut cursor));
+        }
+    }
+    Some(qualified as f64 / (qualified + unqualified) as f64)
+}
+
+/// Fewest distinct boolean identifiers for which [`boolean_prefix_ratio`]
+/// is reported.
+pub(crate) const MIN_BOOLEANS: usize = 4;
+
+/// Predicate prefixes counted by [`boolean_prefix_ratio`].
+const BOOLEAN_PREFIXES: &[&str] = &["is", "has", "should", "can"];
+
+/// Whether `name` opens with a predicate prefix as a word of its own:
+/// `is_ready`, `isReady`, `IsReady`, `HAS_DATA`, but not `island`.
+pub(crate) fn has_boolean_prefix(name: &str) -> bool {
+    let name = name.trim_start_matches('_');
+    BOOLEAN_PREFIXES.iter().any(|prefix| {
+        name.get(..prefix.len()).is_some_and(|head| head.eq_ignore_ascii_case(prefix))
+            && name[prefix.len()..]
+                .chars()
+                .next()
+                .is_some_and(|c| c == '_' || c.is_ascii_uppercase())
+    })
+}
+
+/// Share of the file's boolean identifiers named with a predicate prefix
+/// (`is`, `has`, `should`, `can`).
+///
+/// `boolean_names` returns the identifier nodes a node declares as
+/// boolean: from a declared `bool` type where the grammar has one, else
+/// from a `true` / `false` initializer.  Names are counted once however
+/// often they are assigned.  `None` below [`MIN_BOOLEANS`] distinct names.
+pub(crate) fn boolean_prefix_ratio<'t>(
+    root: Node<'t>,
+    source: &[u8],
+    boolean_names: impl Fn(Node<'t>) -> Vec<Node<'t>>,
+) -> Option<f64> {
+    let mut names = Vec::new();
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        names.extend(boolean_names(node).iter().filter_map(|n| n.utf8_text(source).ok()));
+        let mut cursor = node.walk();
+        stack.extend(node.children(&mut cursor));
+    }
+    names.sort_unstable();
+    names.dedup();
+    if names.len() < MIN_BOOLEANS {
+        return None;
+    }
+    let prefixed = names.iter().filter(|n| has_boolean_prefix(n)).count();
+    Some(prefixed as f64 / names.len() as f64)
+}
+
+/// Fewest parameters for which [`defensive_check_ratio`] is reported.
+pub(crate) const MIN_GUARDABLE_PARAMS: usize = 4;
+
+/// Share of parameters that a null check is defensive for — ones whose
+/// declared type already rules out null, or in untyped code any plain
+/// parameter — that are null-checked in their function anyway (`x is
+/// None`, `x === null`, `x == nil`, `ThrowIfNull(x)`).
+///
+/// `params` returns those parameter name nodes of a function;
+/// `null_checked` returns the identifier a node compares against null, if
+/// it does.  A parameter counts once however often it is checked.  `None`
+/// below [`MIN_GUARDABLE_PARAMS`] parameters.
+pub(crate) fn defensive_check_ratio<'t>(
+    functions: &[Node<'t>],
+    source: &[u8],
+    params: impl Fn(Node<'t>) -> Vec<Node<'t>>,
+    null_checked: impl Fn(Node<'t>) -> Option<Node<'t>>,
+) -> Option<f64> {
+    let (mut total, mut checked) = (0, 0);
+    for &func in functions {
+        let names: Vec<&str> = params(func).iter().filter_map(|n| n.utf8_text(source).ok()).collect();
+        if names.is_empty() {
+            continue;
+        }
+        let mut guarded = std::collections::HashSet::new();
+        let mut stack = vec![func];
+        while let Some(node) = stack.pop() {
+            if let Some(name) = null_checked(node).and_then(|n| n.utf8_text(source).ok()) {
+                guarded.insert(name);
+            }
+            let mut cursor = node.walk();
+            stack.extend(node.children(&mut cursor));
+        }
+        total += names.len();
+        checked += names.iter().filter(|n| guarded.contains(*n)).count();
+    }
+    (total >= MIN_GUARDABLE_PARAMS).then(|| checked as f64 / total as f64)
+}
+
+/// The operand of a binary comparison `node` whose other side is a null
+/// literal of kind `null_kind`, when that operand is a plain identifier.
+pub(crate) fn compared_to_null<'t>(node: Node<'t>, null_kind: &str) -> Option<Node<'t>> {
+    let (left, right) = (node.child_by_field_name("left")?, node.child_by_field_name("right")?);
+    let operand = match (left.kind(), right.kind()) {
+        (_, k) if k == null_kind => left,
+        (k, _) if k == null_kind => right,
+        _ => return None,
+    };
+    (operand.kind() == "identifier").then_some(operand)
+}
+
+/// Fewest functions with a long enough body for which
+/// [`entry_validation_ratio`] is reported.
+pub(crate) const MIN_VALIDATABLE_FUNCTIONS: usize = 4;
+
+/// Fewest body statements for a function to count toward
+/// [`entry_validation_ratio`]; shorter ones are often nothing but a guard.
+const MIN_BODY_STATEMENTS: usize = 3;
+
+/// Share of functions whose body opens with a cluster of two or more guard
+/// statements — early returns and throws, asserts, `ThrowIfNull` — before
+/// any real work: the exhaustive up-front validation AI models write.
+///
+/// `body` returns a function's top-level statements in order (see
+/// [`block_statements`]); `is_guard` says whether one of them is a guard.
+/// Functions with fewer than three statements are skipped.  `None` below
+/// [`MIN_VALIDATABLE_FUNCTIONS`] functions.
+pub(crate) fn entry_validation_ratio<'t>(
+    functions: &[Node<'t>],
+    body: impl Fn(Node<'t>) -> Vec<Node<'t>>,
+    is_guard: impl Fn(Node<'t>) -> bool,
+) -> Option<f64> {
+    let (mut total, mut validated) = (0, 0);
+    for &func in functions {
+        let statements = body(func);
+        if statements.len() < MIN_BODY_STATEMENTS {
+            continue;
+        }
+        total += 1;
+        if statements.iter().take_while(|&&s| is_guard(s)).count() >= 2 {
+            validated += 1;
+        }
+    }
+    (total >= MIN_VALIDATABLE_FUNCTIONS).then(|| validated as f64 / total as f64)
+}
+
+/// The statements of `func`'s `body` field, without comments; empty when
+/// the body is missing or is a bare expression.
+pub(crate) fn block_statements(func: Node<'_>) -> Vec<Node<'_>> {
+    let Some(body) = func.child_by_field_name("body").filter(|b| b.kind().contains("block")) else {
+        return Vec::new();
+    };
+    let mut cursor = body.walk();
+    body.named_children(&mut cursor).filter(|s| !s.is_extra()).collect()
+}
+
+/// Fewest documented functions for which [`doc_placement_consistency`] is
+/// reported.
+pub(crate) const MIN_DOCUMENTED_FUNCTIONS: usize = 5;
+
+/// Share of documented functions whose documentation sits exactly where,
+/// and in the form, the language's convention puts it: a docstring as the
+/// first statement, or a doc comment on the line directly above.
+///
+/// `placement` returns `Some(true)` for a function documented that way,
+/// `Some(false)` for one described some other way (a plain comment, a doc
+/// comment a blank line away, a comment opening the body), and `None` for
+/// an undocumented one.  `None` below [`MIN_DOCUMENTED_FUNCTIONS`].
+pub(crate) fn doc_placement_consistency<'t>(
+    functions: &[Node<'t>],
+    placement: impl Fn(Node<'t>) -> Option<bool>,
+) -> Option<f64> {
+    let placements: Vec<bool> = functions.iter().filter_map(|&f| placement(f)).collect();
+    let canonical = placements.iter().filter(|&&c| c).count();
+    (placements.len() >= MIN_DOCUMENTED_FUNCTIONS).then(|| canonical as f64 / placements.len() as f64)
+}
+
+/// The comment a definition starting at `node` is described by: the
+/// nearest preceding sibling past any `skip` kinds (attributes,
+/// decorators), if it is a comment at most one blank line above.  The flag
+/// says whether it ends on the line directly above.
+pub(crate) fn preceding_comment<'t>(node: Node<'t>, skip: &[&str]) -> Option<(Node<'t>, bool)> {
+    let mut next_row = node.start_position().row;
+    let mut prev = node.prev_sibling();
+    while let Some(p) = prev.filter(|p| skip.contains(&p.kind())) {
+        next_row = p.start_position().row;
+        prev = p.prev_sibling();
+    }
+    let comment = prev.filter(|p| p.kind().contains("comment"))?;
+    // Line comments can end at column 0 of the next line, past their newline.
+    let end = comment.end_position();
+    let last_row = if end.column == 0 { end.row.saturating_sub(1) } else { end.row };
+    let starts_line =
+        comment.prev_sibling().is_none_or(|p| p.end_position().row < comment.start_position().row);
+    (starts_line && last_row < next_row && next_row - last_row <= 2).then_some((comment, last_row + 1 == next_row))
+}
+
+/// Whether the body of `func` opens with a comment, before any statement.
+pub(crate) fn body_opens_with_comment(func: Node<'_>) -> bool {
+    func.child_by_field_name("body")
+        .and_then(|body| body.named_child(0))
+        .is_some_and(|first| first.kind().contains("comment"))
+}
+
+/// The one statement that `node` — a guard clause's consequence — runs:
+/// `node` itself, or the only statement of a block, looking through an
+/// expression statement wrapper.  `None` for empty or multi-statement blocks.
+pub(crate) fn sole_statement(node: Node<'_>) -> Option<Node<'_>> {
+    let mut single = node;
+    if node.kind().contains("block") {
+        let mut cursor = node.walk();
+        let inner: Vec<Node> = node.named_children(&mut cursor).filter(|s| !s.is_extra()).collect();
+        let [only] = inner.as_slice() else {
+            return None;
+        };
+        single = *only;
+    }
+    if single.kind() == "expression_statement" {
+        single = single.named_child(0)?;
+    }
+    Some(single)
+}
+
+/// Formatting features of one signature; see [`signature_uniformity`].
+fn signature_features(sig: &str, modifiers: &[&str]) -> [Option<String>; 7] {
+    let head = sig.split('(').next().unwrap_or(sig);
+    let mods: Vec<&str> = head
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .filter(|w| modifiers.contains(w))
+        .collect();
+    let returns = sig.rfind(')').map(|i| {
+        let tail = sig[i + 1..].trim();
+        let tail = tail.trim_end_matches(['{', ':']).trim_end_matches("=>").trim();
+        !tail.is_empty()
+    });
+    let spacing_after = |c: char| -> Option<String> {
+        let spaces: Vec<bool> = sig
+            .match_indices(c)
+            .filter(|(i, _)| c != ':' || !(sig[..*i].ends_with(':') || sig[i + 1..].starts_with(':')))
+            .filter(|(i, _)| i + 1 < sig.trim_end().len())
+            .map(|(i, _)| sig[i + 1..].starts_with(char::is_whitespace))
+            .collect();
+        match spaces.as_slice() {
+            [] => None,
+            s if s.iter().all(|&b| b) => Some("space".into()),
+            s if s.iter().all(|&b| !b) => Some("tight".into()),
+            _ => Some("mixed".into()),
+        }
+    };
+    let gap = &sig[sig.trim_end().len()..];
+    let gap = if gap.contains('\n') { "newline" } else if gap.is_empty() { "none" } else { "space" };
+    [
+        Some(mods.join(" ")),
+        returns.map(|r| r.to_string()),
+        Some(sig.trim_end().contains('\n').to_string()),
+        spacing_after(','),
+        spacing_after('('),
+        spacing_after(':'),
+        Some(gap.to_string()),
+    ]
+}
@@ -8,6 +8,9 @@ use crate::report::SymbolMetadata;
 
 pub struct PythonCstAnalyzer;
 
+/// Keywords compared by [`super::signature_uniformity`].
+const SIGNATURE_MODIFIERS: &[&str] = &["async"];
+
 impl CstAnalyzer for PythonCstAnalyzer {
     fn name(&self) -> &str {
         "python_cst"
@@ -68,6 +71,24 @@ impl CstAnalyzer for PythonCstAnalyzer {
             metrics.insert("fstring_ratio".into(), 1.0);
         }
 
+        if let Some(u) = super::signature_uniformity(&functions, src_bytes, SIGNATURE_MODIFIERS) {
+            metrics.insert("signature_uniformity".into(), u);
+        }
+
+        // Private by convention: a single leading underscore, not a dunder.
+        let helpers: Vec<Node> = functions
+            .iter()
+            .copied()
+            .filter(|f| {
+                f.child_by_field_name("name")
+                    .and_then(|n| n.utf8_text(src_bytes).ok())
+                    .is_some_and(|n| n.starts_with('_') && !n.starts_with("__"))
+            })
+            .collect();
+        if let Some(r) = super::single_use_helper_ratio(root, src_bytes, &helpers, &["call"]) {
+            metrics.insert("single_use_helper_ratio".into(), r);
+        }
+
         let identifiers = collect_identifiers(root, src_bytes);
         if identifiers.len() >= 10 {
             metrics.insert("identifier_entropy".into(), shannon_entropy(&identifiers));
@@ -81,6 +102,42 @@ impl CstAnalyzer for PythonCstAnalyzer {
             );
         }
 
+        if let Some(r) = super::explicit_member_access_ratio(
+            root,
+            src_bytes,
+            |n| is_self_attribute(n, src_bytes),
+            |n| self_hoisted_bindings(n, src_bytes),
+            &["call"],
+            "block",
+        ) {
+            metrics.insert("explicit_member_access_ratio".into(), r);
+        }
+
+        if let Some(r) = super::boolean_prefix_ratio(root, src_bytes, |n| boolean_bindings(n, src_bytes)) {
+            metrics.insert("boolean_prefix_ratio".into(), r);
+        }
+
+        if let Some(words) = module_docstring_words(root, src_bytes) {
+            metrics.insert("module_docstring_words".into(), words as f64);
+        }
+
+        if let Some(r) = super::defensive_check_ratio(
+            &functions,
+            src_bytes,
+            |f| non_optional_params(f, src_bytes),
+            none_checked,
+        ) {
+            metrics.insert("defensive_check_ratio".into(), r);
+        }
+
+        if let Some(r) = super::entry_validation_ratio(&functions, body_statements, is_guard) {
+            metrics.insert("entry_validation_ratio".into(), r);
+        }
+
+        if let Some(r) = super::doc_placement_consistency(&functions, doc_placement) {
+            metrics.insert("doc_placement_consistency".into(), r);
+        }
+
         metrics
     }
 
@@ -103,12 +160,7 @@ impl CstAnalyzer for PythonCstAnalyzer {
                         .and_then(|c| c.utf8_text(source).ok())
                     {
                         results.push((
-                            SymbolMetadata {
-                                name: name.to_string(),
-                                kind: kind.to_string(),
-                                start_line: node.start_position().row + 1,
-                                end_line: node.end_position().row + 1,
-                            },
+                            symbol(name, kind, node, source),
                             node,
                         ));
                     }
@@ -121,12 +173,7 @@ impl CstAnalyzer for PythonCstAnalyzer {
                         .and_then(|c| c.utf8_text(source).ok())
                     {
                         results.push((
-                            SymbolMetadata {
-                                name: name.to_string(),
-                                kind: "class".to_string(),
-                                start_line: node.start_position().row + 1,
-                                end_line: node.end_position().row + 1,
-                            },
+                            symbol(name, "class", node, source),
                             node,
                         ));
                     }
@@ -199,14 +246,13 @@ fn count_type_annotations(functions: &[Node<'_>], src_bytes: &[u8]) -> (usize, u
                             typed += 1;
                             total += 1;
                         }
-                        "identifier" => {
+                        "identifier"
                             if param
                                 .utf8_text(src_bytes)
                                 .map(|t| t != "self")
-                                .unwrap_or(true)
-                            {
-                                total += 1;
-                            }
+                                .unwrap_or(true) =>
+                        {
+                            total += 1;
                         }
                         _ => {}
                     }
@@ -217,6 +263,90 @@ fn count_type_annotations(functions: &[Node<'_>], src_bytes: &[u8]) -> (usize, u
     (typed, total)
 }
 
+/// Parameters of `func` that are not declared nullable: annotated with a
+/// type that doesn't mention `Optional`, `None` or `Any`, or unannotated,
+/// and without a `None` default.  `self`, `cls` and splats are skipped.
+fn non_optional_params<'t>(func: Node<'t>, src_bytes: &[u8]) -> Vec<Node<'t>> {
+    let Some(params) = func.child_by_field_name("parameters") else {
+        return Vec::new();
+    };
+    let mut cursor = params.walk();
+    params
+        .named_children(&mut cursor)
+        .filter_map(|param| {
+            let name = match param.kind() {
+                "identifier" => param,
+                "typed_parameter" => param.named_child(0).filter(|n| n.kind() == "identifier")?,
+                "default_parameter" | "typed_default_parameter" => param.child_by_field_name("name")?,
+                _ => return None,
+            };
+            let nullable_type = param
+                .child_by_field_name("type")
+                .and_then(|t| t.utf8_text(src_bytes).ok())
+                .is_some_and(|t| ["Optional", "None", "Any"].iter().any(|k| t.contains(k)));
+            let none_default = param.child_by_field_name("value").is_some_and(|v| v.kind() == "none");
+            let receiver = matches!(name.utf8_text(src_bytes), Ok("self" | "cls"));
+            (!nullable_type && !none_default && !receiver).then_some(name)
+        })
+        .collect()
+}
+
+/// The identifier in an `x is None`, `x is not None`, `x == None` or
+/// `x != None` comparison.
+fn none_checked(node: Node<'_>) -> Option<Node<'_>> {
+    if node.kind() != "comparison_operator" || node.named_child_count() != 2 {
+        return None;
+    }
+    let (a, b) = (node.named_child(0)?, node.named_child(1)?);
+    match (a.kind(), b.kind()) {
+        ("identifier", "none") => Some(a),
+        ("none", "identifier") => Some(b),
+        _ => None,
+    }
+}
+
+/// Whether `func` is documented by a docstring rather than by comments
+/// above the `def` or opening its body, for
+/// [`super::doc_placement_consistency`].
+fn doc_placement(func: Node<'_>) -> Option<bool> {
+    if has_docstring(func) {
+        return Some(true);
+    }
+    let anchor = func.parent().filter(|p| p.kind() == "decorated_definition").unwrap_or(func);
+    // A comment before the first statement can sit ahead of the `block` node.
+    let mut cursor = func.walk();
+    let comment_in_body = func.children(&mut cursor).any(|c| c.kind() == "comment");
+    (super::preceding_comment(anchor, &[]).is_some() || comment_in_body || super::body_opens_with_comment(func))
+        .then_some(false)
+}
+
+/// A function's top-level statements, after any docstring.
+fn body_statements(func: Node<'_>) -> Vec<Node<'_>> {
+    let mut statements = super::block_statements(func);
+    let is_docstring =
+        |s: &Node| s.kind() == "expression_statement" && s.named_child(0).is_some_and(|e| e.kind() == "string");
+    if statements.first().is_some_and(is_docstring) {
+        statements.remove(0);
+    }
+    statements
+}
+
+/// `assert ...`, or an `if` without `elif`/`else` that only raises or
+/// returns.
+fn is_guard(statement: Node<'_>) -> bool {
+    match statement.kind() {
+        "assert_statement" => true,
+        "if_statement" => {
+            statement.child_by_field_name("alternative").is_none()
+                && statement
+                    .child_by_field_name("consequence")
+                    .and_then(super::sole_statement)
+                    .is_some_and(|s| matches!(s.kind(), "raise_statement" | "return_statement"))
+        }
+        _ => false,
+    }
+}
+
 fn count_string_styles(root: Node<'_>, src_bytes: &[u8]) -> (usize, usize) {
     let mut fstrings = 0usize;
     let mut old_style = 0usize;
@@ -262,6 +392,18 @@ fn fn_line_count(node: Node<'_>) -> usize {
     (end - start) + 1
 }
 
+/// Symbol metadata for `node`, measured with this grammar's walkers.
+fn symbol(name: &str, kind: &str, node: Node<'_>, source: &[u8]) -> SymbolMetadata {
+    super::symbol_metadata(
+        name,
+        kind,
+        node,
+        source,
+        complexity_of_fn(node),
+        max_nesting_depth(node),
+    )
+}
+
 fn complexity_of_fn(root: Node<'_>) -> usize {
     let decision_kinds = [
         "if_statement",
@@ -315,6 +457,38 @@ fn max_nesting_depth(root: Node<'_>) -> usize {
     max_depth
 }
 
+/// `self.attr`, the receiver member access counted by
+/// [`super::explicit_member_access_ratio`].
+fn is_self_attribute(node: Node<'_>, src_bytes: &[u8]) -> bool {
+    node.kind() == "attribute"
+        && node
+            .child_by_field_name("object")
+            .is_some_and(|o| o.kind() == "identifier" && o.utf8_text(src_bytes) == Ok("self"))
+}
+
+/// Names an assignment hoists out of `self`: `x = self.x` or
+/// `a, b = self.a, self.b`.
+fn self_hoisted_bindings<'t>(node: Node<'t>, src_bytes: &[u8]) -> Vec<Node<'t>> {
+    if node.kind() != "assignment" {
+        return Vec::new();
+    }
+    let (Some(left), Some(right)) = (node.child_by_field_name("left"), node.child_by_field_name("right"))
+    else {
+        return Vec::new();
+    };
+    match (left.kind(), right.kind()) {
+        ("identifier", _) if is_self_attribute(right, src_bytes) => vec![left],
+        ("pattern_list", "expression_list") => {
+            let mut cursor = right.walk();
+            let all_self = right.named_children(&mut cursor).all(|v| is_self_attribute(v, src_bytes));
+            let mut cursor = left.walk();
+            let names: Vec<Node> = left.named_children(&mut cursor).filter(|n| n.kind() == "identifier").collect();
+            if all_self { names } else { Vec::new() }
+        }
+        _ => Vec::new(),
+    }
+}
+
 fn collect_identifiers<'s>(root: Node<'_>, src_bytes: &'s [u8]) -> Vec<&'s str> {
     let mut result = Vec::new();
     let mut stack = vec![root];
@@ -371,6 +545,59 @@ fn inline_comment_ratio(functions: &[Node<'_>], src_bytes: &[u8]) -> (usize, usi
     (comment_lines, code_lines)
 }
 
+/// Word count of the module docstring: a triple-quoted string that is the
+/// first statement of the file (comments and a shebang may precede it).
+/// `None` when the module has no such docstring.
+fn module_docstring_words(root: Node<'_>, src_bytes: &[u8]) -> Option<usize> {
+    let mut cursor = root.walk();
+    let first = root.named_children(&mut cursor).find(|n| n.kind() != "comment")?;
+    if first.kind() != "expression_statement" || first.named_child_count() != 1 {
+        return None;
+    }
+    let string = first.named_child(0).filter(|n| n.kind() == "string")?;
+    let triple_quoted = string
+        .child(0)
+        .and_then(|start| start.utf8_text(src_bytes).ok())
+        .is_some_and(|start| start.ends_with("\"\"\"") || start.ends_with("'''"));
+    if !triple_quoted {
+        return None;
+    }
+    let mut cursor = string.walk();
+    let words = string
+        .named_children(&mut cursor)
+        .filter(|n| n.kind() == "string_content")
+        .filter_map(|n| n.utf8_text(src_bytes).ok())
+        .map(|text| text.split_whitespace().count())
+        .sum();
+    Some(words)
+}
+
+/// Names `node` declares as boolean, for [`super::boolean_prefix_ratio`]:
+/// `bool`-annotated or `True`/`False`-valued variables, `self` attributes
+/// and parameters, and functions annotated `-> bool`.
+fn boolean_bindings<'t>(node: Node<'t>, src_bytes: &[u8]) -> Vec<Node<'t>> {
+    let is_bool = |field: &str| {
+        node.child_by_field_name(field)
+            .is_some_and(|t| matches!(t.kind(), "true" | "false") || t.utf8_text(src_bytes) == Ok("bool"))
+    };
+    let name = match node.kind() {
+        "assignment" if is_bool("type") || is_bool("right") => node.child_by_field_name("left").map(|left| {
+            match left.kind() {
+                "attribute" if is_self_attribute(left, src_bytes) => left.child_by_field_name("attribute").unwrap_or(left),
+                _ => left,
+            }
+        }),
+        // `typed_parameter` has no `name` field; the identifier comes first.
+        "typed_parameter" if is_bool("type") => node.named_child(0),
+        "typed_default_parameter" | "default_parameter" if is_bool("type") || is_bool("value") => {
+            node.child_by_field_name("name")
+        }
+        "function_definition" if is_bool("return_type") => node.child_by_field_name("name"),
+        _ => None,
+    };
+    name.filter(|n| n.kind() == "identifier").into_iter().collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -433,6 +660,14 @@ mod tests {
         assert_eq!(second.start_line, 3);
     }
 
+    #[test]
+    fn symbol_span_covers_decorators() {
+        let source = "@cached\n@route(\"/x\")\ndef handler():\n    pass\n";
+        let syms = parse_and_extract(source);
+        let handler = syms.iter().find(|s| s.name == "handler").unwrap();
+        assert_eq!((handler.start_line, handler.end_line, handler.loc), (1, 4, 4));
+    }
+
     #[test]
     fn docstring_coverage_metrics() {
         let source = r#"
@@ -480,4 +715,157 @@ def describe(item, count):
         let m = parse_and_metrics(source);
         assert!(m["fstring_ratio"] >= 1.0);
     }
+
+    #[test]
+    fn uniform_python_signatures() {
+        let source: String = (0..6)
+            .map(|i| format!("def handler_{i}(event: dict, context: object) -> dict:\n    return event\n\n"))
+            .collect();
+        assert_eq!(parse_and_metrics(&source)["signature_uniformity"], 1.0);
+    }
+
+    #[test]
+    fn explicit_member_access_ratio_counts_hoisted_locals() {
+        let qualified = "class S:\n    def total(self):\n        return self.a + self.b + self.c + self.d + self.e\n\n\
+                         \x20   def scaled(self):\n        return self.a * self.b * self.c * self.d * self.e * self.norm()\n";
+        assert_eq!(parse_and_metrics(qualified)["explicit_member_access_ratio"], 1.0);
+
+        let hoisted = "class S:\n    def total(self):\n        a, b = self.a, self.b\n        c = self.c\n\
+                       \x20       return a + b + c + a + b + c + self.d\n\n\
+                       \x20   def scaled(self):\n        return self.a * self.b * self.c * self.d * self.e\n";
+        // 4 + 5 qualified reads after hoisting 3 names used twice each.
+        let m = parse_and_metrics(hoisted);
+        assert!(!m.contains_key("explicit_member_access_ratio"), "9 reads is below the minimum");
+        let hoisted = hoisted.replace("self.e\n", "self.e * self.f\n");
+        assert_eq!(parse_and_metrics(&hoisted)["explicit_member_access_ratio"], 10.0 / 16.0);
+    }
+
+    #[test]
+    fn boolean_prefix_ratio_uses_annotations_and_literals() {
+        let source = "class Conn:
+    def __init__(self, should_retry: bool, verbose=False):
+        self.is_open = False
+        self.count = 0
+
+    def has_data(self) -> bool:
+        return True
+
+is_ready: bool = True
+done = False
+";
+        assert_eq!(parse_and_metrics(source)["boolean_prefix_ratio"], 4.0 / 6.0);
+    }
+
+    #[test]
+    fn module_docstring_words_only_for_leading_triple_quoted_string() {
+        let documented = "#!/usr/bin/env python3\n# Copyright\n\"\"\"Cache helpers.\n\nWraps the store in an LRU.\n\"\"\"\n\nimport os\n";
+        assert_eq!(parse_and_metrics(documented)["module_docstring_words"], 8.0);
+        assert_eq!(parse_and_metrics("'''Tiny.'''\n")["module_docstring_words"], 1.0);
+
+        let fn_doc_only = "import os\n\ndef load():\n    \"\"\"Load the config file from disk.\"\"\"\n    pass\n";
+        assert!(!parse_and_metrics(fn_doc_only).contains_key("module_docstring_words"));
+        assert!(!parse_and_metrics("\"single quoted\"\nx = 1\n").contains_key("module_docstring_words"));
+    }
+
+    #[test]
+    fn underscore_helpers_called_once_are_single_use() {
+        let source = "def run(self):\n    self._load()\n    _clean()\n    _score()\n    _score()\n    _emit()\n\n\
+                      def _load():\n    pass\n\ndef _clean():\n    pass\n\n\
+                      def _score():\n    pass\n\ndef _emit():\n    pass\n\ndef __repr__():\n    pass\n";
+        assert_eq!(parse_and_metrics(source)["single_use_helper_ratio"], 0.75);
+    }
+
+    #[test]
+    fn defensive_check_ratio_ignores_optional_params() {
+        let source = r#"
+def load(self, path: str, mode: str, retries: int = 3, cache: Optional[dict] = None):
+    if path is None:
+        raise ValueError("path is required")
+    if mode is None:
+        mode = "r"
+    if cache is not None:
+        return cache
+    return open(path, mode)
+
+def save(data, target=None):
+    if target is None:
+        return
+"#;
+        let metrics = parse_and_metrics(source);
+        // path, mode, retries, data: two of them checked; cache and target may be None.
+        assert_eq!(metrics.get("defensive_check_ratio"), Some(&0.5));
+        assert!(!parse_and_metrics("def f(a, b):\n    return a\n").contains_key("defensive_check_ratio"));
+    }
+
+    #[test]
+    fn entry_validation_ratio_counts_leading_guard_clusters() {
+        let source = r#"
+def load(path, mode):
+    """Load a file."""
+    if not path:
+        raise ValueError("path is required")
+    assert mode in ("r", "rb")
+    return open(path, mode)
+
+def save(data, target):
+    if data is None:
+        return
+    if not target:
+        raise ValueError("target is required")
+    target.write(data)
+
+def scale(values, factor):
+    # Only the first check is a guard; the second branch does work.
+    if not values:
+        return []
+    if factor > 1:
+        values = [v * factor for v in values]
+    return values
+
+def total(values):
+    result = 0
+    for v in values:
+        result += v
+    return result
+
+def short(x):
+    return x
+"#;
+        assert_eq!(parse_and_metrics(source)["entry_validation_ratio"], 0.5);
+        let more = format!("{source}\ndef merge(a, b):\n    if a is None:\n        raise TypeError()\n    \
+                            if b is None:\n        raise TypeError()\n    return a + b\n");
+        assert_eq!(parse_and_metrics(&more)["entry_validation_ratio"], 0.6);
+        assert!(!parse_and_metrics(&source[..source.find("def total").unwrap()]).contains_key("entry_validation_ratio"));
+    }
+
+    #[test]
+    fn doc_placement_consistency_prefers_docstrings() {
+        let source = r#"
+def load():
+    """Load the file."""
+    return 1
+
+@cached
+def save():
+    """Save the file."""
+    return 2
+
+def close():
+    """Close the file."""
+
+# Open the file.
+def open_():
+    return 3
+
+def seek():
+    # Move the cursor.
+    return 4
+
+def undocumented():
+    return 5
+"#;
+        assert_eq!(parse_and_metrics(source)["doc_placement_consistency"], 0.6);
+        let (head, _) = source.split_once("def seek").unwrap();
+        assert!(!parse_and_metrics(head).contains_key("doc_placement_consistency"));
+    }
 }
@@ -8,6 +8,9 @@ use crate::report::SymbolMetadata;
 
 pub struct RustCstAnalyzer;
 
+/// Keywords compared by [`super::signature_uniformity`].
+const SIGNATURE_MODIFIERS: &[&str] = &["pub", "async", "unsafe", "const", "extern"];
+
 impl CstAnalyzer for RustCstAnalyzer {
     fn name(&self) -> &str {
         "rust_cst"
@@ -72,12 +75,30 @@ impl CstAnalyzer for RustCstAnalyzer {
             metrics.insert("identifier_entropy".into(), entropy);
         }
 
+        if let Some(u) = super::signature_uniformity(&functions, src_bytes, SIGNATURE_MODIFIERS) {
+            metrics.insert("signature_uniformity".into(), u);
+        }
+
+        let helpers: Vec<Node> = functions
+            .iter()
+            .copied()
+            .filter(|&f| is_private_helper(f, src_bytes))
+            .collect();
+        if let Some(r) = super::single_use_helper_ratio(root, src_bytes, &helpers, &["call_expression"]) {
+            metrics.insert("single_use_helper_ratio".into(), r);
+        }
+
         if imports_are_sorted(root, src_bytes) {
             metrics.insert("imports_sorted".into(), 1.0);
         } else {
             metrics.insert("imports_sorted".into(), 0.0);
         }
 
+        let redundant = redundant_annotation_count(root, src_bytes);
+        if redundant > 0 {
+            metrics.insert("redundant_annotation_count".into(), redundant as f64);
+        }
+
         let (comment_lines, code_lines) = inline_comment_ratio(&functions, src_bytes);
         if code_lines > 0 {
             metrics.insert(
@@ -86,6 +107,31 @@ impl CstAnalyzer for RustCstAnalyzer {
             );
         }
 
+        if let Some(r) = super::explicit_member_access_ratio(
+            root,
+            src_bytes,
+            is_self_field,
+            self_hoisted_bindings,
+            &["call_expression"],
+            "block",
+        ) {
+            metrics.insert("explicit_member_access_ratio".into(), r);
+        }
+
+        if let Some(r) = super::boolean_prefix_ratio(root, src_bytes, |n| boolean_bindings(n, src_bytes)) {
+            metrics.insert("boolean_prefix_ratio".into(), r);
+        }
+
+        if let Some(r) =
+            super::entry_validation_ratio(&functions, super::block_statements, |s| is_guard(s, src_bytes))
+        {
+            metrics.insert("entry_validation_ratio".into(), r);
+        }
+
+        if let Some(r) = super::doc_placement_consistency(&functions, |f| doc_placement(f, src_bytes)) {
+            metrics.insert("doc_placement_consistency".into(), r);
+        }
+
         metrics
     }
 
@@ -108,12 +154,7 @@ impl CstAnalyzer for RustCstAnalyzer {
                         .and_then(|c| c.utf8_text(source).ok())
                     {
                         results.push((
-                            SymbolMetadata {
-                                name: name.to_string(),
-                                kind: kind.to_string(),
-                                start_line: node.start_position().row + 1,
-                                end_line: node.end_position().row + 1,
-                            },
+                            symbol(name, kind, node, source),
                             node,
                         ));
                     }
@@ -153,6 +194,18 @@ fn collect_all_functions<'t>(root: Node<'t>) -> Vec<Node<'t>> {
     result
 }
 
+/// Symbol metadata for `node`, measured with this grammar's walkers.
+fn symbol(name: &str, kind: &str, node: Node<'_>, source: &[u8]) -> SymbolMetadata {
+    super::symbol_metadata(
+        name,
+        kind,
+        node,
+        source,
+        complexity_of_fn(node),
+        max_nesting_depth(node),
+    )
+}
+
 fn complexity_of_fn(root: Node<'_>) -> usize {
     let decision_kinds = [
         "if_expression",
@@ -190,6 +243,26 @@ fn is_pub_fn(node: Node<'_>, src_bytes: &[u8]) -> bool {
     false
 }
 
+/// A function with no visibility modifier that isn't `main`, a `#[test]`,
+/// or a trait method (whose callers live outside the file).
+fn is_private_helper(node: Node<'_>, src_bytes: &[u8]) -> bool {
+    let mut cursor = node.walk();
+    if node.children(&mut cursor).any(|c| c.kind() == "visibility_modifier") {
+        return false;
+    }
+    if node.child_by_field_name("name").and_then(|n| n.utf8_text(src_bytes).ok()) == Some("main") {
+        return false;
+    }
+    let is_test = node.prev_named_sibling().is_some_and(|n| {
+        n.kind() == "attribute_item" && n.utf8_text(src_bytes).is_ok_and(|t| t.contains("test"))
+    });
+    let in_trait = node
+        .parent()
+        .and_then(|list| list.parent())
+        .is_some_and(|p| p.kind() == "trait_item" || p.child_by_field_name("trait").is_some());
+    !is_test && !in_trait
+}
+
 fn has_preceding_doc_comment(node: Node<'_>, src_bytes: &[u8]) -> bool {
     let mut prev = node.prev_named_sibling();
     while let Some(n) = prev {
@@ -215,6 +288,24 @@ fn has_preceding_doc_comment(node: Node<'_>, src_bytes: &[u8]) -> bool {
     false
 }
 
+/// Whether `func` is documented by a `///` or `/** */` comment directly
+/// above it (attributes in between are fine), for
+/// [`super::doc_placement_consistency`].
+fn doc_placement(func: Node<'_>, src_bytes: &[u8]) -> Option<bool> {
+    // Inner `//!` docs describe the enclosing module, not what follows.
+    let comment = super::preceding_comment(func, &["attribute_item"]).filter(|(c, _)| {
+        c.utf8_text(src_bytes).is_ok_and(|t| !t.starts_with("//!") && !t.starts_with("/*!"))
+    });
+    match comment {
+        Some((comment, adjacent)) => {
+            let text = comment.utf8_text(src_bytes).ok()?;
+            let is_doc = (text.starts_with("///") && !text.starts_with("////")) || text.starts_with("/**");
+            Some(is_doc && adjacent)
+        }
+        None => super::body_opens_with_comment(func).then_some(false),
+    }
+}
+
 fn collect_identifiers<'s>(root: Node<'_>, src_bytes: &'s [u8]) -> Vec<&'s str> {
     let mut result = Vec::new();
     let mut stack = vec![root];
@@ -303,6 +394,195 @@ fn imports_are_sorted(root: Node<'_>, src_bytes: &[u8]) -> bool {
     use_texts.windows(2).all(|w| w[0] <= w[1])
 }
 
+/// Associated functions whose return type is conventionally `Self`.
+const CONSTRUCTOR_NAMES: &[&str] = &["new", "default", "from", "with_capacity"];
+
+/// Count `let` bindings whose type annotation restates what the initializer
+/// already makes obvious: `let p: Point = Point { .. }`,
+/// `let v: Vec<u8> = Vec::new()`, `let s: &str = "x"`, `let f: bool = true`,
+/// `let s: String = format!(..)`.  Annotations that steer inference
+/// (`.collect()`, `.parse()`, `.into()`, numeric literals) are necessary and
+/// never counted.
+fn redundant_annotation_count(root: Node<'_>, src_bytes: &[u8]) -> usize {
+    let mut count = 0;
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if node.kind() == "let_declaration" {
+            if let (Some(ty), Some(value)) =
+                (node.child_by_field_name("type"), node.child_by_field_name("value"))
+            {
+                if annotation_is_redundant(ty, value, src_bytes) {
+                    count += 1;
+                }
+            }
+        }
+        let mut cursor = node.walk();
+        stack.extend(node.children(&mut cursor));
+    }
+    count
+}
+
+/// The bare name a type is built around: `Vec` for `Vec<String>`, `Map` for
+/// `std::collections::Map`, and `str` for `&str`.
+fn type_head<'s>(ty: Node<'_>, src_bytes: &'s [u8]) -> Option<&'s str> {
+    match ty.kind() {
+        "generic_type" | "reference_type" => type_head(ty.child_by_field_name("type")?, src_bytes),
+        "scoped_type_identifier" => ty.child_by_field_name("name")?.utf8_text(src_bytes).ok(),
+        "type_identifier" | "primitive_type" => ty.utf8_text(src_bytes).ok(),
+        _ => None,
+    }
+}
+
+/// Last path segment of an expression path, without turbofish generics:
+/// `Vec` for `std::vec::Vec::<u8>`.
+fn last_segment(text: &str) -> &str {
+    let text = text.split("::<").next().unwrap_or(text);
+    text.rsplit("::").next().unwrap_or(text).trim()
+}
+
+fn annotation_is_redundant(ty: Node<'_>, value: Node<'_>, src_bytes: &[u8]) -> bool {
+    let Some(head) = type_head(ty, src_bytes) else {
+        return false;
+    };
+    let text = |n: Node<'_>| n.utf8_text(src_bytes).unwrap_or("");
+    match value.kind() {
+        "struct_expression" => value
+            .child_by_field_name("name")
+            .is_some_and(|n| last_segment(text(n)) == head),
+        "string_literal" | "raw_string_literal" => ty.kind() == "reference_type" && head == "str",
+        "boolean_literal" => head == "bool",
+        "char_literal" => head == "char",
+        "macro_invocation" => {
+            head == "String"
+                && value
+                    .child_by_field_name("macro")
+                    .is_some_and(|m| text(m) == "format")
+        }
+        "call_expression" => {
+            let Some(function) = value.child_by_field_name("function") else {
+                return false;
+            };
+            match function.kind() {
+                "scoped_identifier" => {
+                    let path = function.child_by_field_name("path").map(text).unwrap_or("");
+                    let name = function.child_by_field_name("name").map(text).unwrap_or("");
+                    last_segment(path) == head && CONSTRUCTOR_NAMES.contains(&name)
+                }
+                // `"x".to_string()` / `"x".to_owned()` annotated as String.
+                "field_expression" => {
+                    head == "String"
+                        && function.child_by_field_name("value").is_some_and(|v| v.kind() == "string_literal")
+                        && function
+                            .child_by_field_name("field")
+                            .is_some_and(|f| matches!(text(f), "to_string" | "to_owned"))
+                }
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// `self.field`, the receiver member access counted by
+/// [`super::explicit_member_access_ratio`].
+fn is_self_field(node: Node<'_>) -> bool {
+    node.kind() == "field_expression"
+        && node.child_by_field_name("value").is_some_and(|v| v.kind() == "self")
+}
+
+/// Names a `let` hoists out of `self`: `let x = &self.x;` or
+/// `let Self { a, b, .. } = self;`.
+fn self_hoisted_bindings(node: Node<'_>) -> Vec<Node<'_>> {
+    if node.kind() != "let_declaration" {
+        return Vec::new();
+    }
+    let (Some(pattern), Some(mut value)) =
+        (node.child_by_field_name("pattern"), node.child_by_field_name("value"))
+    else {
+        return Vec::new();
+    };
+    // Look through `&self.x`, `&mut self.x`, and `*self`.
+    while matches!(value.kind(), "reference_expression" | "unary_expression") {
+        match value.child_by_field_name("value").or_else(|| value.named_child(0)) {
+            Some(inner) => value = inner,
+            None => break,
+        }
+    }
+    if pattern.kind() == "identifier" && is_self_field(value) {
+        return vec![pattern];
+    }
+    if pattern.kind() != "struct_pattern" || value.kind() != "self" {
+        return Vec::new();
+    }
+    let mut names = Vec::new();
+    let mut stack = vec![pattern];
+    while let Some(n) = stack.pop() {
+        if matches!(n.kind(), "identifier" | "shorthand_field_identifier") {
+            names.push(n);
+        }
+        let mut cursor = n.walk();
+        stack.extend(n.children(&mut cursor));
+    }
+    names
+}
+
+/// Macros that check a precondition, and ones that bail out of a guard.
+const ASSERT_MACROS: &[&str] = &["assert", "assert_eq", "assert_ne", "debug_assert", "ensure"];
+const EXIT_MACROS: &[&str] = &["panic", "bail", "unreachable"];
+
+/// An `if` without `else` that only returns or panics, an `assert!`-like
+/// macro, or a `let ... else`.
+fn is_guard(statement: Node<'_>, src_bytes: &[u8]) -> bool {
+    let is_macro = |node: Node<'_>, names: &[&str]| {
+        node.kind() == "macro_invocation"
+            && node
+                .child_by_field_name("macro")
+                .and_then(|m| m.utf8_text(src_bytes).ok())
+                .is_some_and(|m| names.contains(&last_segment(m)))
+    };
+    if statement.kind() == "let_declaration" {
+        return statement.child_by_field_name("alternative").is_some();
+    }
+    let Some(inner) = super::sole_statement(statement) else {
+        return false;
+    };
+    match inner.kind() {
+        "if_expression" => {
+            inner.child_by_field_name("alternative").is_none()
+                && inner
+                    .child_by_field_name("consequence")
+                    .and_then(super::sole_statement)
+                    .is_some_and(|s| s.kind() == "return_expression" || is_macro(s, EXIT_MACROS))
+        }
+        _ => is_macro(inner, ASSERT_MACROS),
+    }
+}
+
+/// Names `node` declares as `bool`, for [`super::boolean_prefix_ratio`]:
+/// typed or `true`/`false`-initialized `let`s, parameters, fields, statics
+/// and consts, and functions returning `bool`.
+fn boolean_bindings<'t>(node: Node<'t>, src_bytes: &[u8]) -> Vec<Node<'t>> {
+    let is_bool = |field: &str| {
+        node.child_by_field_name(field)
+            .is_some_and(|t| t.kind() == "boolean_literal" || t.utf8_text(src_bytes) == Ok("bool"))
+    };
+    let name = match node.kind() {
+        "let_declaration" if is_bool("type") || is_bool("value") => node.child_by_field_name("pattern"),
+        "parameter" if is_bool("type") => node.child_by_field_name("pattern"),
+        "field_declaration" | "static_item" | "const_item" if is_bool("type") => node.child_by_field_name("name"),
+        "function_item" if is_bool("return_type") => node.child_by_field_name("name"),
+        _ => None,
+    };
+    // `let mut ready` wraps the identifier in a `mut_pattern`.
+    let name = name.map(|n| match n.kind() {
+        "mut_pattern" => n.named_child(0).unwrap_or(n),
+        _ => n,
+    });
+    name.filter(|n| matches!(n.kind(), "identifier" | "field_identifier"))
+        .into_iter()
+        .collect()
+}
+
 fn inline_comment_ratio(functions: &[Node<'_>], src_bytes: &[u8]) -> (usize, usize) {
     let mut comment_lines = 0usize;
     let mut code_lines = 0usize;
@@ -349,6 +629,140 @@ mod tests {
             .collect()
     }
 
+    #[test]
+    fn uniform_signatures_score_one() {
+        let source: String = (0..6)
+            .map(|i| format!("pub fn step_{i}(input: &str, limit: usize) -> Result<usize> {{\n    Ok(limit)\n}}\n"))
+            .collect();
+        assert_eq!(parse_and_metrics(&source)["signature_uniformity"], 1.0);
+    }
+
+    #[test]
+    fn mixed_signatures_score_lower() {
+        let source = "pub fn a(x: u8) -> u8 { x }\n\
+                      fn b(x:u8,y:u8) { }\n\
+                      pub async fn c(\n    x: u8,\n) -> u8\n{\n    x\n}\n\
+                      fn d() {}\n\
+                      pub(crate) fn e(s: &str) -> &str { s }\n\
+                      unsafe fn f(p: *const u8) { }\n";
+        let u = parse_and_metrics(source)["signature_uniformity"];
+        assert!(u < 0.8, "uniformity = {u}");
+    }
+
+    #[test]
+    fn redundant_annotations_counted() {
+        let source = r#"fn f() {
+    let p: Point = Point { x: 1, y: 2 };
+    let v: Vec<String> = Vec::new();
+    let m: std::collections::HashMap<u8, u8> = HashMap::with_capacity(4);
+    let s: &str = "hello";
+    let o: String = "x".to_string();
+    let g: String = format!("{p:?}");
+    let ok: bool = true;
+}
+"#;
+        assert_eq!(parse_and_metrics(source)["redundant_annotation_count"], 7.0);
+    }
+
+    #[test]
+    fn necessary_annotations_not_counted() {
+        let source = r#"fn f(xs: &[u8]) {
+    let total: u64 = 0;
+    let items: Vec<u8> = xs.iter().copied().collect();
+    let n: usize = "3".parse().unwrap();
+    let name: String = other.into();
+    let p: Point = make_point();
+    let q = Point { x: 1, y: 2 };
+    let w: Wrapper = Inner::new();
+}
+"#;
+        assert!(!parse_and_metrics(source).contains_key("redundant_annotation_count"));
+    }
+
+    #[test]
+    fn explicit_member_access_ratio_counts_hoisted_locals() {
+        let qualified = "impl S {
+    fn total(&self) -> u32 {
+        self.a + self.b + self.c + self.d + self.e
+    }
+    fn scaled(&self) -> u32 {
+        self.a * self.b * self.c * self.d * self.e + self.len()
+    }
+}";
+        assert_eq!(parse_and_metrics(qualified)["explicit_member_access_ratio"], 1.0);
+
+        let hoisted = "impl S {
+    fn total(&self) -> u32 {
+        let Self { a, b, .. } = self;
+        let c = &self.c;
+        a + b + c + a + b + c + self.d + self.e
+    }
+    fn scaled(&self) -> u32 {
+        self.a * self.b * self.c * self.d * self.e * self.f * self.g
+    }
+}";
+        // 10 qualified reads (one in `let c = &self.c`), 6 hoisted uses.
+        assert_eq!(parse_and_metrics(hoisted)["explicit_member_access_ratio"], 10.0 / 16.0);
+        assert!(!parse_and_metrics("impl S { fn a(&self) -> u8 { self.a } }")
+            .contains_key("explicit_member_access_ratio"));
+    }
+
+    #[test]
+    fn boolean_prefix_ratio_uses_declared_types() {
+        let source = "struct S { is_open: bool, has_data: bool, count: u8 }
+                      fn can_retry(n: u8) -> bool { n < 3 }
+                      fn run(should_log: bool, verbose: bool) {
+                          let mut is_done = false;
+                          let island: u8 = 1;
+                      }";
+        assert_eq!(parse_and_metrics(source)["boolean_prefix_ratio"], 5.0 / 6.0);
+        assert!(!parse_and_metrics("fn f(is_a: bool, is_b: bool, c: bool) {}").contains_key("boolean_prefix_ratio"));
+        assert!(super::super::has_boolean_prefix("IsValid") && super::super::has_boolean_prefix("_has_x"));
+        assert!(!super::super::has_boolean_prefix("island") && !super::super::has_boolean_prefix("is"));
+    }
+
+    #[test]
+    fn few_functions_have_no_uniformity() {
+        assert!(!parse_and_metrics("fn a() {}\nfn b() {}\n").contains_key("signature_uniformity"));
+    }
+
+    #[test]
+    fn single_use_helpers_counted_through_self_and_paths() {
+        let source = "pub fn run(&self) {
+                          let parsed = Self::parse_input();
+                          self.validate_input(parsed);
+                          normalize_values();
+                          emit_report();
+                      }
+                      fn parse_input() {}
+                      fn validate_input(x: u8) {}
+                      fn normalize_values() {}
+                      fn emit_report() {}
+";
+        assert_eq!(parse_and_metrics(source)["single_use_helper_ratio"], 1.0);
+    }
+
+    #[test]
+    fn reused_and_excluded_helpers_lower_the_ratio() {
+        let source = "pub fn run() { clamp(1); clamp(2); log_once(); }
+                      fn clamp(x: u8) {}
+                      fn log_once() {}
+                      fn unused() {}
+                      fn spare() {}
+                      fn main() { run(); }
+                      #[test]
+fn checks() { clamp(3); }
+                      impl Drop for S { fn drop(&mut self) {} }
+";
+        assert_eq!(parse_and_metrics(source)["single_use_helper_ratio"], 0.25);
+    }
+
+    #[test]
+    fn few_helpers_have_no_single_use_ratio() {
+        let source = "pub fn run() { a(); b(); }\nfn a() {}\nfn b() {}\n";
+        assert!(!parse_and_metrics(source).contains_key("single_use_helper_ratio"));
+    }
+
     #[test]
     fn extract_free_functions() {
         let source = "fn foo() {}\nfn bar(x: i32) -> i32 { x }\n";
@@ -409,6 +823,13 @@ impl Foo {
         assert!(second.end_line >= second.start_line);
     }
 
+    #[test]
+    fn symbol_span_covers_attributes() {
+        let source = "/// Docs.\n#[inline]\n#[must_use]\nfn first() -> u8 {\n    1\n}\n";
+        let syms = parse_and_extract(source);
+        assert_eq!((syms[0].start_line, syms[0].end_line), (2, 6));
+    }
+
     #[test]
     fn low_complexity_metrics() {
         let source = r#"
@@ -456,4 +877,104 @@ fn main() {}
         assert!(m.contains_key("avg_fn_length"));
         assert!(m["avg_fn_length"] >= 1.0);
     }
+
+    #[test]
+    fn symbol_complexity_metrics() {
+        let source = r#"fn classify(xs: &[i32]) -> i32 {
+    let mut n = 0;
+
+    for x in xs {
+        if *x > 0 {
+            n += 1;
+        }
+    }
+    n
+}
+
+fn flat() -> i32 { 1 }
+"#;
+        let syms = parse_and_extract(source);
+        let classify = syms.iter().find(|s| s.name == "classify").unwrap();
+        assert_eq!(classify.cyclomatic_complexity, 3);
+        assert_eq!(classify.max_nesting_depth, 5); // body > for > block > if > block
+        assert_eq!(classify.loc, 9);
+
+        let flat = syms.iter().find(|s| s.name == "flat").unwrap();
+        assert_eq!(flat.cyclomatic_complexity, 1);
+        assert_eq!(flat.loc, 1);
+    }
+
+    #[test]
+    fn entry_validation_ratio_counts_leading_guard_clusters() {
+        let source = r#"
+fn load(path: &str, mode: u8) -> Result<File> {
+    if path.is_empty() {
+        return Err(Error::EmptyPath);
+    }
+    assert!(mode < 4, "unknown mode");
+    open(path, mode)
+}
+fn save(data: Option<&[u8]>, target: &mut Target) -> Result<()> {
+    let Some(data) = data else { return Ok(()) };
+    // Refuse writes to a closed target.
+    if target.closed {
+        anyhow::bail!("target is closed");
+    }
+    target.write(data)
+}
+fn scale(values: &mut Vec<i32>, factor: i32) {
+    if values.is_empty() {
+        return;
+    }
+    if factor > 1 {
+        values.iter_mut().for_each(|v| *v *= factor);
+    }
+    values.sort();
+}
+fn total(values: &[i32]) -> i32 {
+    let mut result = 0;
+    for v in values {
+        result += v;
+    }
+    result
+}
+fn short() -> i32 { 1 }
+"#;
+        assert_eq!(parse_and_metrics(source)["entry_validation_ratio"], 0.5);
+        let (head, _) = source.split_once("fn total").unwrap();
+        assert!(!parse_and_metrics(head).contains_key("entry_validation_ratio"));
+    }
+
+    #[test]
+    fn doc_placement_consistency_wants_adjacent_doc_comments() {
+        let source = r#"//! Module docs.
+
+/// Loads the file.
+#[inline]
+fn load() {}
+
+/// Saves the file.
+fn save() {}
+
+/** Closes the file. */
+fn close() {}
+
+// Opens the file.
+fn open() {}
+
+/// Detached by a blank line.
+
+fn flush() {}
+
+fn seek() {
+    // Moves the cursor.
+    move_cursor();
+}
+
+fn undocumented() {}
+"#;
+        assert_eq!(parse_and_metrics(source)["doc_placement_consistency"], 0.5);
+        let (head, _) = source.split_once("// Opens").unwrap();
+        assert!(!parse_and_metrics(head).contains_key("doc_placement_consistency"));
+    }
 }
@@ -6,7 +6,17 @@ use std::collections::HashMap;
 use crate::language::Language;
 use crate::report::{Signal, SymbolMetadata};
 
+/// Line count below which the built-in text analyzers are skipped, unless
+/// overridden via `[min_lines]` in `.vibecheck`.
+pub const DEFAULT_MIN_LINES: usize = 10;
+
 /// Trait for text-pattern source code analyzers.
+///
+/// Analyzers are stored as `Box<dyn Analyzer>`, so the trait must stay
+/// object-safe: no generic methods, no methods returning `Self`, and no
+/// `Self: Sized` bounds on required methods.  Implementors must also be
+/// `Send + Sync` because a [`crate::pipeline::Pipeline`] may be shared across
+/// threads.
 pub trait Analyzer: Send + Sync {
     /// A short name identifying this analyzer.
     fn name(&self) -> &str;
@@ -14,6 +24,14 @@ pub trait Analyzer: Send + Sync {
     /// Analyze Rust source code (the default / fallback language).
     fn analyze(&self, source: &str) -> Vec<Signal>;
 
+    /// Fewest source lines this analyzer needs before its signals mean
+    /// anything.  The [`crate::pipeline::Pipeline`] skips it on shorter input
+    /// (and always on empty input); `[min_lines]` in `.vibecheck` overrides
+    /// it.  Defaults to `0`, so custom analyzers run on any non-empty input.
+    fn min_lines(&self) -> usize {
+        0
+    }
+
     /// Analyze Rust source (alias used by the dispatch table).
     /// Defaults to [`analyze`].
     fn analyze_rust(&self, source: &str) -> Vec<Signal> {
@@ -35,6 +53,19 @@ pub trait Analyzer: Send + Sync {
         self.analyze(source)
     }
 
+    /// Analyze C# source.  Defaults to no signals — unlike the other
+    /// languages, falling back to the Rust-oriented [`analyze`] would only
+    /// produce noise, so analyzers opt in by overriding this.
+    fn analyze_csharp(&self, _source: &str) -> Vec<Signal> {
+        vec![]
+    }
+
+    /// Analyze Terraform / HCL configuration.  Defaults to no signals, for
+    /// the same reason as [`analyze_csharp`](Analyzer::analyze_csharp).
+    fn analyze_hcl(&self, _source: &str) -> Vec<Signal> {
+        vec![]
+    }
+
     /// Fully-provided language dispatch — **never override**.
     ///
     /// Routes the call to the appropriate `analyze_<lang>` method based on
@@ -45,11 +76,30 @@ pub trait Analyzer: Send + Sync {
             Some(Language::Python)            => self.analyze_python(source),
             Some(Language::JavaScript)        => self.analyze_javascript(source),
             Some(Language::Go)                => self.analyze_go(source),
+            Some(Language::CSharp)            => self.analyze_csharp(source),
+            Some(Language::Hcl)               => self.analyze_hcl(source),
         }
     }
+
+    /// Like [`analyze_with_language`](Analyzer::analyze_with_language), but
+    /// also given the syntax tree the [`crate::pipeline::Pipeline`] parsed
+    /// for `source` (`None` when the language has no grammar or the parse
+    /// failed or timed out).  Defaults to ignoring the tree; analyzers that
+    /// read declarations override this instead of parsing again.
+    fn analyze_with_tree(
+        &self,
+        source: &str,
+        lang: Option<Language>,
+        _tree: Option<&tree_sitter::Tree>,
+    ) -> Vec<Signal> {
+        self.analyze_with_language(source, lang)
+    }
 }
 
 /// Trait for tree-sitter CST analyzers.
+///
+/// Subject to the same object-safety and `Send + Sync` constraints as
+/// [`Analyzer`].
 pub trait CstAnalyzer: Send + Sync {
     /// A short name identifying this analyzer.
     fn name(&self) -> &str;
@@ -93,14 +143,122 @@ pub trait CstAnalyzer: Send + Sync {
     }
 }
 
+/// Boxed text analyzers, in run order.
+pub type TextAnalyzers = Vec<Box<dyn Analyzer>>;
+
+/// Boxed CST analyzers.
+pub type CstAnalyzers = Vec<Box<dyn CstAnalyzer>>;
+
+/// A runtime collection of text and CST analyzers.
+///
+/// This is the extension point for embedders: start from
+/// [`AnalyzerRegistry::with_defaults`] (or an empty [`AnalyzerRegistry::new`]),
+/// register any custom [`Analyzer`] / [`CstAnalyzer`] implementations, and
+/// hand the registry to [`crate::pipeline::Pipeline::from_registry`].
+///
+/// Registered analyzers are boxed as trait objects, so they must be
+/// `'static` and satisfy the traits' object-safety and `Send + Sync`
+/// requirements.
+///
+/// ```
+/// use vibecheck_core::analyzers::{Analyzer, AnalyzerRegistry};
+/// use vibecheck_core::heuristics::DefaultHeuristics;
+/// use vibecheck_core::pipeline::Pipeline;
+/// use vibecheck_core::report::{ModelFamily, Signal};
+///
+/// struct ShoutingAnalyzer;
+///
+/// impl Analyzer for ShoutingAnalyzer {
+///     fn name(&self) -> &str { "shouting" }
+///     fn analyze(&self, source: &str) -> Vec<Signal> {
+///         if source.contains("FIXME") {
+///             vec![Signal::new("custom.shouting.fixme", "shouting", "FIXME present",
+///                 ModelFamily::Human, 1.0)]
+///         } else {
+///             vec![]
+///         }
+///     }
+/// }
+///
+/// let mut registry = AnalyzerRegistry::with_defaults();
+/// registry.register(ShoutingAnalyzer);
+/// let pipeline = Pipeline::from_registry(registry, Box::new(DefaultHeuristics));
+/// let report = pipeline.run("// FIXME\nfn main() {}", None);
+/// assert!(report.signals.iter().any(|s| s.id == "custom.shouting.fixme"));
+/// ```
+#[derive(Default)]
+pub struct AnalyzerRegistry {
+    analyzers: TextAnalyzers,
+    cst_analyzers: CstAnalyzers,
+}
+
+impl AnalyzerRegistry {
+    /// An empty registry with no analyzers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A registry pre-populated with [`default_analyzers`] and
+    /// [`default_cst_analyzers`].
+    pub fn with_defaults() -> Self {
+        Self {
+            analyzers: default_analyzers(),
+            cst_analyzers: default_cst_analyzers(),
+        }
+    }
+
+    /// Add a text analyzer.  Analyzers run in registration order.
+    pub fn register<A: Analyzer + 'static>(&mut self, analyzer: A) -> &mut Self {
+        self.analyzers.push(Box::new(analyzer));
+        self
+    }
+
+    /// Add a CST analyzer.  Only analyzers whose
+    /// [`CstAnalyzer::target_language`] matches the file are run.
+    pub fn register_cst<C: CstAnalyzer + 'static>(&mut self, analyzer: C) -> &mut Self {
+        self.cst_analyzers.push(Box::new(analyzer));
+        self
+    }
+
+    /// The registered text analyzers.
+    pub fn analyzers(&self) -> &[Box<dyn Analyzer>] {
+        &self.analyzers
+    }
+
+    /// The registered CST analyzers.
+    pub fn cst_analyzers(&self) -> &[Box<dyn CstAnalyzer>] {
+        &self.cst_analyzers
+    }
+
+    /// Consume the registry, returning `(analyzers, cst_analyzers)`.
+    pub fn into_parts(self) -> (TextAnalyzers, CstAnalyzers) {
+        (self.analyzers, self.cst_analyzers)
+    }
+}
+
 /// Returns the default set of text analyzers.
 pub fn default_analyzers() -> Vec<Box<dyn Analyzer>> {
+    analyzers_with_naming(text::naming::NamingAnalyzer::default())
+}
+
+/// Returns the default set of text analyzers with a custom-configured
+/// [`text::naming::NamingAnalyzer`] (e.g. a `.vibecheck` generic-name list).
+pub fn analyzers_with_naming(naming: text::naming::NamingAnalyzer) -> Vec<Box<dyn Analyzer>> {
+    analyzers_with(naming, text::code_structure::CodeStructureAnalyzer::default())
+}
+
+/// Returns the default set of text analyzers with custom-configured naming
+/// and code-structure analyzers (e.g. an `.editorconfig` line-length limit).
+pub fn analyzers_with(
+    naming: text::naming::NamingAnalyzer,
+    structure: text::code_structure::CodeStructureAnalyzer,
+) -> Vec<Box<dyn Analyzer>> {
     vec![
         Box::new(text::comment_style::CommentStyleAnalyzer),
         Box::new(text::ai_signals::AiSignalsAnalyzer),
         Box::new(text::error_handling::ErrorHandlingAnalyzer),
-        Box::new(text::naming::NamingAnalyzer),
-        Box::new(text::code_structure::CodeStructureAnalyzer),
+        Box::new(naming),
+        Box::new(structure),
         Box::new(text::idiom_usage::IdiomUsageAnalyzer),
     ]
 }
@@ -112,6 +270,7 @@ pub fn default_cst_analyzers() -> Vec<Box<dyn CstAnalyzer>> {
         Box::new(cst::python::PythonCstAnalyzer),
         Box::new(cst::javascript::JavaScriptCstAnalyzer),
         Box::new(cst::go::GoCstAnalyzer),
+        Box::new(cst::csharp::CSharpCstAnalyzer),
     ]
 }
 
@@ -167,6 +326,12 @@ mod tests {
         }
     }
 
+    #[test]
+    fn analyze_csharp_defaults_to_no_signals() {
+        assert!(EchoAnalyzer.analyze_with_language("x", Some(Language::CSharp)).is_empty());
+        assert!(EchoAnalyzer.analyze_with_language("x", Some(Language::Hcl)).is_empty());
+    }
+
     #[test]
     fn default_analyzers_are_nonempty() {
         assert!(!default_analyzers().is_empty());
@@ -176,4 +341,30 @@ mod tests {
     fn default_cst_analyzers_are_nonempty() {
         assert!(!default_cst_analyzers().is_empty());
     }
+
+    #[test]
+    fn registry_new_is_empty() {
+        let registry = AnalyzerRegistry::new();
+        assert!(registry.analyzers().is_empty());
+        assert!(registry.cst_analyzers().is_empty());
+    }
+
+    #[test]
+    fn registry_with_defaults_matches_default_sets() {
+        let registry = AnalyzerRegistry::with_defaults();
+        assert_eq!(registry.analyzers().len(), default_analyzers().len());
+        assert_eq!(registry.cst_analyzers().len(), default_cst_analyzers().len());
+    }
+
+    #[test]
+    fn registry_register_appends_in_order() {
+        let mut registry = AnalyzerRegistry::new();
+        registry.register(EchoAnalyzer).register(EchoAnalyzer);
+        registry.register_cst(cst::go::GoCstAnalyzer);
+        let (analyzers, cst_analyzers) = registry.into_parts();
+        assert_eq!(analyzers.len(), 2);
+        assert_eq!(analyzers[0].name(), "echo");
+        assert_eq!(cst_analyzers.len(), 1);
+        assert_eq!(cst_analyzers[0].target_language(), Language::Go);
+    }
 }
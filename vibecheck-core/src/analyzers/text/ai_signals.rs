@@ -1,118 +1,104 @@
-use crate::analyzers::Analyzer;
+use super::hcl;
+use crate::analyzers::{Analyzer, DEFAULT_MIN_LINES};
 use crate::heuristics::signal_ids;
 use crate::report::{ModelFamily, Signal};
 
 pub struct AiSignalsAnalyzer;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::analyzers::Analyzer;
-    use crate::report::ModelFamily;
-
-    fn run(source: &str) -> Vec<Signal> {
-        AiSignalsAnalyzer.analyze(source)
-    }
-
-    #[test]
-    fn short_source_no_signals() {
-        let source = (0..5).map(|i| format!("let x{i} = {i};")).collect::<Vec<_>>().join("\n");
-        assert!(run(&source).is_empty());
-    }
-
-    #[test]
-    fn no_todo_in_large_file_is_claude() {
-        // 35 lines, no TODO/FIXME → Claude signal weight 0.8
-        let source = (0..35).map(|i| format!("let x{i} = {i};")).collect::<Vec<_>>().join("\n");
-        let signals = run(&source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Claude && s.weight == 0.8),
-            "expected no-TODO Claude signal (weight 0.8)"
-        );
-    }
-
-    #[test]
-    fn todo_present_suppresses_no_todo_signal() {
-        let mut lines: Vec<String> = (0..35).map(|i| format!("let x{i} = {i};")).collect();
-        lines.push("// TODO: fix this later".to_string());
-        let source = lines.join("\n");
-        let signals = run(&source);
-        assert!(
-            !signals.iter().any(|s| s.description.contains("TODO/FIXME") && s.weight == 1.5),
-            "should not emit no-TODO signal when TODO is present"
-        );
+impl AiSignalsAnalyzer {
+    /// Point `signal` at `lines[i]`: its 1-based line and first non-blank
+    /// column, with the trimmed line as the snippet.
+    fn located(signal: Signal, lines: &[&str], i: usize) -> Signal {
+        let column = lines[i].len() - lines[i].trim_start().len() + 1;
+        signal.with_location(i + 1, column, lines[i].trim())
     }
 
-    #[test]
-    fn commented_out_code_is_human() {
-        // 2+ commented-out code lines → Human signal weight 2.0
-        let mut lines: Vec<&str> = vec![
-            "// let old_value = compute();",
-            "// let result = old_value * 2;",
-        ];
-        // Pad to 10+ lines so the guard passes
-        for _ in 0..10 { lines.push("let x = 1;"); }
-        let source = lines.join("\n");
-        let signals = run(&source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Human && s.weight == 2.0),
-            "expected commented-out code Human signal (weight 2.0)"
-        );
+    /// Stub bodies (per `is_stub`, given all lines and an index) in a file
+    /// with no TODO/FIXME marker: placeholder code left with nothing
+    /// flagging it as unfinished.  Needs 2+ stubs.
+    fn silent_stubs(
+        lines: &[&str],
+        is_stub: fn(&[&str], usize) -> bool,
+        id: &str,
+    ) -> Option<Signal> {
+        let has_todo = lines.iter().any(|l| {
+            let upper = l.to_uppercase();
+            upper.contains("TODO") || upper.contains("FIXME")
+        });
+        if has_todo {
+            return None;
+        }
+        let stubs = (0..lines.len()).filter(|&i| is_stub(lines, i)).count();
+        (stubs >= 2).then(|| {
+            Signal::new(
+                id,
+                "ai_signals",
+                format!("{stubs} stub bodies with no TODO/FIXME marker"),
+                ModelFamily::Gpt,
+                1.0,
+            )
+        })
     }
 
-    #[test]
-    fn all_functions_documented_is_claude() {
-        let source = "\
-// padding\n// padding\n// padding\n// padding\n// padding\n\
-/// Does thing one.\npub fn thing_one() {}\n\
-/// Does thing two.\npub fn thing_two() {}\n\
-/// Does thing three.\npub fn thing_three() {}";
-        let signals = run(source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Claude && s.weight == 2.0),
-            "expected all-documented-functions Claude signal (weight 2.0)"
-        );
+    /// A "not implemented" message in a panic/throw/raise.
+    fn mentions_not_implemented(line: &str) -> bool {
+        let lower = line.to_lowercase();
+        lower.contains("not implemented") || lower.contains("unimplemented")
     }
 
-    fn large_clean_source(prefix: &str) -> String {
-        // 35 lines of clean code with no TODO/FIXME/trailing whitespace
-        let mut lines: Vec<String> = (0..35).map(|i| format!("{prefix}line_{i} = {i}")).collect();
-        lines[0] = format!("{prefix}line_0 = 0");
-        lines.join("\n")
+    /// `unimplemented!()`, `panic!("not implemented")`, or a one-line empty
+    /// `fn … {}` body.
+    fn is_rust_stub(lines: &[&str], i: usize) -> bool {
+        let t = lines[i].trim();
+        if t.starts_with("//") {
+            return false;
+        }
+        t.contains("unimplemented!(")
+            || (t.contains("panic!(") && Self::mentions_not_implemented(t))
+            || (t.contains("fn ") && t.ends_with("{}") && !t.contains("fn main("))
     }
 
-    #[test]
-    fn python_no_todo_is_claude() {
-        let source = large_clean_source("");
-        let signals = AiSignalsAnalyzer.analyze_python(&source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Claude),
-            "expected Claude signal for Python source with no TODO"
-        );
+    /// `raise NotImplementedError`, or a `def` whose whole body is `pass` or
+    /// `...` (a one-line docstring in between is allowed).
+    fn is_python_stub(lines: &[&str], i: usize) -> bool {
+        let t = lines[i].trim();
+        if t.starts_with("raise NotImplementedError") {
+            return true;
+        }
+        if t != "pass" && t != "..." {
+            return false;
+        }
+        let mut above = lines[..i].iter().rev().map(|l| l.trim()).filter(|l| !l.is_empty());
+        let mut header = above.next();
+        if header.is_some_and(|h| h.len() >= 6 && h.starts_with("\"\"\"") && h.ends_with("\"\"\"")) {
+            header = above.next();
+        }
+        header.is_some_and(|h| {
+            (h.starts_with("def ") || h.starts_with("async def ")) && h.ends_with(':')
+        })
     }
 
-    #[test]
-    fn javascript_no_todo_is_claude() {
-        let source = large_clean_source("const ");
-        let signals = AiSignalsAnalyzer.analyze_javascript(&source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Claude),
-            "expected Claude signal for JS source with no TODO"
-        );
+    /// `throw new Error("not implemented")`, or a one-line empty
+    /// `function … {}` body.
+    fn is_js_stub(lines: &[&str], i: usize) -> bool {
+        let t = lines[i].trim();
+        if t.starts_with("//") {
+            return false;
+        }
+        (t.starts_with("throw ") && Self::mentions_not_implemented(t))
+            || (t.contains("function") && t.ends_with("{}"))
     }
 
-    #[test]
-    fn go_no_todo_is_claude() {
-        let source = large_clean_source("var ");
-        let signals = AiSignalsAnalyzer.analyze_go(&source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Claude),
-            "expected Claude signal for Go source with no TODO"
-        );
+    /// `panic("not implemented")`, or a one-line empty `func … {}` body.
+    fn is_go_stub(lines: &[&str], i: usize) -> bool {
+        let t = lines[i].trim();
+        if t.starts_with("//") {
+            return false;
+        }
+        (t.starts_with("panic(") && Self::mentions_not_implemented(t))
+            || (t.starts_with("func ") && t.ends_with("{}") && !t.starts_with("func main("))
     }
-}
 
-impl AiSignalsAnalyzer {
     /// Language-agnostic signals shared across Rust / Python / JS / Go.
     ///
     /// Each caller passes the language-specific signal ID constants so that
@@ -127,10 +113,6 @@ impl AiSignalsAnalyzer {
         let mut signals = Vec::new();
         let lines: Vec<&str> = source.lines().collect();
         let total_lines = lines.len();
-        if total_lines < 10 {
-            return signals;
-        }
-
         // Absence of TODO/FIXME — AI rarely leaves these
         let has_todo = lines.iter().any(|l| {
             let upper = l.to_uppercase();
@@ -209,6 +191,7 @@ impl AiSignalsAnalyzer {
         );
         let lines: Vec<&str> = source.lines().collect();
         let total_lines = lines.len();
+        signals.extend(Self::silent_stubs(&lines, Self::is_python_stub, signal_ids::PYTHON_AI_SIGNALS_SILENT_STUBS));
 
         // Linter suppression comments — human workaround
         let suppression_count = lines
@@ -229,9 +212,10 @@ impl AiSignalsAnalyzer {
         }
 
         // Commented-out code (Python style)
-        let commented_code = lines
+        let commented_code: Vec<usize> = lines
             .iter()
-            .filter(|l| {
+            .enumerate()
+            .filter(|(_, l)| {
                 let t = l.trim();
                 t.starts_with("# def ")
                     || t.starts_with("# class ")
@@ -239,14 +223,19 @@ impl AiSignalsAnalyzer {
                     || t.starts_with("# return ")
                     || t.starts_with("# print(")
             })
-            .count();
-        if commented_code >= 2 {
-            signals.push(Signal::new(
-                signal_ids::PYTHON_AI_SIGNALS_COMMENTED_OUT_CODE,
-                "ai_signals",
-                format!("{commented_code} lines of commented-out code"),
-                ModelFamily::Human,
-                2.0,
+            .map(|(i, _)| i)
+            .collect();
+        if commented_code.len() >= 2 {
+            signals.push(Self::located(
+                Signal::new(
+                    signal_ids::PYTHON_AI_SIGNALS_COMMENTED_OUT_CODE,
+                    "ai_signals",
+                    format!("{} lines of commented-out code", commented_code.len()),
+                    ModelFamily::Human,
+                    2.0,
+                ),
+                &lines,
+                commented_code[0],
             ));
         }
 
@@ -305,6 +294,7 @@ impl AiSignalsAnalyzer {
         );
         let lines: Vec<&str> = source.lines().collect();
         let total_lines = lines.len();
+        signals.extend(Self::silent_stubs(&lines, Self::is_js_stub, signal_ids::JS_AI_SIGNALS_SILENT_STUBS));
 
         // Linter/type-checker suppressions
         let suppression_count = lines
@@ -327,9 +317,10 @@ impl AiSignalsAnalyzer {
         }
 
         // Commented-out code (JS style)
-        let commented_code = lines
+        let commented_code: Vec<usize> = lines
             .iter()
-            .filter(|l| {
+            .enumerate()
+            .filter(|(_, l)| {
                 let t = l.trim();
                 t.starts_with("// const ")
                     || t.starts_with("// let ")
@@ -338,14 +329,19 @@ impl AiSignalsAnalyzer {
                     || t.starts_with("// return ")
                     || t.starts_with("// console.")
             })
-            .count();
-        if commented_code >= 2 {
-            signals.push(Signal::new(
-                signal_ids::JS_AI_SIGNALS_COMMENTED_OUT_CODE,
-                "ai_signals",
-                format!("{commented_code} lines of commented-out code"),
-                ModelFamily::Human,
-                2.0,
+            .map(|(i, _)| i)
+            .collect();
+        if commented_code.len() >= 2 {
+            signals.push(Self::located(
+                Signal::new(
+                    signal_ids::JS_AI_SIGNALS_COMMENTED_OUT_CODE,
+                    "ai_signals",
+                    format!("{} lines of commented-out code", commented_code.len()),
+                    ModelFamily::Human,
+                    2.0,
+                ),
+                &lines,
+                commented_code[0],
             ));
         }
 
@@ -376,20 +372,26 @@ impl AiSignalsAnalyzer {
         }
 
         // console.log left in code — debugging artifact
-        let console_log = lines
+        let console_log: Vec<usize> = lines
             .iter()
-            .filter(|l| {
+            .enumerate()
+            .filter(|(_, l)| {
                 let t = l.trim();
                 !t.starts_with("//") && t.contains("console.log(")
             })
-            .count();
-        if console_log >= 3 {
-            signals.push(Signal::new(
-                signal_ids::JS_AI_SIGNALS_CONSOLE_LOG,
-                "ai_signals",
-                format!("{console_log} console.log calls — likely debugging artifacts"),
-                ModelFamily::Human,
-                2.0,
+            .map(|(i, _)| i)
+            .collect();
+        if console_log.len() >= 3 {
+            signals.push(Self::located(
+                Signal::new(
+                    signal_ids::JS_AI_SIGNALS_CONSOLE_LOG,
+                    "ai_signals",
+                    format!("{} console.log calls — likely debugging artifacts", console_log.len()),
+                    ModelFamily::Human,
+                    2.0,
+                ),
+                &lines,
+                console_log[0],
             ));
         }
 
@@ -406,6 +408,7 @@ impl AiSignalsAnalyzer {
         );
         let lines: Vec<&str> = source.lines().collect();
         let total_lines = lines.len();
+        signals.extend(Self::silent_stubs(&lines, Self::is_go_stub, signal_ids::GO_AI_SIGNALS_SILENT_STUBS));
 
         // //nolint suppressions
         let suppression_count = lines
@@ -434,9 +437,10 @@ impl AiSignalsAnalyzer {
         }
 
         // Commented-out code (Go style)
-        let commented_code = lines
+        let commented_code: Vec<usize> = lines
             .iter()
-            .filter(|l| {
+            .enumerate()
+            .filter(|(_, l)| {
                 let t = l.trim();
                 t.starts_with("// func ")
                     || t.starts_with("// var ")
@@ -445,14 +449,19 @@ impl AiSignalsAnalyzer {
                     || t.starts_with("// return ")
                     || t.starts_with("// fmt.")
             })
-            .count();
-        if commented_code >= 2 {
-            signals.push(Signal::new(
-                signal_ids::GO_AI_SIGNALS_COMMENTED_OUT_CODE,
-                "ai_signals",
-                format!("{commented_code} lines of commented-out code"),
-                ModelFamily::Human,
-                2.0,
+            .map(|(i, _)| i)
+            .collect();
+        if commented_code.len() >= 2 {
+            signals.push(Self::located(
+                Signal::new(
+                    signal_ids::GO_AI_SIGNALS_COMMENTED_OUT_CODE,
+                    "ai_signals",
+                    format!("{} lines of commented-out code", commented_code.len()),
+                    ModelFamily::Human,
+                    2.0,
+                ),
+                &lines,
+                commented_code[0],
             ));
         }
 
@@ -486,6 +495,35 @@ impl AiSignalsAnalyzer {
 
         signals
     }
+
+    fn analyze_hcl_impl(source: &str) -> Vec<Signal> {
+        let mut signals = Vec::new();
+        // Variable description completeness
+        let variables: Vec<_> = hcl::blocks(source)
+            .into_iter()
+            .filter(|b| b.kind == "variable")
+            .collect();
+        let described = variables.iter().filter(|b| b.has_attribute("description")).count();
+        if variables.len() >= 3 && described == variables.len() {
+            signals.push(Signal::new(
+                signal_ids::HCL_AI_SIGNALS_ALL_VARS_DESCRIBED,
+                "ai_signals",
+                format!("All {} variables have a description", variables.len()),
+                ModelFamily::Claude,
+                1.5,
+            ));
+        } else if variables.len() >= 3 && described * 2 < variables.len() {
+            signals.push(Signal::new(
+                signal_ids::HCL_AI_SIGNALS_UNDESCRIBED_VARS,
+                "ai_signals",
+                format!("Only {described} of {} variables have a description", variables.len()),
+                ModelFamily::Human,
+                1.0,
+            ));
+        }
+
+        signals
+    }
 }
 
 impl Analyzer for AiSignalsAnalyzer {
@@ -493,6 +531,10 @@ impl Analyzer for AiSignalsAnalyzer {
         "ai_signals"
     }
 
+    fn min_lines(&self) -> usize {
+        DEFAULT_MIN_LINES
+    }
+
     fn analyze_python(&self, source: &str) -> Vec<Signal> {
         Self::analyze_python_impl(source)
     }
@@ -505,15 +547,15 @@ impl Analyzer for AiSignalsAnalyzer {
         Self::analyze_go_impl(source)
     }
 
+    fn analyze_hcl(&self, source: &str) -> Vec<Signal> {
+        Self::analyze_hcl_impl(source)
+    }
+
 
     fn analyze(&self, source: &str) -> Vec<Signal> {
         let mut signals = Vec::new();
         let lines: Vec<&str> = source.lines().collect();
         let total_lines = lines.len();
-        if total_lines < 10 {
-            return signals;
-        }
-
         // Absence of TODO/FIXME — AI rarely leaves these
         let has_todo = lines.iter().any(|l| {
             let upper = l.to_uppercase();
@@ -529,6 +571,8 @@ impl Analyzer for AiSignalsAnalyzer {
             ));
         }
 
+        signals.extend(Self::silent_stubs(&lines, Self::is_rust_stub, signal_ids::RUST_AI_SIGNALS_SILENT_STUBS));
+
         // No dead code markers (#[allow(dead_code)], #[allow(unused)])
         let dead_code_markers = ["allow(dead_code)", "allow(unused)", "#[cfg(dead_code)]"];
         let has_dead_code = lines
@@ -581,21 +625,25 @@ impl Analyzer for AiSignalsAnalyzer {
         }
 
         // Commented-out code is a human signal
-        let commented_code = lines.iter().filter(|l| {
+        let commented_code: Vec<usize> = lines.iter().enumerate().filter(|(_, l)| {
             let trimmed = l.trim();
             trimmed.starts_with("// let ")
                 || trimmed.starts_with("// fn ")
                 || trimmed.starts_with("// use ")
                 || trimmed.starts_with("// println!")
                 || trimmed.starts_with("// pub ")
-        }).count();
-        if commented_code >= 2 {
-            signals.push(Signal::new(
-                signal_ids::RUST_AI_SIGNALS_COMMENTED_OUT_CODE,
-                self.name(),
-                format!("{commented_code} lines of commented-out code"),
-                ModelFamily::Human,
-                2.0,
+        }).map(|(i, _)| i).collect();
+        if commented_code.len() >= 2 {
+            signals.push(Self::located(
+                Signal::new(
+                    signal_ids::RUST_AI_SIGNALS_COMMENTED_OUT_CODE,
+                    self.name(),
+                    format!("{} lines of commented-out code", commented_code.len()),
+                    ModelFamily::Human,
+                    2.0,
+                ),
+                &lines,
+                commented_code[0],
             ));
         }
 
@@ -641,3 +689,184 @@ impl Analyzer for AiSignalsAnalyzer {
         signals
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzers::Analyzer;
+    use crate::heuristics::DefaultHeuristics;
+    use crate::pipeline::Pipeline;
+    use crate::report::ModelFamily;
+
+    fn run(source: &str) -> Vec<Signal> {
+        AiSignalsAnalyzer.analyze(source)
+    }
+
+    #[test]
+    fn short_source_no_signals() {
+        let source = (0..5).map(|i| format!("let x{i} = {i};")).collect::<Vec<_>>().join("\n");
+        let pipeline = Pipeline::with_heuristics(
+            vec![Box::new(AiSignalsAnalyzer)],
+            vec![],
+            Box::new(DefaultHeuristics),
+        );
+        assert!(pipeline.run(&source, None).signals.is_empty());
+    }
+
+    #[test]
+    fn no_todo_in_large_file_is_claude() {
+        // 35 lines, no TODO/FIXME → Claude signal weight 0.8
+        let source = (0..35).map(|i| format!("let x{i} = {i};")).collect::<Vec<_>>().join("\n");
+        let signals = run(&source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Claude && s.weight == 0.8),
+            "expected no-TODO Claude signal (weight 0.8)"
+        );
+    }
+
+    #[test]
+    fn todo_present_suppresses_no_todo_signal() {
+        let mut lines: Vec<String> = (0..35).map(|i| format!("let x{i} = {i};")).collect();
+        lines.push("// TODO: fix this later".to_string());
+        let source = lines.join("\n");
+        let signals = run(&source);
+        assert!(
+            !signals.iter().any(|s| s.description.contains("TODO/FIXME") && s.weight == 1.5),
+            "should not emit no-TODO signal when TODO is present"
+        );
+    }
+
+    #[test]
+    fn commented_out_code_is_human() {
+        // 2+ commented-out code lines → Human signal weight 2.0
+        let mut lines: Vec<&str> = vec![
+            "// let old_value = compute();",
+            "// let result = old_value * 2;",
+        ];
+        // Pad to 10+ lines so the guard passes
+        lines.extend(std::iter::repeat_n("let x = 1;", 10));
+        let source = lines.join("\n");
+        let signals = run(&source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Human && s.weight == 2.0),
+            "expected commented-out code Human signal (weight 2.0)"
+        );
+    }
+
+    #[test]
+    fn commented_out_code_and_console_log_carry_locations() {
+        let rust = "fn main() {\n    let x = 1;\n    // let y = 2;\n    // println!(\"{y}\");\n}\n";
+        let signal = run(rust)
+            .into_iter()
+            .find(|s| s.id == signal_ids::RUST_AI_SIGNALS_COMMENTED_OUT_CODE)
+            .unwrap();
+        assert_eq!(signal.location, Some((3, 5)));
+        assert_eq!(signal.snippet.as_deref(), Some("// let y = 2;"));
+
+        let js = "function f() {\n  return 1;\n}\nconsole.log(1);\nconsole.log(2);\n  console.log(3);\n";
+        let signal = AiSignalsAnalyzer
+            .analyze_javascript(js)
+            .into_iter()
+            .find(|s| s.id == signal_ids::JS_AI_SIGNALS_CONSOLE_LOG)
+            .unwrap();
+        assert_eq!(signal.location, Some((4, 1)));
+
+        let no_todo = run(&"let x = 1;\n".repeat(30));
+        assert!(no_todo.iter().all(|s| s.location.is_none() && s.snippet.is_none()), "whole-file signals");
+    }
+
+    #[test]
+    fn all_functions_documented_is_claude() {
+        let source = "\
+// padding\n// padding\n// padding\n// padding\n// padding\n\
+/// Does thing one.\npub fn thing_one() {}\n\
+/// Does thing two.\npub fn thing_two() {}\n\
+/// Does thing three.\npub fn thing_three() {}";
+        let signals = run(source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Claude && s.weight == 2.0),
+            "expected all-documented-functions Claude signal (weight 2.0)"
+        );
+    }
+
+    fn large_clean_source(prefix: &str) -> String {
+        // 35 lines of clean code with no TODO/FIXME/trailing whitespace
+        let mut lines: Vec<String> = (0..35).map(|i| format!("{prefix}line_{i} = {i}")).collect();
+        lines[0] = format!("{prefix}line_0 = 0");
+        lines.join("\n")
+    }
+
+    #[test]
+    fn python_no_todo_is_claude() {
+        let source = large_clean_source("");
+        let signals = AiSignalsAnalyzer.analyze_python(&source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Claude),
+            "expected Claude signal for Python source with no TODO"
+        );
+    }
+
+    #[test]
+    fn javascript_no_todo_is_claude() {
+        let source = large_clean_source("const ");
+        let signals = AiSignalsAnalyzer.analyze_javascript(&source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Claude),
+            "expected Claude signal for JS source with no TODO"
+        );
+    }
+
+    #[test]
+    fn go_no_todo_is_claude() {
+        let source = large_clean_source("var ");
+        let signals = AiSignalsAnalyzer.analyze_go(&source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Claude),
+            "expected Claude signal for Go source with no TODO"
+        );
+    }
+
+    #[test]
+    fn silent_stubs_fire_without_todo_markers() {
+        let fired = |signals: Vec<Signal>, id: &str| signals.iter().any(|s| s.id == id);
+        let rust = "fn load(&self) -> Config {\n    unimplemented!()\n}\n\nfn save(&self) {}\nfn main() {}\n";
+        assert!(fired(run(rust), signal_ids::RUST_AI_SIGNALS_SILENT_STUBS));
+        let flagged = format!("// TODO: finish these\n{rust}");
+        assert!(!fired(run(&flagged), signal_ids::RUST_AI_SIGNALS_SILENT_STUBS));
+
+        let python = "def load(self):\n    \"\"\"Load.\"\"\"\n    pass\n\ndef save(self):\n    raise NotImplementedError\n\nfor x in xs:\n    pass\n";
+        assert!(fired(AiSignalsAnalyzer.analyze_python(python), signal_ids::PYTHON_AI_SIGNALS_SILENT_STUBS));
+        let one = "def load(self):\n    pass\n\nfor x in xs:\n    pass\n";
+        assert!(!fired(AiSignalsAnalyzer.analyze_python(one), signal_ids::PYTHON_AI_SIGNALS_SILENT_STUBS));
+
+        let js = "function load() {}\nfunction save() {\n  throw new Error(\"Not implemented\");\n}\n";
+        assert!(fired(AiSignalsAnalyzer.analyze_javascript(js), signal_ids::JS_AI_SIGNALS_SILENT_STUBS));
+
+        let go = "func (s *Store) Load() {}\nfunc (s *Store) Save() error {\n\tpanic(\"not implemented\")\n}\n";
+        assert!(fired(AiSignalsAnalyzer.analyze_go(go), signal_ids::GO_AI_SIGNALS_SILENT_STUBS));
+    }
+
+    fn hcl_variables(described: &[bool]) -> String {
+        described
+            .iter()
+            .enumerate()
+            .map(|(i, &d)| {
+                let desc = if d { "  description = \"Setting.\"\n" } else { "" };
+                format!("variable \"setting_{i}\" {{\n  type = string\n{desc}}}\n\n")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn hcl_all_vars_described_is_claude() {
+        let signals = AiSignalsAnalyzer.analyze_hcl(&hcl_variables(&[true; 3]));
+        assert!(signals.iter().any(|s| s.id == signal_ids::HCL_AI_SIGNALS_ALL_VARS_DESCRIBED));
+    }
+
+    #[test]
+    fn hcl_mostly_undescribed_vars_is_human() {
+        let signals = AiSignalsAnalyzer.analyze_hcl(&hcl_variables(&[true, false, false, false]));
+        assert!(signals.iter().any(|s| s.id == signal_ids::HCL_AI_SIGNALS_UNDESCRIBED_VARS));
+        assert!(!signals.iter().any(|s| s.id == signal_ids::HCL_AI_SIGNALS_ALL_VARS_DESCRIBED));
+    }
+}
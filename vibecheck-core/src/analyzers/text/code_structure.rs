@@ -1,142 +1,144 @@
-use crate::analyzers::Analyzer;
+use crate::analyzers::{Analyzer, DEFAULT_MIN_LINES};
 use crate::heuristics::signal_ids;
 use crate::report::{ModelFamily, Signal};
 
-pub struct CodeStructureAnalyzer;
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::analyzers::Analyzer;
-    use crate::report::ModelFamily;
-
-    fn run(source: &str) -> Vec<Signal> {
-        CodeStructureAnalyzer.analyze(source)
-    }
-
-    #[test]
-    fn sorted_imports_is_gpt() {
-        let source = "\
-use std::collections::HashMap;\n\
-use std::fmt;\n\
-use std::path::PathBuf;\n\
-let x = 1;\nlet y = 2;\nlet z = 3;\nlet a = 4;\nlet b = 5;\nlet c = 6;\nlet d = 7;";
-        let signals = run(source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Gpt && s.weight == 0.5
-                && s.description.contains("sorted")),
-            "expected sorted imports Gpt signal (weight 0.5)"
-        );
-    }
-
-    #[test]
-    fn high_annotation_ratio_is_gpt() {
-        // 8 annotated out of 10 total = 80% > 70%
-        let source = "\
-let x: i32 = 1;\n\
-let y: String = String::new();\n\
-let z: Vec<u8> = vec![];\n\
-let w: bool = true;\n\
-let a: u64 = 0;\n\
-let b: f64 = 0.0;\n\
-let c: usize = 0;\n\
-let d: i64 = 0;\n\
-let v = 0;\n\
-let u = 0;";
-        let signals = run(source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Gpt && s.weight == 1.0),
-            "expected high annotation ratio Gpt signal (weight 1.0)"
-        );
-    }
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CodeStructureAnalyzer {
+    /// Project line-length limit (e.g. from `.editorconfig`) replacing the
+    /// per-language default in the `*.structure.lines_under_*` checks.
+    max_line_length: Option<usize>,
+}
 
-    #[test]
-    fn low_annotation_ratio_is_gemini() {
-        // <20% annotated with 5+ let bindings
-        let source = "\
-let value_one = 1;\n\
-let value_two = 2;\n\
-let value_three = 3;\n\
-let value_four = 4;\n\
-let value_five = 5;\n\
-let value_six = 6;\n\
-let x: i32 = 0;\n\
-let y = 0;\nlet z = 0;\nlet a = 0;";
-        let signals = run(source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Gemini && s.weight == 0.8
-                && s.description.contains("inference")),
-            "expected low annotation ratio Gemini signal (weight 0.8)"
-        );
-    }
+/// Significant lines per window when looking for duplicated blocks.
+const DUP_WINDOW: usize = 4;
 
-    #[test]
-    fn all_lines_under_100_chars_is_gemini() {
-        // 10+ non-empty lines, all ≤ 100 chars
-        let source = (0..12)
-            .map(|i| format!("let value_{i} = {i};"))
-            .collect::<Vec<_>>()
-            .join("\n");
-        let signals = run(&source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Gemini && s.weight == 0.4
-                && s.description.contains("100")),
-            "expected all-lines-under-100 Gemini signal (weight 0.4)"
-        );
-    }
+/// Binary operators whose spacing formatters and authors treat uniformly,
+/// longest first so `===` is not read as `==`.  Single-character operators
+/// are left out: `-x`, `*ptr`, `&x`, `<T>` and Python keyword arguments
+/// (`f(x=1)`) make their spacing ambiguous.
+const SPACED_OPERATORS: &[&str] = &[
+    "===", "!==", "<<=", ">>=", "**=", "//=", "==", "!=", "<=", ">=", "&&", "||", "+=", "-=",
+    "*=", "/=", "%=", "|=", "&=", "^=", ":=",
+];
 
-    fn make_lines(n: usize, prefix: &str) -> String {
-        (0..n).map(|i| format!("{prefix}line_{i} = {i}")).collect::<Vec<_>>().join("\n")
-    }
+/// Lines too generic to count towards duplication: blank, punctuation-only
+/// (closing braces, `});`), comments, and imports.
+fn is_trivial_line(line: &str) -> bool {
+    !line.chars().any(char::is_alphanumeric)
+        || ["//", "#", "/*", "*", "use ", "import ", "from ", "package ", "using "]
+            .iter()
+            .any(|p| line.starts_with(p))
+}
 
-    #[test]
-    fn python_short_lines_is_gemini() {
-        let source = make_lines(12, "");
-        let signals = CodeStructureAnalyzer.analyze_python(&source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Gemini),
-            "expected Gemini signal for short Python lines"
-        );
-    }
+/// One piece of source as split by [`scan_source`].
+enum Token {
+    /// A character outside every comment and literal.
+    Code(char),
+    /// A `//`, `/* */` or `#` comment spanning `newlines` line breaks.  A
+    /// line comment's terminating newline is left as [`Token::Code`].
+    Comment { newlines: usize },
+    /// A string or char literal opened with `quote`; `triple` marks a Python
+    /// triple-quoted string.
+    Literal { quote: char, triple: bool, newlines: usize },
+}
 
-    #[test]
-    fn python_sorted_imports_is_gpt() {
-        let mut lines: Vec<String> = vec![
-            "import abc".into(),
-            "import collections".into(),
-            "import sys".into(),
-        ];
-        lines.extend((0..10).map(|i| format!("x_{i} = {i}")));
-        let source = lines.join("\n");
-        let signals = CodeStructureAnalyzer.analyze_python(&source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Gpt),
-            "expected Gpt signal for sorted Python imports"
-        );
+/// Split `source` into code characters, comments and literals, calling
+/// `visit` for each in order.
+///
+/// `python` selects `#` comments and triple-quoted strings over `//` and
+/// `/* */` comments and backtick template literals.  `char_quotes` marks
+/// languages where `'` delimits a single character (Rust, Go): a `'` not
+/// closed within one (possibly escaped) character is a Rust lifetime and is
+/// passed through as code.
+fn scan_source(source: &str, python: bool, char_quotes: bool, mut visit: impl FnMut(Token)) {
+    let chars: Vec<char> = source.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        if (python && c == '#') || (!python && c == '/' && next == Some('/')) {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            visit(Token::Comment { newlines: 0 });
+            continue;
+        }
+        if !python && c == '/' && next == Some('*') {
+            let mut newlines = 0;
+            i += 2;
+            while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                newlines += usize::from(chars[i] == '\n');
+                i += 1;
+            }
+            i += 2;
+            visit(Token::Comment { newlines });
+            continue;
+        }
+        if python && (c == '"' || c == '\'') && next == Some(c) && chars.get(i + 2) == Some(&c) {
+            let mut newlines = 0;
+            i += 3;
+            while i < chars.len()
+                && !(chars[i] == c && chars.get(i + 1) == Some(&c) && chars.get(i + 2) == Some(&c))
+            {
+                newlines += usize::from(chars[i] == '\n');
+                i += if chars[i] == '\\' { 2 } else { 1 };
+            }
+            i += 3;
+            visit(Token::Literal { quote: c, triple: true, newlines });
+            continue;
+        }
+        if char_quotes && c == '\'' {
+            let close = if next == Some('\\') {
+                (i + 3..chars.len().min(i + 12)).find(|&j| chars[j] == '\'')
+            } else {
+                Some(i + 2).filter(|&j| chars.get(j) == Some(&'\''))
+            };
+            match close {
+                Some(j) => {
+                    visit(Token::Literal { quote: c, triple: false, newlines: 0 });
+                    i = j + 1;
+                }
+                None => {
+                    visit(Token::Code(c));
+                    i += 1;
+                }
+            }
+            continue;
+        }
+        if c == '"' || c == '\'' || (!python && c == '`') {
+            let mut newlines = 0;
+            i += 1;
+            while i < chars.len() && chars[i] != c && (c == '`' || chars[i] != '\n') {
+                newlines += usize::from(chars[i] == '\n');
+                i += if chars[i] == '\\' { 2 } else { 1 };
+            }
+            i += 1;
+            visit(Token::Literal { quote: c, triple: false, newlines });
+            continue;
+        }
+        visit(Token::Code(c));
+        i += 1;
     }
+}
 
-    #[test]
-    fn javascript_short_lines_is_gemini() {
-        let source = make_lines(12, "const ");
-        let signals = CodeStructureAnalyzer.analyze_javascript(&source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Gemini),
-            "expected Gemini signal for short JS lines"
-        );
+impl CodeStructureAnalyzer {
+    /// Build an analyzer that measures the `*.structure.lines_under_*`
+    /// signals against `max_line_length` instead of the language default
+    /// (88 for Python, 100 for Rust and JS, 120 for Go).  `None` keeps the
+    /// defaults.
+    pub fn with_max_line_length(max_line_length: Option<usize>) -> Self {
+        Self { max_line_length }
     }
 
-    #[test]
-    fn go_short_lines_is_gemini() {
-        let source = make_lines(12, "var ");
-        let signals = CodeStructureAnalyzer.analyze_go(&source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Gemini),
-            "expected Gemini signal for short Go lines"
-        );
+    /// The `lines_under_*` limit and signal description: the language
+    /// `default` and its `style` note, or the configured project limit.
+    fn line_limit(&self, default: usize, style: &str) -> (usize, String) {
+        match self.max_line_length {
+            Some(limit) => (limit, format!("All lines under {limit} chars — matches the project's max_line_length")),
+            None => (default, format!("All lines under {default} chars — {style}")),
+        }
     }
-}
 
-impl CodeStructureAnalyzer {
     /// Detect function length metrics and emit compact_fns / very_short_fns signals.
     fn detect_fn_length_signals(
         fn_start_lines: &[usize],
@@ -178,6 +180,190 @@ impl CodeStructureAnalyzer {
         signals
     }
 
+    /// Count string literals opened with `'` versus `"`.
+    ///
+    /// Quote characters inside a string of the opposite type and inside
+    /// comments are ignored.  Python triple-quoted strings and JS template
+    /// literals are skipped entirely — their delimiter is set by convention
+    /// (docstrings, interpolation), not by the author's quote preference.
+    fn count_quote_styles(source: &str, python: bool) -> (usize, usize) {
+        let (mut single, mut double) = (0usize, 0usize);
+        scan_source(source, python, false, |token| match token {
+            Token::Literal { quote: '"', triple: false, .. } => double += 1,
+            Token::Literal { quote: '\'', triple: false, .. } => single += 1,
+            _ => {}
+        });
+        (single, double)
+    }
+
+    /// Emit consistent_quotes when one quote style covers ≥95% of 6+ string
+    /// literals, or mixed_quotes when the minority style reaches 30%.
+    fn detect_quote_style(
+        source: &str,
+        python: bool,
+        consistent_quotes_id: &str,
+        mixed_quotes_id: &str,
+    ) -> Option<Signal> {
+        let (single, double) = Self::count_quote_styles(source, python);
+        let total = single + double;
+        if total < 6 {
+            return None;
+        }
+        let minority = single.min(double) as f64 / total as f64;
+        if minority <= 0.05 {
+            let style = if single > double { "single" } else { "double" };
+            Some(Signal::new(
+                consistent_quotes_id,
+                "structure",
+                format!("Consistent {style}-quoted strings ({total} literals)"),
+                ModelFamily::Gpt,
+                0.8,
+            ))
+        } else if minority >= 0.3 {
+            Some(Signal::new(
+                mixed_quotes_id,
+                "structure",
+                format!("Mixed quote styles ({single} single, {double} double)"),
+                ModelFamily::Human,
+                1.2,
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Count multi-line argument lists / array literals whose last element
+    /// ends with a trailing comma vs. those that don't.
+    ///
+    /// Only `)` and `]` closers on their own line are considered — `}` is too
+    /// often a block end.  Element lines carrying a `//` comment are skipped
+    /// so URLs and commented-out code can't skew the count.
+    fn count_trailing_commas(lines: &[&str]) -> (usize, usize) {
+        let mut trailing = 0;
+        let mut missing = 0;
+        for pair in lines.windows(2) {
+            let closer = pair[1].trim_start();
+            if !(closer.starts_with(')') || closer.starts_with(']')) {
+                continue;
+            }
+            let last = pair[0].trim_end();
+            let last_trimmed = last.trim_start();
+            if last_trimmed.is_empty()
+                || last_trimmed.starts_with("//")
+                || last_trimmed.starts_with('*')
+                || last.contains("//")
+            {
+                continue;
+            }
+            match last.chars().last() {
+                Some(',') => trailing += 1,
+                // Empty list, statement, or chained-call continuation.
+                Some('(' | '[' | '{' | ';' | '.' | '\\' | '`') => {}
+                Some(_) => missing += 1,
+                None => {}
+            }
+        }
+        (trailing, missing)
+    }
+
+    /// Emit trailing_commas when ≥90% of 4+ multi-line lists end in a comma.
+    fn detect_trailing_commas(lines: &[&str], trailing_commas_id: &str) -> Option<Signal> {
+        let (trailing, missing) = Self::count_trailing_commas(lines);
+        let total = trailing + missing;
+        if total < 4 || (trailing as f64 / total as f64) < 0.9 {
+            return None;
+        }
+        Some(Signal::new(
+            trailing_commas_id,
+            "structure",
+            format!("Trailing commas in {trailing}/{total} multi-line lists"),
+            ModelFamily::Gpt,
+            0.8,
+        ))
+    }
+
+    /// Emit duplicated_blocks when 2+ regions of the file repeat an earlier
+    /// run of [`DUP_WINDOW`] significant lines.
+    ///
+    /// Lines are normalized (trimmed, whitespace collapsed) and trivial ones
+    /// — blank, punctuation-only such as `}` or `});`, comments, imports —
+    /// are dropped first, so closing braces and import blocks never count.
+    /// Windows are compared by a rolling hash over per-line hashes, then
+    /// confirmed line by line.  Windows made of one repeated line are ignored.
+    fn detect_duplicated_blocks(lines: &[&str], duplicated_blocks_id: &str) -> Option<Signal> {
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::HashMap;
+        use std::hash::{Hash, Hasher};
+
+        const BASE: u64 = 1_000_003;
+
+        let significant: Vec<String> = lines
+            .iter()
+            .map(|l| l.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|l| !is_trivial_line(l))
+            .collect();
+        if significant.len() < DUP_WINDOW * 2 {
+            return None;
+        }
+        let line_hashes: Vec<u64> = significant
+            .iter()
+            .map(|l| {
+                let mut h = DefaultHasher::new();
+                l.hash(&mut h);
+                h.finish()
+            })
+            .collect();
+        let top = BASE.wrapping_pow(DUP_WINDOW as u32 - 1);
+
+        let mut first_seen: HashMap<u64, usize> = HashMap::new();
+        let mut duplicated = vec![false; significant.len()];
+        let mut hash = 0u64;
+        for (i, &lh) in line_hashes.iter().enumerate() {
+            if i >= DUP_WINDOW {
+                hash = hash.wrapping_sub(line_hashes[i - DUP_WINDOW].wrapping_mul(top));
+            }
+            hash = hash.wrapping_mul(BASE).wrapping_add(lh);
+            if i + 1 < DUP_WINDOW {
+                continue;
+            }
+            let start = i + 1 - DUP_WINDOW;
+            let window = &significant[start..=i];
+            if window.iter().all(|l| *l == window[0]) {
+                continue;
+            }
+            match first_seen.get(&hash) {
+                Some(&earlier)
+                    if earlier + DUP_WINDOW <= start
+                        && significant[earlier..earlier + DUP_WINDOW] == *window =>
+                {
+                    duplicated[start..=i].iter_mut().for_each(|d| *d = true);
+                }
+                Some(_) => {}
+                None => {
+                    first_seen.insert(hash, start);
+                }
+            }
+        }
+
+        // Each maximal run of duplicated lines is one region.
+        let regions = duplicated
+            .iter()
+            .enumerate()
+            .filter(|&(i, &d)| d && (i == 0 || !duplicated[i - 1]))
+            .count();
+        if regions < 2 {
+            return None;
+        }
+        let dup_lines = duplicated.iter().filter(|&&d| d).count();
+        Some(Signal::new(
+            duplicated_blocks_id,
+            "structure",
+            format!("{regions} duplicated blocks ({dup_lines} repeated lines) within the file"),
+            ModelFamily::Copilot,
+            1.0,
+        ))
+    }
+
     /// Detect mixed indentation (tabs + spaces) as format_inconsistent.
     fn detect_format_inconsistent(
         lines: &[&str],
@@ -198,14 +384,186 @@ impl CodeStructureAnalyzer {
         }
     }
 
-    fn analyze_python_impl(source: &str) -> Vec<Signal> {
+    /// Detect a mix of CRLF and LF line endings in the raw source.
+    ///
+    /// `str::lines` strips both terminators, so this scans `source` itself.
+    /// When the minority style forms one contiguous run the file most likely
+    /// had a block pasted in from elsewhere (mixed_line_endings); endings that
+    /// flip back and forth point at hand edits across differently configured
+    /// editors (interleaved_line_endings).
+    fn detect_mixed_line_endings(
+        source: &str,
+        mixed_id: &str,
+        interleaved_id: &str,
+    ) -> Option<Signal> {
+        let endings: Vec<bool> = source
+            .split_inclusive('\n')
+            .filter(|l| l.ends_with('\n'))
+            .map(|l| l.ends_with("\r\n"))
+            .collect();
+        let crlf = endings.iter().filter(|&&c| c).count();
+        let lf = endings.len() - crlf;
+        if crlf == 0 || lf == 0 {
+            return None;
+        }
+        let minority = crlf < lf;
+        let minority_runs = endings
+            .iter()
+            .enumerate()
+            .filter(|&(i, &c)| c == minority && (i == 0 || endings[i - 1] != minority))
+            .count();
+        if minority_runs == 1 {
+            Some(Signal::new(
+                mixed_id,
+                "structure",
+                format!("Mixed line endings ({crlf} CRLF, {lf} LF) in one contiguous block"),
+                ModelFamily::Copilot,
+                1.0,
+            ))
+        } else {
+            Some(Signal::new(
+                interleaved_id,
+                "structure",
+                format!("Interleaved line endings ({crlf} CRLF, {lf} LF) across {minority_runs} regions"),
+                ModelFamily::Human,
+                0.8,
+            ))
+        }
+    }
+
+    /// Replace comments with nothing and every string or char literal with a
+    /// single `_`, so operator-like text inside them is never counted.  Line
+    /// breaks inside either are kept so line numbers still line up.
+    fn mask_literals(source: &str, python: bool, char_quotes: bool) -> String {
+        let mut out = String::with_capacity(source.len());
+        scan_source(source, python, char_quotes, |token| match token {
+            Token::Code(c) => out.push(c),
+            Token::Comment { newlines } => out.extend(std::iter::repeat_n('\n', newlines)),
+            Token::Literal { newlines, .. } => {
+                out.extend(std::iter::repeat_n('\n', newlines));
+                out.push('_');
+            }
+        });
+        out
+    }
+
+    /// Count [`SPACED_OPERATORS`] written with whitespace on both sides vs.
+    /// with operands on both sides (`a==b`).
+    ///
+    /// Operators at a line boundary or with space on one side only (unary
+    /// `&&x`, continuation lines) are ambiguous and not counted.
+    fn count_operator_spacing(source: &str, python: bool, char_quotes: bool) -> (usize, usize) {
+        let masked = Self::mask_literals(source, python, char_quotes);
+        let is_operand = |c: char| c.is_alphanumeric() || matches!(c, '_' | ')' | ']');
+        let (mut spaced, mut tight) = (0, 0);
+        for line in masked.lines() {
+            let mut rest = line;
+            let mut before: Option<char> = None;
+            while let Some(c) = rest.chars().next() {
+                let Some(op) = SPACED_OPERATORS.iter().find(|op| rest.starts_with(**op)) else {
+                    before = Some(c);
+                    rest = &rest[c.len_utf8()..];
+                    continue;
+                };
+                rest = &rest[op.len()..];
+                let after = rest.chars().next();
+                match (before, after) {
+                    (Some(b), Some(a)) if b.is_whitespace() && a.is_whitespace() => spaced += 1,
+                    (Some(b), Some(a)) if is_operand(b) && (is_operand(a) || "([-!".contains(a)) => tight += 1,
+                    _ => {}
+                }
+                before = op.chars().last();
+            }
+        }
+        (spaced, tight)
+    }
+
+    /// Emit uniform_operator_spacing when 20+ binary operators are all (or
+    /// all but 2%) surrounded by spaces.  Formatters (rustfmt, black,
+    /// prettier, gofmt) produce the same result, so the weight stays low.
+    fn detect_operator_spacing(
+        source: &str,
+        python: bool,
+        char_quotes: bool,
+        uniform_spacing_id: &str,
+    ) -> Option<Signal> {
+        let (spaced, tight) = Self::count_operator_spacing(source, python, char_quotes);
+        let total = spaced + tight;
+        if total < 20 || tight as f64 / total as f64 > 0.02 {
+            return None;
+        }
+        Some(Signal::new(
+            uniform_spacing_id,
+            "structure",
+            format!("Uniform spacing around {spaced}/{total} binary operators"),
+            ModelFamily::Gpt,
+            0.4,
+        ))
+    }
+
+    /// Split the leading import region of `lines` into blank-line-separated
+    /// sections of import statements.
+    ///
+    /// The region starts at the first line satisfying `is_import` and ends at
+    /// the first other code line.  Comment lines (section headings such as
+    /// `# third-party`) are skipped, and lines inside an open `(` or `{`
+    /// continue the statement above, so multi-line imports count once.
+    fn import_sections(lines: &[&str], is_import: impl Fn(&str) -> bool) -> Vec<usize> {
+        let mut sections = Vec::new();
+        let mut current = 0usize;
+        let mut depth = 0i32;
+        let start = lines.iter().position(|l| is_import(l.trim())).unwrap_or(lines.len());
+        for line in &lines[start..] {
+            let t = line.trim();
+            if depth == 0 {
+                if t.is_empty() {
+                    if current > 0 {
+                        sections.push(current);
+                        current = 0;
+                    }
+                    continue;
+                } else if t.starts_with("//") || t.starts_with('#') {
+                    continue;
+                } else if is_import(t) {
+                    current += 1;
+                } else {
+                    break;
+                }
+            }
+            depth += t.chars().map(|c| match c {
+                '(' | '{' => 1,
+                ')' | '}' => -1,
+                _ => 0,
+            }).sum::<i32>();
+            depth = depth.max(0);
+        }
+        if current > 0 {
+            sections.push(current);
+        }
+        sections
+    }
+
+    /// Emit grouped_imports when 4+ imports are split into 2+ sections by
+    /// blank lines — the stdlib / third-party / local layout of isort and
+    /// goimports, which models reproduce even where no such tool runs.
+    fn detect_grouped_imports(sections: &[usize], grouped_imports_id: &str) -> Option<Signal> {
+        let total: usize = sections.iter().sum();
+        if sections.len() < 2 || total < 4 {
+            return None;
+        }
+        Some(Signal::new(
+            grouped_imports_id,
+            "structure",
+            format!("{total} imports grouped into {} blank-line-separated sections", sections.len()),
+            ModelFamily::Gpt,
+            0.5,
+        ))
+    }
+
+    fn analyze_python_impl(&self, source: &str) -> Vec<Signal> {
         let mut signals = Vec::new();
         let lines: Vec<&str> = source.lines().collect();
         let total_lines = lines.len();
-        if total_lines < 10 {
-            return signals;
-        }
-
         // Sorted imports (import x before import y)
         let import_lines: Vec<&str> = lines
             .iter()
@@ -258,12 +616,12 @@ impl CodeStructureAnalyzer {
             .map(|l| l.len())
             .collect();
         if non_empty.len() >= 10 {
-            let over_88 = non_empty.iter().filter(|&&l| l > 88).count();
-            if over_88 == 0 {
+            let (limit, description) = self.line_limit(88, "PEP 8 / Black-style discipline");
+            if non_empty.iter().all(|&l| l <= limit) {
                 signals.push(Signal::new(
                     signal_ids::PYTHON_STRUCTURE_LINES_UNDER_88,
                     "structure",
-                    "All lines under 88 chars — PEP 8 / Black-style discipline",
+                    description,
                     ModelFamily::Gemini,
                     0.4,
                 ));
@@ -309,18 +667,40 @@ impl CodeStructureAnalyzer {
         if let Some(s) = Self::detect_format_inconsistent(&lines, signal_ids::PYTHON_STRUCTURE_FORMAT_INCONSISTENT) {
             signals.push(s);
         }
+        signals.extend(Self::detect_mixed_line_endings(
+            source,
+            signal_ids::PYTHON_STRUCTURE_MIXED_LINE_ENDINGS,
+            signal_ids::PYTHON_STRUCTURE_INTERLEAVED_LINE_ENDINGS,
+        ));
+
+        // Copy-paste duplication
+        signals.extend(Self::detect_duplicated_blocks(&lines, signal_ids::PYTHON_STRUCTURE_DUPLICATED_BLOCKS));
+        signals.extend(Self::detect_operator_spacing(
+            source,
+            true,
+            false,
+            signal_ids::PYTHON_STRUCTURE_UNIFORM_OPERATOR_SPACING,
+        ));
+        signals.extend(Self::detect_grouped_imports(
+            &Self::import_sections(&lines, |t| t.starts_with("import ") || t.starts_with("from ")),
+            signal_ids::PYTHON_STRUCTURE_GROUPED_IMPORTS,
+        ));
+
+        // Quote style consistency
+        signals.extend(Self::detect_quote_style(
+            source,
+            true,
+            signal_ids::PYTHON_STRUCTURE_CONSISTENT_QUOTES,
+            signal_ids::PYTHON_STRUCTURE_MIXED_QUOTES,
+        ));
 
         signals
     }
 
-    fn analyze_javascript_impl(source: &str) -> Vec<Signal> {
+    fn analyze_javascript_impl(&self, source: &str) -> Vec<Signal> {
         let mut signals = Vec::new();
         let lines: Vec<&str> = source.lines().collect();
         let total_lines = lines.len();
-        if total_lines < 10 {
-            return signals;
-        }
-
         // Sorted imports
         let import_lines: Vec<&str> = lines
             .iter()
@@ -371,11 +751,12 @@ impl CodeStructureAnalyzer {
             .collect();
         if non_empty.len() >= 10 {
             let over_100 = non_empty.iter().filter(|&&l| l > 100).count();
-            if over_100 == 0 {
+            let (limit, description) = self.line_limit(100, "disciplined formatting");
+            if non_empty.iter().all(|&l| l <= limit) {
                 signals.push(Signal::new(
                     signal_ids::JS_STRUCTURE_LINES_UNDER_100,
                     "structure",
-                    "All lines under 100 chars — disciplined formatting",
+                    description,
                     ModelFamily::Gemini,
                     0.4,
                 ));
@@ -429,18 +810,45 @@ impl CodeStructureAnalyzer {
         if let Some(s) = Self::detect_format_inconsistent(&lines, signal_ids::JS_STRUCTURE_FORMAT_INCONSISTENT) {
             signals.push(s);
         }
+        signals.extend(Self::detect_mixed_line_endings(
+            source,
+            signal_ids::JS_STRUCTURE_MIXED_LINE_ENDINGS,
+            signal_ids::JS_STRUCTURE_INTERLEAVED_LINE_ENDINGS,
+        ));
+
+        // Quote style consistency
+        signals.extend(Self::detect_quote_style(
+            source,
+            false,
+            signal_ids::JS_STRUCTURE_CONSISTENT_QUOTES,
+            signal_ids::JS_STRUCTURE_MIXED_QUOTES,
+        ));
+
+        // Trailing comma consistency
+        signals.extend(Self::detect_trailing_commas(&lines, signal_ids::JS_STRUCTURE_TRAILING_COMMAS));
+
+        // Copy-paste duplication
+        signals.extend(Self::detect_duplicated_blocks(&lines, signal_ids::JS_STRUCTURE_DUPLICATED_BLOCKS));
+        signals.extend(Self::detect_operator_spacing(
+            source,
+            false,
+            false,
+            signal_ids::JS_STRUCTURE_UNIFORM_OPERATOR_SPACING,
+        ));
+        signals.extend(Self::detect_grouped_imports(
+            &Self::import_sections(&lines, |t| {
+                t.starts_with("import ") || (t.starts_with("const ") && t.contains("require("))
+            }),
+            signal_ids::JS_STRUCTURE_GROUPED_IMPORTS,
+        ));
 
         signals
     }
 
-    fn analyze_go_impl(source: &str) -> Vec<Signal> {
+    fn analyze_go_impl(&self, source: &str) -> Vec<Signal> {
         let mut signals = Vec::new();
         let lines: Vec<&str> = source.lines().collect();
         let total_lines = lines.len();
-        if total_lines < 10 {
-            return signals;
-        }
-
         // Sorted imports (Go typically groups stdlib + third-party)
         let import_block: Vec<&str> = lines
             .iter()
@@ -493,12 +901,12 @@ impl CodeStructureAnalyzer {
             .map(|l| l.len())
             .collect();
         if non_empty.len() >= 10 {
-            let over_120 = non_empty.iter().filter(|&&l| l > 120).count();
-            if over_120 == 0 {
+            let (limit, description) = self.line_limit(120, "gofmt-style discipline");
+            if non_empty.iter().all(|&l| l <= limit) {
                 signals.push(Signal::new(
                     signal_ids::GO_STRUCTURE_LINES_UNDER_120,
                     "structure",
-                    "All lines under 120 chars — gofmt-style discipline",
+                    description,
                     ModelFamily::Gemini,
                     0.4,
                 ));
@@ -542,6 +950,27 @@ impl CodeStructureAnalyzer {
         if let Some(s) = Self::detect_format_inconsistent(&lines, signal_ids::GO_STRUCTURE_FORMAT_INCONSISTENT) {
             signals.push(s);
         }
+        signals.extend(Self::detect_mixed_line_endings(
+            source,
+            signal_ids::GO_STRUCTURE_MIXED_LINE_ENDINGS,
+            signal_ids::GO_STRUCTURE_INTERLEAVED_LINE_ENDINGS,
+        ));
+
+        // Copy-paste duplication
+        signals.extend(Self::detect_duplicated_blocks(&lines, signal_ids::GO_STRUCTURE_DUPLICATED_BLOCKS));
+        signals.extend(Self::detect_operator_spacing(
+            source,
+            false,
+            true,
+            signal_ids::GO_STRUCTURE_UNIFORM_OPERATOR_SPACING,
+        ));
+        // Sections inside the `import ( ... )` block
+        if let Some(open) = lines.iter().position(|l| l.trim() == "import (") {
+            signals.extend(Self::detect_grouped_imports(
+                &Self::import_sections(&lines[open + 1..], |t| t.ends_with('"')),
+                signal_ids::GO_STRUCTURE_GROUPED_IMPORTS,
+            ));
+        }
 
         signals
     }
@@ -552,18 +981,18 @@ impl Analyzer for CodeStructureAnalyzer {
         "structure"
     }
 
-    fn analyze_python(&self, source: &str) -> Vec<Signal> { Self::analyze_python_impl(source) }
-    fn analyze_javascript(&self, source: &str) -> Vec<Signal> { Self::analyze_javascript_impl(source) }
-    fn analyze_go(&self, source: &str) -> Vec<Signal> { Self::analyze_go_impl(source) }
+    fn min_lines(&self) -> usize {
+        DEFAULT_MIN_LINES
+    }
+
+    fn analyze_python(&self, source: &str) -> Vec<Signal> { self.analyze_python_impl(source) }
+    fn analyze_javascript(&self, source: &str) -> Vec<Signal> { self.analyze_javascript_impl(source) }
+    fn analyze_go(&self, source: &str) -> Vec<Signal> { self.analyze_go_impl(source) }
 
     fn analyze(&self, source: &str) -> Vec<Signal> {
         let mut signals = Vec::new();
         let lines: Vec<&str> = source.lines().collect();
         let total_lines = lines.len();
-        if total_lines < 10 {
-            return signals;
-        }
-
         // Type annotations on let bindings
         let let_lines: Vec<&&str> = lines
             .iter()
@@ -659,13 +1088,13 @@ impl Analyzer for CodeStructureAnalyzer {
             .map(|l| l.len())
             .collect();
         if non_empty_lines.len() >= 10 {
-            let max_len = non_empty_lines.iter().max().copied().unwrap_or(0);
             let over_100 = non_empty_lines.iter().filter(|&&l| l > 100).count();
-            if over_100 == 0 && max_len <= 100 {
+            let (limit, description) = self.line_limit(100, "disciplined formatting");
+            if non_empty_lines.iter().all(|&l| l <= limit) {
                 signals.push(Signal::new(
                     signal_ids::RUST_STRUCTURE_LINES_UNDER_100,
                     self.name(),
-                    "All lines under 100 chars — disciplined formatting",
+                    description,
                     ModelFamily::Gemini,
                     0.4,
                 ));
@@ -720,6 +1149,27 @@ impl Analyzer for CodeStructureAnalyzer {
         if let Some(s) = Self::detect_format_inconsistent(&lines, signal_ids::RUST_STRUCTURE_FORMAT_INCONSISTENT) {
             signals.push(s);
         }
+        signals.extend(Self::detect_mixed_line_endings(
+            source,
+            signal_ids::RUST_STRUCTURE_MIXED_LINE_ENDINGS,
+            signal_ids::RUST_STRUCTURE_INTERLEAVED_LINE_ENDINGS,
+        ));
+
+        // Trailing comma consistency
+        signals.extend(Self::detect_trailing_commas(&lines, signal_ids::RUST_STRUCTURE_TRAILING_COMMAS));
+
+        // Copy-paste duplication
+        signals.extend(Self::detect_duplicated_blocks(&lines, signal_ids::RUST_STRUCTURE_DUPLICATED_BLOCKS));
+        signals.extend(Self::detect_operator_spacing(
+            source,
+            false,
+            true,
+            signal_ids::RUST_STRUCTURE_UNIFORM_OPERATOR_SPACING,
+        ));
+        signals.extend(Self::detect_grouped_imports(
+            &Self::import_sections(&lines, |t| t.starts_with("use ") || t.starts_with("pub use ")),
+            signal_ids::RUST_STRUCTURE_GROUPED_IMPORTS,
+        ));
 
         // Derive macro usage (AI loves deriving everything)
         let derive_count = lines
@@ -750,3 +1200,346 @@ impl Analyzer for CodeStructureAnalyzer {
         signals
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzers::Analyzer;
+    use crate::report::ModelFamily;
+
+    fn run(source: &str) -> Vec<Signal> {
+        CodeStructureAnalyzer::default().analyze(source)
+    }
+
+    #[test]
+    fn sorted_imports_is_gpt() {
+        let source = "\
+use std::collections::HashMap;\n\
+use std::fmt;\n\
+use std::path::PathBuf;\n\
+let x = 1;\nlet y = 2;\nlet z = 3;\nlet a = 4;\nlet b = 5;\nlet c = 6;\nlet d = 7;";
+        let signals = run(source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Gpt && s.weight == 0.5
+                && s.description.contains("sorted")),
+            "expected sorted imports Gpt signal (weight 0.5)"
+        );
+    }
+
+    #[test]
+    fn high_annotation_ratio_is_gpt() {
+        // 8 annotated out of 10 total = 80% > 70%
+        let source = "\
+let x: i32 = 1;\n\
+let y: String = String::new();\n\
+let z: Vec<u8> = vec![];\n\
+let w: bool = true;\n\
+let a: u64 = 0;\n\
+let b: f64 = 0.0;\n\
+let c: usize = 0;\n\
+let d: i64 = 0;\n\
+let v = 0;\n\
+let u = 0;";
+        let signals = run(source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Gpt && s.weight == 1.0),
+            "expected high annotation ratio Gpt signal (weight 1.0)"
+        );
+    }
+
+    #[test]
+    fn low_annotation_ratio_is_gemini() {
+        // <20% annotated with 5+ let bindings
+        let source = "\
+let value_one = 1;\n\
+let value_two = 2;\n\
+let value_three = 3;\n\
+let value_four = 4;\n\
+let value_five = 5;\n\
+let value_six = 6;\n\
+let x: i32 = 0;\n\
+let y = 0;\nlet z = 0;\nlet a = 0;";
+        let signals = run(source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Gemini && s.weight == 0.8
+                && s.description.contains("inference")),
+            "expected low annotation ratio Gemini signal (weight 0.8)"
+        );
+    }
+
+    #[test]
+    fn all_lines_under_100_chars_is_gemini() {
+        // 10+ non-empty lines, all ≤ 100 chars
+        let source = (0..12)
+            .map(|i| format!("let value_{i} = {i};"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let signals = run(&source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Gemini && s.weight == 0.4
+                && s.description.contains("100")),
+            "expected all-lines-under-100 Gemini signal (weight 0.4)"
+        );
+    }
+
+    #[test]
+    fn custom_max_line_length_replaces_the_language_default() {
+        let source = (0..12)
+            .map(|i| format!("let value_{i} = \"{}\";", "x".repeat(80)))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let under = |analyzer: CodeStructureAnalyzer| {
+            analyzer
+                .analyze(&source)
+                .into_iter()
+                .find(|s| s.id == signal_ids::RUST_STRUCTURE_LINES_UNDER_100)
+        };
+        assert!(under(CodeStructureAnalyzer::default()).is_some());
+        assert!(under(CodeStructureAnalyzer::with_max_line_length(Some(80))).is_none());
+        let signal = under(CodeStructureAnalyzer::with_max_line_length(Some(99))).unwrap();
+        assert!(signal.description.contains("under 99 chars"), "{}", signal.description);
+
+        let go = source.replace("let ", "var ");
+        let fires = |limit| {
+            CodeStructureAnalyzer::with_max_line_length(limit)
+                .analyze_go(&go)
+                .iter()
+                .any(|s| s.id == signal_ids::GO_STRUCTURE_LINES_UNDER_120)
+        };
+        assert!(fires(None));
+        assert!(!fires(Some(90)));
+    }
+
+    fn make_lines(n: usize, prefix: &str) -> String {
+        (0..n).map(|i| format!("{prefix}line_{i} = {i}")).collect::<Vec<_>>().join("\n")
+    }
+
+    #[test]
+    fn python_short_lines_is_gemini() {
+        let source = make_lines(12, "");
+        let signals = CodeStructureAnalyzer::default().analyze_python(&source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Gemini),
+            "expected Gemini signal for short Python lines"
+        );
+    }
+
+    #[test]
+    fn python_sorted_imports_is_gpt() {
+        let mut lines: Vec<String> = vec![
+            "import abc".into(),
+            "import collections".into(),
+            "import sys".into(),
+        ];
+        lines.extend((0..10).map(|i| format!("x_{i} = {i}")));
+        let source = lines.join("\n");
+        let signals = CodeStructureAnalyzer::default().analyze_python(&source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Gpt),
+            "expected Gpt signal for sorted Python imports"
+        );
+    }
+
+    #[test]
+    fn javascript_short_lines_is_gemini() {
+        let source = make_lines(12, "const ");
+        let signals = CodeStructureAnalyzer::default().analyze_javascript(&source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Gemini),
+            "expected Gemini signal for short JS lines"
+        );
+    }
+
+    #[test]
+    fn go_short_lines_is_gemini() {
+        let source = make_lines(12, "var ");
+        let signals = CodeStructureAnalyzer::default().analyze_go(&source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Gemini),
+            "expected Gemini signal for short Go lines"
+        );
+    }
+
+    const VALIDATE: &str = "    let name = input.name.trim();\n    if name.is_empty() {\n        return Err(Error::EmptyName);\n    }\n    let email = input.email.to_lowercase();\n    record.update(name, email)?;";
+
+    #[test]
+    fn repeated_blocks_are_duplicated_blocks() {
+        let source = ["create", "update", "upsert"]
+            .iter()
+            .map(|f| format!("fn {f}(input: &Input, record: &mut Record) -> Result<()> {{\n{VALIDATE}\n    Ok(())\n}}\n"))
+            .collect::<String>();
+        let signals = run(&source);
+        let dup = signals
+            .iter()
+            .find(|s| s.id == signal_ids::RUST_STRUCTURE_DUPLICATED_BLOCKS)
+            .expect("three copies of the same body should be flagged");
+        assert!(dup.description.starts_with("2 duplicated blocks"), "{}", dup.description);
+    }
+
+    #[test]
+    fn braces_imports_and_single_copies_are_not_duplication() {
+        let mut source: String = (0..6).map(|i| format!("use crate::m{i};\n")).collect();
+        source.push_str("use std::io;\nuse std::fs;\nuse std::fmt;\nuse std::io;\nuse std::fs;\nuse std::fmt;\n");
+        for i in 0..8 {
+            source.push_str(&format!("fn f{i}() {{\n    step_{i}();\n}}\n}}\n"));
+        }
+        source.push_str(&format!("fn once() {{\n{VALIDATE}\n}}\n"));
+        let signals = run(&source);
+        assert!(!signals.iter().any(|s| s.id == signal_ids::RUST_STRUCTURE_DUPLICATED_BLOCKS));
+    }
+
+    #[test]
+    fn python_duplicated_blocks() {
+        let body = "    value = payload.get(\"value\")\n    if value is None:\n        raise ValueError(\"missing value\")\n    cleaned = value.strip()\n    return store.save(cleaned)\n";
+        let source: String = (0..3).map(|i| format!("def handler_{i}(payload, store):\n{body}\n")).collect();
+        let signals = CodeStructureAnalyzer::default().analyze_python(&source);
+        assert!(signals.iter().any(|s| s.id == signal_ids::PYTHON_STRUCTURE_DUPLICATED_BLOCKS));
+    }
+
+    #[test]
+    fn trailing_comma_counts_skip_blocks_and_comments() {
+        let src = "foo(\n    a,\n    b,\n)\nlet v = [\n    1,\n    2\n];\nif x {\n    y\n}\nbar(\n    c, // see http://x\n)\nbaz(\n)";
+        let lines: Vec<&str> = src.lines().collect();
+        assert_eq!(CodeStructureAnalyzer::count_trailing_commas(&lines), (1, 1));
+    }
+
+    #[test]
+    fn rust_consistent_trailing_commas_is_gpt() {
+        let source = (0..5)
+            .map(|i| format!("let v{i} = call(\n    first_{i},\n    second_{i},\n);"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let signals = run(&source);
+        assert!(signals.iter().any(|s| s.id == signal_ids::RUST_STRUCTURE_TRAILING_COMMAS));
+    }
+
+    #[test]
+    fn javascript_mixed_trailing_commas_no_signal() {
+        let source = (0..6)
+            .map(|i| {
+                let comma = if i % 2 == 0 { "," } else { "" };
+                format!("const v{i} = call(\n    first{i},\n    second{i}{comma}\n);")
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let signals = CodeStructureAnalyzer::default().analyze_javascript(&source);
+        assert!(!signals.iter().any(|s| s.id == signal_ids::JS_STRUCTURE_TRAILING_COMMAS));
+    }
+
+    #[test]
+    fn quote_counts_ignore_nested_and_comments() {
+        let src = "a = \"it's\"\nb = 'say \"hi\"'\n# don't 'count' \"this\"\nc = \"\"\"doc's\"\"\"\n";
+        assert_eq!(CodeStructureAnalyzer::count_quote_styles(src, true), (1, 1));
+        let js = "const a = \"it's\";\n// 'nope'\n/* \"nope\" */\nconst b = `x ${'y'}`;\n";
+        assert_eq!(CodeStructureAnalyzer::count_quote_styles(js, false), (0, 1));
+    }
+
+    #[test]
+    fn python_consistent_quotes_is_gpt() {
+        let source = (0..10).map(|i| format!("name_{i} = \"value {i}\"")).collect::<Vec<_>>().join("\n");
+        let signals = CodeStructureAnalyzer::default().analyze_python(&source);
+        assert!(signals.iter().any(|s| s.id == signal_ids::PYTHON_STRUCTURE_CONSISTENT_QUOTES));
+        assert!(!signals.iter().any(|s| s.id == signal_ids::PYTHON_STRUCTURE_MIXED_QUOTES));
+    }
+
+    #[test]
+    fn javascript_mixed_quotes_is_human() {
+        let source = (0..10)
+            .map(|i| if i % 2 == 0 { format!("const v{i} = 'a';") } else { format!("const v{i} = \"b\";") })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let signals = CodeStructureAnalyzer::default().analyze_javascript(&source);
+        assert!(signals.iter().any(|s| s.id == signal_ids::JS_STRUCTURE_MIXED_QUOTES
+            && s.family == ModelFamily::Human));
+    }
+
+    #[test]
+    fn pasted_crlf_block_is_mixed_line_endings() {
+        let mut source: String = (0..8).map(|i| format!("let a{i} = {i};\n")).collect();
+        source.push_str(&(0..4).map(|i| format!("let b{i} = {i};\r\n")).collect::<String>());
+        let signals = run(&source);
+        let s = signals
+            .iter()
+            .find(|s| s.id == signal_ids::RUST_STRUCTURE_MIXED_LINE_ENDINGS)
+            .expect("one CRLF block in an LF file should be flagged");
+        assert_eq!(s.family, ModelFamily::Copilot);
+    }
+
+    #[test]
+    fn alternating_endings_are_interleaved() {
+        let source: String = (0..12)
+            .map(|i| format!("x{i} = {i}{}", if i % 3 == 0 { "\r\n" } else { "\n" }))
+            .collect();
+        let signals = CodeStructureAnalyzer::default().analyze_python(&source);
+        let s = signals
+            .iter()
+            .find(|s| s.id == signal_ids::PYTHON_STRUCTURE_INTERLEAVED_LINE_ENDINGS)
+            .expect("scattered CRLF lines should be flagged as interleaved");
+        assert_eq!(s.family, ModelFamily::Human);
+    }
+
+    #[test]
+    fn operator_spacing_ignores_strings_comments_and_unary() {
+        let source = "if a == b && c != d { x += 1; } // a==b\n\
+                      let s = \"x==y\"; let r: &&str = &&s; f(|&&v| v);\n\
+                      fn g<'a>(x: &'a str) -> bool { x.len()>=2 }\n";
+        assert_eq!(CodeStructureAnalyzer::count_operator_spacing(source, false, true), (4, 1));
+        let py = "if a == b:  # c==d\n    s = 'x!=y' + \"\"\"e<=f\"\"\"\n    f(k=1)\n";
+        assert_eq!(CodeStructureAnalyzer::count_operator_spacing(py, true, false), (1, 0));
+    }
+
+    #[test]
+    fn uniform_operator_spacing_flagged_only_when_near_perfect() {
+        let uniform: String = (0..25).map(|i| format!("if a{i} == b && c >= {i} {{ n += 1; }}\n")).collect();
+        let signals = run(&uniform);
+        let s = signals
+            .iter()
+            .find(|s| s.id == signal_ids::RUST_STRUCTURE_UNIFORM_OPERATOR_SPACING)
+            .expect("75 spaced operators should be flagged");
+        assert_eq!(s.family, ModelFamily::Gpt);
+
+        let mixed: String = (0..25)
+            .map(|i| if i % 5 == 0 { format!("if a{i}==b {{ n+=1; }}\n") } else { format!("if a{i} == b {{ n += 1; }}\n") })
+            .collect();
+        assert!(!run(&mixed).iter().any(|s| s.id == signal_ids::RUST_STRUCTURE_UNIFORM_OPERATOR_SPACING));
+        assert!(!run("let x = a == b;\n").iter().any(|s| s.id == signal_ids::RUST_STRUCTURE_UNIFORM_OPERATOR_SPACING));
+    }
+
+    #[test]
+    fn import_sections_follow_blank_lines_and_continuations() {
+        let lines: Vec<&str> = "\"\"\"Doc.\"\"\"\nimport os\nimport sys\n\n# third-party\nimport requests\nfrom flask import (\n    Flask,\n\n    request,\n)\n\nfrom app import db\nx = 1\nimport late\n"
+            .lines()
+            .collect();
+        let is_import = |t: &str| t.starts_with("import ") || t.starts_with("from ");
+        assert_eq!(CodeStructureAnalyzer::import_sections(&lines, is_import), [2, 2, 1]);
+    }
+
+    #[test]
+    fn sectioned_imports_are_grouped_but_a_flat_sorted_block_is_not() {
+        let body: String = (0..10).map(|i| format!("x_{i} = {i}\n")).collect();
+        let has_grouped = |source: &str| {
+            CodeStructureAnalyzer::default()
+                .analyze_python(source)
+                .iter()
+                .any(|s| s.id == signal_ids::PYTHON_STRUCTURE_GROUPED_IMPORTS)
+        };
+        let flat = format!("import abc\nimport collections\nimport os\nimport sys\n\n{body}");
+        assert!(!has_grouped(&flat), "a single sorted block is not grouped");
+        let grouped = format!("import os\nimport sys\n\nimport numpy\n\nfrom app import db\n\n{body}");
+        assert!(has_grouped(&grouped));
+
+        let go = "package main\n\nimport (\n\t\"fmt\"\n\t\"os\"\n\n\t\"github.com/pkg/errors\"\n\tlog \"github.com/sirupsen/logrus\"\n)\n\nfunc main() {\n\tfmt.Println(os.Args)\n}\n";
+        assert!(CodeStructureAnalyzer::default()
+            .analyze_go(go)
+            .iter()
+            .any(|s| s.id == signal_ids::GO_STRUCTURE_GROUPED_IMPORTS));
+    }
+
+    #[test]
+    fn uniform_line_endings_are_not_flagged() {
+        let crlf: String = (0..12).map(|i| format!("let a{i} = {i};\r\n")).collect();
+        let lf = crlf.replace("\r\n", "\n");
+        assert!(CodeStructureAnalyzer::detect_mixed_line_endings(&crlf, "m", "i").is_none());
+        assert!(CodeStructureAnalyzer::detect_mixed_line_endings(&lf, "m", "i").is_none());
+    }
+}
@@ -1,129 +1,127 @@
+use super::hcl;
 use crate::analyzers::Analyzer;
 use crate::heuristics::signal_ids;
 use crate::report::{ModelFamily, Signal};
 
 pub struct CommentStyleAnalyzer;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::analyzers::Analyzer;
-    use crate::report::ModelFamily;
+/// Leading verbs of commit-message / changelog summaries, in imperative and
+/// past-tense forms ("Add support for X", "Fixed bug in Y").
+const COMMIT_VERBS: &[&str] = &[
+    "Add", "Added", "Adds", "Fix", "Fixed", "Fixes", "Refactor", "Refactored",
+    "Update", "Updated", "Remove", "Removed", "Implement", "Implemented",
+    "Introduce", "Introduced", "Rename", "Renamed", "Bump", "Bumped",
+    "Revert", "Reverted", "Migrate", "Migrated", "Improve", "Improved",
+];
+
+/// Connectives that mark a comment as explaining *why*, which a changelog
+/// entry never does ("Update the cache here because ...").
+const EXPLANATORY_CONNECTIVES: &[&str] = &[
+    " because ", " since ", " so that ", " otherwise ", " unless ", " when ", " if ", " so ",
+];
+
+/// Labels that mark a comment above imports as a group header ("Standard
+/// library", "Third-party") rather than an explanation of one import.
+const IMPORT_GROUP_LABELS: &[&str] = &[
+    "standard library", "stdlib", "third-party", "third party", "external", "internal",
+    "local", "dependencies", "imports",
+];
 
-    fn run(source: &str) -> Vec<Signal> {
-        CommentStyleAnalyzer.analyze(source)
-    }
-
-    #[test]
-    fn empty_source_no_signals() {
-        assert!(run("").is_empty());
-    }
-
-    #[test]
-    fn high_comment_density_is_claude() {
-        // 5 comment lines out of 25 total = 20% > 15%
-        let lines: Vec<&str> = std::iter::repeat("// a comment")
-            .take(5)
-            .chain(std::iter::repeat("let x = 1;").take(20))
-            .collect();
-        let source = lines.join("\n");
-        let signals = run(&source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Claude && s.weight == 1.5),
-            "expected high density Claude signal (weight 1.5)"
-        );
+impl CommentStyleAnalyzer {
+    /// Emit commit_style when 3+ line comments read like commit summaries:
+    /// a capitalized change verb ([`COMMIT_VERBS`]) up front, at most ten
+    /// words, and no explanatory connective.  Doc comments are skipped.
+    fn detect_commit_style(lines: &[&str], prefix: &str, commit_style_id: &str) -> Option<Signal> {
+        let count = lines
+            .iter()
+            .filter_map(|l| l.trim_start().strip_prefix(prefix))
+            .filter(|body| !body.starts_with(['/', '!']))
+            .map(str::trim)
+            .filter(|body| {
+                let words: Vec<&str> = body.split_whitespace().collect();
+                let padded = format!(" {} ", body.to_lowercase());
+                words.len() >= 2
+                    && words.len() <= 10
+                    && COMMIT_VERBS.contains(&words[0])
+                    && !body.ends_with('?')
+                    && !EXPLANATORY_CONNECTIVES.iter().any(|c| padded.contains(c))
+            })
+            .count();
+        (count >= 3).then(|| {
+            Signal::new(
+                commit_style_id,
+                "comments",
+                format!("{count} comments read like commit messages (\"Add …\", \"Fix …\")"),
+                ModelFamily::Claude,
+                1.2,
+            )
+        })
     }
 
-    #[test]
-    fn low_comment_density_is_human() {
-        // 25 lines, 0 comments → 0% < 3% and > 20 lines
-        let source = std::iter::repeat("let x = 1;").take(25).collect::<Vec<_>>().join("\n");
-        let signals = run(&source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Human && s.weight == 1.0),
-            "expected low density Human signal (weight 1.0)"
-        );
+    /// Emit import_comments when 3+ imports each sit directly under their
+    /// own explanatory comment, covering at least half of the file's
+    /// imports.  A comment counts only when exactly one import follows it and
+    /// it reads as prose (4+ words, no group label like "Third-party"), so
+    /// section headers over a block of imports don't.
+    fn detect_import_comments(
+        lines: &[&str],
+        prefix: &str,
+        is_import: fn(&[&str], usize) -> bool,
+        import_comments_id: &str,
+    ) -> Option<Signal> {
+        let imports = (0..lines.len()).filter(|&i| is_import(lines, i)).count();
+        let explained = (1..lines.len())
+            .filter(|&i| is_import(lines, i) && !(i + 1 < lines.len() && is_import(lines, i + 1)))
+            .filter_map(|i| lines[i - 1].trim_start().strip_prefix(prefix))
+            .filter(|body| !body.starts_with(['/', '!']))
+            .filter(|body| {
+                let lower = body.trim().to_lowercase();
+                lower.split_whitespace().count() >= 4 && !IMPORT_GROUP_LABELS.iter().any(|l| lower.contains(l))
+            })
+            .count();
+        (explained >= 3 && explained * 2 >= imports).then(|| {
+            Signal::new(
+                import_comments_id,
+                "comments",
+                format!("{explained} of {imports} imports each explained by their own comment"),
+                ModelFamily::Claude,
+                1.0,
+            )
+        })
     }
 
-    #[test]
-    fn teaching_voice_3_plus_is_claude() {
-        let source = "// note that this is correct\n// this ensures safety\n// this allows reuse\nlet x = 1;";
-        let signals = run(source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Claude && s.weight == 1.5),
-            "expected teaching voice Claude signal (weight 1.5)"
-        );
+    fn is_rust_import(lines: &[&str], i: usize) -> bool {
+        let t = lines[i].trim_start();
+        t.starts_with("use ") || t.starts_with("pub use ") || t.starts_with("extern crate ")
     }
 
-    #[test]
-    fn teaching_voice_1_is_gpt() {
-        let source = "// note that this works\nlet x = 1;\nlet y = 2;";
-        let signals = run(source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Gpt && s.weight == 0.8),
-            "expected single teaching phrase Gpt signal (weight 0.8)"
-        );
+    fn is_python_import(lines: &[&str], i: usize) -> bool {
+        let t = lines[i].trim_start();
+        t.starts_with("import ") || (t.starts_with("from ") && t.contains(" import "))
     }
 
-    #[test]
-    fn five_doc_comments_is_claude() {
-        let source = "/// doc one\n/// doc two\n/// doc three\n/// doc four\n/// doc five\nlet x = 1;";
-        let signals = run(source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Claude && s.weight == 1.5),
-            "expected doc comments Claude signal (weight 1.5)"
-        );
+    fn is_js_import(lines: &[&str], i: usize) -> bool {
+        let t = lines[i].trim_start();
+        t.starts_with("import ") || (t.contains("= require(") && !t.contains("=>"))
     }
 
-    #[test]
-    fn terse_markers_is_human() {
-        let source = "// TODO: fix this\n// HACK: workaround needed\nlet x = 1;";
-        let signals = run(source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Human && s.weight == 2.0),
-            "expected terse markers Human signal (weight 2.0)"
-        );
-    }
-
-    #[test]
-    fn python_high_comment_density_is_claude() {
-        // Many # comments relative to code lines
-        let mut lines: Vec<String> = (0..10).map(|_| "# This explains the approach".into()).collect();
-        lines.extend((0..5).map(|i| format!("x{i} = {i}")));
-        let source = lines.join("\n");
-        let signals = CommentStyleAnalyzer.analyze_python(&source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Claude),
-            "expected Claude signal for high Python comment density"
-        );
-    }
-
-    #[test]
-    fn javascript_high_comment_density_is_claude() {
-        let mut lines: Vec<String> = (0..10).map(|_| "// This explains the approach".into()).collect();
-        lines.extend((0..5).map(|i| format!("const x{i} = {i};")));
-        let source = lines.join("\n");
-        let signals = CommentStyleAnalyzer.analyze_javascript(&source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Claude),
-            "expected Claude signal for high JS comment density"
-        );
-    }
-
-    #[test]
-    fn go_high_comment_density_is_claude() {
-        let mut lines: Vec<String> = (0..10).map(|_| "// This explains the approach".into()).collect();
-        lines.extend((0..5).map(|i| format!("var x{i} int = {i}")));
-        let source = lines.join("\n");
-        let signals = CommentStyleAnalyzer.analyze_go(&source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Claude),
-            "expected Claude signal for high Go comment density"
-        );
+    /// `import "fmt"`, or a path line inside an `import ( … )` block.
+    fn is_go_import(lines: &[&str], i: usize) -> bool {
+        let t = lines[i].trim();
+        if !t.ends_with('"') || t.starts_with("//") {
+            return false;
+        }
+        if t.starts_with("import ") {
+            return true;
+        }
+        lines[..i]
+            .iter()
+            .rev()
+            .map(|l| l.trim())
+            .find(|l| l.starts_with("import (") || *l == ")")
+            .is_some_and(|l| l.starts_with("import ("))
     }
-}
 
-impl CommentStyleAnalyzer {
     /// New comment signals shared across languages.
     ///
     /// Takes precomputed comment lines (as lowercase strings) and raw lines.
@@ -472,6 +470,13 @@ impl CommentStyleAnalyzer {
             signal_ids::PYTHON_COMMENTS_EXTERNAL_REFS,
             signal_ids::PYTHON_COMMENTS_VERBOSE_OBVIOUS,
         ));
+        signals.extend(Self::detect_commit_style(&lines, "#", signal_ids::PYTHON_COMMENTS_COMMIT_STYLE));
+        signals.extend(Self::detect_import_comments(
+            &lines,
+            "#",
+            Self::is_python_import,
+            signal_ids::PYTHON_COMMENTS_IMPORT_COMMENTS,
+        ));
 
         signals
     }
@@ -493,6 +498,13 @@ impl CommentStyleAnalyzer {
             source,
         );
         let lines: Vec<&str> = source.lines().collect();
+        signals.extend(Self::detect_commit_style(&lines, "//", signal_ids::JS_COMMENTS_COMMIT_STYLE));
+        signals.extend(Self::detect_import_comments(
+            &lines,
+            "//",
+            Self::is_js_import,
+            signal_ids::JS_COMMENTS_IMPORT_COMMENTS,
+        ));
 
         // JSDoc blocks (/** ... */)
         let jsdoc_count = lines.iter().filter(|l| l.trim().starts_with("/**")).count();
@@ -511,7 +523,7 @@ impl CommentStyleAnalyzer {
 
     fn analyze_go_impl(source: &str) -> Vec<Signal> {
         // Go uses // for all comments, same as Rust — reuse slash comment logic
-        Self::analyze_slash_comments(
+        let mut signals = Self::analyze_slash_comments(
             "comments",
             signal_ids::GO_COMMENTS_HIGH_DENSITY,
             signal_ids::GO_COMMENTS_LOW_DENSITY,
@@ -525,7 +537,62 @@ impl CommentStyleAnalyzer {
             signal_ids::GO_COMMENTS_EXTERNAL_REFS,
             signal_ids::GO_COMMENTS_VERBOSE_OBVIOUS,
             source,
-        )
+        );
+        let lines: Vec<&str> = source.lines().collect();
+        signals.extend(Self::detect_commit_style(&lines, "//", signal_ids::GO_COMMENTS_COMMIT_STYLE));
+        signals.extend(Self::detect_import_comments(
+            &lines,
+            "//",
+            Self::is_go_import,
+            signal_ids::GO_COMMENTS_IMPORT_COMMENTS,
+        ));
+        signals
+    }
+
+    fn analyze_csharp_impl(source: &str) -> Vec<Signal> {
+        let mut signals = Vec::new();
+        let lines: Vec<&str> = source.lines().collect();
+        // XML doc comments: /// <summary>, <param>, <returns>
+        let summaries = lines
+            .iter()
+            .filter(|l| l.trim_start().starts_with("/// <summary>"))
+            .count();
+        if summaries >= 3 {
+            signals.push(Signal::new(
+                signal_ids::CSHARP_COMMENTS_XML_DOCS,
+                "comments",
+                format!("{summaries} XML <summary> doc comments — thorough API documentation"),
+                ModelFamily::Claude,
+                1.2,
+            ));
+        }
+
+        signals
+    }
+
+    fn analyze_hcl_impl(source: &str) -> Vec<Signal> {
+        let mut signals = Vec::new();
+        let lines: Vec<&str> = source.lines().collect();
+        let density = lines.iter().filter(|l| hcl::is_comment(l)).count() as f64 / lines.len() as f64;
+        if density > 0.15 {
+            signals.push(Signal::new(
+                signal_ids::HCL_COMMENTS_HIGH_DENSITY,
+                "comments",
+                format!("High comment density ({:.0}%)", density * 100.0),
+                ModelFamily::Claude,
+                1.2,
+            ));
+        } else if density < 0.03 && lines.len() > 20 {
+            signals.push(Signal::new(
+                signal_ids::HCL_COMMENTS_LOW_DENSITY,
+                "comments",
+                "Very low comment density",
+                ModelFamily::Human,
+                1.0,
+            ));
+        }
+
+        signals
     }
 }
 
@@ -537,6 +604,8 @@ impl Analyzer for CommentStyleAnalyzer {
     fn analyze_python(&self, source: &str) -> Vec<Signal> { Self::analyze_python_impl(source) }
     fn analyze_javascript(&self, source: &str) -> Vec<Signal> { Self::analyze_javascript_impl(source) }
     fn analyze_go(&self, source: &str) -> Vec<Signal> { Self::analyze_go_impl(source) }
+    fn analyze_csharp(&self, source: &str) -> Vec<Signal> { Self::analyze_csharp_impl(source) }
+    fn analyze_hcl(&self, source: &str) -> Vec<Signal> { Self::analyze_hcl_impl(source) }
 
     fn analyze(&self, source: &str) -> Vec<Signal> {
         let mut signals = Vec::new();
@@ -645,7 +714,237 @@ impl Analyzer for CommentStyleAnalyzer {
             signal_ids::RUST_COMMENTS_EXTERNAL_REFS,
             signal_ids::RUST_COMMENTS_VERBOSE_OBVIOUS,
         ));
+        signals.extend(Self::detect_commit_style(&lines, "//", signal_ids::RUST_COMMENTS_COMMIT_STYLE));
+        signals.extend(Self::detect_import_comments(
+            &lines,
+            "//",
+            Self::is_rust_import,
+            signal_ids::RUST_COMMENTS_IMPORT_COMMENTS,
+        ));
 
         signals
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzers::Analyzer;
+    use crate::report::ModelFamily;
+
+    fn run(source: &str) -> Vec<Signal> {
+        CommentStyleAnalyzer.analyze(source)
+    }
+
+    #[test]
+    fn empty_source_no_signals() {
+        assert!(run("").is_empty());
+    }
+
+    #[test]
+    fn high_comment_density_is_claude() {
+        // 5 comment lines out of 25 total = 20% > 15%
+        let lines: Vec<&str> = std::iter::repeat_n("// a comment", 5)
+            .chain(std::iter::repeat_n("let x = 1;", 20))
+            .collect();
+        let source = lines.join("\n");
+        let signals = run(&source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Claude && s.weight == 1.5),
+            "expected high density Claude signal (weight 1.5)"
+        );
+    }
+
+    #[test]
+    fn low_comment_density_is_human() {
+        // 25 lines, 0 comments → 0% < 3% and > 20 lines
+        let source = std::iter::repeat_n("let x = 1;", 25).collect::<Vec<_>>().join("\n");
+        let signals = run(&source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Human && s.weight == 1.0),
+            "expected low density Human signal (weight 1.0)"
+        );
+    }
+
+    #[test]
+    fn teaching_voice_3_plus_is_claude() {
+        let source = "// note that this is correct\n// this ensures safety\n// this allows reuse\nlet x = 1;";
+        let signals = run(source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Claude && s.weight == 1.5),
+            "expected teaching voice Claude signal (weight 1.5)"
+        );
+    }
+
+    #[test]
+    fn teaching_voice_1_is_gpt() {
+        let source = "// note that this works\nlet x = 1;\nlet y = 2;";
+        let signals = run(source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Gpt && s.weight == 0.8),
+            "expected single teaching phrase Gpt signal (weight 0.8)"
+        );
+    }
+
+    #[test]
+    fn five_doc_comments_is_claude() {
+        let source = "/// doc one\n/// doc two\n/// doc three\n/// doc four\n/// doc five\nlet x = 1;";
+        let signals = run(source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Claude && s.weight == 1.5),
+            "expected doc comments Claude signal (weight 1.5)"
+        );
+    }
+
+    #[test]
+    fn terse_markers_is_human() {
+        let source = "// TODO: fix this\n// HACK: workaround needed\nlet x = 1;";
+        let signals = run(source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Human && s.weight == 2.0),
+            "expected terse markers Human signal (weight 2.0)"
+        );
+    }
+
+    #[test]
+    fn python_high_comment_density_is_claude() {
+        // Many # comments relative to code lines
+        let mut lines: Vec<String> = (0..10).map(|_| "# This explains the approach".into()).collect();
+        lines.extend((0..5).map(|i| format!("x{i} = {i}")));
+        let source = lines.join("\n");
+        let signals = CommentStyleAnalyzer.analyze_python(&source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Claude),
+            "expected Claude signal for high Python comment density"
+        );
+    }
+
+    #[test]
+    fn javascript_high_comment_density_is_claude() {
+        let mut lines: Vec<String> = (0..10).map(|_| "// This explains the approach".into()).collect();
+        lines.extend((0..5).map(|i| format!("const x{i} = {i};")));
+        let source = lines.join("\n");
+        let signals = CommentStyleAnalyzer.analyze_javascript(&source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Claude),
+            "expected Claude signal for high JS comment density"
+        );
+    }
+
+    #[test]
+    fn go_high_comment_density_is_claude() {
+        let mut lines: Vec<String> = (0..10).map(|_| "// This explains the approach".into()).collect();
+        lines.extend((0..5).map(|i| format!("var x{i} int = {i}")));
+        let source = lines.join("\n");
+        let signals = CommentStyleAnalyzer.analyze_go(&source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Claude),
+            "expected Claude signal for high Go comment density"
+        );
+    }
+
+    #[test]
+    fn csharp_xml_doc_comments_are_claude() {
+        let mut lines: Vec<String> = Vec::new();
+        for i in 0..3 {
+            lines.push("/// <summary>Does a thing.</summary>".into());
+            lines.push(format!("public void Run{i}() {{ }}"));
+        }
+        lines.extend((0..5).map(|i| format!("var x{i} = {i};")));
+        let source = lines.join("\n");
+        let signals = CommentStyleAnalyzer.analyze_csharp(&source);
+        assert!(signals.iter().any(|s| s.id == signal_ids::CSHARP_COMMENTS_XML_DOCS));
+    }
+
+    fn commented(comments: &[&str], marker: &str) -> String {
+        comments
+            .iter()
+            .enumerate()
+            .map(|(i, c)| format!("{marker} {c}\nlet v{i} = compute({i});\n"))
+            .collect()
+    }
+
+    #[test]
+    fn commit_message_comments_are_commit_style() {
+        let source = commented(
+            &["Add support for nested keys", "Fixed bug in parser", "Refactor lookup to use index", "Compute totals"],
+            "//",
+        );
+        let signal = run(&source)
+            .into_iter()
+            .find(|s| s.id == signal_ids::RUST_COMMENTS_COMMIT_STYLE)
+            .expect("three changelog-style comments");
+        assert!(signal.description.starts_with("3 comments"));
+    }
+
+    #[test]
+    fn explanatory_and_doc_comments_are_not_commit_style() {
+        let source = commented(
+            &[
+                "Add one because ranges are exclusive",
+                "Update the cursor so that the next read starts fresh",
+                "Fix up the offset when the buffer wrapped",
+                "/ Add two numbers together",
+                "adding padding keeps alignment",
+                "Remove this once the upstream fix lands in the next release of the library",
+            ],
+            "//",
+        );
+        assert!(!run(&source).iter().any(|s| s.id == signal_ids::RUST_COMMENTS_COMMIT_STYLE));
+    }
+
+    #[test]
+    fn python_commit_style() {
+        let source = commented(&["Added retry logic", "Update timeout handling", "Remove legacy fallback"], "#");
+        let signals = CommentStyleAnalyzer.analyze_python(&source);
+        assert!(signals.iter().any(|s| s.id == signal_ids::PYTHON_COMMENTS_COMMIT_STYLE));
+    }
+
+    #[test]
+    fn per_import_explanations_are_import_comments() {
+        let source = "// Serde lets us derive the JSON encoding\nuse serde::Serialize;\n\
+// HashMap gives constant-time lookups by key\nuse std::collections::HashMap;\n\
+// Arc allows sharing the cache across threads\nuse std::sync::Arc;\n\nfn main() {}\n";
+        let signal = run(source)
+            .into_iter()
+            .find(|s| s.id == signal_ids::RUST_COMMENTS_IMPORT_COMMENTS)
+            .expect("three explained imports");
+        assert!(signal.description.starts_with("3 of 3 imports"));
+
+        let python = "# Used to build paths relative to the config\nimport os\n\
+# Needed for parsing the command-line flags\nimport argparse\n\
+# Provides the JSON encoder for the output\nfrom json import dumps\n";
+        let signals = CommentStyleAnalyzer.analyze_python(python);
+        assert!(signals.iter().any(|s| s.id == signal_ids::PYTHON_COMMENTS_IMPORT_COMMENTS));
+
+        let go = "import (\n\t// Formatting for the status line output\n\t\"fmt\"\n\n\
+\t// Reads the config from the environment here\n\t\"os\"\n\n\
+\t// Sorting keeps the report output stable\n\t\"sort\"\n)\n";
+        let signals = CommentStyleAnalyzer.analyze_go(go);
+        assert!(signals.iter().any(|s| s.id == signal_ids::GO_COMMENTS_IMPORT_COMMENTS));
+    }
+
+    #[test]
+    fn import_section_headers_are_not_import_comments() {
+        let headers = "// Standard library imports\nuse std::fs;\nuse std::io;\n\n\
+// Third-party crates we depend on\nuse serde::Serialize;\n\n\
+// Local modules from this crate\nuse crate::cache::Cache;\n\n\
+// Everything the parser needs goes here\nuse crate::parser::{Parser, Token};\n";
+        assert!(!run(headers).iter().any(|s| s.id == signal_ids::RUST_COMMENTS_IMPORT_COMMENTS));
+
+        let js = "// std\nimport fs from \"fs\";\n// db\nimport pg from \"pg\";\n// ui\nimport React from \"react\";\n";
+        let signals = CommentStyleAnalyzer.analyze_javascript(js);
+        assert!(!signals.iter().any(|s| s.id == signal_ids::JS_COMMENTS_IMPORT_COMMENTS));
+    }
+
+    #[test]
+    fn hcl_comment_density() {
+        let commented = "# Bucket for access logs\nresource \"aws_s3_bucket\" \"logs\" {\n  bucket = \"logs\"\n}\n".repeat(3);
+        let signals = CommentStyleAnalyzer.analyze_hcl(&commented);
+        assert!(signals.iter().any(|s| s.id == signal_ids::HCL_COMMENTS_HIGH_DENSITY));
+
+        let bare = "locals {\n  region = \"us-east-1\"\n}\n".repeat(8);
+        let signals = CommentStyleAnalyzer.analyze_hcl(&bare);
+        assert!(signals.iter().any(|s| s.id == signal_ids::HCL_COMMENTS_LOW_DENSITY));
+    }
+}
@@ -0,0 +1,180 @@
+//! Language-agnostic identifier entropy for the text layer.
+//!
+//! Mirrors the CST analyzers' `identifier_entropy` metric (Shannon entropy of
+//! the characters across all identifier occurrences) using a plain tokenizer,
+//! so entropy-based signals still fire when a tree-sitter grammar fails to
+//! load or parse.
+
+use std::collections::HashMap;
+
+use crate::report::{ModelFamily, Signal};
+
+/// Same cut-offs as the `*_cst.entropy.high` / `.low` heuristics.
+const HIGH_ENTROPY: f64 = 4.0;
+const LOW_ENTROPY: f64 = 3.0;
+
+/// Minimum identifier occurrences before entropy means anything (as in CST).
+const MIN_IDENTIFIERS: usize = 10;
+
+pub(crate) const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+    "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+    "mut", "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true",
+    "type", "unsafe", "use", "where", "while",
+];
+
+pub(crate) const PYTHON_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class",
+    "continue", "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if",
+    "import", "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "self",
+    "try", "while", "with", "yield",
+];
+
+pub(crate) const JS_KEYWORDS: &[&str] = &[
+    "async", "await", "break", "case", "catch", "class", "const", "continue", "default",
+    "delete", "do", "else", "export", "extends", "false", "finally", "for", "from", "function",
+    "if", "import", "in", "instanceof", "interface", "let", "new", "null", "of", "return",
+    "static", "super", "switch", "this", "throw", "true", "try", "type", "typeof", "undefined",
+    "var", "void", "while", "yield",
+];
+
+pub(crate) const GO_KEYWORDS: &[&str] = &[
+    "break", "case", "chan", "const", "continue", "default", "defer", "else", "fallthrough",
+    "false", "for", "func", "go", "goto", "if", "import", "interface", "map", "nil", "package",
+    "range", "return", "select", "struct", "switch", "true", "type", "var",
+];
+
+/// Identifier occurrences in `source`, skipping `comment`-prefixed line
+/// tails, double-quoted strings, numbers, and `keywords`.
+pub(crate) fn identifiers<'s>(source: &'s str, comment: &str, keywords: &[&str]) -> Vec<&'s str> {
+    let mut result = Vec::new();
+    for line in source.lines() {
+        let code = line.find(comment).map_or(line, |i| &line[..i]);
+        let mut in_string = false;
+        let mut escaped = false;
+        let mut start = None;
+        for (i, c) in code.char_indices().chain(std::iter::once((code.len(), ' '))) {
+            if in_string {
+                match c {
+                    _ if escaped => escaped = false,
+                    '\\' => escaped = true,
+                    '"' => in_string = false,
+                    _ => {}
+                }
+                continue;
+            }
+            if c.is_alphanumeric() || c == '_' {
+                start.get_or_insert(i);
+                continue;
+            }
+            if let Some(s) = start.take() {
+                let token = &code[s..i];
+                if !token.starts_with(|c: char| c.is_ascii_digit()) && !keywords.contains(&token) {
+                    result.push(token);
+                }
+            }
+            in_string = c == '"';
+        }
+    }
+    result
+}
+
+/// Shannon entropy (bits) of the characters across all `identifiers`.
+pub(crate) fn shannon_entropy(identifiers: &[&str]) -> f64 {
+    let mut freq: HashMap<char, usize> = HashMap::new();
+    let mut total = 0usize;
+    for c in identifiers.iter().flat_map(|id| id.chars()) {
+        *freq.entry(c).or_insert(0) += 1;
+        total += 1;
+    }
+    if total == 0 {
+        return 0.0;
+    }
+    -freq
+        .values()
+        .map(|&count| {
+            let p = count as f64 / total as f64;
+            p * p.log2()
+        })
+        .sum::<f64>()
+}
+
+/// `high_id` (Claude) for entropy ≥ 4.0 or `low_id` (Human) below 3.0, once
+/// there are at least ten identifier occurrences.
+///
+/// Weighted below the CST variants, which fire for the same files whenever
+/// the grammar loads.
+pub(crate) fn entropy_signal(
+    source: &str,
+    comment: &str,
+    keywords: &[&str],
+    high_id: &str,
+    low_id: &str,
+) -> Option<Signal> {
+    let ids = identifiers(source, comment, keywords);
+    if ids.len() < MIN_IDENTIFIERS {
+        return None;
+    }
+    let entropy = shannon_entropy(&ids);
+    if entropy >= HIGH_ENTROPY {
+        Some(Signal::new(
+            high_id,
+            "naming",
+            format!("High identifier entropy ({entropy:.2}) — diverse, descriptive names"),
+            ModelFamily::Claude,
+            0.5,
+        ))
+    } else if entropy < LOW_ENTROPY {
+        Some(Signal::new(
+            low_id,
+            "naming",
+            format!("Low identifier entropy ({entropy:.2}) — repetitive or terse names"),
+            ModelFamily::Human,
+            0.6,
+        ))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identifiers_skip_keywords_strings_comments_and_numbers() {
+        let source = "let total = count + 42; // running sum\nprintln!(\"{total} items\", total);";
+        assert_eq!(
+            identifiers(source, "//", RUST_KEYWORDS),
+            ["total", "count", "println", "total"]
+        );
+    }
+
+    #[test]
+    fn entropy_of_repeated_char_is_zero() {
+        assert_eq!(shannon_entropy(&["aaa", "a"]), 0.0);
+        assert_eq!(shannon_entropy(&[]), 0.0);
+    }
+
+    #[test]
+    fn descriptive_identifiers_are_high_entropy() {
+        let source = "let connection_pool = build_pool(max_connections);\n\
+                      let request_handler = RequestHandler::new(connection_pool);\n\
+                      let response_writer = wrap_output(request_handler, buffer_size);\n\
+                      validate_configuration(timeout_millis, retry_backoff);";
+        let signal = entropy_signal(source, "//", RUST_KEYWORDS, "high", "low").unwrap();
+        assert_eq!(signal.id, "high");
+    }
+
+    #[test]
+    fn terse_identifiers_are_low_entropy() {
+        let source = "a = b + a\nb = a + b\na = a * b\nb = b - a\na = b\nb = a";
+        let signal = entropy_signal(source, "#", PYTHON_KEYWORDS, "high", "low").unwrap();
+        assert_eq!(signal.id, "low");
+    }
+
+    #[test]
+    fn too_few_identifiers_no_signal() {
+        assert!(entropy_signal("x = y", "#", PYTHON_KEYWORDS, "high", "low").is_none());
+    }
+}
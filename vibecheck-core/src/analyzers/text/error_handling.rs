@@ -1,234 +1,35 @@
-use crate::analyzers::Analyzer;
+use crate::analyzers::{Analyzer, DEFAULT_MIN_LINES};
 use crate::heuristics::signal_ids;
 use crate::report::{ModelFamily, Signal};
 
 pub struct ErrorHandlingAnalyzer;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::analyzers::Analyzer;
-    use crate::report::ModelFamily;
-
-    fn run(source: &str) -> Vec<Signal> {
-        ErrorHandlingAnalyzer.analyze(source)
-    }
-
-    fn pad(base: &str, total: usize) -> String {
-        let mut lines: Vec<String> = base.lines().map(|l| l.to_string()).collect();
-        while lines.len() < total {
-            lines.push("let padding = 0;".to_string());
-        }
-        lines.join("\n")
-    }
-
-    #[test]
-    fn short_source_no_signals() {
-        let source = (0..5).map(|i| format!("let x{i} = {i};")).collect::<Vec<_>>().join("\n");
-        assert!(run(&source).is_empty());
-    }
-
-    #[test]
-    fn zero_unwrap_in_large_file_is_claude() {
-        let source = pad("fn process() -> Result<(), String> { Ok(()) }", 35);
-        let signals = run(&source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Claude && s.weight == 1.5),
-            "expected zero-unwrap Claude signal (weight 1.5)"
-        );
-    }
-
-    #[test]
-    fn five_unwraps_is_human() {
-        let lines: Vec<String> = (0..5)
-            .map(|_| "let v = opt.unwrap();".to_string())
-            .chain((0..30).map(|i| format!("let x{i} = {i};")))
-            .collect();
-        let source = lines.join("\n");
-        let signals = run(&source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Human && s.weight == 1.5),
-            "expected 5+ unwraps Human signal (weight 1.5)"
-        );
-    }
-
-    #[test]
-    fn one_to_three_unwraps_is_copilot() {
-        let lines: Vec<String> = vec![
-            "let v = opt.unwrap();".to_string(),
-            "let w = other.unwrap();".to_string(),
-        ]
-        .into_iter()
-        .chain((0..10).map(|i| format!("let x{i} = {i};")))
-        .collect();
-        let source = lines.join("\n");
-        let signals = run(&source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Copilot && s.weight == 0.5),
-            "expected 1-3 unwraps Copilot signal (weight 0.5)"
-        );
-    }
-
-    #[test]
-    fn two_expect_calls_is_claude() {
-        let lines: Vec<String> = vec![
-            r#"let v = file.expect("file missing");"#.to_string(),
-            r#"let w = conn.expect("conn failed");"#.to_string(),
-        ]
-        .into_iter()
-        .chain((0..10).map(|i| format!("let x{i} = {i};")))
-        .collect();
-        let source = lines.join("\n");
-        let signals = run(&source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Claude && s.weight == 1.0
-                && s.description.contains("expect")),
-            "expected .expect() Claude signal (weight 1.0)"
-        );
-    }
-
-    #[test]
-    fn three_question_marks_is_claude() {
-        let lines: Vec<String> = vec![
-            "let a = foo()?;".to_string(),
-            "let b = bar()?;".to_string(),
-            "let c = baz()?;".to_string(),
-        ]
-        .into_iter()
-        .chain((0..10).map(|i| format!("let x{i} = {i};")))
-        .collect();
-        let source = lines.join("\n");
-        let signals = run(&source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Claude && s.weight == 1.0
-                && s.description.contains("?")),
-            "expected ? operator Claude signal (weight 1.0)"
-        );
-    }
-
-    #[test]
-    fn rust_unwrap_signal_not_emitted_for_python_file() {
-        // A Python file with `.unwrap()` text (unlikely but possible) should not
-        // trigger Rust-specific error-handling signals.
-        use crate::language::Language;
-        let python_source = (0..35)
-            .map(|i| format!("result_{i} = compute_{i}()  # no .unwrap() here"))
-            .collect::<Vec<_>>()
-            .join("\n");
-        let signals = ErrorHandlingAnalyzer.analyze_with_language(&python_source, Some(Language::Python));
-        assert!(
-            !signals.iter().any(|s| s.description.contains("unwrap")),
-            "Rust .unwrap() signal fired on a Python file"
-        );
-    }
-
-    #[test]
-    fn python_broad_except_is_human() {
-        use crate::language::Language;
-        let source = vec![
-            "try:",
-            "    do_thing()",
-            "except Exception:",
-            "    pass",
-            "try:",
-            "    do_other()",
-            "except Exception:",
-            "    pass",
-        ]
-        .into_iter()
-        .chain((0..5).map(|_| "x = 1"))
-        .collect::<Vec<_>>()
-        .join("\n");
-        let signals = ErrorHandlingAnalyzer.analyze_with_language(&source, Some(Language::Python));
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Human && s.description.contains("broad")),
-            "expected broad except Human signal"
-        );
-    }
-
-    #[test]
-    fn go_fmt_errorf_wrap_is_claude() {
-        use crate::language::Language;
-        let source = (0..12)
-            .map(|i| {
-                if i < 2 {
-                    format!("return fmt.Errorf(\"step {i}: %w\", err)")
-                } else {
-                    format!("x := step{i}()")
-                }
-            })
-            .collect::<Vec<_>>()
-            .join("\n");
-        let signals = ErrorHandlingAnalyzer.analyze_with_language(&source, Some(Language::Go));
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Claude && s.description.contains("Errorf")),
-            "expected fmt.Errorf Claude signal"
-        );
-    }
-
-    #[test]
-    fn two_panics_is_human() {
-        let lines: Vec<String> = vec![
-            r#"panic!("something went wrong");"#.to_string(),
-            r#"panic!("unreachable state");"#.to_string(),
-        ]
-        .into_iter()
-        .chain((0..10).map(|i| format!("let x{i} = {i};")))
-        .collect();
-        let source = lines.join("\n");
-        let signals = run(&source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Human && s.weight == 1.5
-                && s.description.contains("panic")),
-            "expected panic!() Human signal (weight 1.5)"
-        );
-    }
-
-    #[test]
-    fn javascript_try_catch_is_human() {
-        let source: Vec<String> = vec![
-            "try { doThing(); } catch (e) { console.error(e); }".into(),
-            "try { doOther(); } catch (e) { console.error(e); }".into(),
-        ].into_iter()
-        .chain((0..10).map(|i| format!("const x{i} = {i};")))
-        .collect();
-        let source = source.join("\n");
-        let signals = ErrorHandlingAnalyzer.analyze_javascript(&source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Human),
-            "expected Human signal for JS try/catch"
-        );
-    }
-
-    #[test]
-    fn go_error_returns_is_claude() {
-        let source: Vec<String> = (0..10)
-            .map(|i| {
-                if i < 3 {
-                    format!("if err != nil {{ return fmt.Errorf(\"step {i}: %w\", err) }}")
-                } else {
-                    format!("x{i} := step{i}()")
-                }
-            })
-            .collect();
-        let source = source.join("\n");
-        let signals = ErrorHandlingAnalyzer.analyze_go(&source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Claude),
-            "expected Claude signal for Go error wrapping"
-        );
-    }
-}
+/// Openings characteristic of AI-written error messages, matched
+/// case-insensitively against the start of the message.
+const AI_ERROR_OPENERS: &[&str] = &[
+    "failed to ",
+    "unable to ",
+    "an error occurred",
+    "an unexpected error",
+    "error occurred while",
+    "something went wrong",
+];
+
+/// Error-context call sites per language: only the first string literal
+/// after one of these on a line is treated as an error message.
+const RUST_ERROR_MARKERS: &[&str] = &[
+    "Err(", "panic!(", "anyhow!(", "bail!(", "format_err!(", ".expect(", ".context(",
+    ".with_context(", "Error::new(",
+];
+const PYTHON_ERROR_MARKERS: &[&str] = &["raise "];
+const JS_ERROR_MARKERS: &[&str] = &["throw ", "Error(", "reject("];
+const GO_ERROR_MARKERS: &[&str] = &["errors.New(", "fmt.Errorf(", "panic("];
 
 impl ErrorHandlingAnalyzer {
     fn analyze_python_impl(source: &str) -> Vec<Signal> {
         let mut signals = Vec::new();
         let lines: Vec<&str> = source.lines().collect();
         let total_lines = lines.len();
-        if total_lines < 10 {
-            return signals;
-        }
-
         // Bare or overly broad except clause — human shortcut
         let broad_except = lines
             .iter()
@@ -294,17 +95,21 @@ impl ErrorHandlingAnalyzer {
             ));
         }
 
+        signals.extend(ai_phrasing_signal(
+            &lines,
+            PYTHON_ERROR_MARKERS,
+            &['"', '\''],
+            signal_ids::PYTHON_ERRORS_AI_PHRASING,
+            ModelFamily::Gpt,
+            1.2,
+        ));
+
         signals
     }
 
     fn analyze_javascript_impl(source: &str) -> Vec<Signal> {
         let mut signals = Vec::new();
         let lines: Vec<&str> = source.lines().collect();
-        let total_lines = lines.len();
-        if total_lines < 10 {
-            return signals;
-        }
-
         // console.error / console.warn left in code — human debugging artifact
         let console_err = lines
             .iter()
@@ -383,17 +188,21 @@ impl ErrorHandlingAnalyzer {
             ));
         }
 
+        signals.extend(ai_phrasing_signal(
+            &lines,
+            JS_ERROR_MARKERS,
+            &['"', '\'', '`'],
+            signal_ids::JS_ERRORS_AI_PHRASING,
+            ModelFamily::Gpt,
+            1.2,
+        ));
+
         signals
     }
 
     fn analyze_go_impl(source: &str) -> Vec<Signal> {
         let mut signals = Vec::new();
         let lines: Vec<&str> = source.lines().collect();
-        let total_lines = lines.len();
-        if total_lines < 10 {
-            return signals;
-        }
-
         // Simple if err != nil { return err } — idiomatic but not AI-specific
         let simple_err_return = lines
             .iter()
@@ -457,15 +266,96 @@ impl ErrorHandlingAnalyzer {
             ));
         }
 
+        signals.extend(ai_phrasing_signal(
+            &lines,
+            GO_ERROR_MARKERS,
+            &['"', '`'],
+            signal_ids::GO_ERRORS_AI_PHRASING,
+            ModelFamily::Claude,
+            1.0,
+        ));
+
         signals
     }
 }
 
+/// Emit `id` when 3+ error messages — and at least half of all error
+/// messages — open with an [`AI_ERROR_OPENERS`] template.
+///
+/// Only the first string literal after an error-context `marker` on each
+/// non-comment line is inspected, so log lines and ordinary strings that
+/// happen to say "Failed to…" are never counted.
+fn ai_phrasing_signal(
+    lines: &[&str],
+    markers: &[&str],
+    quotes: &[char],
+    id: &str,
+    family: ModelFamily,
+    weight: f64,
+) -> Option<Signal> {
+    let messages: Vec<String> = lines
+        .iter()
+        .filter(|l| {
+            let t = l.trim_start();
+            !t.starts_with("//") && !t.starts_with('#')
+        })
+        .filter_map(|l| {
+            let start = markers.iter().filter_map(|m| l.find(m).map(|i| i + m.len())).min()?;
+            first_string_literal(&l[start..], quotes)
+        })
+        .collect();
+    let ai_like = messages
+        .iter()
+        .filter(|m| {
+            let m = m.to_lowercase();
+            AI_ERROR_OPENERS.iter().any(|o| m.starts_with(o))
+        })
+        .count();
+    if ai_like < 3 || ai_like * 2 < messages.len() {
+        return None;
+    }
+    Some(Signal::new(
+        id,
+        "errors",
+        format!(
+            "{ai_like}/{} error messages use AI-style phrasing (\"Failed to…\", \"Unable to…\")",
+            messages.len()
+        ),
+        family,
+        weight,
+    ))
+}
+
+/// Contents of the first string literal in `text` delimited by one of
+/// `quotes`, honouring backslash escapes.  `None` if there is none or it is
+/// unterminated on this line.
+fn first_string_literal(text: &str, quotes: &[char]) -> Option<String> {
+    let (open, quote) = text.char_indices().find(|(_, c)| quotes.contains(c))?;
+    let mut out = String::new();
+    let mut escaped = false;
+    for c in text[open + quote.len_utf8()..].chars() {
+        match c {
+            _ if escaped => {
+                out.push(c);
+                escaped = false;
+            }
+            '\\' => escaped = true,
+            _ if c == quote => return Some(out),
+            _ => out.push(c),
+        }
+    }
+    None
+}
+
 impl Analyzer for ErrorHandlingAnalyzer {
     fn name(&self) -> &str {
         "errors"
     }
 
+    fn min_lines(&self) -> usize {
+        DEFAULT_MIN_LINES
+    }
+
     fn analyze_python(&self, source: &str) -> Vec<Signal> { Self::analyze_python_impl(source) }
     fn analyze_javascript(&self, source: &str) -> Vec<Signal> { Self::analyze_javascript_impl(source) }
     fn analyze_go(&self, source: &str) -> Vec<Signal> { Self::analyze_go_impl(source) }
@@ -474,10 +364,6 @@ impl Analyzer for ErrorHandlingAnalyzer {
         let mut signals = Vec::new();
         let lines: Vec<&str> = source.lines().collect();
         let total_lines = lines.len();
-        if total_lines < 10 {
-            return signals;
-        }
-
         // Count .unwrap() calls
         let unwrap_count = lines
             .iter()
@@ -572,6 +458,300 @@ impl Analyzer for ErrorHandlingAnalyzer {
             ));
         }
 
+        signals.extend(ai_phrasing_signal(
+            &lines,
+            RUST_ERROR_MARKERS,
+            &['"'],
+            signal_ids::RUST_ERRORS_AI_PHRASING,
+            ModelFamily::Claude,
+            1.2,
+        ));
+
         signals
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzers::Analyzer;
+    use crate::heuristics::DefaultHeuristics;
+    use crate::pipeline::Pipeline;
+    use crate::report::ModelFamily;
+
+    fn run(source: &str) -> Vec<Signal> {
+        ErrorHandlingAnalyzer.analyze(source)
+    }
+
+    fn pad(base: &str, total: usize) -> String {
+        let mut lines: Vec<String> = base.lines().map(|l| l.to_string()).collect();
+        while lines.len() < total {
+            lines.push("let padding = 0;".to_string());
+        }
+        lines.join("\n")
+    }
+
+    #[test]
+    fn short_source_no_signals() {
+        let source = (0..5).map(|i| format!("let x{i} = {i};")).collect::<Vec<_>>().join("\n");
+        let pipeline = Pipeline::with_heuristics(
+            vec![Box::new(ErrorHandlingAnalyzer)],
+            vec![],
+            Box::new(DefaultHeuristics),
+        );
+        assert!(pipeline.run(&source, None).signals.is_empty());
+    }
+
+    #[test]
+    fn zero_unwrap_in_large_file_is_claude() {
+        let source = pad("fn process() -> Result<(), String> { Ok(()) }", 35);
+        let signals = run(&source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Claude && s.weight == 1.5),
+            "expected zero-unwrap Claude signal (weight 1.5)"
+        );
+    }
+
+    #[test]
+    fn five_unwraps_is_human() {
+        let lines: Vec<String> = (0..5)
+            .map(|_| "let v = opt.unwrap();".to_string())
+            .chain((0..30).map(|i| format!("let x{i} = {i};")))
+            .collect();
+        let source = lines.join("\n");
+        let signals = run(&source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Human && s.weight == 1.5),
+            "expected 5+ unwraps Human signal (weight 1.5)"
+        );
+    }
+
+    #[test]
+    fn one_to_three_unwraps_is_copilot() {
+        let lines: Vec<String> = vec![
+            "let v = opt.unwrap();".to_string(),
+            "let w = other.unwrap();".to_string(),
+        ]
+        .into_iter()
+        .chain((0..10).map(|i| format!("let x{i} = {i};")))
+        .collect();
+        let source = lines.join("\n");
+        let signals = run(&source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Copilot && s.weight == 0.5),
+            "expected 1-3 unwraps Copilot signal (weight 0.5)"
+        );
+    }
+
+    #[test]
+    fn two_expect_calls_is_claude() {
+        let lines: Vec<String> = vec![
+            r#"let v = file.expect("file missing");"#.to_string(),
+            r#"let w = conn.expect("conn failed");"#.to_string(),
+        ]
+        .into_iter()
+        .chain((0..10).map(|i| format!("let x{i} = {i};")))
+        .collect();
+        let source = lines.join("\n");
+        let signals = run(&source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Claude && s.weight == 1.0
+                && s.description.contains("expect")),
+            "expected .expect() Claude signal (weight 1.0)"
+        );
+    }
+
+    #[test]
+    fn three_question_marks_is_claude() {
+        let lines: Vec<String> = vec![
+            "let a = foo()?;".to_string(),
+            "let b = bar()?;".to_string(),
+            "let c = baz()?;".to_string(),
+        ]
+        .into_iter()
+        .chain((0..10).map(|i| format!("let x{i} = {i};")))
+        .collect();
+        let source = lines.join("\n");
+        let signals = run(&source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Claude && s.weight == 1.0
+                && s.description.contains("?")),
+            "expected ? operator Claude signal (weight 1.0)"
+        );
+    }
+
+    #[test]
+    fn rust_unwrap_signal_not_emitted_for_python_file() {
+        // A Python file with `.unwrap()` text (unlikely but possible) should not
+        // trigger Rust-specific error-handling signals.
+        use crate::language::Language;
+        let python_source = (0..35)
+            .map(|i| format!("result_{i} = compute_{i}()  # no .unwrap() here"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let signals = ErrorHandlingAnalyzer.analyze_with_language(&python_source, Some(Language::Python));
+        assert!(
+            !signals.iter().any(|s| s.description.contains("unwrap")),
+            "Rust .unwrap() signal fired on a Python file"
+        );
+    }
+
+    #[test]
+    fn python_broad_except_is_human() {
+        use crate::language::Language;
+        let source = vec![
+            "try:",
+            "    do_thing()",
+            "except Exception:",
+            "    pass",
+            "try:",
+            "    do_other()",
+            "except Exception:",
+            "    pass",
+        ]
+        .into_iter()
+        .chain((0..5).map(|_| "x = 1"))
+        .collect::<Vec<_>>()
+        .join("\n");
+        let signals = ErrorHandlingAnalyzer.analyze_with_language(&source, Some(Language::Python));
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Human && s.description.contains("broad")),
+            "expected broad except Human signal"
+        );
+    }
+
+    #[test]
+    fn go_fmt_errorf_wrap_is_claude() {
+        use crate::language::Language;
+        let source = (0..12)
+            .map(|i| {
+                if i < 2 {
+                    format!("return fmt.Errorf(\"step {i}: %w\", err)")
+                } else {
+                    format!("x := step{i}()")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let signals = ErrorHandlingAnalyzer.analyze_with_language(&source, Some(Language::Go));
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Claude && s.description.contains("Errorf")),
+            "expected fmt.Errorf Claude signal"
+        );
+    }
+
+    #[test]
+    fn two_panics_is_human() {
+        let lines: Vec<String> = vec![
+            r#"panic!("something went wrong");"#.to_string(),
+            r#"panic!("unreachable state");"#.to_string(),
+        ]
+        .into_iter()
+        .chain((0..10).map(|i| format!("let x{i} = {i};")))
+        .collect();
+        let source = lines.join("\n");
+        let signals = run(&source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Human && s.weight == 1.5
+                && s.description.contains("panic")),
+            "expected panic!() Human signal (weight 1.5)"
+        );
+    }
+
+    #[test]
+    fn javascript_try_catch_is_human() {
+        let source: Vec<String> = vec![
+            "try { doThing(); } catch (e) { console.error(e); }".into(),
+            "try { doOther(); } catch (e) { console.error(e); }".into(),
+        ].into_iter()
+        .chain((0..10).map(|i| format!("const x{i} = {i};")))
+        .collect();
+        let source = source.join("\n");
+        let signals = ErrorHandlingAnalyzer.analyze_javascript(&source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Human),
+            "expected Human signal for JS try/catch"
+        );
+    }
+
+    #[test]
+    fn rust_ai_error_phrasing_is_claude() {
+        let source = pad(
+            "fn load() -> anyhow::Result<()> {\n\
+             let cfg = read().context(\"Failed to read configuration file\")?;\n\
+             let db = open(&cfg).map_err(|e| anyhow!(\"Unable to open database: {e}\"))?;\n\
+             if db.is_empty() { bail!(\"An error occurred while loading records\"); }\n\
+             Ok(())\n}",
+            12,
+        );
+        let signals = run(&source);
+        assert!(signals.iter().any(|s| s.id == signal_ids::RUST_ERRORS_AI_PHRASING));
+    }
+
+    #[test]
+    fn ai_phrasing_ignores_strings_outside_error_context() {
+        let source = pad(
+            "fn report() {\n\
+             println!(\"Failed to connect, retrying\");\n\
+             log::warn!(\"Unable to reach mirror\");\n\
+             let msg = \"An error occurred while syncing\";\n\
+             }",
+            12,
+        );
+        let signals = run(&source);
+        assert!(!signals.iter().any(|s| s.id == signal_ids::RUST_ERRORS_AI_PHRASING));
+    }
+
+    #[test]
+    fn terse_error_messages_do_not_trigger_ai_phrasing() {
+        let source = pad(
+            "raise ValueError(\"bad port\")\n\
+             raise KeyError(f\"no such user {name}\")\n\
+             raise RuntimeError(\"Failed to bind socket\")\n\
+             raise OSError(\"disk full\")",
+            12,
+        );
+        let signals = ErrorHandlingAnalyzer.analyze_python(&source);
+        assert!(!signals.iter().any(|s| s.id == signal_ids::PYTHON_ERRORS_AI_PHRASING));
+    }
+
+    #[test]
+    fn js_and_go_ai_error_phrasing() {
+        let js = pad(
+            "throw new Error(`Failed to fetch user ${id}`);\n\
+             throw new TypeError('Unable to parse response body');\n\
+             return Promise.reject(new Error(\"An unexpected error occurred\"));",
+            12,
+        );
+        let signals = ErrorHandlingAnalyzer.analyze_javascript(&js);
+        assert!(signals.iter().any(|s| s.id == signal_ids::JS_ERRORS_AI_PHRASING));
+
+        let go = pad(
+            "return fmt.Errorf(\"failed to open %s: %w\", path, err)\n\
+             return errors.New(\"unable to allocate buffer\")\n\
+             return fmt.Errorf(\"failed to decode header: %w\", err)",
+            12,
+        );
+        let signals = ErrorHandlingAnalyzer.analyze_go(&go);
+        assert!(signals.iter().any(|s| s.id == signal_ids::GO_ERRORS_AI_PHRASING));
+    }
+
+    #[test]
+    fn go_error_returns_is_claude() {
+        let source: Vec<String> = (0..10)
+            .map(|i| {
+                if i < 3 {
+                    format!("if err != nil {{ return fmt.Errorf(\"step {i}: %w\", err) }}")
+                } else {
+                    format!("x{i} := step{i}()")
+                }
+            })
+            .collect();
+        let source = source.join("\n");
+        let signals = ErrorHandlingAnalyzer.analyze_go(&source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Claude),
+            "expected Claude signal for Go error wrapping"
+        );
+    }
+}
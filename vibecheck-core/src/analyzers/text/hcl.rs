@@ -0,0 +1,148 @@
+//! Minimal line-based block scanner for Terraform / HCL.
+//!
+//! There is no tree-sitter grammar for HCL in the tree, so the text
+//! analyzers share this scanner to find top-level blocks such as
+//! `resource "aws_s3_bucket" "logs" { ... }` and their body lines.
+
+/// A top-level HCL block: its type, quoted labels, and the lines between
+/// the opening and closing braces.
+#[derive(Debug)]
+pub(crate) struct Block<'s> {
+    pub kind: &'s str,
+    pub labels: Vec<&'s str>,
+    pub body: Vec<&'s str>,
+}
+
+impl Block<'_> {
+    /// Whether the block sets `attr` directly in its body (`attr = ...`).
+    pub fn has_attribute(&self, attr: &str) -> bool {
+        self.body.iter().any(|line| {
+            line.trim_start()
+                .strip_prefix(attr)
+                .is_some_and(|rest| rest.trim_start().starts_with('='))
+        })
+    }
+
+    /// The label naming this block: the second label of `resource` / `data`
+    /// blocks, the first of everything else.
+    pub fn name(&self) -> Option<&str> {
+        match self.kind {
+            "resource" | "data" => self.labels.get(1).copied(),
+            _ => self.labels.first().copied(),
+        }
+    }
+}
+
+/// Whether `line` is a `#`, `//`, or `/* */` comment line.
+pub(crate) fn is_comment(line: &str) -> bool {
+    let t = line.trim_start();
+    t.starts_with('#') || t.starts_with("//") || t.starts_with("/*") || t.starts_with("* ")
+}
+
+/// Parse `kind "label" "label" {` into the block type and its labels.
+fn block_header(line: &str) -> Option<(&str, Vec<&str>)> {
+    let t = line.trim();
+    let rest = t.strip_suffix('{')?.trim_end();
+    let (kind, mut rest) = rest.split_once(' ').unwrap_or((rest, ""));
+    if kind.is_empty() || !kind.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return None;
+    }
+    let mut labels = Vec::new();
+    while let Some(quoted) = rest.trim_start().strip_prefix('"') {
+        let (label, after) = quoted.split_once('"')?;
+        labels.push(label);
+        rest = after;
+    }
+    rest.trim().is_empty().then_some((kind, labels))
+}
+
+/// Net `{` minus `}` on a line, ignoring braces inside strings and comments.
+fn brace_delta(line: &str) -> i32 {
+    let mut delta = 0;
+    let mut in_string = false;
+    let mut prev = ' ';
+    for c in line.chars() {
+        match c {
+            '"' if prev != '\\' => in_string = !in_string,
+            '#' if !in_string => break,
+            '/' if !in_string && prev == '/' => break,
+            '{' if !in_string => delta += 1,
+            '}' if !in_string => delta -= 1,
+            _ => {}
+        }
+        prev = c;
+    }
+    delta
+}
+
+/// All top-level blocks in `source`, in order.
+pub(crate) fn blocks(source: &str) -> Vec<Block<'_>> {
+    let mut result = Vec::new();
+    let mut current: Option<Block> = None;
+    let mut depth = 0;
+    for line in source.lines() {
+        if is_comment(line) {
+            continue;
+        }
+        if depth == 0 {
+            if let Some((kind, labels)) = block_header(line) {
+                current = Some(Block { kind, labels, body: Vec::new() });
+            }
+        } else if let Some(block) = current.as_mut() {
+            block.body.push(line);
+        }
+        depth = (depth + brace_delta(line)).max(0);
+        if depth == 0 {
+            if let Some(mut block) = current.take() {
+                // The closing brace line is not part of the body.
+                if block.body.last().is_some_and(|l| l.trim() == "}") {
+                    block.body.pop();
+                }
+                result.push(block);
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blocks_parse_kind_labels_and_body() {
+        let source = "# storage\n\
+                      resource \"aws_s3_bucket\" \"logs\" {\n\
+                        bucket = \"logs-${var.env}\"\n\
+                        tags = {\n\
+                          Name = \"logs\"\n\
+                        }\n\
+                      }\n\
+                      \n\
+                      variable \"env\" {\n\
+                        description = \"Deployment environment\"\n\
+                      }\n";
+        let blocks = blocks(source);
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].kind, "resource");
+        assert_eq!(blocks[0].labels, ["aws_s3_bucket", "logs"]);
+        assert_eq!(blocks[0].name(), Some("logs"));
+        assert_eq!(blocks[0].body.len(), 4);
+        assert!(!blocks[0].has_attribute("description"));
+        assert_eq!(blocks[1].name(), Some("env"));
+        assert!(blocks[1].has_attribute("description"));
+    }
+
+    #[test]
+    fn braces_in_strings_and_comments_are_ignored() {
+        assert_eq!(brace_delta("name = \"{not a block}\" # }"), 0);
+        assert_eq!(brace_delta("locals { // }"), 1);
+    }
+
+    #[test]
+    fn non_block_lines_are_not_headers() {
+        assert!(block_header("tags = {").is_none());
+        assert!(block_header("bucket = \"x\"").is_none());
+        assert_eq!(block_header("terraform {"), Some(("terraform", vec![])));
+    }
+}
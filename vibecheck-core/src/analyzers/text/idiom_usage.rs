@@ -1,4 +1,4 @@
-use crate::analyzers::Analyzer;
+use crate::analyzers::{Analyzer, DEFAULT_MIN_LINES};
 use crate::heuristics::signal_ids;
 use crate::report::{ModelFamily, Signal};
 
@@ -8,11 +8,6 @@ impl IdiomUsageAnalyzer {
     fn analyze_python_impl(source: &str) -> Vec<Signal> {
         let mut signals = Vec::new();
         let lines: Vec<&str> = source.lines().collect();
-        let total_lines = lines.len();
-        if total_lines < 10 {
-            return signals;
-        }
-
         // List/dict/set comprehensions — idiomatic Python
         let comprehension_count = lines
             .iter()
@@ -121,11 +116,6 @@ impl IdiomUsageAnalyzer {
     fn analyze_javascript_impl(source: &str) -> Vec<Signal> {
         let mut signals = Vec::new();
         let lines: Vec<&str> = source.lines().collect();
-        let total_lines = lines.len();
-        if total_lines < 10 {
-            return signals;
-        }
-
         // Arrow functions vs regular function declarations
         let arrow_fn_count = lines.iter().filter(|l| l.contains("=>")).count();
         let regular_fn_count = lines
@@ -233,11 +223,6 @@ impl IdiomUsageAnalyzer {
     fn analyze_go_impl(source: &str) -> Vec<Signal> {
         let mut signals = Vec::new();
         let lines: Vec<&str> = source.lines().collect();
-        let total_lines = lines.len();
-        if total_lines < 10 {
-            return signals;
-        }
-
         // Compile-time interface satisfaction check: var _ Interface = (*Impl)(nil)
         let interface_check = lines
             .iter()
@@ -316,6 +301,57 @@ impl IdiomUsageAnalyzer {
 
         signals
     }
+
+    fn analyze_csharp_impl(source: &str) -> Vec<Signal> {
+        let mut signals = Vec::new();
+        let lines: Vec<&str> = source.lines().collect();
+        // LINQ method chains and query syntax
+        let linq_methods = [".Where(", ".Select(", ".OrderBy(", ".GroupBy(", ".FirstOrDefault(", ".Any("];
+        let linq_count = lines
+            .iter()
+            .filter(|l| {
+                let t = l.trim();
+                !t.starts_with("//")
+                    && (linq_methods.iter().any(|m| t.contains(m))
+                        || (t.contains("from ") && t.contains(" in ") && t.contains("select ")))
+            })
+            .count();
+        if linq_count >= 3 {
+            signals.push(Signal::new(
+                signal_ids::CSHARP_IDIOMS_LINQ,
+                "idioms",
+                format!("{linq_count} lines using LINQ — declarative collection handling"),
+                ModelFamily::Gpt,
+                1.0,
+            ));
+        }
+
+        // async/await throughout
+        let async_methods = lines.iter().filter(|l| l.contains("async Task")).count();
+        let awaits = lines.iter().filter(|l| l.contains("await ")).count();
+        if async_methods >= 2 && awaits >= 2 {
+            signals.push(Signal::new(
+                signal_ids::CSHARP_IDIOMS_ASYNC_AWAIT,
+                "idioms",
+                format!("{async_methods} async Task methods — async-first design"),
+                ModelFamily::Copilot,
+                0.8,
+            ));
+        }
+
+        // Nullable reference types opted in
+        if lines.iter().any(|l| l.trim() == "#nullable enable") {
+            signals.push(Signal::new(
+                signal_ids::CSHARP_IDIOMS_NULLABLE_ENABLE,
+                "idioms",
+                "#nullable enable — nullable reference types opted in",
+                ModelFamily::Gemini,
+                1.0,
+            ));
+        }
+
+        signals
+    }
 }
 
 impl Analyzer for IdiomUsageAnalyzer {
@@ -323,18 +359,18 @@ impl Analyzer for IdiomUsageAnalyzer {
         "idioms"
     }
 
+    fn min_lines(&self) -> usize {
+        DEFAULT_MIN_LINES
+    }
+
     fn analyze_python(&self, source: &str) -> Vec<Signal> { Self::analyze_python_impl(source) }
     fn analyze_javascript(&self, source: &str) -> Vec<Signal> { Self::analyze_javascript_impl(source) }
     fn analyze_go(&self, source: &str) -> Vec<Signal> { Self::analyze_go_impl(source) }
+    fn analyze_csharp(&self, source: &str) -> Vec<Signal> { Self::analyze_csharp_impl(source) }
 
     fn analyze(&self, source: &str) -> Vec<Signal> {
         let mut signals = Vec::new();
         let lines: Vec<&str> = source.lines().collect();
-        let total_lines = lines.len();
-        if total_lines < 10 {
-            return signals;
-        }
-
         // Iterator chain usage (map, filter, flat_map, collect, fold)
         let iterator_methods = [".map(", ".filter(", ".flat_map(", ".collect()", ".fold(", ".filter_map("];
         let iterator_count = lines
@@ -817,4 +853,36 @@ mod tests {
             "expected return type annotations Claude signal"
         );
     }
+
+    // --- C# ---
+
+    #[test]
+    fn csharp_linq_is_gpt() {
+        let mut lines: Vec<String> = vec![
+            "var active = users.Where(u => u.Active);".into(),
+            "var names = active.Select(u => u.Name);".into(),
+            "var first = names.FirstOrDefault();".into(),
+        ];
+        lines.extend((0..10).map(|i| format!("var x{i} = Step{i}();")));
+        let source = lines.join("\n");
+        let signals = IdiomUsageAnalyzer.analyze_csharp(&source);
+        assert!(
+            signals.iter().any(|s| s.id == signal_ids::CSHARP_IDIOMS_LINQ),
+            "expected LINQ signal"
+        );
+    }
+
+    #[test]
+    fn csharp_async_and_nullable() {
+        let mut lines: Vec<String> = vec![
+            "#nullable enable".into(),
+            "public async Task<User?> LoadAsync() => await repo.GetAsync();".into(),
+            "public async Task SaveAsync(User u) => await repo.PutAsync(u);".into(),
+        ];
+        lines.extend((0..10).map(|i| format!("var x{i} = Step{i}();")));
+        let source = lines.join("\n");
+        let signals = IdiomUsageAnalyzer.analyze_csharp(&source);
+        assert!(signals.iter().any(|s| s.id == signal_ids::CSHARP_IDIOMS_ASYNC_AWAIT));
+        assert!(signals.iter().any(|s| s.id == signal_ids::CSHARP_IDIOMS_NULLABLE_ENABLE));
+    }
 }
@@ -1,6 +1,10 @@
 pub mod ai_signals;
 pub mod code_structure;
 pub mod comment_style;
+mod entropy;
 pub mod error_handling;
+mod hcl;
 pub mod idiom_usage;
 pub mod naming;
+mod naming_roles;
+pub mod test_style;
@@ -1,155 +1,136 @@
-use crate::analyzers::Analyzer;
+use tree_sitter::Tree;
+
+use crate::analyzers::text::entropy::{self, entropy_signal};
+use crate::analyzers::text::hcl;
+use crate::analyzers::text::naming_roles::{follows_convention, identifier_roles};
+use crate::analyzers::{Analyzer, DEFAULT_MIN_LINES};
 use crate::heuristics::signal_ids;
+use crate::language::Language;
 use crate::report::{ModelFamily, Signal};
 
-pub struct NamingAnalyzer;
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::analyzers::Analyzer;
-    use crate::report::ModelFamily;
-
-    fn run(source: &str) -> Vec<Signal> {
-        NamingAnalyzer.analyze(source)
-    }
-
-    #[test]
-    fn short_source_no_signals() {
-        let source = (0..5).map(|i| format!("let x{i} = {i};")).collect::<Vec<_>>().join("\n");
-        assert!(run(&source).is_empty());
-    }
+/// Identifiers counted by the `*.naming.generic_names` signals unless
+/// overridden via `[naming] generic_names` in `.vibecheck`.
+pub const DEFAULT_GENERIC_NAMES: &[&str] =
+    &["result", "data", "temp", "value", "item", "element", "output"];
 
-    #[test]
-    fn long_variable_names_is_claude() {
-        // avg name length > 12: all names are long descriptive identifiers
-        let source = "\
-let configuration_data = 1;\n\
-let processed_result_value = 2;\n\
-let transformation_output = 3;\n\
-let initialization_state = 4;\n\
-let connection_manager = 5;\n\
-let error_description = 6;\n\
-let request_handler = 7;\n\
-let response_buffer = 8;\n\
-let authentication_token = 9;\n\
-let serialization_context = 10;";
-        let signals = run(source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Claude && s.weight == 1.5),
-            "expected long variable names Claude signal (weight 1.5)"
-        );
-    }
+pub struct NamingAnalyzer {
+    generic_names: Vec<String>,
+    /// Judge casing against each identifier's role (see
+    /// [`with_conventions`](Self::with_conventions)).
+    conventions: bool,
+}
 
-    #[test]
-    fn short_variable_names_is_human() {
-        // avg name length < 4: use single/double char names
-        let source = "\
-let x = 1;\nlet y = 2;\nlet z = 3;\nlet a = 4;\nlet b = 5;\n\
-let c = 6;\nlet d = 7;\nlet e = 8;\nlet f = 9;\nlet g = 0;";
-        let signals = run(source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Human && s.weight == 1.5),
-            "expected short variable names Human signal (weight 1.5)"
-        );
-    }
-
-    #[test]
-    fn three_single_char_names_is_human() {
-        let source = "\
-let x = 1;\nlet y = 2;\nlet z = 3;\n\
-let value_one = 10;\nlet value_two = 20;\n\
-let result = 30;\nlet output = 40;\nlet data = 50;\nlet item = 60;\nlet entry = 70;";
-        let signals = run(source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Human && s.weight == 2.0),
-            "expected 3+ single-char names Human signal (weight 2.0)"
-        );
+impl Default for NamingAnalyzer {
+    fn default() -> Self {
+        Self::with_generic_names(DEFAULT_GENERIC_NAMES.iter().map(|s| s.to_string()).collect())
     }
+}
 
-    #[test]
-    fn five_vars_no_single_char_is_claude() {
-        let source = "\
-let value_one = 1;\nlet value_two = 2;\nlet value_three = 3;\n\
-let value_four = 4;\nlet value_five = 5;\n\
-let extra_one = 6;\nlet extra_two = 7;\nlet extra_three = 8;\nlet extra_four = 9;\nlet extra_five = 0;";
-        let signals = run(source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Claude && s.weight == 1.0),
-            "expected no single-char names Claude signal (weight 1.0)"
-        );
+impl NamingAnalyzer {
+    /// Build an analyzer that treats `names` as the generic placeholder list
+    /// instead of [`DEFAULT_GENERIC_NAMES`].  Matching is case-insensitive.
+    pub fn with_generic_names(names: Vec<String>) -> Self {
+        Self {
+            generic_names: names.into_iter().map(|n| n.to_lowercase()).collect(),
+            conventions: true,
+        }
     }
 
-    #[test]
-    fn python_long_names_is_claude() {
-        // Python source with very descriptive function/variable names
-        let source = "\
-def process_authentication_token(user_context):\n    pass\n\
-def handle_connection_manager(request_handler):\n    pass\n\
-def validate_configuration_data(initialization_state):\n    pass\n\
-def serialize_transformation_output(processed_result_value):\n    pass\n\
-def compute_serialization_context():\n    pass\n\
-def build_error_description():\n    pass\n\
-authentication_token = None\n\
-configuration_data = None\n\
-processed_result_value = None\n\
-transformation_output = None\n";
-        let signals = NamingAnalyzer.analyze_python(source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Claude),
-            "expected Claude signal for very descriptive Python names"
-        );
+    /// Whether the `*.naming.mixed_conventions` signals respect each
+    /// language's casing conventions (on by default).  When on, identifiers
+    /// are classified by role from the syntax tree the pipeline parsed (see
+    /// [`Analyzer::analyze_with_tree`]) and only names breaking the
+    /// convention for their role count, so Rust's PascalCase types next to
+    /// snake_case functions are not "mixed" but a camelCase local is.  When
+    /// off, or without a tree (no file path, or a parse that failed or timed
+    /// out), any camelCase + snake_case mix among the extracted names fires.
+    pub fn with_conventions(mut self, enabled: bool) -> Self {
+        self.conventions = enabled;
+        self
     }
 
-    #[test]
-    fn python_short_source_no_signals() {
-        let source = "x = 1\ny = 2\n";
-        let signals = NamingAnalyzer.analyze_python(source);
-        assert!(signals.is_empty());
-    }
+    /// Mixed naming conventions: 2+ identifiers breaking `lang`'s casing
+    /// convention for their role alongside 2+ following it, or, with
+    /// conventions off (or no syntax `tree`), 2+ camelCase and 2+ snake_case
+    /// `names`.
+    fn mixed_conventions_signal(
+        &self,
+        signal_id: &str,
+        lang: Language,
+        source: &str,
+        tree: Option<&Tree>,
+        names: &[String],
+    ) -> Option<Signal> {
+        let roles = tree.filter(|_| self.conventions).map(|tree| identifier_roles(tree, source, lang));
+        if let Some(roles) = roles {
+            let (follow, breaking): (Vec<_>, Vec<_>) =
+                roles.iter().partition(|(name, role)| follows_convention(name, *role, lang));
+            return (breaking.len() >= 2 && follow.len() >= 2).then(|| {
+                Signal::new(
+                    signal_id,
+                    self.name(),
+                    format!(
+                        "{} identifiers break {lang} naming conventions (e.g. `{}`)",
+                        breaking.len(),
+                        breaking[0].0
+                    ),
+                    ModelFamily::Copilot,
+                    1.5,
+                )
+            });
+        }
 
-    #[test]
-    fn javascript_long_names_is_claude() {
-        let source = "\
-const processAuthenticationToken = () => {};\n\
-const handleConnectionManager = () => {};\n\
-const validateConfigurationData = () => {};\n\
-const serializeTransformationOutput = () => {};\n\
-const computeSerializationContext = () => {};\n\
-const buildErrorDescription = () => {};\n\
-let authenticationToken = null;\n\
-let configurationData = null;\n\
-let processedResultValue = null;\n\
-let transformationOutput = null;\n";
-        let signals = NamingAnalyzer.analyze_javascript(source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Claude),
-            "expected Claude signal for very descriptive JS names"
-        );
+        let camel_count = names
+            .iter()
+            .filter(|n| n.len() > 2 && n.chars().any(|c| c.is_uppercase()) && !n.contains('_'))
+            .count();
+        let snake_count = names
+            .iter()
+            .filter(|n| {
+                n.contains('_')
+                    && n.chars()
+                        .all(|c| c.is_lowercase() || c == '_' || c.is_numeric())
+            })
+            .count();
+        (camel_count >= 2 && snake_count >= 2).then(|| {
+            Signal::new(
+                signal_id,
+                self.name(),
+                format!("{camel_count} camelCase + {snake_count} snake_case identifiers"),
+                ModelFamily::Copilot,
+                1.5,
+            )
+        })
     }
 
-    #[test]
-    fn go_long_names_is_claude() {
-        let source = "\
-func ProcessAuthenticationToken() {}\n\
-func HandleConnectionManager() {}\n\
-func ValidateConfigurationData() {}\n\
-func SerializeTransformationOutput() {}\n\
-func ComputeSerializationContext() {}\n\
-func BuildErrorDescription() {}\n\
-var authenticationToken string\n\
-var configurationData string\n\
-var processedResultValue string\n\
-var transformationOutput string\n";
-        let signals = NamingAnalyzer.analyze_go(source);
-        assert!(
-            signals.iter().any(|s| s.family == ModelFamily::Claude),
-            "expected Claude signal for very descriptive Go names"
-        );
+    /// Generic placeholder names (`result`, `data`, `item`, …) dominating the
+    /// identifier set: 3+ occurrences making up at least a quarter of all names.
+    fn generic_names_signal(
+        &self,
+        signal_id: &str,
+        source_name: &str,
+        names: &[String],
+    ) -> Option<Signal> {
+        let generic = names
+            .iter()
+            .filter(|n| self.generic_names.contains(&n.to_lowercase()))
+            .count();
+        if generic >= 3 && generic * 4 >= names.len() {
+            Some(Signal::new(
+                signal_id,
+                source_name,
+                format!(
+                    "{generic} of {} identifiers are generic placeholders (result, data, item, etc.)",
+                    names.len()
+                ),
+                ModelFamily::Gpt,
+                1.0,
+            ))
+        } else {
+            None
+        }
     }
-}
 
-impl NamingAnalyzer {
     /// Extract identifier names from Python assignments and definitions.
     fn python_names(lines: &[&str]) -> Vec<String> {
         let mut names = Vec::new();
@@ -182,6 +163,7 @@ impl NamingAnalyzer {
 
     #[allow(clippy::too_many_arguments)]
     fn analyze_names(
+        &self,
         source_name: &str,
         very_descriptive_id: &str,
         descriptive_id: &str,
@@ -189,8 +171,8 @@ impl NamingAnalyzer {
         short_names_id: &str,
         many_single_char_id: &str,
         no_single_char_id: &str,
-        mixed_conventions_id: &str,
         domain_abbreviations_id: &str,
+        generic_names_id: &str,
         names: &[String],
     ) -> Vec<Signal> {
         let mut signals = Vec::new();
@@ -253,29 +235,6 @@ impl NamingAnalyzer {
             ));
         }
 
-        // Mixed naming conventions (both camelCase and snake_case)
-        let camel_count = names
-            .iter()
-            .filter(|n| n.len() > 2 && n.chars().any(|c| c.is_uppercase()) && !n.contains('_'))
-            .count();
-        let snake_count = names
-            .iter()
-            .filter(|n| {
-                n.contains('_')
-                    && n.chars()
-                        .all(|c| c.is_lowercase() || c == '_' || c.is_numeric())
-            })
-            .count();
-        if camel_count >= 2 && snake_count >= 2 {
-            signals.push(Signal::new(
-                mixed_conventions_id,
-                source_name,
-                format!("{camel_count} camelCase + {snake_count} snake_case identifiers"),
-                ModelFamily::Copilot,
-                1.5,
-            ));
-        }
-
         // Domain abbreviations
         const ABBREVIATIONS: &[&str] = &[
             "txn", "cfg", "ctx", "req", "resp", "db", "msg", "buf", "idx", "len", "err", "fmt",
@@ -302,16 +261,15 @@ impl NamingAnalyzer {
             ));
         }
 
+        signals.extend(self.generic_names_signal(generic_names_id, source_name, names));
+
         signals
     }
 
-    fn analyze_python_impl(source: &str) -> Vec<Signal> {
+    fn analyze_python_impl(&self, source: &str, tree: Option<&Tree>) -> Vec<Signal> {
         let lines: Vec<&str> = source.lines().collect();
-        if lines.len() < 10 {
-            return vec![];
-        }
         let names = Self::python_names(&lines);
-        Self::analyze_names(
+        let mut signals = self.analyze_names(
             "naming",
             signal_ids::PYTHON_NAMING_VERY_DESCRIPTIVE,
             signal_ids::PYTHON_NAMING_DESCRIPTIVE,
@@ -319,18 +277,29 @@ impl NamingAnalyzer {
             signal_ids::PYTHON_NAMING_SHORT_NAMES,
             signal_ids::PYTHON_NAMING_MANY_SINGLE_CHAR,
             signal_ids::PYTHON_NAMING_NO_SINGLE_CHAR,
-            signal_ids::PYTHON_NAMING_MIXED_CONVENTIONS,
             signal_ids::PYTHON_NAMING_DOMAIN_ABBREVIATIONS,
+            signal_ids::PYTHON_NAMING_GENERIC_NAMES,
             &names,
-        )
+        );
+        signals.extend(self.mixed_conventions_signal(
+            signal_ids::PYTHON_NAMING_MIXED_CONVENTIONS,
+            Language::Python,
+            source,
+            tree,
+            &names,
+        ));
+        signals.extend(entropy_signal(
+            source,
+            "#",
+            entropy::PYTHON_KEYWORDS,
+            signal_ids::PYTHON_NAMING_HIGH_ENTROPY,
+            signal_ids::PYTHON_NAMING_LOW_ENTROPY,
+        ));
+        signals
     }
 
-    fn analyze_javascript_impl(source: &str) -> Vec<Signal> {
+    fn analyze_javascript_impl(&self, source: &str, tree: Option<&Tree>) -> Vec<Signal> {
         let lines: Vec<&str> = source.lines().collect();
-        if lines.len() < 10 {
-            return vec![];
-        }
-
         // Extract names from const/let/var and function declarations
         let names: Vec<String> = lines
             .iter()
@@ -349,7 +318,7 @@ impl NamingAnalyzer {
             .filter(|n| !n.is_empty() && n.chars().all(|c| c.is_alphanumeric() || c == '_'))
             .collect();
 
-        Self::analyze_names(
+        let mut signals = self.analyze_names(
             "naming",
             signal_ids::JS_NAMING_VERY_DESCRIPTIVE,
             signal_ids::JS_NAMING_DESCRIPTIVE,
@@ -357,18 +326,29 @@ impl NamingAnalyzer {
             signal_ids::JS_NAMING_SHORT_NAMES,
             signal_ids::JS_NAMING_MANY_SINGLE_CHAR,
             signal_ids::JS_NAMING_NO_SINGLE_CHAR,
-            signal_ids::JS_NAMING_MIXED_CONVENTIONS,
             signal_ids::JS_NAMING_DOMAIN_ABBREVIATIONS,
+            signal_ids::JS_NAMING_GENERIC_NAMES,
+            &names,
+        );
+        signals.extend(self.mixed_conventions_signal(
+            signal_ids::JS_NAMING_MIXED_CONVENTIONS,
+            Language::JavaScript,
+            source,
+            tree,
             &names,
-        )
+        ));
+        signals.extend(entropy_signal(
+            source,
+            "//",
+            entropy::JS_KEYWORDS,
+            signal_ids::JS_NAMING_HIGH_ENTROPY,
+            signal_ids::JS_NAMING_LOW_ENTROPY,
+        ));
+        signals
     }
 
-    fn analyze_go_impl(source: &str) -> Vec<Signal> {
+    fn analyze_go_impl(&self, source: &str, tree: Option<&Tree>) -> Vec<Signal> {
         let lines: Vec<&str> = source.lines().collect();
-        if lines.len() < 10 {
-            return vec![];
-        }
-
         // Extract names from var, :=, func declarations
         let mut names: Vec<String> = Vec::new();
         for line in &lines {
@@ -400,7 +380,7 @@ impl NamingAnalyzer {
             }
         }
 
-        Self::analyze_names(
+        let mut signals = self.analyze_names(
             "naming",
             signal_ids::GO_NAMING_VERY_DESCRIPTIVE,
             signal_ids::GO_NAMING_DESCRIPTIVE,
@@ -408,10 +388,61 @@ impl NamingAnalyzer {
             signal_ids::GO_NAMING_SHORT_NAMES,
             signal_ids::GO_NAMING_MANY_SINGLE_CHAR,
             signal_ids::GO_NAMING_NO_SINGLE_CHAR,
-            signal_ids::GO_NAMING_MIXED_CONVENTIONS,
             signal_ids::GO_NAMING_DOMAIN_ABBREVIATIONS,
+            signal_ids::GO_NAMING_GENERIC_NAMES,
+            &names,
+        );
+        signals.extend(self.mixed_conventions_signal(
+            signal_ids::GO_NAMING_MIXED_CONVENTIONS,
+            Language::Go,
+            source,
+            tree,
             &names,
-        )
+        ));
+        signals.extend(entropy_signal(
+            source,
+            "//",
+            entropy::GO_KEYWORDS,
+            signal_ids::GO_NAMING_HIGH_ENTROPY,
+            signal_ids::GO_NAMING_LOW_ENTROPY,
+        ));
+        signals
+    }
+
+    /// Terraform names resources, variables, and outputs in snake_case by
+    /// convention; hyphenated or camelCase labels creep in by hand.
+    fn analyze_hcl_impl(&self, source: &str) -> Vec<Signal> {
+        let mut signals = Vec::new();
+        let blocks = hcl::blocks(source);
+        let names: Vec<&str> = blocks
+            .iter()
+            .filter(|b| matches!(b.kind, "resource" | "data" | "module" | "variable" | "output"))
+            .filter_map(|b| b.name())
+            .collect();
+        let snake = names
+            .iter()
+            .filter(|n| n.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'))
+            .count();
+        let other = names.len() - snake;
+        if names.len() >= 4 && other == 0 {
+            signals.push(Signal::new(
+                signal_ids::HCL_NAMING_CONSISTENT_SNAKE_CASE,
+                "naming",
+                format!("All {} block names are snake_case", names.len()),
+                ModelFamily::Gpt,
+                0.8,
+            ));
+        } else if snake >= 2 && other >= 2 {
+            signals.push(Signal::new(
+                signal_ids::HCL_NAMING_MIXED_CONVENTIONS,
+                "naming",
+                format!("{snake} snake_case + {other} hyphenated or camelCase block names"),
+                ModelFamily::Human,
+                1.2,
+            ));
+        }
+
+        signals
     }
 }
 
@@ -420,16 +451,42 @@ impl Analyzer for NamingAnalyzer {
         "naming"
     }
 
-    fn analyze_python(&self, source: &str) -> Vec<Signal> { Self::analyze_python_impl(source) }
-    fn analyze_javascript(&self, source: &str) -> Vec<Signal> { Self::analyze_javascript_impl(source) }
-    fn analyze_go(&self, source: &str) -> Vec<Signal> { Self::analyze_go_impl(source) }
+    fn min_lines(&self) -> usize {
+        DEFAULT_MIN_LINES
+    }
+
+    fn analyze_python(&self, source: &str) -> Vec<Signal> { self.analyze_python_impl(source, None) }
+    fn analyze_javascript(&self, source: &str) -> Vec<Signal> { self.analyze_javascript_impl(source, None) }
+    fn analyze_go(&self, source: &str) -> Vec<Signal> { self.analyze_go_impl(source, None) }
+    fn analyze_hcl(&self, source: &str) -> Vec<Signal> { self.analyze_hcl_impl(source) }
 
     fn analyze(&self, source: &str) -> Vec<Signal> {
+        self.analyze_rust_impl(source, None)
+    }
+
+    /// Reads identifier roles for the mixed-conventions check from `tree`.
+    fn analyze_with_tree(&self, source: &str, lang: Option<Language>, tree: Option<&Tree>) -> Vec<Signal> {
+        match lang {
+            None | Some(Language::Rust) => self.analyze_rust_impl(source, tree),
+            Some(Language::Python) => self.analyze_python_impl(source, tree),
+            Some(Language::JavaScript) => self.analyze_javascript_impl(source, tree),
+            Some(Language::Go) => self.analyze_go_impl(source, tree),
+            Some(Language::CSharp | Language::Hcl) => self.analyze_with_language(source, lang),
+        }
+    }
+}
+
+impl NamingAnalyzer {
+    fn analyze_rust_impl(&self, source: &str, tree: Option<&Tree>) -> Vec<Signal> {
         let mut signals = Vec::new();
         let lines: Vec<&str> = source.lines().collect();
-        if lines.len() < 10 {
-            return signals;
-        }
+        signals.extend(entropy_signal(
+            source,
+            "//",
+            entropy::RUST_KEYWORDS,
+            signal_ids::RUST_NAMING_HIGH_ENTROPY,
+            signal_ids::RUST_NAMING_LOW_ENTROPY,
+        ));
 
         // Extract variable/binding names from let statements
         let let_names: Vec<&str> = lines
@@ -557,33 +614,18 @@ impl Analyzer for NamingAnalyzer {
             ));
         }
 
-        // Mixed naming conventions (both camelCase and snake_case in same file)
         let all_names: Vec<String> = let_names
             .iter()
             .chain(fn_names.iter())
             .map(|n| n.to_string())
             .collect();
-        let camel_count = all_names
-            .iter()
-            .filter(|n| n.len() > 2 && n.chars().any(|c| c.is_uppercase()) && !n.contains('_'))
-            .count();
-        let snake_count = all_names
-            .iter()
-            .filter(|n| {
-                n.contains('_')
-                    && n.chars()
-                        .all(|c| c.is_lowercase() || c == '_' || c.is_numeric())
-            })
-            .count();
-        if camel_count >= 2 && snake_count >= 2 {
-            signals.push(Signal::new(
-                signal_ids::RUST_NAMING_MIXED_CONVENTIONS,
-                self.name(),
-                format!("{camel_count} camelCase + {snake_count} snake_case identifiers"),
-                ModelFamily::Copilot,
-                1.5,
-            ));
-        }
+        signals.extend(self.mixed_conventions_signal(
+            signal_ids::RUST_NAMING_MIXED_CONVENTIONS,
+            Language::Rust,
+            source,
+            tree,
+            &all_names,
+        ));
 
         // Domain abbreviations
         const ABBREVIATIONS: &[&str] = &[
@@ -611,6 +653,306 @@ impl Analyzer for NamingAnalyzer {
             ));
         }
 
+        signals.extend(self.generic_names_signal(
+            signal_ids::RUST_NAMING_GENERIC_NAMES,
+            self.name(),
+            &all_names,
+        ));
+
         signals
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzers::Analyzer;
+    use crate::heuristics::DefaultHeuristics;
+    use crate::pipeline::Pipeline;
+    use crate::report::ModelFamily;
+
+    fn run(source: &str) -> Vec<Signal> {
+        NamingAnalyzer::default().analyze(source)
+    }
+
+    /// Signals for `source` given the syntax tree the pipeline would parse.
+    fn run_with_tree(analyzer: &NamingAnalyzer, source: &str, lang: Language) -> Vec<Signal> {
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&crate::language::get_ts_language(lang).unwrap()).unwrap();
+        let tree = parser.parse(source, None);
+        analyzer.analyze_with_tree(source, Some(lang), tree.as_ref())
+    }
+
+    #[test]
+    fn short_source_no_signals() {
+        let source = (0..5).map(|i| format!("let x{i} = {i};")).collect::<Vec<_>>().join("\n");
+        let pipeline = Pipeline::with_heuristics(
+            vec![Box::new(NamingAnalyzer::default())],
+            vec![],
+            Box::new(DefaultHeuristics),
+        );
+        assert!(pipeline.run(&source, None).signals.is_empty());
+    }
+
+    #[test]
+    fn long_variable_names_is_claude() {
+        // avg name length > 12: all names are long descriptive identifiers
+        let source = "\
+let configuration_data = 1;\n\
+let processed_result_value = 2;\n\
+let transformation_output = 3;\n\
+let initialization_state = 4;\n\
+let connection_manager = 5;\n\
+let error_description = 6;\n\
+let request_handler = 7;\n\
+let response_buffer = 8;\n\
+let authentication_token = 9;\n\
+let serialization_context = 10;";
+        let signals = run(source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Claude && s.weight == 1.5),
+            "expected long variable names Claude signal (weight 1.5)"
+        );
+    }
+
+    #[test]
+    fn short_variable_names_is_human() {
+        // avg name length < 4: use single/double char names
+        let source = "\
+let x = 1;\nlet y = 2;\nlet z = 3;\nlet a = 4;\nlet b = 5;\n\
+let c = 6;\nlet d = 7;\nlet e = 8;\nlet f = 9;\nlet g = 0;";
+        let signals = run(source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Human && s.weight == 1.5),
+            "expected short variable names Human signal (weight 1.5)"
+        );
+    }
+
+    #[test]
+    fn three_single_char_names_is_human() {
+        let source = "\
+let x = 1;\nlet y = 2;\nlet z = 3;\n\
+let value_one = 10;\nlet value_two = 20;\n\
+let result = 30;\nlet output = 40;\nlet data = 50;\nlet item = 60;\nlet entry = 70;";
+        let signals = run(source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Human && s.weight == 2.0),
+            "expected 3+ single-char names Human signal (weight 2.0)"
+        );
+    }
+
+    #[test]
+    fn five_vars_no_single_char_is_claude() {
+        let source = "\
+let value_one = 1;\nlet value_two = 2;\nlet value_three = 3;\n\
+let value_four = 4;\nlet value_five = 5;\n\
+let extra_one = 6;\nlet extra_two = 7;\nlet extra_three = 8;\nlet extra_four = 9;\nlet extra_five = 0;";
+        let signals = run(source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Claude && s.weight == 1.0),
+            "expected no single-char names Claude signal (weight 1.0)"
+        );
+    }
+
+    #[test]
+    fn generic_names_is_gpt() {
+        let source = "\
+let result = 1;\nlet data = 2;\nlet item = 3;\nlet output = 4;\n\
+let connection_pool = 5;\nlet retry_budget = 6;\n\
+fn main() {}\nfn helper() {}\nfn other() {}\nfn last() {}";
+        let signals = run(source);
+        assert!(
+            signals.iter().any(|s| s.id == signal_ids::RUST_NAMING_GENERIC_NAMES),
+            "expected generic names signal"
+        );
+    }
+
+    #[test]
+    fn generic_names_sparse_no_signal() {
+        let source = "\
+let result = 1;\nlet data = 2;\nlet connection_pool = 3;\nlet retry_budget = 4;\n\
+let request_timeout = 5;\nlet backoff_factor = 6;\nlet max_attempts = 7;\n\
+let jitter_window = 8;\nlet idle_timeout = 9;\nlet queue_depth = 10;";
+        let signals = run(source);
+        assert!(!signals.iter().any(|s| s.id == signal_ids::RUST_NAMING_GENERIC_NAMES));
+    }
+
+    #[test]
+    fn generic_names_list_is_overridable() {
+        let source = "\
+let result = 1;\nlet data = 2;\nlet item = 3;\nlet output = 4;\n\
+let foo = 5;\nlet bar = 6;\nlet baz = 7;\n\
+fn main() {}\nfn helper() {}\nfn other() {}";
+        let custom = NamingAnalyzer::with_generic_names(vec!["foo".into(), "bar".into()]);
+        assert!(!custom
+            .analyze(source)
+            .iter()
+            .any(|s| s.id == signal_ids::RUST_NAMING_GENERIC_NAMES));
+
+        let custom = NamingAnalyzer::with_generic_names(vec!["Foo".into(), "bar".into(), "baz".into()]);
+        assert!(custom
+            .analyze(source)
+            .iter()
+            .any(|s| s.id == signal_ids::RUST_NAMING_GENERIC_NAMES));
+    }
+
+    #[test]
+    fn python_generic_names_is_gpt() {
+        let source = "\
+result = compute()\n\
+data = load()\n\
+item = data[0]\n\
+value = item.value\n\
+output = []\n\
+temp = None\n\
+def process(x):\n    pass\n\
+def render(y):\n    pass\n";
+        let signals = NamingAnalyzer::default().analyze_python(source);
+        assert!(signals.iter().any(|s| s.id == signal_ids::PYTHON_NAMING_GENERIC_NAMES));
+    }
+
+    #[test]
+    fn python_long_names_is_claude() {
+        // Python source with very descriptive function/variable names
+        let source = "\
+def process_authentication_token(user_context):\n    pass\n\
+def handle_connection_manager(request_handler):\n    pass\n\
+def validate_configuration_data(initialization_state):\n    pass\n\
+def serialize_transformation_output(processed_result_value):\n    pass\n\
+def compute_serialization_context():\n    pass\n\
+def build_error_description():\n    pass\n\
+authentication_token = None\n\
+configuration_data = None\n\
+processed_result_value = None\n\
+transformation_output = None\n";
+        let signals = NamingAnalyzer::default().analyze_python(source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Claude),
+            "expected Claude signal for very descriptive Python names"
+        );
+    }
+
+    #[test]
+    fn python_short_source_no_signals() {
+        let source = "x = 1\ny = 2\n";
+        let pipeline = Pipeline::with_heuristics(
+            vec![Box::new(NamingAnalyzer::default())],
+            vec![],
+            Box::new(DefaultHeuristics),
+        );
+        let report = pipeline.run(source, Some("short.py".into()));
+        assert!(report.signals.is_empty());
+    }
+
+    #[test]
+    fn javascript_long_names_is_claude() {
+        let source = "\
+const processAuthenticationToken = () => {};\n\
+const handleConnectionManager = () => {};\n\
+const validateConfigurationData = () => {};\n\
+const serializeTransformationOutput = () => {};\n\
+const computeSerializationContext = () => {};\n\
+const buildErrorDescription = () => {};\n\
+let authenticationToken = null;\n\
+let configurationData = null;\n\
+let processedResultValue = null;\n\
+let transformationOutput = null;\n";
+        let signals = NamingAnalyzer::default().analyze_javascript(source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Claude),
+            "expected Claude signal for very descriptive JS names"
+        );
+    }
+
+    #[test]
+    fn go_long_names_is_claude() {
+        let source = "\
+func ProcessAuthenticationToken() {}\n\
+func HandleConnectionManager() {}\n\
+func ValidateConfigurationData() {}\n\
+func SerializeTransformationOutput() {}\n\
+func ComputeSerializationContext() {}\n\
+func BuildErrorDescription() {}\n\
+var authenticationToken string\n\
+var configurationData string\n\
+var processedResultValue string\n\
+var transformationOutput string\n";
+        let signals = NamingAnalyzer::default().analyze_go(source);
+        assert!(
+            signals.iter().any(|s| s.family == ModelFamily::Claude),
+            "expected Claude signal for very descriptive Go names"
+        );
+        assert!(signals.iter().any(|s| s.id == signal_ids::GO_NAMING_HIGH_ENTROPY));
+    }
+
+    #[test]
+    fn terse_rust_identifiers_are_low_entropy() {
+        let source = (0..10).map(|_| "    a = a + b;\n").collect::<String>();
+        let signals = run(&source);
+        assert!(signals.iter().any(|s| s.id == signal_ids::RUST_NAMING_LOW_ENTROPY));
+    }
+
+    #[test]
+    fn mixed_conventions_respect_rust_roles() {
+        let source = "\
+struct HttpClient;\n\
+enum RetryPolicy { Never, Always }\n\
+fn parse_header(raw: Option<&str>) -> u32 {\n\
+    let Some(header) = raw else { return 0 };\n\
+    let Ok(parsed) = header.parse() else { return 0 };\n\
+    let header_len = parsed;\n\
+    header_len\n\
+}\n\
+fn read_body(raw: &str) -> usize {\n\
+    let body_len = raw.len();\n\
+    body_len\n\
+}\n";
+        let mixed = |signals: Vec<Signal>| signals.iter().any(|s| s.id == signal_ids::RUST_NAMING_MIXED_CONVENTIONS);
+        let raw = NamingAnalyzer::default().with_conventions(false);
+        assert!(mixed(run_with_tree(&raw, source, Language::Rust)), "`let Some(..)` reads as camelCase");
+        assert!(!mixed(run_with_tree(&NamingAnalyzer::default(), source, Language::Rust)));
+        assert!(mixed(run(source)), "without a tree, the raw count applies");
+
+        let camel_locals = source.replace("header_len", "headerLen").replace("body_len", "bodyLen");
+        let signals = run_with_tree(&NamingAnalyzer::default(), &camel_locals, Language::Rust);
+        let signal = signals.iter().find(|s| s.id == signal_ids::RUST_NAMING_MIXED_CONVENTIONS).unwrap();
+        assert_eq!(signal.description, "2 identifiers break Rust naming conventions (e.g. `headerLen`)");
+    }
+
+    #[test]
+    fn mixed_conventions_flag_camel_case_python_locals() {
+        let source = "\
+MAX_USERS = 100\n\
+class UserStore:\n\
+    def load_users(self, path):\n\
+        userCount = 0\n\
+        return userCount\n\
+\n\
+def save_users(store, path):\n\
+    lastSaved = None\n\
+    return lastSaved\n";
+        let signals = run_with_tree(&NamingAnalyzer::default(), source, Language::Python);
+        assert!(signals.iter().any(|s| s.id == signal_ids::PYTHON_NAMING_MIXED_CONVENTIONS));
+    }
+
+    fn hcl_resources(names: &[&str]) -> String {
+        names
+            .iter()
+            .map(|n| format!("resource \"aws_instance\" \"{n}\" {{\n  ami = \"ami-123\"\n}}\n"))
+            .collect()
+    }
+
+    #[test]
+    fn hcl_snake_case_names_are_consistent() {
+        let source = hcl_resources(&["web_server", "worker", "bastion_host", "db_primary"]);
+        let signals = NamingAnalyzer::default().analyze_hcl(&source);
+        assert!(signals.iter().any(|s| s.id == signal_ids::HCL_NAMING_CONSISTENT_SNAKE_CASE));
+    }
+
+    #[test]
+    fn hcl_mixed_names_are_human() {
+        let source = hcl_resources(&["web_server", "worker", "bastion-host", "dbPrimary"]);
+        let signals = NamingAnalyzer::default().analyze_hcl(&source);
+        assert!(signals.iter().any(|s| s.id == signal_ids::HCL_NAMING_MIXED_CONVENTIONS));
+    }
+}
@@ -0,0 +1,204 @@
+//! Identifier roles read from the syntax tree, so the naming analyzer can
+//! judge each name against its language's casing convention for that role
+//! instead of counting camelCase and snake_case tokens file-wide.
+
+use tree_sitter::{Node, Tree};
+
+use crate::language::Language;
+
+/// What a declared identifier names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Role {
+    /// Structs, enums and their variants, traits, classes, type aliases.
+    Type,
+    /// Functions and methods.
+    Function,
+    /// Locals, parameters, and (in Python and JS) module-level bindings.
+    Variable,
+    /// `const` and `static` items.
+    Constant,
+}
+
+/// Every identifier `source` declares, with its role, in source order,
+/// read from `tree` (the syntax tree of `source` in `lang`).
+pub(crate) fn identifier_roles(tree: &Tree, source: &str, lang: Language) -> Vec<(String, Role)> {
+    let mut roles = Vec::new();
+    let mut stack = vec![tree.root_node()];
+    while let Some(node) = stack.pop() {
+        for (name, role) in declared(node, lang) {
+            if let Ok(text) = name.utf8_text(source.as_bytes()) {
+                roles.push((text.to_string(), role));
+            }
+        }
+        let mut cursor = node.walk();
+        let children: Vec<Node> = node.named_children(&mut cursor).collect();
+        stack.extend(children.into_iter().rev());
+    }
+    roles
+}
+
+/// The identifiers `node` itself declares (not those of its descendants).
+fn declared<'a>(node: Node<'a>, lang: Language) -> Vec<(Node<'a>, Role)> {
+    let field = |name: &str| node.child_by_field_name(name);
+    let named = |role: Role| field("name").map(|n| (n, role)).into_iter().collect::<Vec<_>>();
+    let identifier = |n: Option<Node<'a>>, role: Role| {
+        n.filter(|n| n.kind() == "identifier").map(|n| (n, role)).into_iter().collect::<Vec<_>>()
+    };
+    match (lang, node.kind()) {
+        (Language::Rust, "function_item" | "function_signature_item") => named(Role::Function),
+        (
+            Language::Rust,
+            "struct_item" | "enum_item" | "enum_variant" | "trait_item" | "type_item" | "union_item",
+        ) => named(Role::Type),
+        (Language::Rust, "const_item" | "static_item") => named(Role::Constant),
+        (Language::Rust, "let_declaration" | "parameter") => identifier(field("pattern"), Role::Variable),
+
+        (Language::Python, "function_definition") => named(Role::Function),
+        (Language::Python, "class_definition") => named(Role::Type),
+        (Language::Python, "assignment") => identifier(field("left"), Role::Variable),
+        (Language::Python, "parameters") => {
+            let mut cursor = node.walk();
+            let params: Vec<Node> = node.named_children(&mut cursor).collect();
+            params
+                .into_iter()
+                .filter_map(|p| match p.kind() {
+                    "identifier" => Some(p),
+                    "typed_parameter" => p.named_child(0).filter(|n| n.kind() == "identifier"),
+                    "default_parameter" | "typed_default_parameter" => p.child_by_field_name("name"),
+                    _ => None,
+                })
+                .map(|n| (n, Role::Variable))
+                .collect()
+        }
+
+        (
+            Language::JavaScript,
+            "function_declaration" | "generator_function_declaration" | "method_definition",
+        ) => named(Role::Function),
+        (Language::JavaScript, "class_declaration") => named(Role::Type),
+        (Language::JavaScript, "variable_declarator") => {
+            let is_function =
+                field("value").is_some_and(|v| matches!(v.kind(), "arrow_function" | "function_expression"));
+            identifier(field("name"), if is_function { Role::Function } else { Role::Variable })
+        }
+        (Language::JavaScript, "formal_parameters") => {
+            let mut cursor = node.walk();
+            let params: Vec<Node> = node.named_children(&mut cursor).collect();
+            params
+                .into_iter()
+                .filter_map(|p| match p.kind() {
+                    "identifier" => Some(p),
+                    "assignment_pattern" => {
+                        p.child_by_field_name("left").filter(|n| n.kind() == "identifier")
+                    }
+                    _ => None,
+                })
+                .map(|n| (n, Role::Variable))
+                .collect()
+        }
+
+        (Language::Go, "function_declaration" | "method_declaration") => named(Role::Function),
+        (Language::Go, "type_spec") => named(Role::Type),
+        (Language::Go, "const_spec") => all_named(node, "name", Role::Constant),
+        (Language::Go, "var_spec" | "parameter_declaration") => all_named(node, "name", Role::Variable),
+        (Language::Go, "short_var_declaration") => {
+            let Some(left) = field("left") else { return Vec::new() };
+            let mut cursor = left.walk();
+            let names: Vec<Node> = left.named_children(&mut cursor).collect();
+            names.into_iter().filter(|n| n.kind() == "identifier").map(|n| (n, Role::Variable)).collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Every child of `node` in `field` (Go declares several names per spec).
+fn all_named<'a>(node: Node<'a>, field: &str, role: Role) -> Vec<(Node<'a>, Role)> {
+    let mut cursor = node.walk();
+    let names: Vec<Node<'a>> = node.children_by_field_name(field, &mut cursor).collect();
+    names.into_iter().map(|n| (n, role)).collect()
+}
+
+fn is_snake(name: &str) -> bool {
+    name.chars().all(|c| c.is_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+fn is_screaming_snake(name: &str) -> bool {
+    name.chars().all(|c| c.is_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+fn is_pascal(name: &str) -> bool {
+    name.starts_with(char::is_uppercase) && !name.contains('_')
+}
+
+fn is_camel(name: &str) -> bool {
+    name.starts_with(char::is_lowercase) && !name.contains('_')
+}
+
+/// Whether `name` follows `lang`'s casing convention for `role`.  Leading
+/// and trailing underscores (`_unused`, `__init__`) are ignored.
+pub(crate) fn follows_convention(name: &str, role: Role, lang: Language) -> bool {
+    let name = name.trim_matches('_');
+    if name.is_empty() {
+        return true;
+    }
+    match (lang, role) {
+        (Language::Go, _) => is_pascal(name) || is_camel(name),
+        (_, Role::Type) => is_pascal(name),
+        (Language::Rust, Role::Constant) => is_screaming_snake(name),
+        (Language::Rust, _) => is_snake(name),
+        (Language::Python, Role::Function) => is_snake(name),
+        // Module-level constants are SCREAMING_SNAKE by PEP 8.
+        (Language::Python, _) => is_snake(name) || is_screaming_snake(name),
+        // PascalCase for components and constructors.
+        (Language::JavaScript, Role::Function) => is_camel(name) || is_pascal(name),
+        (Language::JavaScript, _) => is_camel(name) || is_pascal(name) || is_screaming_snake(name),
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roles(source: &str, lang: Language) -> Vec<(String, Role)> {
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&crate::language::get_ts_language(lang).unwrap()).unwrap();
+        identifier_roles(&parser.parse(source, None).unwrap(), source, lang)
+    }
+
+    fn breaking(source: &str, lang: Language) -> Vec<String> {
+        roles(source, lang)
+            .into_iter()
+            .filter(|(name, role)| !follows_convention(name, *role, lang))
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    #[test]
+    fn rust_types_and_functions_may_differ_in_case() {
+        let source = "struct HttpClient;\nconst MAX_RETRIES: u32 = 3;\n\
+                      fn send_request(_retry_count: u32) {\n    let response_body = 1;\n\
+                      \x20   let bodyLen = 2;\n}\n";
+        let roles = roles(source, Language::Rust);
+        assert_eq!(roles.len(), 6, "{roles:?}");
+        assert_eq!(breaking(source, Language::Rust), ["bodyLen"]);
+    }
+
+    #[test]
+    fn python_classes_functions_and_constants() {
+        let source = "MAX_SIZE = 10\nclass UserStore:\n    def __init__(self, maxSize=1):\n        \
+                      userName = None\n\ndef loadUsers(path: str):\n    pass\n";
+        assert_eq!(breaking(source, Language::Python), ["maxSize", "userName", "loadUsers"]);
+    }
+
+    #[test]
+    fn javascript_and_go_conventions() {
+        let source = "const API_URL = 'x';\nclass Cache {}\nconst Button = () => null;\n\
+                      function fetch_data(user_id) {\n  let itemCount = 0;\n}\n";
+        assert_eq!(breaking(source, Language::JavaScript), ["fetch_data", "user_id"]);
+
+        let source = "package main\n\ntype userStore struct{}\n\nfunc LoadAll(max_items int) {\n\t\
+                      total_count := 0\n\tnextID := 1\n}\n";
+        assert_eq!(breaking(source, Language::Go), ["max_items", "total_count"]);
+    }
+}
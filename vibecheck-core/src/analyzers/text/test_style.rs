@@ -0,0 +1,475 @@
+//! Heuristics for test code.
+//!
+//! Not part of [`crate::analyzers::default_analyzers`]: the pipeline runs
+//! this analyzer only for files [`crate::language::is_test_file`] accepts,
+//! since test-shaped patterns mean something different in production code.
+
+use crate::analyzers::{Analyzer, DEFAULT_MIN_LINES};
+use crate::heuristics::signal_ids;
+use crate::report::{ModelFamily, Signal};
+
+pub struct TestStyleAnalyzer;
+
+/// Words in test names that mark a deliberate edge-case probe.
+const EDGE_CASE_WORDS: &[&str] = &[
+    "empty",
+    "zero",
+    "negative",
+    "null",
+    "none",
+    "nil",
+    "boundary",
+    "overflow",
+    "unicode",
+    "large",
+    "max",
+    "min",
+    "single",
+    "whitespace",
+    "invalid",
+    "missing",
+    "duplicate",
+];
+
+/// What a language-specific scan found in a test file.
+#[derive(Default)]
+struct TestScan {
+    /// `(name, body line count)` per test case.
+    cases: Vec<(String, usize)>,
+    assertions: usize,
+    /// Assertions that carry a custom failure message.
+    with_message: usize,
+}
+
+/// Signal IDs for one language, in the order
+/// `[exhaustive_cases, assert_messages, edge_case_names]`.
+type TestSignalIds = [&'static str; 3];
+
+impl TestStyleAnalyzer {
+    fn signals_from_scan(&self, scan: &TestScan, ids: TestSignalIds) -> Vec<Signal> {
+        let mut signals = Vec::new();
+        let [exhaustive_id, messages_id, edge_id] = ids;
+
+        let case_count = scan.cases.len();
+        if case_count >= 8 {
+            let avg = scan.cases.iter().map(|(_, len)| len).sum::<usize>() as f64
+                / case_count as f64;
+            if avg <= 8.0 {
+                signals.push(Signal::new(
+                    exhaustive_id,
+                    self.name(),
+                    format!(
+                        "{case_count} test cases averaging {avg:.1} lines — one micro-case per test"
+                    ),
+                    ModelFamily::Gpt,
+                    1.0,
+                ));
+            }
+        }
+
+        if scan.assertions >= 5 {
+            let ratio = scan.with_message as f64 / scan.assertions as f64;
+            if ratio >= 0.6 {
+                signals.push(Signal::new(
+                    messages_id,
+                    self.name(),
+                    format!(
+                        "{:.0}% of assertions carry a custom failure message",
+                        ratio * 100.0
+                    ),
+                    ModelFamily::Claude,
+                    1.0,
+                ));
+            }
+        }
+
+        let mut probed: Vec<&str> = EDGE_CASE_WORDS
+            .iter()
+            .copied()
+            .filter(|word| {
+                scan.cases
+                    .iter()
+                    .any(|(name, _)| name_words(name).any(|w| w == *word))
+            })
+            .collect();
+        probed.sort_unstable();
+        if probed.len() >= 3 {
+            signals.push(Signal::new(
+                edge_id,
+                self.name(),
+                format!("Test names probe {} edge cases ({})", probed.len(), probed.join(", ")),
+                ModelFamily::Gemini,
+                0.8,
+            ));
+        }
+
+        signals
+    }
+
+    fn analyze_rust_impl(&self, source: &str) -> Vec<Signal> {
+        let lines: Vec<&str> = source.lines().collect();
+        let mut scan = TestScan::default();
+        let mut pending_test = false;
+        for (i, line) in lines.iter().enumerate() {
+            let trimmed = line.trim();
+            if trimmed == "#[test]" || trimmed.starts_with("#[tokio::test") {
+                pending_test = true;
+                continue;
+            }
+            if pending_test && !trimmed.starts_with("#[") {
+                pending_test = false;
+                if let Some(name) = trimmed
+                    .split("fn ")
+                    .nth(1)
+                    .and_then(|rest| rest.split('(').next())
+                {
+                    scan.cases.push((name.trim().to_string(), brace_block_len(&lines, i)));
+                }
+            }
+            for (mac, required) in [("assert_eq!(", 2), ("assert_ne!(", 2), ("assert!(", 1)] {
+                if let Some(pos) = trimmed.find(mac) {
+                    count_assertion(&mut scan, &trimmed[pos + mac.len()..], required);
+                    break;
+                }
+            }
+        }
+        self.signals_from_scan(
+            &scan,
+            [
+                signal_ids::RUST_TESTS_EXHAUSTIVE_CASES,
+                signal_ids::RUST_TESTS_ASSERT_MESSAGES,
+                signal_ids::RUST_TESTS_EDGE_CASE_NAMES,
+            ],
+        )
+    }
+
+    fn analyze_python_impl(&self, source: &str) -> Vec<Signal> {
+        let lines: Vec<&str> = source.lines().collect();
+        let mut scan = TestScan::default();
+        for (i, line) in lines.iter().enumerate() {
+            let trimmed = line.trim();
+            if let Some(rest) = trimmed.strip_prefix("def test_") {
+                let name = rest.split('(').next().unwrap_or("");
+                let indent = indent_of(line);
+                let body = lines[i + 1..]
+                    .iter()
+                    .take_while(|l| l.trim().is_empty() || indent_of(l) > indent)
+                    .filter(|l| !l.trim().is_empty())
+                    .count();
+                scan.cases.push((name.to_string(), body + 1));
+            } else if let Some(rest) = trimmed.strip_prefix("assert ") {
+                let args = top_level_args(&format!("{rest})"));
+                scan.assertions += 1;
+                if args.len() >= 2 {
+                    scan.with_message += 1;
+                }
+            } else if let Some(pos) = trimmed.find("self.assert") {
+                let call = &trimmed[pos + "self.".len()..];
+                let Some((method, args)) = call.split_once('(') else {
+                    continue;
+                };
+                let required = match method {
+                    "assertTrue" | "assertFalse" | "assertIsNone" | "assertIsNotNone" => 1,
+                    _ => 2,
+                };
+                if args.contains("msg=") {
+                    scan.assertions += 1;
+                    scan.with_message += 1;
+                } else {
+                    count_assertion(&mut scan, args, required);
+                }
+            }
+        }
+        self.signals_from_scan(
+            &scan,
+            [
+                signal_ids::PYTHON_TESTS_EXHAUSTIVE_CASES,
+                signal_ids::PYTHON_TESTS_ASSERT_MESSAGES,
+                signal_ids::PYTHON_TESTS_EDGE_CASE_NAMES,
+            ],
+        )
+    }
+
+    fn analyze_javascript_impl(&self, source: &str) -> Vec<Signal> {
+        let lines: Vec<&str> = source.lines().collect();
+        let mut scan = TestScan::default();
+        for (i, line) in lines.iter().enumerate() {
+            let trimmed = line.trim();
+            if let Some(name) = js_test_title(trimmed) {
+                scan.cases.push((name, brace_block_len(&lines, i)));
+            }
+            if let Some(pos) = trimmed.find("expect(") {
+                count_assertion(&mut scan, &trimmed[pos + "expect(".len()..], 1);
+            } else if let Some(pos) = trimmed.find("assert.") {
+                if let Some(args) = trimmed[pos..].split_once('(').map(|(_, a)| a) {
+                    let required = if trimmed[pos..].starts_with("assert.ok(") { 1 } else { 2 };
+                    count_assertion(&mut scan, args, required);
+                }
+            } else if let Some(pos) = trimmed.find("assert(") {
+                count_assertion(&mut scan, &trimmed[pos + "assert(".len()..], 1);
+            }
+        }
+        self.signals_from_scan(
+            &scan,
+            [
+                signal_ids::JS_TESTS_EXHAUSTIVE_CASES,
+                signal_ids::JS_TESTS_ASSERT_MESSAGES,
+                signal_ids::JS_TESTS_EDGE_CASE_NAMES,
+            ],
+        )
+    }
+
+    fn analyze_go_impl(&self, source: &str) -> Vec<Signal> {
+        let lines: Vec<&str> = source.lines().collect();
+        let mut scan = TestScan::default();
+        for (i, line) in lines.iter().enumerate() {
+            let trimmed = line.trim();
+            if let Some(rest) = trimmed.strip_prefix("func Test") {
+                if rest.contains("*testing.T") {
+                    let name = rest.split('(').next().unwrap_or("");
+                    scan.cases.push((name.to_string(), brace_block_len(&lines, i)));
+                }
+                continue;
+            }
+            // Table-driven rows: `{name: "empty input", ...}` or `{"empty input", ...}`.
+            if let Some(row) = trimmed.strip_prefix('{') {
+                let row = row.trim_start();
+                let row = row.strip_prefix("name:").map(str::trim_start).unwrap_or(row);
+                if let Some(name) = row.strip_prefix('"').and_then(|r| r.split('"').next()) {
+                    scan.cases.push((name.to_string(), 1));
+                }
+            }
+            if let Some(pos) = trimmed.find("t.Errorf(").or_else(|| trimmed.find("t.Fatalf(")) {
+                let args = top_level_args(&trimmed[pos + "t.Errorf(".len()..]);
+                scan.assertions += 1;
+                let words = args.first().map(|m| m.split_whitespace().count()).unwrap_or(0);
+                if words >= 6 {
+                    scan.with_message += 1;
+                }
+            } else if let Some(pos) = trimmed.find("assert.").or_else(|| trimmed.find("require.")) {
+                if let Some((method, args)) = trimmed[pos..].split_once('(') {
+                    let required = match method.rsplit('.').next().unwrap_or("") {
+                        "True" | "False" | "Nil" | "NotNil" | "NoError" | "Error" | "Empty" => 2,
+                        _ => 3,
+                    };
+                    count_assertion(&mut scan, args, required);
+                }
+            }
+        }
+        self.signals_from_scan(
+            &scan,
+            [
+                signal_ids::GO_TESTS_EXHAUSTIVE_CASES,
+                signal_ids::GO_TESTS_ASSERT_MESSAGES,
+                signal_ids::GO_TESTS_EDGE_CASE_NAMES,
+            ],
+        )
+    }
+}
+
+impl Analyzer for TestStyleAnalyzer {
+    fn name(&self) -> &str {
+        "tests"
+    }
+
+    fn min_lines(&self) -> usize {
+        DEFAULT_MIN_LINES
+    }
+
+    fn analyze_python(&self, source: &str) -> Vec<Signal> { self.analyze_python_impl(source) }
+    fn analyze_javascript(&self, source: &str) -> Vec<Signal> { self.analyze_javascript_impl(source) }
+    fn analyze_go(&self, source: &str) -> Vec<Signal> { self.analyze_go_impl(source) }
+
+    fn analyze(&self, source: &str) -> Vec<Signal> {
+        self.analyze_rust_impl(source)
+    }
+}
+
+/// Record one assertion whose argument list starts at `args` (just past the
+/// opening paren); anything beyond `required` arguments is a message.
+fn count_assertion(scan: &mut TestScan, args: &str, required: usize) {
+    scan.assertions += 1;
+    if top_level_args(args).len() > required {
+        scan.with_message += 1;
+    }
+}
+
+/// Split a call's arguments (text after the opening paren) on top-level
+/// commas, stopping at the matching close paren.  Single-line only: an
+/// argument list that continues onto the next line is cut short.
+fn top_level_args(s: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    for c in s.chars() {
+        if let Some(q) = quote {
+            current.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' | '`' => {
+                quote = Some(c);
+                current.push(c);
+            }
+            '(' | '[' | '{' => {
+                depth += 1;
+                current.push(c);
+            }
+            ')' | ']' | '}' if depth == 0 => break,
+            ')' | ']' | '}' => {
+                depth -= 1;
+                current.push(c);
+            }
+            ',' if depth == 0 => args.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    args.push(current);
+    args.into_iter()
+        .map(|a| a.trim().to_string())
+        .filter(|a| !a.is_empty())
+        .collect()
+}
+
+/// Lines from `start` through the line that closes the first `{` opened on
+/// or after it.  Falls back to 1 when no block opens.
+fn brace_block_len(lines: &[&str], start: usize) -> usize {
+    let mut depth = 0i32;
+    let mut opened = false;
+    for (offset, line) in lines[start..].iter().enumerate() {
+        for c in line.chars() {
+            match c {
+                '{' => {
+                    depth += 1;
+                    opened = true;
+                }
+                '}' => depth -= 1,
+                _ => {}
+            }
+        }
+        if opened && depth <= 0 {
+            return offset + 1;
+        }
+    }
+    1
+}
+
+/// Title of an `it("...")` / `test("...")` call (including `.only`/`.skip`).
+fn js_test_title(trimmed: &str) -> Option<String> {
+    let rest = trimmed
+        .strip_prefix("it")
+        .or_else(|| trimmed.strip_prefix("test"))?;
+    let rest = rest
+        .strip_prefix(".only")
+        .or_else(|| rest.strip_prefix(".skip"))
+        .unwrap_or(rest);
+    let rest = rest.strip_prefix('(')?.trim_start();
+    let quote = rest.chars().next().filter(|c| matches!(c, '"' | '\'' | '`'))?;
+    rest[1..].split(quote).next().map(str::to_string)
+}
+
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+/// Lowercase words of a test name, splitting snake_case, camelCase, and
+/// spaces (`testEmptyInput`, `test_empty_input`, `"handles empty input"`).
+fn name_words(name: &str) -> impl Iterator<Item = String> + '_ {
+    let mut spaced = String::with_capacity(name.len() + 8);
+    let mut prev_lower = false;
+    for c in name.chars() {
+        if c.is_uppercase() && prev_lower {
+            spaced.push(' ');
+        }
+        prev_lower = c.is_lowercase() || c.is_ascii_digit();
+        spaced.push(if c.is_alphanumeric() { c } else { ' ' });
+    }
+    spaced
+        .split_whitespace()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .into_iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(signals: &[Signal]) -> Vec<&str> {
+        signals.iter().map(|s| s.id.as_str()).collect()
+    }
+
+    #[test]
+    fn rust_micro_cases_with_messages_and_edge_names() {
+        let mut source = String::from("#[cfg(test)]\nmod tests {\n    use super::*;\n\n");
+        for name in [
+            "empty_input", "zero_length", "negative_offset", "unicode_name",
+            "single_item", "large_batch", "max_value", "plain_case",
+        ] {
+            source.push_str(&format!(
+                "    #[test]\n    fn {name}() {{\n        assert_eq!(parse(\"{name}\"), 1, \"{name} should parse\");\n    }}\n\n"
+            ));
+        }
+        source.push_str("}\n");
+        let signals = TestStyleAnalyzer.analyze(&source);
+        let ids = ids(&signals);
+        assert!(ids.contains(&signal_ids::RUST_TESTS_EXHAUSTIVE_CASES), "{ids:?}");
+        assert!(ids.contains(&signal_ids::RUST_TESTS_ASSERT_MESSAGES), "{ids:?}");
+        assert!(ids.contains(&signal_ids::RUST_TESTS_EDGE_CASE_NAMES), "{ids:?}");
+    }
+
+    #[test]
+    fn rust_bare_asserts_have_no_message() {
+        let source = "#[test]\nfn roundtrip() {\n".to_string()
+            + &"    assert_eq!(decode(encode(x)), x);\n".repeat(10)
+            + "}\n";
+        let signals = TestStyleAnalyzer.analyze(&source);
+        assert!(signals.is_empty(), "{:?}", ids(&signals));
+    }
+
+    #[test]
+    fn python_unittest_messages_counted() {
+        let source = "class T(unittest.TestCase):\n".to_string()
+            + &(0..6)
+                .map(|i| format!(
+                    "    def test_case_{i}(self):\n        self.assertEqual(f({i}), {i}, msg=\"f is identity\")\n"
+                ))
+                .collect::<String>();
+        let ids = ids(&TestStyleAnalyzer.analyze_python(&source)).join(",");
+        assert!(ids.contains(signal_ids::PYTHON_TESTS_ASSERT_MESSAGES), "{ids}");
+    }
+
+    #[test]
+    fn js_test_titles_and_go_table_rows_are_cases() {
+        assert_eq!(js_test_title("it('handles empty input', () => {"), Some("handles empty input".into()));
+        assert_eq!(js_test_title("test.only(\"x\", async () => {"), Some("x".into()));
+        assert_eq!(js_test_title("items.forEach(x => {"), None);
+
+        let source = "func TestParse(t *testing.T) {\n\ttests := []struct{ name string; in string }{\n".to_string()
+            + "\t\t{name: \"empty input\", in: \"\"},\n\t\t{\"nil map\", \"x\"},\n\t\t{\"negative count\", \"-1\"},\n"
+            + "\t}\n\tfor _, tt := range tests {\n\t\t_ = tt\n\t}\n}\n\n\n";
+        let ids = ids(&TestStyleAnalyzer.analyze_go(&source)).join(",");
+        assert!(ids.contains(signal_ids::GO_TESTS_EDGE_CASE_NAMES), "{ids}");
+    }
+
+    #[test]
+    fn top_level_args_ignores_nested_commas_and_strings() {
+        assert_eq!(top_level_args("f(a, b), \"x, y\");"), vec!["f(a, b)", "\"x, y\""]);
+        assert_eq!(top_level_args("a, b,)"), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn name_words_splits_camel_and_snake() {
+        let words: Vec<_> = name_words("TestEmptyInput_nil").collect();
+        assert_eq!(words, vec!["test", "empty", "input", "nil"]);
+    }
+}
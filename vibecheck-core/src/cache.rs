@@ -1,21 +1,30 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
-use std::sync::{Mutex, OnceLock};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, SystemTime};
 
-use redb::{Database, TableDefinition};
+use redb::{Database, ReadableTable, ReadableTableMetadata, TableDefinition};
 use sha2::{Digest, Sha256};
 
 use crate::merkle::DirNode;
-use crate::report::{Report, SymbolReport};
+use crate::report::{analyzer_version, Report, SymbolReport};
 
-/// SHA-256 of the embedded heuristics.toml, computed once.
-/// Mixed into every content hash so cache entries auto-invalidate
-/// when signal definitions change.
+/// Bump when cached report types gain fields that old entries would
+/// silently default (e.g. per-symbol complexity in `SymbolMetadata`), or
+/// when existing fields change meaning (e.g. symbol spans now covering
+/// decorators).
+const REPORT_SCHEMA_VERSION: u32 = 5;
+
+/// SHA-256 of the embedded heuristics.toml and report schema version,
+/// computed once.  Mixed into every content hash so cache entries
+/// auto-invalidate when signal definitions or report shapes change.
 fn heuristics_epoch() -> &'static [u8; 32] {
     static EPOCH: OnceLock<[u8; 32]> = OnceLock::new();
     EPOCH.get_or_init(|| {
         let mut h = Sha256::new();
         h.update(include_str!("../heuristics.toml").as_bytes());
+        h.update(REPORT_SCHEMA_VERSION.to_le_bytes());
         let result = h.finalize();
         let mut hash = [0u8; 32];
         hash.copy_from_slice(&result);
@@ -48,6 +57,25 @@ impl std::error::Error for CacheError {
     }
 }
 
+/// Snapshot of cache health, returned by [`Cache::stats`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Stored entries: reports, symbol lists, and directory hashes.
+    pub entries: u64,
+    /// Size of the database file; `None` for in-memory backends.
+    pub size_on_disk: Option<u64>,
+    /// Write time of the oldest entry that recorded one.
+    pub oldest_entry: Option<SystemTime>,
+    /// Report and symbol lookups answered from the cache by this process.
+    pub hits: u64,
+    /// Report and symbol lookups this process had to analyze afresh.
+    pub misses: u64,
+    /// Hits recorded by every process that used this cache, this one included.
+    pub lifetime_hits: u64,
+    /// Misses recorded by every process that used this cache, this one included.
+    pub lifetime_misses: u64,
+}
+
 /// Low-level key-value cache backend. Implementations handle raw bytes;
 /// higher-level typed access is provided by [`Cache`].
 pub trait CacheBackend: Send + Sync {
@@ -55,6 +83,19 @@ pub trait CacheBackend: Send + Sync {
     fn put(&self, key: &[u8], value: &[u8]) -> Result<(), CacheError>;
     fn delete(&self, key: &[u8]) -> Result<(), CacheError>;
     fn contains(&self, key: &[u8]) -> Result<bool, CacheError>;
+
+    /// Entry count, footprint, oldest write time, and persisted lookup
+    /// counters, as far as the backend tracks them.  The per-process
+    /// `hits`/`misses` are filled in by [`Cache::stats`].
+    fn stats(&self) -> Result<CacheStats, CacheError> {
+        Ok(CacheStats::default())
+    }
+
+    /// Add to the persisted lookup counters.  Backends without persistent
+    /// storage may ignore this.
+    fn record_lookups(&self, _hits: u64, _misses: u64) -> Result<(), CacheError> {
+        Ok(())
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -62,21 +103,32 @@ pub trait CacheBackend: Send + Sync {
 // ---------------------------------------------------------------------------
 
 const KV_TABLE: TableDefinition<&[u8], &[u8]> = TableDefinition::new("kv_v2");
+/// Unix time (seconds) each `KV_TABLE` key was last written.
+const WRITTEN_TABLE: TableDefinition<&[u8], u64> = TableDefinition::new("written_v1");
+/// Lifetime `"hits"` / `"misses"` counters.
+const LOOKUPS_TABLE: TableDefinition<&str, u64> = TableDefinition::new("lookups_v1");
 
 /// Persistent cache backend backed by a redb embedded database.
 pub struct RedbBackend {
     db: Database,
+    path: PathBuf,
 }
 
 impl RedbBackend {
     pub fn open(dir: &Path) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
         std::fs::create_dir_all(dir)?;
-        let db_path = dir.join("cache.redb");
-        let db = Database::create(&db_path)?;
-        Ok(Self { db })
+        let path = dir.join("cache.redb");
+        let db = Database::create(&path)?;
+        Ok(Self { db, path })
     }
 }
 
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
 impl CacheBackend for RedbBackend {
     fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, CacheError> {
         let read_txn = self.db.begin_read().map_err(|e| CacheError::Backend(e.into()))?;
@@ -96,6 +148,8 @@ impl CacheBackend for RedbBackend {
         {
             let mut table = write_txn.open_table(KV_TABLE).map_err(|e| CacheError::Backend(e.into()))?;
             table.insert(key, value).map_err(|e| CacheError::Backend(e.into()))?;
+            let mut written = write_txn.open_table(WRITTEN_TABLE).map_err(|e| CacheError::Backend(e.into()))?;
+            written.insert(key, unix_now()).map_err(|e| CacheError::Backend(e.into()))?;
         }
         write_txn.commit().map_err(|e| CacheError::Backend(e.into()))?;
         Ok(())
@@ -109,6 +163,9 @@ impl CacheBackend for RedbBackend {
                 Err(_) => return Ok(()),
             };
             table.remove(key).map_err(|e| CacheError::Backend(e.into()))?;
+            if let Ok(mut written) = write_txn.open_table(WRITTEN_TABLE) {
+                written.remove(key).map_err(|e| CacheError::Backend(e.into()))?;
+            }
         }
         write_txn.commit().map_err(|e| CacheError::Backend(e.into()))?;
         Ok(())
@@ -117,6 +174,52 @@ impl CacheBackend for RedbBackend {
     fn contains(&self, key: &[u8]) -> Result<bool, CacheError> {
         self.get(key).map(|v| v.is_some())
     }
+
+    fn stats(&self) -> Result<CacheStats, CacheError> {
+        let read_txn = self.db.begin_read().map_err(|e| CacheError::Backend(e.into()))?;
+        let mut stats = CacheStats {
+            size_on_disk: std::fs::metadata(&self.path).ok().map(|m| m.len()),
+            ..CacheStats::default()
+        };
+        // Tables are created on first write, so a missing one means empty.
+        if let Ok(table) = read_txn.open_table(KV_TABLE) {
+            stats.entries = table.len().map_err(|e| CacheError::Backend(e.into()))?;
+        }
+        if let Ok(written) = read_txn.open_table(WRITTEN_TABLE) {
+            let mut oldest: Option<u64> = None;
+            for entry in written.iter().map_err(|e| CacheError::Backend(e.into()))? {
+                let (_, time) = entry.map_err(|e| CacheError::Backend(e.into()))?;
+                oldest = Some(oldest.map_or(time.value(), |o| o.min(time.value())));
+            }
+            stats.oldest_entry = oldest.map(|secs| SystemTime::UNIX_EPOCH + Duration::from_secs(secs));
+        }
+        if let Ok(lookups) = read_txn.open_table(LOOKUPS_TABLE) {
+            for (name, counter) in [("hits", &mut stats.lifetime_hits), ("misses", &mut stats.lifetime_misses)] {
+                if let Some(value) = lookups.get(name).map_err(|e| CacheError::Backend(e.into()))? {
+                    *counter = value.value();
+                }
+            }
+        }
+        Ok(stats)
+    }
+
+    fn record_lookups(&self, hits: u64, misses: u64) -> Result<(), CacheError> {
+        let mut write_txn = self.db.begin_write().map_err(|e| CacheError::Backend(e.into()))?;
+        // Counters are advisory; don't pay for an fsync on every cache hit.
+        write_txn.set_durability(redb::Durability::Eventual);
+        {
+            let mut table = write_txn.open_table(LOOKUPS_TABLE).map_err(|e| CacheError::Backend(e.into()))?;
+            for (name, delta) in [("hits", hits), ("misses", misses)] {
+                let current = table
+                    .get(name)
+                    .map_err(|e| CacheError::Backend(e.into()))?
+                    .map_or(0, |v| v.value());
+                table.insert(name, current + delta).map_err(|e| CacheError::Backend(e.into()))?;
+            }
+        }
+        write_txn.commit().map_err(|e| CacheError::Backend(e.into()))?;
+        Ok(())
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -164,6 +267,108 @@ impl CacheBackend for InMemoryBackend {
         let store = self.store.lock().unwrap();
         Ok(store.contains_key(key))
     }
+
+    fn stats(&self) -> Result<CacheStats, CacheError> {
+        let store = self.store.lock().unwrap();
+        Ok(CacheStats {
+            entries: store.len() as u64,
+            ..CacheStats::default()
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// LruBackend
+// ---------------------------------------------------------------------------
+
+/// In-memory cache backend bounded to `capacity` entries.  Unlike
+/// [`InMemoryBackend`], a full cache evicts its least recently used entry
+/// to make room; `get` and `put` both count as a use.
+pub struct LruBackend {
+    state: Mutex<LruState>,
+    capacity: usize,
+}
+
+#[derive(Default)]
+struct LruState {
+    /// Key → (value, tick of last use).
+    entries: HashMap<Vec<u8>, (Vec<u8>, u64)>,
+    /// Tick of last use → key; the first entry is the eviction candidate.
+    recency: BTreeMap<u64, Vec<u8>>,
+    tick: u64,
+}
+
+impl LruState {
+    /// Mark `key` (which must be present) as just used.
+    fn touch(&mut self, key: &[u8]) {
+        self.tick += 1;
+        let tick = self.tick;
+        if let Some((_, last)) = self.entries.get_mut(key) {
+            self.recency.remove(last);
+            *last = tick;
+            self.recency.insert(tick, key.to_vec());
+        }
+    }
+}
+
+impl LruBackend {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            state: Mutex::new(LruState::default()),
+            capacity,
+        }
+    }
+}
+
+impl CacheBackend for LruBackend {
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, CacheError> {
+        let mut state = self.state.lock().unwrap();
+        let value = state.entries.get(key).map(|(v, _)| v.clone());
+        if value.is_some() {
+            state.touch(key);
+        }
+        Ok(value)
+    }
+
+    fn put(&self, key: &[u8], value: &[u8]) -> Result<(), CacheError> {
+        if self.capacity == 0 {
+            return Ok(());
+        }
+        let mut state = self.state.lock().unwrap();
+        if let Some((v, _)) = state.entries.get_mut(key) {
+            *v = value.to_vec();
+        } else {
+            if state.entries.len() >= self.capacity {
+                if let Some((_, oldest)) = state.recency.pop_first() {
+                    state.entries.remove(&oldest);
+                }
+            }
+            state.entries.insert(key.to_vec(), (value.to_vec(), 0));
+        }
+        state.touch(key);
+        Ok(())
+    }
+
+    fn delete(&self, key: &[u8]) -> Result<(), CacheError> {
+        let mut state = self.state.lock().unwrap();
+        if let Some((_, tick)) = state.entries.remove(key) {
+            state.recency.remove(&tick);
+        }
+        Ok(())
+    }
+
+    fn contains(&self, key: &[u8]) -> Result<bool, CacheError> {
+        let state = self.state.lock().unwrap();
+        Ok(state.entries.contains_key(key))
+    }
+
+    fn stats(&self) -> Result<CacheStats, CacheError> {
+        let state = self.state.lock().unwrap();
+        Ok(CacheStats {
+            entries: state.entries.len() as u64,
+            ..CacheStats::default()
+        })
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -211,16 +416,48 @@ impl CacheBackend for TieredBackend {
         }
         self.cold.contains(key)
     }
+
+    /// The cold tier holds every entry; the hot tier is a subset.
+    fn stats(&self) -> Result<CacheStats, CacheError> {
+        self.cold.stats()
+    }
+
+    fn record_lookups(&self, hits: u64, misses: u64) -> Result<(), CacheError> {
+        self.cold.record_lookups(hits, misses)
+    }
 }
 
 // ---------------------------------------------------------------------------
 // Cache — public API (unchanged signatures)
 // ---------------------------------------------------------------------------
 
+/// Report and symbol lookups by every [`Cache`] in this process.
+static PROCESS_HITS: AtomicU64 = AtomicU64::new(0);
+static PROCESS_MISSES: AtomicU64 = AtomicU64::new(0);
+
+/// Lookups against one on-disk cache not yet added to its lifetime counters.
+#[derive(Default)]
+struct PendingLookups {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+/// Pending lookups per cache directory opened by this process, shared by
+/// every [`Cache`] opened on that directory.
+fn pending_lookups() -> &'static Mutex<HashMap<PathBuf, Arc<PendingLookups>>> {
+    static PENDING: OnceLock<Mutex<HashMap<PathBuf, Arc<PendingLookups>>>> = OnceLock::new();
+    PENDING.get_or_init(Default::default)
+}
+
 /// Content-addressed cache for analysis reports, symbol data, and directory
 /// hashes. Backed by a [`CacheBackend`] (default: [`TieredBackend`]).
+///
+/// Report and symbol lookups are counted per process; [`Cache::flush_lookups`]
+/// adds them to the on-disk lifetime counters.
 pub struct Cache {
     backend: Box<dyn CacheBackend>,
+    /// Lookups not yet flushed, for caches opened on a directory.
+    pending: Option<Arc<PendingLookups>>,
 }
 
 impl Cache {
@@ -228,20 +465,78 @@ impl Cache {
     pub fn open(dir: &Path) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
         let cold = RedbBackend::open(dir)?;
         let hot = InMemoryBackend::new(1024);
-        Ok(Self {
-            backend: Box::new(TieredBackend::new(hot, cold)),
-        })
+        let pending = pending_lookups()
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(dir.to_path_buf())
+            .or_default()
+            .clone();
+        Ok(Self { pending: Some(pending), ..Self::with_backend(Box::new(TieredBackend::new(hot, cold))) })
+    }
+
+    /// A cache that lives only in memory and holds at most `capacity`
+    /// entries, evicting the least recently used.  Nothing touches disk, so
+    /// this suits long-running processes (an editor integration, a server)
+    /// that want bounded memory rather than a persistent cache.
+    pub fn in_memory(capacity: usize) -> Self {
+        Self::with_backend(Box::new(LruBackend::new(capacity)))
     }
 
     /// Construct a cache with a custom backend.
     pub fn with_backend(backend: Box<dyn CacheBackend>) -> Self {
-        Self { backend }
+        Self { backend, pending: None }
+    }
+
+    /// Entry count, on-disk size, oldest entry, and hit/miss counters.
+    ///
+    /// `hits`/`misses` cover report and symbol lookups by every cache in
+    /// this process; the lifetime counters add those recorded by earlier
+    /// processes.
+    pub fn stats(&self) -> Result<CacheStats, CacheError> {
+        let mut stats = self.backend.stats()?;
+        stats.hits = PROCESS_HITS.load(Ordering::Relaxed);
+        stats.misses = PROCESS_MISSES.load(Ordering::Relaxed);
+        if let Some(pending) = &self.pending {
+            stats.lifetime_hits += pending.hits.load(Ordering::Relaxed);
+            stats.lifetime_misses += pending.misses.load(Ordering::Relaxed);
+        }
+        Ok(stats)
+    }
+
+    /// Add this process's lookups to the lifetime counters of every cache
+    /// directory it opened.  Each flush is a write transaction, so call it
+    /// once when a run is done rather than per lookup.  Best effort: a
+    /// directory that cannot be opened keeps its counts for a later flush.
+    pub fn flush_lookups() {
+        let pending = pending_lookups().lock().unwrap_or_else(|e| e.into_inner());
+        for (dir, lookups) in pending.iter() {
+            let hits = lookups.hits.swap(0, Ordering::Relaxed);
+            let misses = lookups.misses.swap(0, Ordering::Relaxed);
+            // A cache removed since it was opened is not recreated.
+            if hits + misses == 0 || !dir.join("cache.redb").exists() {
+                continue;
+            }
+            let recorded = RedbBackend::open(dir).is_ok_and(|b| b.record_lookups(hits, misses).is_ok());
+            if !recorded {
+                lookups.hits.fetch_add(hits, Ordering::Relaxed);
+                lookups.misses.fetch_add(misses, Ordering::Relaxed);
+            }
+        }
+    }
+
+    fn count_lookup<T>(&self, found: Option<T>) -> Option<T> {
+        let hit = found.is_some();
+        (if hit { &PROCESS_HITS } else { &PROCESS_MISSES }).fetch_add(1, Ordering::Relaxed);
+        if let Some(pending) = &self.pending {
+            (if hit { &pending.hits } else { &pending.misses }).fetch_add(1, Ordering::Relaxed);
+        }
+        found
     }
 
     /// Resolve the cache directory, checking (in priority order):
     /// 1. Explicit override (e.g. from `.vibecheck` config `[cache] dir`)
     /// 2. `VIBECHECK_CACHE_DIR` environment variable
-    /// 3. Platform default: `~/.cache/vibecheck/`
+    /// 3. Platform default (see [`Cache::default_path`])
     pub fn resolve_path(config_override: Option<&Path>) -> PathBuf {
         if let Some(dir) = config_override {
             return dir.to_path_buf();
@@ -252,10 +547,12 @@ impl Cache {
         Self::default_path()
     }
 
-    /// Platform default cache directory: `~/.cache/vibecheck/` (Linux),
+    /// Platform default cache directory: `$XDG_CACHE_HOME/vibecheck/`
+    /// (Linux, falling back to `~/.cache/vibecheck/`),
     /// `~/Library/Caches/vibecheck/` (macOS), `%LOCALAPPDATA%/vibecheck/` (Windows).
     pub fn default_path() -> PathBuf {
-        dirs::cache_dir()
+        directories::BaseDirs::new()
+            .map(|b| b.cache_dir().to_path_buf())
             .unwrap_or_else(|| PathBuf::from(".cache"))
             .join("vibecheck")
     }
@@ -272,6 +569,19 @@ impl Cache {
         hash
     }
 
+    /// Like [`Cache::hash_content`], but also mixes in `salt` — typically a
+    /// fingerprint of the effective `.vibecheck` settings — so reports
+    /// produced under different weights never share a cache entry.
+    pub fn hash_content_with(content: &[u8], salt: &[u8]) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(Self::hash_content(content));
+        hasher.update(salt);
+        let result = hasher.finalize();
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(&result);
+        hash
+    }
+
     fn ns_key(ns: u8, key: &[u8]) -> Vec<u8> {
         let mut k = Vec::with_capacity(1 + key.len());
         k.push(ns);
@@ -280,10 +590,19 @@ impl Cache {
     }
 
     /// Look up a cached `Report` by file-content hash.
+    ///
+    /// Entries written by a build with a different [`analyzer_version`] are
+    /// treated as misses, so upgrading vibecheck never serves stale results.
     pub fn get(&self, hash: &[u8; 32]) -> Option<Report> {
         let key = Self::ns_key(NS_REPORT, hash);
-        let bytes = self.backend.get(&key).ok()??;
-        serde_json::from_slice(&bytes).ok()
+        let report = self
+            .backend
+            .get(&key)
+            .ok()
+            .flatten()
+            .and_then(|bytes| serde_json::from_slice::<Report>(&bytes).ok())
+            .filter(|r| r.metadata.analyzer_version == analyzer_version());
+        self.count_lookup(report)
     }
 
     /// Store a `Report` under the given file-content hash.
@@ -301,8 +620,13 @@ impl Cache {
     /// Look up cached `SymbolReport`s by file-content hash.
     pub fn get_symbols(&self, hash: &[u8; 32]) -> Option<Vec<SymbolReport>> {
         let key = Self::ns_key(NS_SYMBOL, hash);
-        let bytes = self.backend.get(&key).ok()??;
-        serde_json::from_slice(&bytes).ok()
+        let symbols = self
+            .backend
+            .get(&key)
+            .ok()
+            .flatten()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok());
+        self.count_lookup(symbols)
     }
 
     /// Store `SymbolReport`s under the given file-content hash.
@@ -364,6 +688,12 @@ mod tests {
                 file_path: None,
                 lines_of_code: 10,
                 signal_count: 0,
+                analyzer_version: analyzer_version().to_string(),
+                generated: false,
+                timed_out: false,
+                parse_errors: 0,
+                language: None,
+                lossy: false,
             },
             symbol_reports: None,
         };
@@ -374,6 +704,25 @@ mod tests {
         assert_eq!(retrieved.attribution.primary, ModelFamily::Claude);
     }
 
+    #[test]
+    fn file_cache_rejects_reports_from_other_analyzer_versions() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Cache::open(dir.path()).unwrap();
+        let mut report = crate::analyze("fn main() {}");
+        assert_eq!(report.metadata.analyzer_version, analyzer_version());
+
+        report.metadata.analyzer_version = "0ld0ld".into();
+        cache.put(&[1u8; 32], &report).unwrap();
+        assert!(cache.get(&[1u8; 32]).is_none());
+
+        // Entries predating the field deserialize with an empty version.
+        let mut json = serde_json::to_value(&report).unwrap();
+        json["metadata"].as_object_mut().unwrap().remove("analyzer_version");
+        let key = Cache::ns_key(NS_REPORT, &[2u8; 32]);
+        cache.backend.put(&key, &serde_json::to_vec(&json).unwrap()).unwrap();
+        assert!(cache.get(&[2u8; 32]).is_none());
+    }
+
     #[test]
     fn file_cache_miss_returns_none() {
         let dir = tempfile::tempdir().unwrap();
@@ -421,6 +770,7 @@ mod tests {
                 kind: "function".to_string(),
                 start_line: 1,
                 end_line: 5,
+                ..Default::default()
             },
             attribution: Attribution {
                 primary: ModelFamily::Claude,
@@ -478,6 +828,80 @@ mod tests {
         assert_eq!(backend.get(b"a").unwrap().unwrap(), b"2");
     }
 
+    #[test]
+    fn lru_backend_evicts_least_recently_used() {
+        let backend = LruBackend::new(2);
+        backend.put(b"a", b"1").unwrap();
+        backend.put(b"b", b"2").unwrap();
+        backend.put(b"c", b"3").unwrap();
+        assert!(!backend.contains(b"a").unwrap(), "oldest entry evicted");
+        assert!(backend.contains(b"b").unwrap());
+        assert!(backend.contains(b"c").unwrap());
+        assert_eq!(backend.stats().unwrap().entries, 2);
+    }
+
+    #[test]
+    fn lru_backend_get_and_update_refresh_recency() {
+        let backend = LruBackend::new(2);
+        backend.put(b"a", b"1").unwrap();
+        backend.put(b"b", b"2").unwrap();
+        backend.get(b"a").unwrap();
+        backend.put(b"c", b"3").unwrap();
+        assert!(backend.contains(b"a").unwrap(), "read refreshed a");
+        assert!(!backend.contains(b"b").unwrap());
+
+        backend.put(b"a", b"4").unwrap();
+        backend.put(b"d", b"5").unwrap();
+        assert_eq!(backend.get(b"a").unwrap().unwrap(), b"4");
+        assert!(!backend.contains(b"c").unwrap());
+
+        backend.delete(b"a").unwrap();
+        backend.put(b"e", b"6").unwrap();
+        assert!(backend.contains(b"d").unwrap(), "delete freed a slot");
+        assert_eq!(backend.stats().unwrap().entries, 2);
+    }
+
+    #[test]
+    fn lru_backend_with_zero_capacity_stores_nothing() {
+        let backend = LruBackend::new(0);
+        backend.put(b"a", b"1").unwrap();
+        assert!(backend.get(b"a").unwrap().is_none());
+    }
+
+    #[test]
+    fn in_memory_cache_evicts_reports() {
+        use crate::report::{Attribution, ModelFamily, ReportMetadata};
+
+        let cache = Cache::in_memory(2);
+        let report = Report {
+            attribution: Attribution {
+                primary: ModelFamily::Human,
+                confidence: 0.5,
+                scores: HashMap::from([(ModelFamily::Human, 0.5)]),
+            },
+            signals: vec![],
+            metadata: ReportMetadata {
+                file_path: None,
+                lines_of_code: 1,
+                signal_count: 0,
+                analyzer_version: analyzer_version().to_string(),
+                generated: false,
+                timed_out: false,
+                parse_errors: 0,
+                language: None,
+                lossy: false,
+            },
+            symbol_reports: None,
+        };
+        for i in 0..3u8 {
+            cache.put(&[i; 32], &report).unwrap();
+        }
+        assert!(cache.get(&[0u8; 32]).is_none());
+        assert!(cache.get(&[2u8; 32]).is_some());
+        let stats = cache.stats().unwrap();
+        assert_eq!((stats.entries, stats.size_on_disk), (2, None));
+    }
+
     #[test]
     fn redb_backend_round_trip() {
         let dir = tempfile::tempdir().unwrap();
@@ -541,6 +965,12 @@ mod tests {
                 file_path: None,
                 lines_of_code: 1,
                 signal_count: 0,
+                analyzer_version: analyzer_version().to_string(),
+                generated: false,
+                timed_out: false,
+                parse_errors: 0,
+                language: None,
+                lossy: false,
             },
             symbol_reports: None,
         };
@@ -563,6 +993,36 @@ mod tests {
         assert!(resolved.ends_with("vibecheck"), "expected path ending with 'vibecheck', got: {resolved:?}");
     }
 
+    #[test]
+    fn stats_count_entries_lookups_and_age() {
+        let dir = tempfile::tempdir().unwrap();
+        let report = crate::analyze("fn main() {}");
+        let before = SystemTime::now() - Duration::from_secs(1);
+        {
+            let cache = Cache::open(dir.path()).unwrap();
+            assert_eq!(cache.stats().unwrap().entries, 0);
+            cache.put(&[1; 32], &report).unwrap();
+            cache.put_symbols(&[2; 32], &[]).unwrap();
+            assert!(cache.get(&[1; 32]).is_some());
+            assert!(cache.get(&[3; 32]).is_none());
+
+            let stats = cache.stats().unwrap();
+            assert_eq!(stats.entries, 2);
+            assert!(stats.size_on_disk.is_some_and(|n| n > 0));
+            assert!(stats.oldest_entry.is_some_and(|t| t >= before));
+            assert_eq!((stats.lifetime_hits, stats.lifetime_misses), (1, 1));
+            assert!(stats.hits >= 1 && stats.misses >= 1, "process counters include this cache");
+        }
+        Cache::flush_lookups();
+
+        // Counts survive into the next process that opens the cache.
+        let cache = Cache::open(dir.path()).unwrap();
+        assert!(cache.get_symbols(&[2; 32]).is_some());
+        let stats = cache.stats().unwrap();
+        assert_eq!((stats.lifetime_hits, stats.lifetime_misses), (2, 1));
+        assert_eq!(Cache::with_backend(Box::new(InMemoryBackend::new(4))).stats().unwrap().size_on_disk, None);
+    }
+
     #[test]
     fn default_path_ends_with_vibecheck() {
         let p = Cache::default_path();
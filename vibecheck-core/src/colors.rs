@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
 use crate::report::ModelFamily;
 
 /// Dependency-injection seam for model-family color mapping.
@@ -12,6 +15,12 @@ pub trait ColorTheme: Send + Sync {
     /// Returns an owned `String` so implementations can derive the value
     /// from [`ModelFamily::rgb`] without needing a static lookup table.
     fn svg_color(&self, family: ModelFamily) -> String;
+    /// 24-bit color for a `family` verdict at `confidence`, letting truecolor
+    /// terminals show certainty as saturation.  `None` (the default) keeps
+    /// the flat [`terminal_color`](Self::terminal_color).
+    fn confidence_rgb(&self, _family: ModelFamily, _confidence: f64) -> Option<(u8, u8, u8)> {
+        None
+    }
 }
 
 /// The default color theme — derives all colors from the canonical
@@ -26,6 +35,7 @@ impl ColorTheme for DefaultTheme {
             ModelFamily::Gemini  => "blue",
             ModelFamily::Copilot => "cyan",
             ModelFamily::Human   => "yellow",
+            ModelFamily::Custom(_) => "white",
         }
     }
 
@@ -33,21 +43,81 @@ impl ColorTheme for DefaultTheme {
         let (r, g, b) = family.rgb();
         format!("#{r:02x}{g:02x}{b:02x}")
     }
+
+    fn confidence_rgb(&self, family: ModelFamily, confidence: f64) -> Option<(u8, u8, u8)> {
+        Some(family.confidence_rgb(confidence))
+    }
+}
+
+/// Neutral grey shared by every custom family — distinct from all five
+/// built-in colors without implying a relationship to any of them.
+pub const CUSTOM_FAMILY_RGB: (u8, u8, u8) = (139, 148, 158);
+
+/// Muted grey that verdict colors fade toward as confidence drops.
+pub const LOW_CONFIDENCE_RGB: (u8, u8, u8) = (110, 118, 129);
+
+type ColorOverrides = RwLock<HashMap<ModelFamily, (u8, u8, u8)>>;
+
+/// Process-wide family color overrides, e.g. from a `.vibecheck` `[colors]`
+/// table.
+fn overrides() -> &'static ColorOverrides {
+    static OVERRIDES: OnceLock<ColorOverrides> = OnceLock::new();
+    OVERRIDES.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Recolor families for the rest of the process: every later
+/// [`ModelFamily::rgb`] call — and so the TUI, SVG output and the truecolor
+/// confidence gradient — returns the given color.  Families not listed keep
+/// their current color.  The 16-color [`ColorTheme::terminal_color`] names
+/// are unaffected, since they cannot represent an arbitrary RGB value.
+pub fn set_family_colors(colors: &[(ModelFamily, (u8, u8, u8))]) {
+    let mut map = overrides().write().unwrap_or_else(|e| e.into_inner());
+    map.extend(colors.iter().copied());
+}
+
+/// Parse a `#rrggbb` (or `rrggbb`) hex color.
+pub fn parse_hex(hex: &str) -> Option<(u8, u8, u8)> {
+    let hex = hex.trim();
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some((channel(0)?, channel(2)?, channel(4)?))
 }
 
 impl ModelFamily {
     /// Canonical RGB color for this model family — the **single source of truth**
-    /// for all color consumers (TUI, SVG, web, etc.).
+    /// for all color consumers (TUI, SVG, web, etc.).  Honours
+    /// [`set_family_colors`] overrides.
     pub fn rgb(self) -> (u8, u8, u8) {
+        let overridden = overrides().read().unwrap_or_else(|e| e.into_inner()).get(&self).copied();
+        overridden.unwrap_or_else(|| self.default_rgb())
+    }
+
+    /// The built-in color, ignoring overrides.
+    fn default_rgb(self) -> (u8, u8, u8) {
         match self {
             ModelFamily::Claude  => (210, 168, 255), // purple
             ModelFamily::Gpt     => (126, 231, 135), // green
             ModelFamily::Gemini  => (121, 192, 255), // blue
             ModelFamily::Copilot => ( 57, 197, 207), // teal
             ModelFamily::Human   => (227, 179,  65), // gold
+            ModelFamily::Custom(_) => CUSTOM_FAMILY_RGB,
         }
     }
 
+    /// [`Self::rgb`] faded toward [`LOW_CONFIDENCE_RGB`] by confidence: the
+    /// full color at 100%, 30% of it at a coin-flip 50% or below.
+    pub fn confidence_rgb(self, confidence: f64) -> (u8, u8, u8) {
+        let t = if confidence.is_nan() { 0.0 } else { ((confidence - 0.5) / 0.5).clamp(0.0, 1.0) };
+        let mix = 0.3 + 0.7 * t;
+        let lerp = |grey: u8, full: u8| (grey as f64 + (full as f64 - grey as f64) * mix).round() as u8;
+        let (r, g, b) = self.rgb();
+        let (gr, gg, gb) = LOW_CONFIDENCE_RGB;
+        (lerp(gr, r), lerp(gg, g), lerp(gb, b))
+    }
+
     /// Hex color used in SVG output — derived from [`Self::rgb`].
     pub fn svg_color(self) -> String {
         DefaultTheme.svg_color(self)
@@ -96,6 +166,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn confidence_rgb_saturates_with_confidence() {
+        let family = ModelFamily::Claude;
+        assert_eq!(family.confidence_rgb(1.0), family.rgb());
+        assert_eq!(family.confidence_rgb(0.2), family.confidence_rgb(0.5));
+        assert_eq!(family.confidence_rgb(f64::NAN), family.confidence_rgb(0.0));
+        // Distance from the muted grey grows with confidence.
+        let spread = |(r, g, b): (u8, u8, u8)| {
+            let (gr, gg, gb) = LOW_CONFIDENCE_RGB;
+            r.abs_diff(gr) as u32 + g.abs_diff(gg) as u32 + b.abs_diff(gb) as u32
+        };
+        assert!(spread(family.confidence_rgb(0.95)) > spread(family.confidence_rgb(0.55)));
+    }
+
+    #[test]
+    fn parse_hex_accepts_six_digit_colors() {
+        assert_eq!(parse_hex("#d2a8ff"), Some((210, 168, 255)));
+        assert_eq!(parse_hex("7EE787"), Some((126, 231, 135)));
+        assert_eq!(parse_hex("#fff"), None);
+        assert_eq!(parse_hex("#gggggg"), None);
+        assert_eq!(parse_hex("#ééé"), None);
+    }
+
+    #[test]
+    fn overrides_recolor_every_renderer() {
+        // A custom family, so the built-in palette tests are not disturbed.
+        let family = ModelFamily::custom("colors-test-model");
+        assert_eq!(family.rgb(), CUSTOM_FAMILY_RGB);
+        set_family_colors(&[(family, (255, 0, 128))]);
+        assert_eq!(family.rgb(), (255, 0, 128));
+        assert_eq!(family.svg_color(), "#ff0080");
+        assert_eq!(family.confidence_rgb(1.0), (255, 0, 128));
+    }
+
     #[test]
     fn abbrev_nonempty_for_all_families() {
         for family in [
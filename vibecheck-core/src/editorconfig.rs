@@ -0,0 +1,241 @@
+//! Minimal `.editorconfig` support: just enough to read the
+//! `max_line_length` a project declares for a file, so line-length signals
+//! can measure the project's own limit instead of a per-language guess.
+//!
+//! Discovery follows the EditorConfig rules: `.editorconfig` files are read
+//! from the file's directory upward, stopping after one that sets
+//! `root = true`; nearer files take precedence, and within a file the last
+//! matching section wins.  Section globs support `*`, `**`, `?`, `[...]`
+//! and `{a,b}`; numeric ranges (`{1..3}`) are not supported and never match.
+
+use std::path::Path;
+
+const EDITORCONFIG: &str = ".editorconfig";
+
+/// The `max_line_length` that `.editorconfig` sets for the file at `path`,
+/// or `None` when no `.editorconfig` applies, none of the matching sections
+/// set it, or it is set to `off`.
+pub fn max_line_length(path: &Path) -> Option<usize> {
+    let mut dir = path.parent();
+    while let Some(d) = dir {
+        if let Ok(text) = std::fs::read_to_string(d.join(EDITORCONFIG)) {
+            let file = parse(&text);
+            let relative = path.strip_prefix(d).unwrap_or(path);
+            let relative = relative.to_string_lossy().replace('\\', "/");
+            if let Some(value) = file.lookup(&relative, "max_line_length") {
+                return value.parse().ok();
+            }
+            if file.root {
+                break;
+            }
+        }
+        dir = d.parent();
+    }
+    None
+}
+
+/// One parsed `.editorconfig` file.
+#[derive(Debug, Default)]
+struct EditorConfig {
+    root: bool,
+    /// `(glob, [(key, value)])` in file order.  Keys and values are
+    /// lowercased, as the format is case-insensitive for both.
+    sections: Vec<(String, Vec<(String, String)>)>,
+}
+
+impl EditorConfig {
+    /// The value of `key` from the last section matching `relative` (a
+    /// `/`-separated path relative to this file's directory).
+    fn lookup(&self, relative: &str, key: &str) -> Option<&str> {
+        self.sections
+            .iter()
+            .rev()
+            .filter(|(glob, _)| section_matches(glob, relative))
+            .find_map(|(_, pairs)| pairs.iter().rev().find(|(k, _)| k == key))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+fn parse(text: &str) -> EditorConfig {
+    let mut config = EditorConfig::default();
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if let Some(glob) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            config.sections.push((glob.to_string(), Vec::new()));
+        } else if let Some((key, value)) = line.split_once('=') {
+            let key = key.trim().to_ascii_lowercase();
+            let value = value.trim().to_ascii_lowercase();
+            match config.sections.last_mut() {
+                Some((_, pairs)) => pairs.push((key, value)),
+                // Only `root` is meaningful before the first section.
+                None if key == "root" => config.root = value == "true",
+                None => {}
+            }
+        }
+    }
+    config
+}
+
+/// Whether section header `glob` applies to `relative`.  A glob without a
+/// `/` matches the file name in any directory; one with a `/` is anchored at
+/// the `.editorconfig` directory.
+fn section_matches(glob: &str, relative: &str) -> bool {
+    let glob = match glob.strip_prefix('/') {
+        Some(anchored) => anchored.to_string(),
+        None if glob.contains('/') => glob.to_string(),
+        None => format!("**/{glob}"),
+    };
+    expand_braces(&glob)
+        .iter()
+        .any(|g| glob_match(g.as_bytes(), relative.as_bytes()))
+}
+
+/// Expand `{a,b}` alternations into separate globs.  A brace group without a
+/// comma (including numeric ranges) is kept literal.
+fn expand_braces(glob: &str) -> Vec<String> {
+    let Some(open) = glob.find('{') else {
+        return vec![glob.to_string()];
+    };
+    let mut depth = 0;
+    let mut close = None;
+    for (i, c) in glob[open..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    close = Some(open + i);
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+    let Some(close) = close else {
+        return vec![glob.to_string()];
+    };
+    let (head, body, tail) = (&glob[..open], &glob[open + 1..close], &glob[close + 1..]);
+
+    // Split on top-level commas only, so nested groups expand recursively.
+    let mut alternatives = Vec::new();
+    let (mut depth, mut start) = (0, 0);
+    for (i, c) in body.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            ',' if depth == 0 => {
+                alternatives.push(&body[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    alternatives.push(&body[start..]);
+    if alternatives.len() == 1 {
+        return expand_braces(tail)
+            .into_iter()
+            .map(|t| format!("{head}{{{body}}}{t}"))
+            .collect();
+    }
+    alternatives
+        .into_iter()
+        .flat_map(|alt| expand_braces(&format!("{head}{alt}{tail}")))
+        .collect()
+}
+
+/// Match a brace-free glob against a `/`-separated path: `*` and `?` stop at
+/// `/`, `**` crosses it, and `[...]` / `[!...]` match one character.
+fn glob_match(glob: &[u8], path: &[u8]) -> bool {
+    match glob {
+        [] => path.is_empty(),
+        [b'*', b'*', b'/', rest @ ..] => {
+            // `**/` also matches zero directories.
+            glob_match(rest, path)
+                || (0..path.len()).any(|i| path[i] == b'/' && glob_match(rest, &path[i + 1..]))
+        }
+        [b'*', b'*', rest @ ..] => (0..=path.len()).any(|i| glob_match(rest, &path[i..])),
+        [b'*', rest @ ..] => (0..=path.len())
+            .take_while(|&i| i == 0 || path[i - 1] != b'/')
+            .any(|i| glob_match(rest, &path[i..])),
+        [b'?', rest @ ..] => matches!(path, [c, tail @ ..] if *c != b'/' && glob_match(rest, tail)),
+        [b'[', rest @ ..] => match rest.iter().position(|&c| c == b']') {
+            Some(end) if end > 0 => {
+                let (class, rest) = (&rest[..end], &rest[end + 1..]);
+                let (negated, class) = match class {
+                    [b'!', class @ ..] => (true, class),
+                    class => (false, class),
+                };
+                matches!(path, [c, tail @ ..]
+                    if *c != b'/' && class_contains(class, *c) != negated && glob_match(rest, tail))
+            }
+            _ => matches!(path, [b'[', tail @ ..] if glob_match(rest, tail)),
+        },
+        [b'\\', c, rest @ ..] | [c, rest @ ..] => {
+            matches!(path, [p, tail @ ..] if p == c && glob_match(rest, tail))
+        }
+    }
+}
+
+/// Whether character class body `class` (e.g. `a-z_`) contains `c`.
+fn class_contains(class: &[u8], c: u8) -> bool {
+    let mut i = 0;
+    while i < class.len() {
+        if i + 2 < class.len() && class[i + 1] == b'-' {
+            if (class[i]..=class[i + 2]).contains(&c) {
+                return true;
+            }
+            i += 3;
+        } else {
+            if class[i] == c {
+                return true;
+            }
+            i += 1;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn globs_follow_editorconfig_rules() {
+        assert!(section_matches("*", "src/lib.rs"));
+        assert!(section_matches("*.py", "pkg/mod.py"));
+        assert!(!section_matches("*.py", "pkg/mod.rs"));
+        assert!(section_matches("*.{js,ts}", "web/app.ts"));
+        assert!(section_matches("src/*.rs", "src/lib.rs"));
+        assert!(!section_matches("src/*.rs", "src/a/lib.rs"));
+        assert!(section_matches("/src/**.rs", "src/a/lib.rs"));
+        assert!(section_matches("lib/**/*.go", "lib/main.go"));
+        assert!(section_matches("[Mm]akefile", "Makefile"));
+        assert!(!section_matches("*.[!r]s", "lib.rs"));
+        assert!(!section_matches("file{1..3}.py", "file1.py"));
+    }
+
+    #[test]
+    fn nearest_matching_section_wins_and_root_stops_discovery() {
+        let dir = tempfile::tempdir().unwrap();
+        let sub = dir.path().join("sub");
+        std::fs::create_dir(&sub).unwrap();
+        std::fs::write(
+            dir.path().join(EDITORCONFIG),
+            "root = true\n\n[*]\nmax_line_length = 80\n\n[*.rs]\nmax_line_length = 110\n",
+        )
+        .unwrap();
+        assert_eq!(max_line_length(&dir.path().join("main.rs")), Some(110));
+        assert_eq!(max_line_length(&sub.join("app.py")), Some(80));
+
+        std::fs::write(sub.join(EDITORCONFIG), "[*.py]\nmax_line_length = 72\n").unwrap();
+        assert_eq!(max_line_length(&sub.join("app.py")), Some(72));
+        assert_eq!(max_line_length(&sub.join("app.go")), Some(80), "falls through to the parent");
+
+        std::fs::write(sub.join(EDITORCONFIG), "root = true\n[*.py]\nmax_line_length = off\n").unwrap();
+        assert_eq!(max_line_length(&sub.join("app.py")), None);
+        assert_eq!(max_line_length(&sub.join("app.go")), None, "root stops discovery");
+    }
+}
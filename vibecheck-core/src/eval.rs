@@ -0,0 +1,145 @@
+//! Attribution accuracy against a labeled corpus: a confusion matrix plus
+//! per-family precision, recall, and F1.
+//!
+//! Record one `(true family, predicted family)` pair per file with
+//! [`Evaluation::record`], then read the metrics back.  This is how weight
+//! changes should be validated: re-run the same corpus before and after and
+//! compare [`Evaluation::weighted_f1`].
+
+use std::collections::HashMap;
+
+use crate::report::ModelFamily;
+
+/// Precision, recall, and F1 for one family.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FamilyScore {
+    pub family: ModelFamily,
+    /// Share of files predicted as this family that really are; `0.0` when
+    /// nothing was predicted as it.
+    pub precision: f64,
+    /// Share of this family's files predicted as it; `0.0` without support.
+    pub recall: f64,
+    /// Harmonic mean of precision and recall; `0.0` when both are.
+    pub f1: f64,
+    /// Number of files truly of this family.
+    pub support: usize,
+}
+
+/// Confusion counts accumulated over a labeled corpus.
+#[derive(Debug, Clone, Default)]
+pub struct Evaluation {
+    /// `(true, predicted)` → file count.
+    counts: HashMap<(ModelFamily, ModelFamily), usize>,
+}
+
+impl Evaluation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record one file whose true family is `truth` and which was
+    /// attributed to `predicted`.
+    pub fn record(&mut self, truth: ModelFamily, predicted: ModelFamily) {
+        *self.counts.entry((truth, predicted)).or_default() += 1;
+    }
+
+    /// Number of recorded files.
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Files of family `truth` that were attributed to `predicted`.
+    pub fn count(&self, truth: ModelFamily, predicted: ModelFamily) -> usize {
+        self.counts.get(&(truth, predicted)).copied().unwrap_or(0)
+    }
+
+    /// Every family seen as a label or a prediction: built-in families in
+    /// [`ModelFamily::all`] order, then custom ones by name.  These are the
+    /// rows and columns of the confusion matrix.
+    pub fn families(&self) -> Vec<ModelFamily> {
+        let mut families: Vec<ModelFamily> = self.counts.keys().flat_map(|&(t, p)| [t, p]).collect();
+        families.sort_by_key(|f| (ModelFamily::all().iter().position(|b| b == f).unwrap_or(usize::MAX), f.name()));
+        families.dedup();
+        families
+    }
+
+    /// Share of files attributed to their true family; `0.0` when empty.
+    pub fn accuracy(&self) -> f64 {
+        let correct: usize = self.counts.iter().filter(|((t, p), _)| t == p).map(|(_, &n)| n).sum();
+        ratio(correct, self.total())
+    }
+
+    /// Precision, recall, and F1 for each of [`families`](Self::families).
+    pub fn scores(&self) -> Vec<FamilyScore> {
+        self.families()
+            .into_iter()
+            .map(|family| {
+                let hits = self.count(family, family);
+                let predicted: usize = self.counts.iter().filter(|((_, p), _)| *p == family).map(|(_, &n)| n).sum();
+                let support: usize = self.counts.iter().filter(|((t, _), _)| *t == family).map(|(_, &n)| n).sum();
+                let (precision, recall) = (ratio(hits, predicted), ratio(hits, support));
+                let f1 = if precision + recall > 0.0 {
+                    2.0 * precision * recall / (precision + recall)
+                } else {
+                    0.0
+                };
+                FamilyScore { family, precision, recall, f1, support }
+            })
+            .collect()
+    }
+
+    /// Mean F1 over families weighted by their support, so families with
+    /// more labeled files count for more; `0.0` when empty.
+    pub fn weighted_f1(&self) -> f64 {
+        let total = self.total();
+        if total == 0 {
+            return 0.0;
+        }
+        self.scores().iter().map(|s| s.f1 * s.support as f64).sum::<f64>() / total as f64
+    }
+}
+
+fn ratio(n: usize, d: usize) -> f64 {
+    if d == 0 {
+        0.0
+    } else {
+        n as f64 / d as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn metrics_from_confusion_counts() {
+        use ModelFamily::{Claude, Gpt, Human};
+        let mut eval = Evaluation::new();
+        for (truth, predicted, n) in [(Claude, Claude, 3), (Claude, Gpt, 1), (Human, Human, 4), (Human, Claude, 2)] {
+            for _ in 0..n {
+                eval.record(truth, predicted);
+            }
+        }
+        assert_eq!(eval.total(), 10);
+        assert_eq!(eval.families(), [Claude, Gpt, Human]);
+        assert_eq!(eval.count(Human, Claude), 2);
+        assert!((eval.accuracy() - 0.7).abs() < 1e-9);
+
+        let scores = eval.scores();
+        let claude = scores[0];
+        assert!((claude.precision - 0.6).abs() < 1e-9);
+        assert!((claude.recall - 0.75).abs() < 1e-9);
+        assert!((claude.f1 - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!((scores[1].support, scores[1].f1), (0, 0.0), "predicted but never labeled");
+
+        let human_f1 = 2.0 * (4.0 / 6.0) / (1.0 + 4.0 / 6.0);
+        assert!((eval.weighted_f1() - (claude.f1 * 4.0 + human_f1 * 6.0) / 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn empty_evaluation_is_all_zero() {
+        let eval = Evaluation::new();
+        assert_eq!((eval.total(), eval.accuracy(), eval.weighted_f1()), (0, 0.0, 0.0));
+        assert!(eval.scores().is_empty());
+    }
+}
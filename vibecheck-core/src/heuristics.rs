@@ -7,7 +7,9 @@
 //! # Production use
 //! [`ConfiguredHeuristics`] is the production implementation.  It reads a
 //! `[heuristics]` table from the `.vibecheck` config file and falls back to
-//! [`DefaultHeuristics`] for any signal not explicitly overridden.
+//! [`DefaultHeuristics`] for any signal not explicitly overridden.  A named
+//! weight profile from [`PROFILES`] can be layered underneath the explicit
+//! overrides to dial overall sensitivity.
 //!
 //! # Testing / DI
 //! [`InertHeuristics`] is a lightweight test double that always returns
@@ -42,6 +44,11 @@ pub enum HeuristicLanguage {
     Js,
     /// Go text analyzer signals.
     Go,
+    /// C# text analyzer signals.
+    #[serde(rename = "csharp")]
+    CSharp,
+    /// Terraform / HCL text analyzer signals.
+    Hcl,
     /// Rust CST analyzer signals.
     RustCst,
     /// Python CST analyzer signals.
@@ -50,6 +57,11 @@ pub enum HeuristicLanguage {
     JsCst,
     /// Go CST analyzer signals.
     GoCst,
+    /// C# CST analyzer signals.
+    #[serde(rename = "csharp_cst")]
+    CSharpCst,
+    /// React component signals, matched only for `.jsx` / `.tsx` files.
+    Jsx,
     /// Language-agnostic signals.
     All,
 }
@@ -61,10 +73,14 @@ impl fmt::Display for HeuristicLanguage {
             HeuristicLanguage::Python    => "python",
             HeuristicLanguage::Js        => "js",
             HeuristicLanguage::Go        => "go",
+            HeuristicLanguage::CSharp    => "csharp",
+            HeuristicLanguage::Hcl       => "hcl",
             HeuristicLanguage::RustCst   => "rust_cst",
             HeuristicLanguage::PythonCst => "python_cst",
             HeuristicLanguage::JsCst     => "js_cst",
             HeuristicLanguage::GoCst     => "go_cst",
+            HeuristicLanguage::CSharpCst => "csharp_cst",
+            HeuristicLanguage::Jsx       => "jsx",
             HeuristicLanguage::All       => "all",
         })
     }
@@ -77,18 +93,23 @@ impl From<Language> for HeuristicLanguage {
             Language::Python     => HeuristicLanguage::Python,
             Language::JavaScript => HeuristicLanguage::Js,
             Language::Go         => HeuristicLanguage::Go,
+            Language::CSharp     => HeuristicLanguage::CSharp,
+            Language::Hcl        => HeuristicLanguage::Hcl,
         }
     }
 }
 
 impl HeuristicLanguage {
-    /// Map a file-level `Language` to the corresponding CST heuristic language.
-    pub fn cst_from(lang: Language) -> Self {
+    /// Map a file-level `Language` to the corresponding CST heuristic language,
+    /// or `None` for languages without CST analysis.
+    pub fn cst_from(lang: Language) -> Option<Self> {
         match lang {
-            Language::Rust       => HeuristicLanguage::RustCst,
-            Language::Python     => HeuristicLanguage::PythonCst,
-            Language::JavaScript => HeuristicLanguage::JsCst,
-            Language::Go         => HeuristicLanguage::GoCst,
+            Language::Rust       => Some(HeuristicLanguage::RustCst),
+            Language::Python     => Some(HeuristicLanguage::PythonCst),
+            Language::JavaScript => Some(HeuristicLanguage::JsCst),
+            Language::Go         => Some(HeuristicLanguage::GoCst),
+            Language::CSharp     => Some(HeuristicLanguage::CSharpCst),
+            Language::Hcl        => None,
         }
     }
 }
@@ -219,6 +240,14 @@ pub trait HeuristicsProvider: Send + Sync {
     fn is_enabled(&self, id: &str) -> bool {
         self.weight(id) != 0.0
     }
+
+    /// Return `false` if every signal emitted by the analyzer named `source`
+    /// (e.g. `"structure"`) should be suppressed from scoring and output.
+    ///
+    /// Defaults to `true`.
+    fn is_source_enabled(&self, _source: &str) -> bool {
+        true
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -257,28 +286,114 @@ impl HeuristicsProvider for InertHeuristics {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Weight profiles — named preset weight sets
+// ---------------------------------------------------------------------------
+
+/// A named preset that rescales every default weight by attribution target.
+#[derive(Debug, Clone, Copy)]
+pub struct WeightProfile {
+    /// Name used in `.vibecheck` (`profile = "strict"`) and `--profile`.
+    pub name: &'static str,
+    /// One-line summary shown in help and docs.
+    pub description: &'static str,
+    /// Multiplier applied to signals pointing at an AI family.
+    pub ai_scale: f64,
+    /// Multiplier applied to signals pointing at [`ModelFamily::Human`].
+    pub human_scale: f64,
+}
+
+/// Built-in weight profiles, selectable by name.
+pub const PROFILES: &[WeightProfile] = &[
+    WeightProfile {
+        name: "strict",
+        description: "amplify AI tells, discount human evidence",
+        ai_scale: 1.5,
+        human_scale: 0.75,
+    },
+    WeightProfile {
+        name: "balanced",
+        description: "the default weights",
+        ai_scale: 1.0,
+        human_scale: 1.0,
+    },
+    WeightProfile {
+        name: "lenient",
+        description: "require stronger AI evidence before attributing",
+        ai_scale: 0.6,
+        human_scale: 1.25,
+    },
+];
+
+/// Signal-ID → weight overrides for the built-in profile called `name`, or
+/// `None` if there is no such profile.
+///
+/// Only signals whose weight actually changes are included, so `balanced`
+/// yields an empty map.
+pub fn profile_overrides(name: &str) -> Option<HashMap<&'static str, f64>> {
+    let profile = PROFILES.iter().find(|p| p.name == name)?;
+    Some(
+        all_heuristics()
+            .iter()
+            .filter_map(|h| {
+                let scale = if h.family == ModelFamily::Human {
+                    profile.human_scale
+                } else {
+                    profile.ai_scale
+                };
+                (scale != 1.0).then_some((h.id, h.default_weight * scale))
+            })
+            .collect(),
+    )
+}
+
 // ---------------------------------------------------------------------------
 // ConfiguredHeuristics — TOML-loaded overrides
 // ---------------------------------------------------------------------------
 
 /// Heuristics loaded from the `[heuristics]` table in `.vibecheck`.
 ///
-/// Any signal not present in the overrides map falls back to
-/// [`DefaultHeuristics`].
+/// Any signal not present in the overrides map falls back to the selected
+/// weight profile, then to [`DefaultHeuristics`].  Entries from
+/// `[ignore] signals` are suppressed outright, independent of their weight.
 pub struct ConfiguredHeuristics {
     overrides: HashMap<String, f64>,
+    profile: HashMap<&'static str, f64>,
+    suppressed: Vec<String>,
 }
 
 impl ConfiguredHeuristics {
     /// Build from a map of signal-ID → weight overrides (e.g. parsed from
     /// the `[heuristics]` TOML section).
     pub fn from_config(overrides: HashMap<String, f64>) -> Self {
-        Self { overrides }
+        Self { overrides, profile: HashMap::new(), suppressed: vec![] }
+    }
+
+    /// Layer a weight profile (see [`profile_overrides`]) under the explicit
+    /// overrides.
+    pub fn with_profile(mut self, profile: HashMap<&'static str, f64>) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    /// Suppress signals matching any entry: an exact signal ID, a dotted ID
+    /// prefix (`"rust.structure"`), or an analyzer name (`"structure"`).
+    pub fn with_suppressed(mut self, suppressed: Vec<String>) -> Self {
+        self.suppressed = suppressed;
+        self
     }
 
-    /// Returns `true` if no overrides are configured (fast path: use defaults).
+    /// Returns `true` if no overrides, profile, or suppressions are
+    /// configured (fast path: use defaults).
     pub fn is_empty(&self) -> bool {
-        self.overrides.is_empty()
+        self.overrides.is_empty() && self.profile.is_empty() && self.suppressed.is_empty()
+    }
+
+    fn is_suppressed(&self, id: &str) -> bool {
+        self.suppressed.iter().any(|s| {
+            id.strip_prefix(s.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+        })
     }
 }
 
@@ -286,9 +401,18 @@ impl HeuristicsProvider for ConfiguredHeuristics {
     fn weight(&self, id: &str) -> f64 {
         self.overrides
             .get(id)
+            .or_else(|| self.profile.get(id))
             .copied()
             .unwrap_or_else(|| DefaultHeuristics.weight(id))
     }
+
+    fn is_enabled(&self, id: &str) -> bool {
+        !self.is_suppressed(id) && self.weight(id) != 0.0
+    }
+
+    fn is_source_enabled(&self, source: &str) -> bool {
+        !self.suppressed.iter().any(|s| s == source)
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -354,6 +478,53 @@ mod tests {
         assert!(h.is_enabled(signal_ids::RUST_ERRORS_MANY_UNWRAPS));
     }
 
+    #[test]
+    fn configured_heuristics_suppresses_ids_prefixes_and_sources() {
+        let h = ConfiguredHeuristics::from_config(HashMap::new()).with_suppressed(vec![
+            "rust.errors.zero_unwrap".into(),
+            "python.naming".into(),
+            "structure".into(),
+        ]);
+        assert!(!h.is_enabled(signal_ids::RUST_ERRORS_ZERO_UNWRAP));
+        assert!(h.is_enabled(signal_ids::RUST_ERRORS_MANY_UNWRAPS));
+        assert!(!h.is_enabled("python.naming.snake_case"));
+        assert!(h.is_enabled("python.naming_extra.x"), "prefix must end at a dot");
+        assert!(!h.is_source_enabled("structure"));
+        assert!(h.is_source_enabled("comments"));
+        // Suppression leaves the configured weight alone.
+        assert_eq!(
+            h.weight(signal_ids::RUST_ERRORS_ZERO_UNWRAP),
+            DefaultHeuristics.weight(signal_ids::RUST_ERRORS_ZERO_UNWRAP),
+        );
+    }
+
+    #[test]
+    fn profiles_scale_by_target_family() {
+        assert!(profile_overrides("balanced").unwrap().is_empty());
+        assert!(profile_overrides("paranoid").is_none());
+
+        let strict = profile_overrides("strict").unwrap();
+        let ai = all_heuristics().iter().find(|h| h.family != ModelFamily::Human).unwrap();
+        let human = all_heuristics().iter().find(|h| h.family == ModelFamily::Human).unwrap();
+        assert_eq!(strict[ai.id], ai.default_weight * 1.5);
+        assert_eq!(strict[human.id], human.default_weight * 0.75);
+
+        let lenient = profile_overrides("lenient").unwrap();
+        assert!(lenient[ai.id] < ai.default_weight);
+        assert!(lenient[human.id] > human.default_weight);
+    }
+
+    #[test]
+    fn explicit_overrides_win_over_profile() {
+        let mut overrides = HashMap::new();
+        overrides.insert("rust.errors.zero_unwrap".to_string(), 3.0);
+        let h = ConfiguredHeuristics::from_config(overrides)
+            .with_profile(profile_overrides("strict").unwrap());
+        assert_eq!(h.weight(signal_ids::RUST_ERRORS_ZERO_UNWRAP), 3.0);
+        assert_eq!(h.weight(signal_ids::RUST_ERRORS_MANY_UNWRAPS), 1.5 * 0.75);
+        assert!(!h.is_empty());
+    }
+
     #[test]
     fn no_family_exceeds_35_percent() {
         let mut counts: std::collections::HashMap<ModelFamily, usize> = std::collections::HashMap::new();
@@ -370,6 +541,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn builtin_signals_use_builtin_families() {
+        // Unknown names deserialize as custom families, so a typo in
+        // heuristics.toml would otherwise go unnoticed.
+        for spec in all_heuristics() {
+            assert!(
+                ModelFamily::all().contains(&spec.family),
+                "{} targets non-built-in family {:?}",
+                spec.id,
+                spec.family
+            );
+        }
+    }
+
     #[test]
     fn inert_heuristics_matches_defaults() {
         let inert = InertHeuristics;
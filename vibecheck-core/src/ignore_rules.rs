@@ -6,8 +6,8 @@
 //!
 //! # Production use
 //! [`IgnoreConfig`] is the production implementation.  It discovers and
-//! parses a `.vibecheck` TOML file (walking upward to the git root) and
-//! honours `.gitignore` by default.
+//! parses a `.vibecheck` TOML file (walking upward to the git root), reads a
+//! `.vibecheckignore` file beside it, and honours `.gitignore` by default.
 //!
 //! # Testing / DI
 //! [`AllowAll`] and [`PatternIgnore`] are lightweight test doubles that
@@ -17,6 +17,9 @@ use std::path::{Path, PathBuf};
 
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
 
+use crate::pipeline::{ConfidenceBound, SizeBucket};
+use crate::report::ModelFamily;
+
 // ---------------------------------------------------------------------------
 // Trait
 // ---------------------------------------------------------------------------
@@ -86,6 +89,8 @@ impl IgnoreRules for PatternIgnore {
 
 #[derive(serde::Deserialize, Default)]
 struct ConfigFile {
+    /// Optional top-level `profile = "..."`: built-in weight profile name.
+    profile: Option<String>,
     #[serde(default)]
     ignore: IgnoreSection,
     /// Optional `[heuristics]` table: signal-ID → weight override.
@@ -94,6 +99,112 @@ struct ConfigFile {
     /// Optional `[cache]` table: cache directory override.
     #[serde(default)]
     cache: CacheSection,
+    /// Optional `[naming]` table: naming analyzer tuning.
+    #[serde(default)]
+    naming: NamingSection,
+    /// Optional `[min_lines]` table: minimum source lines per analyzer.
+    #[serde(default)]
+    min_lines: MinLinesSection,
+    /// Optional `[size_buckets]` table: `"LO-HI"` line range → confidence
+    /// multiplier.
+    #[serde(default)]
+    size_buckets: std::collections::HashMap<String, f64>,
+    /// Optional `[generated]` table: generated-code header detection.
+    #[serde(default)]
+    generated: GeneratedSection,
+    /// Optional `[colors]` table: family name → `#rrggbb` color.
+    #[serde(default)]
+    colors: std::collections::HashMap<String, String>,
+    /// Optional `[limits]` table: per-file analysis limits.
+    #[serde(default)]
+    limits: LimitsSection,
+    /// Optional `[symbols]` table: symbol-level attribution tuning.
+    #[serde(default)]
+    symbols: SymbolsSection,
+    /// Optional `[confidence_bounds]` table: family name → score floor and
+    /// ceiling.
+    #[serde(default)]
+    confidence_bounds: std::collections::HashMap<String, BoundsSection>,
+    /// Optional `[assert]` table: families each file's verdict must be in.
+    #[serde(default)]
+    assert: Option<AssertSection>,
+    /// Optional `[corpus]` table: weight priors from a corpus store.
+    #[serde(default)]
+    corpus: CorpusSection,
+}
+
+#[derive(serde::Deserialize, Default)]
+struct CorpusSection {
+    /// Corpus store (SQLite) to read weight priors from, relative to the
+    /// config root.
+    store: Option<String>,
+    /// How far to move weights toward the corpus priors, `0.0`–`1.0`.
+    mix: Option<f64>,
+}
+
+#[derive(serde::Deserialize, Default)]
+struct AssertSection {
+    /// Families every file may be attributed to.
+    allowed_families: Option<Vec<String>>,
+    /// `[[assert.override]]` entries replacing `allowed_families` for the
+    /// files their `paths` match; the last match wins.
+    #[serde(default, rename = "override")]
+    overrides: Vec<AssertOverride>,
+}
+
+#[derive(serde::Deserialize)]
+struct AssertOverride {
+    /// Gitignore-syntax globs, relative to the config root.
+    paths: Vec<String>,
+    allowed_families: Vec<String>,
+}
+
+#[derive(serde::Deserialize, Default)]
+struct BoundsSection {
+    min: Option<f64>,
+    max: Option<f64>,
+}
+
+#[derive(serde::Deserialize, Default)]
+struct SymbolsSection {
+    /// Pseudo-signal count pulling each symbol's attribution toward the
+    /// file's; `0` (the default) disables smoothing.
+    smoothing: Option<f64>,
+}
+
+#[derive(serde::Deserialize, Default)]
+struct LimitsSection {
+    /// Give up on tree-sitter parsing of one file after this many
+    /// milliseconds; `0` disables the limit.
+    parse_timeout_ms: Option<u64>,
+    /// Run one file's analyzers in parallel from this many lines; `0`
+    /// keeps them sequential.
+    parallel_min_lines: Option<usize>,
+}
+
+#[derive(serde::Deserialize, Default)]
+struct GeneratedSection {
+    /// Replace the default generated-code header markers (`DO NOT EDIT`,
+    /// `@generated`, …); an empty list disables detection.
+    markers: Option<Vec<String>>,
+}
+
+#[derive(serde::Deserialize, Default)]
+struct MinLinesSection {
+    /// Applies to every analyzer without its own entry.
+    default: Option<usize>,
+    /// Analyzer name (`naming`, `comments`, …) → minimum line count.
+    #[serde(flatten)]
+    analyzers: std::collections::HashMap<String, usize>,
+}
+
+#[derive(serde::Deserialize, Default)]
+struct NamingSection {
+    /// Replace the default generic placeholder list (`result`, `data`, …).
+    generic_names: Option<Vec<String>>,
+    /// `false` makes mixed-convention signals count raw camelCase and
+    /// snake_case names instead of judging each against its role.
+    conventions: Option<bool>,
 }
 
 #[derive(serde::Deserialize, Default)]
@@ -113,6 +224,9 @@ struct IgnoreSection {
     /// Respect the global gitignore (`~/.gitignore_global`, etc.) (default: `true`).
     #[serde(default = "bool_true")]
     use_global_gitignore: bool,
+    /// Signal IDs, dotted ID prefixes, or analyzer names to suppress.
+    #[serde(default)]
+    signals: Vec<String>,
 }
 
 impl Default for IgnoreSection {
@@ -121,6 +235,7 @@ impl Default for IgnoreSection {
             patterns: vec![],
             use_gitignore: true,
             use_global_gitignore: true,
+            signals: vec![],
         }
     }
 }
@@ -133,11 +248,19 @@ fn bool_true() -> bool {
 // IgnoreConfig — production implementation
 // ---------------------------------------------------------------------------
 
-/// Full implementation: reads `.vibecheck` TOML + respects `.gitignore`.
+/// `[corpus] mix` used when a store is configured without one.
+pub const DEFAULT_CORPUS_MIX: f64 = 0.5;
+
+/// Full implementation: reads `.vibecheck` TOML and `.vibecheckignore`, and
+/// respects `.gitignore`.
 ///
 /// # Config file format (`.vibecheck`)
 ///
 /// ```toml
+/// # Built-in weight profile: "strict", "balanced", or "lenient".
+/// # Must appear before the first [section].
+/// profile = "strict"
+///
 /// [ignore]
 /// # Extra patterns (gitignore glob syntax), additive on top of .gitignore.
 /// patterns = ["vendor/", "dist/", "*.min.js"]
@@ -147,8 +270,71 @@ fn bool_true() -> bool {
 ///
 /// # Set to false to disable the global gitignore (default: true).
 /// use_global_gitignore = true
+///
+/// # Signals to drop from scoring and output: exact IDs, dotted ID
+/// # prefixes, or whole analyzer names.
+/// signals = ["rust.errors.zero_unwrap", "python.naming", "structure"]
+///
+/// [min_lines]
+/// # Skip text analyzers on files shorter than this (built-in default: 10).
+/// default = 5
+/// # Per-analyzer overrides, keyed by analyzer name.
+/// naming = 20
+///
+/// [size_buckets]
+/// # Confidence multiplier by file length, as inclusive "LO-HI" line ranges
+/// # ("LO-" is open-ended). The first range containing a file applies.
+/// 0-19  = 0.5
+/// 20-49 = 0.8
+///
+/// [colors]
+/// # Recolor model families in the TUI, SVG output and truecolor terminals.
+/// claude = "#ff79c6"
+///
+/// [limits]
+/// # Stop parsing a file after this many milliseconds (default 5000; 0 = no
+/// # limit). Timed-out files get a text-only report.
+/// parse_timeout_ms = 2000
+/// # Run a file's analyzers in parallel from this many lines (default 2000;
+/// # 0 = never).
+/// parallel_min_lines = 5000
+///
+/// [symbols]
+/// # Pull each symbol's attribution toward the file's, as if the file
+/// # verdict were this many extra signals (default 0 = off).
+/// smoothing = 5
+///
+/// [confidence_bounds]
+/// # Clamp a family's final score (and the confidence when it is the
+/// # verdict) into [min, max]; either bound may be omitted.
+/// copilot = { max = 0.7 }
+///
+/// [assert]
+/// # Fail `vibecheck analyze` when a file's verdict is not one of these.
+/// allowed_families = ["human"]
+///
+/// # Different families for the files matching `paths` (gitignore syntax);
+/// # the last matching override wins.
+/// [[assert.override]]
+/// paths = ["examples/", "scripts/*.py"]
+/// allowed_families = ["human", "claude"]
+///
+/// [corpus]
+/// # Blend weights with priors from a corpus store built by
+/// # `vibecheck eval --record` (needs the `corpus` feature).
+/// store = "corpus.db"
+/// # 0 keeps the configured weights, 1 scales them fully by the priors
+/// # (default 0.5).
+/// mix = 0.3
 /// ```
 ///
+/// # `.vibecheckignore`
+/// A `.vibecheckignore` file in the config root uses full gitignore syntax
+/// (negation, anchoring, directory-only patterns) for vibecheck-specific
+/// exclusions.  When rules conflict, `.vibecheckignore` beats
+/// `[ignore] patterns`, which beat `.gitignore` — so `!keep.rs` in
+/// `.vibecheckignore` re-includes a file excluded by either of the others.
+///
 /// # Discovery
 /// [`IgnoreConfig::load`] walks upward from the given path looking for a
 /// `.vibecheck` file or a `.git` directory, using the first match as the
@@ -157,14 +343,81 @@ pub struct IgnoreConfig {
     root: PathBuf,
     pub(crate) use_gitignore: bool,
     pub(crate) use_global_gitignore: bool,
-    /// Combined matcher: root `.gitignore` rules + extra `.vibecheck` patterns.
+    /// Combined matcher: root `.gitignore` rules + extra `.vibecheck` patterns
+    /// + `.vibecheckignore`, in increasing precedence.
     combined: Gitignore,
-    /// Extra patterns only (used by `is_extra_ignored` for walker secondary filter).
+    /// Extra patterns + `.vibecheckignore` only (used by `is_extra_ignored`
+    /// for walker secondary filter).
     extra: Gitignore,
     /// Signal-ID → weight overrides from the `[heuristics]` TOML table.
     heuristics: std::collections::HashMap<String, f64>,
+    /// Built-in weight profile name from the top-level `profile` key.
+    profile: Option<String>,
     /// Optional cache directory override from `[cache] dir`.
     cache_dir: Option<PathBuf>,
+    /// Optional generic-identifier list from `[naming] generic_names`.
+    generic_names: Option<Vec<String>>,
+    /// Convention-aware naming switch from `[naming] conventions`.
+    naming_conventions: Option<bool>,
+    /// Suppressed signal IDs / prefixes / analyzer names from `[ignore] signals`.
+    ignored_signals: Vec<String>,
+    /// Global minimum line count from `[min_lines] default`.
+    min_lines: Option<usize>,
+    /// Per-analyzer minimum line counts from the rest of `[min_lines]`.
+    analyzer_min_lines: std::collections::HashMap<String, usize>,
+    /// Confidence multipliers by file length from `[size_buckets]`, sorted
+    /// by lower bound.
+    size_buckets: Vec<SizeBucket>,
+    /// Optional generated-code marker list from `[generated] markers`.
+    generated_markers: Option<Vec<String>>,
+    /// Family color overrides from `[colors]`.
+    family_colors: Vec<(ModelFamily, (u8, u8, u8))>,
+    /// Parse timeout in milliseconds from `[limits] parse_timeout_ms`.
+    parse_timeout_ms: Option<u64>,
+    /// Parallel analysis threshold from `[limits] parallel_min_lines`.
+    parallel_min_lines: Option<usize>,
+    /// Symbol attribution smoothing strength from `[symbols] smoothing`.
+    symbol_smoothing: Option<f64>,
+    /// Per-family score bounds from `[confidence_bounds]`, sorted by name.
+    confidence_bounds: Vec<(ModelFamily, ConfidenceBound)>,
+    /// Absolute cache directories (configured and platform default) that
+    /// walks never descend into; see [`is_own_state`](Self::is_own_state).
+    own_dirs: Vec<PathBuf>,
+    /// Verdict expectations from `[assert]`, if the section is present.
+    family_assertions: Option<FamilyAssertions>,
+    /// Absolute corpus store path from `[corpus] store`.
+    corpus_store: Option<PathBuf>,
+    /// Prior blend from `[corpus] mix`.
+    corpus_mix: Option<f64>,
+    /// Problems found while loading; see [`diagnostics`](Self::diagnostics).
+    diagnostics: Vec<String>,
+}
+
+/// Which families files may be attributed to, from a `.vibecheck`
+/// `[assert]` section.  See [`IgnoreConfig::family_assertions`].
+pub struct FamilyAssertions {
+    /// Absolute config root the override globs are relative to.
+    root: PathBuf,
+    /// `allowed_families`; `None` checks only files an override matches.
+    allowed: Option<Vec<ModelFamily>>,
+    /// `[[assert.override]]` matchers with their families, in file order.
+    overrides: Vec<(Gitignore, Vec<ModelFamily>)>,
+}
+
+impl FamilyAssertions {
+    /// The families `path` may be attributed to: those of the last
+    /// override whose paths match it, else `allowed_families`.  `None`
+    /// when neither applies and the file is not checked.
+    pub fn allowed_for(&self, path: &Path) -> Option<&[ModelFamily]> {
+        let abs = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+        let matched = abs.strip_prefix(&self.root).ok().and_then(|rel| {
+            self.overrides
+                .iter()
+                .rev()
+                .find(|(m, _)| m.matched_path_or_any_parents(rel, false).is_ignore())
+        });
+        matched.map(|(_, families)| families.as_slice()).or(self.allowed.as_deref())
+    }
 }
 
 impl IgnoreConfig {
@@ -184,34 +437,61 @@ impl IgnoreConfig {
         let f: ConfigFile = toml::from_str(&s)
             .map_err(|e| anyhow::anyhow!("failed to parse {}: {e}", path.display()))?;
         let root = path.parent().unwrap_or(path).to_path_buf();
-        Ok(Self::from_section(root, f.ignore, f.heuristics, f.cache))
+        Ok(Self::from_config_file(root, f))
+    }
+
+    /// Use the built-in weight profile `name` instead of the configured one,
+    /// e.g. for a `--profile` flag.  Unknown names leave the configured
+    /// profile in place.
+    pub fn with_profile(mut self, name: &str) -> Self {
+        if crate::heuristics::PROFILES.iter().any(|p| p.name == name) {
+            self.profile = Some(name.to_string());
+        }
+        self
     }
 
     /// Build an [`ignore::WalkBuilder`] pre-configured with gitignore settings.
     ///
-    /// The walker handles `.gitignore` files across the entire tree natively
-    /// (including nested `.gitignore` files in subdirectories).  After
-    /// receiving each entry, call [`is_extra_ignored`] to also apply any
-    /// additional patterns declared in `.vibecheck`.
+    /// The walker handles `.gitignore` and `.vibecheckignore` files across
+    /// the entire tree natively (including nested files in subdirectories,
+    /// with `.vibecheckignore` taking precedence).  After receiving each
+    /// entry, call [`is_extra_ignored`] to also apply any additional patterns
+    /// declared in `.vibecheck`.
     pub fn build_walker(&self, path: &Path) -> ignore::WalkBuilder {
         let mut b = ignore::WalkBuilder::new(path);
         b.git_ignore(self.use_gitignore)
             .git_global(self.use_global_gitignore)
             .git_exclude(self.use_gitignore)
+            .add_custom_ignore_filename(VIBECHECKIGNORE)
             .hidden(false);
         b
     }
 
-    /// Returns `true` if `path` matches any of the extra patterns declared in
-    /// `.vibecheck` (gitignore rules are *not* checked here — the walker
-    /// handles those natively).  Use as a secondary filter on walker entries.
+    /// Returns `true` if `path` is excluded by the extra patterns declared in
+    /// `.vibecheck` or the root `.vibecheckignore` (gitignore rules are *not*
+    /// checked here — the walker handles those natively).  Use as a
+    /// secondary filter on walker entries.
     pub fn is_extra_ignored(&self, path: &Path) -> bool {
+        if self.is_own_state(path) {
+            return true;
+        }
         let rel = path.strip_prefix(&self.root).unwrap_or(path);
         self.extra
             .matched_path_or_any_parents(rel, path.is_dir())
             .is_ignore()
     }
 
+    /// Returns `true` for vibecheck's own files: `.vibecheck`,
+    /// `.vibecheckignore`, and anything inside the cache directory, so a
+    /// run at a repository root never analyzes its own state.  Checked
+    /// before every other rule; `!` negations cannot re-include these.
+    fn is_own_state(&self, path: &Path) -> bool {
+        let own_file = path.file_name().and_then(|n| n.to_str()).is_some_and(|n| OWN_FILES.contains(&n));
+        own_file
+            || (!self.own_dirs.is_empty()
+                && std::path::absolute(path).is_ok_and(|abs| self.own_dirs.iter().any(|d| abs.starts_with(d))))
+    }
+
     // -- internals -----------------------------------------------------------
 
     /// Return the signal-ID → weight override map from the `[heuristics]` table.
@@ -221,45 +501,233 @@ impl IgnoreConfig {
         self.heuristics.clone()
     }
 
+    /// Return the weight profile name from the top-level `profile` key, if
+    /// configured.  Unknown names are dropped with a warning at load time.
+    pub fn profile(&self) -> Option<&str> {
+        self.profile.as_deref()
+    }
+
+    /// Return the suppressed signal entries from `[ignore] signals`.
+    pub fn ignored_signals(&self) -> &[String] {
+        &self.ignored_signals
+    }
+
     /// Return the cache directory override from `[cache] dir`, if configured.
     pub fn cache_dir(&self) -> Option<&Path> {
         self.cache_dir.as_deref()
     }
 
+    /// Return the generic placeholder list from `[naming] generic_names`, if
+    /// configured.  `None` means "use the built-in list".
+    pub fn generic_names(&self) -> Option<&[String]> {
+        self.generic_names.as_deref()
+    }
+
+    /// Return the `[naming] conventions` switch, if configured.  `None`
+    /// means convention-aware (the default).
+    pub fn naming_conventions(&self) -> Option<bool> {
+        self.naming_conventions
+    }
+
+    /// Return the global minimum line count from `[min_lines] default`, if
+    /// configured.  `None` means each analyzer uses its own default.
+    pub fn min_lines(&self) -> Option<usize> {
+        self.min_lines
+    }
+
+    /// Return the per-analyzer minimum line counts from `[min_lines]`.
+    pub fn analyzer_min_lines(&self) -> &std::collections::HashMap<String, usize> {
+        &self.analyzer_min_lines
+    }
+
+    /// Return the file-length confidence multipliers from `[size_buckets]`,
+    /// sorted by lower bound.  Empty means no adjustment.
+    pub fn size_buckets(&self) -> &[SizeBucket] {
+        &self.size_buckets
+    }
+
+    /// Return the generated-code header markers from `[generated] markers`,
+    /// if configured.  `None` means "use the built-in list".
+    pub fn generated_markers(&self) -> Option<&[String]> {
+        self.generated_markers.as_deref()
+    }
+
+    /// Return the per-file parse timeout in milliseconds from `[limits]
+    /// parse_timeout_ms`, if configured.  `Some(0)` means "no limit"; `None`
+    /// means "use the built-in default".
+    pub fn parse_timeout_ms(&self) -> Option<u64> {
+        self.parse_timeout_ms
+    }
+
+    /// Return the line count from which a file's analyzers run in parallel,
+    /// from `[limits] parallel_min_lines`.  `Some(0)` means "never"; `None`
+    /// means "use the built-in default".
+    pub fn parallel_min_lines(&self) -> Option<usize> {
+        self.parallel_min_lines
+    }
+
+    /// Return the verdict expectations from `[assert]`, if the section is
+    /// present.  `vibecheck analyze` enforces them when `--assert-family`
+    /// is not given.
+    pub fn family_assertions(&self) -> Option<&FamilyAssertions> {
+        self.family_assertions.as_ref()
+    }
+
+    /// Return the corpus store and blend from `[corpus]`, with `mix`
+    /// defaulting to [`DEFAULT_CORPUS_MIX`], if a store is configured.
+    pub fn corpus_priors(&self) -> Option<(&Path, f64)> {
+        let store = self.corpus_store.as_deref()?;
+        Some((store, self.corpus_mix.unwrap_or(DEFAULT_CORPUS_MIX)))
+    }
+
+    /// Return the symbol attribution smoothing strength from `[symbols]
+    /// smoothing`, if configured.
+    pub fn symbol_smoothing(&self) -> Option<f64> {
+        self.symbol_smoothing
+    }
+
+    /// Return the per-family score floors and ceilings from
+    /// `[confidence_bounds]`.  Empty means no clamping.
+    pub fn confidence_bounds(&self) -> &[(ModelFamily, ConfidenceBound)] {
+        &self.confidence_bounds
+    }
+
+    /// Return the family color overrides from `[colors]`, for
+    /// [`crate::colors::set_family_colors`].
+    pub fn family_colors(&self) -> &[(ModelFamily, (u8, u8, u8))] {
+        &self.family_colors
+    }
+
+    /// Problems found while loading that fell back to defaults or dropped
+    /// an entry, e.g. an unknown profile or an invalid `[colors]` value.
+    /// Loading never prints them: a config is loaded once per analyzed
+    /// file, so callers report these once per config root instead.
+    pub fn diagnostics(&self) -> &[String] {
+        &self.diagnostics
+    }
+
     fn load_from_root(root: PathBuf) -> Self {
         let cfg_path = root.join(".vibecheck");
-        let (section, heuristics, cache) = if cfg_path.is_file() {
+        let parsed = if cfg_path.is_file() {
             std::fs::read_to_string(&cfg_path)
                 .ok()
                 .and_then(|s| toml::from_str::<ConfigFile>(&s).ok())
-                .map(|f| (f.ignore, f.heuristics, f.cache))
-                .unwrap_or_else(|| {
-                    eprintln!("vibecheck: warning: failed to parse .vibecheck; using defaults");
-                    (IgnoreSection::default(), std::collections::HashMap::new(), CacheSection::default())
-                })
         } else {
-            (IgnoreSection::default(), std::collections::HashMap::new(), CacheSection::default())
+            Some(ConfigFile::default())
+        };
+        let Some(file) = parsed else {
+            let mut config = Self::from_config_file(root, ConfigFile::default());
+            config.diagnostics.push("failed to parse .vibecheck; using defaults".to_string());
+            return config;
         };
-        Self::from_section(root, section, heuristics, cache)
+        Self::from_config_file(root, file)
     }
 
-    fn from_section(
-        root: PathBuf,
-        section: IgnoreSection,
-        heuristics: std::collections::HashMap<String, f64>,
-        cache: CacheSection,
-    ) -> Self {
+    fn from_config_file(root: PathBuf, file: ConfigFile) -> Self {
+        let section = file.ignore;
         let combined = build_combined(&root, &section.patterns, section.use_gitignore);
         let extra = build_extra(&root, &section.patterns);
-        let cache_dir = cache.dir.map(PathBuf::from);
+        let cache_dir = file.cache.dir.map(PathBuf::from);
+        let mut diagnostics = Vec::new();
+        let profile = file.profile.filter(|name| {
+            let known = crate::heuristics::PROFILES.iter().any(|p| p.name == name);
+            if !known {
+                diagnostics.push(format!("unknown profile `{name}` in .vibecheck; using defaults"));
+            }
+            known
+        });
+        let mut size_buckets: Vec<SizeBucket> = file
+            .size_buckets
+            .iter()
+            .filter_map(|(range, &multiplier)| {
+                let bucket = SizeBucket::parse(range, multiplier);
+                if bucket.is_none() {
+                    diagnostics.push(format!("invalid [size_buckets] entry `{range} = {multiplier}`; ignoring"));
+                }
+                bucket
+            })
+            .collect();
+        size_buckets.sort_by_key(|b| (b.min_lines, b.max_lines));
+        let mut family_colors: Vec<_> = file
+            .colors
+            .iter()
+            .filter_map(|(name, hex)| {
+                let Some(family) = ModelFamily::from_name(name) else {
+                    diagnostics.push(format!("unknown family `{name}` in [colors]; ignoring"));
+                    return None;
+                };
+                let rgb = crate::colors::parse_hex(hex);
+                if rgb.is_none() {
+                    diagnostics.push(format!("invalid [colors] entry `{name} = \"{hex}\"` (expected #rrggbb); ignoring"));
+                }
+                Some((family, rgb?))
+            })
+            .collect();
+        family_colors.sort_by_key(|(family, _)| family.name());
+        let symbol_smoothing = file.symbols.smoothing.filter(|&s| {
+            let valid = s.is_finite() && s >= 0.0;
+            if !valid {
+                diagnostics.push(format!("invalid [symbols] smoothing `{s}` (expected >= 0); ignoring"));
+            }
+            valid
+        });
+        let mut confidence_bounds: Vec<_> = file
+            .confidence_bounds
+            .iter()
+            .filter_map(|(name, b)| {
+                let Some(family) = ModelFamily::from_name(name) else {
+                    diagnostics.push(format!("unknown family `{name}` in [confidence_bounds]; ignoring"));
+                    return None;
+                };
+                let bound = ConfidenceBound::new(b.min, b.max);
+                if bound.is_none() {
+                    diagnostics.push(format!(
+                        "invalid [confidence_bounds] entry for `{name}` (expected 0 <= min <= max <= 1); ignoring"
+                    ));
+                }
+                Some((family, bound?))
+            })
+            .collect();
+        confidence_bounds.sort_by_key(|(family, _)| family.name());
+        let own_dirs = own_cache_dirs(&root, cache_dir.as_deref());
+        let family_assertions = file.assert.map(|section| family_assertions(&root, section, &mut diagnostics));
+        let corpus_store = file.corpus.store.map(|store| root.join(store));
+        if corpus_store.is_some() && !cfg!(feature = "corpus") {
+            diagnostics.push("[corpus] store needs the `corpus` feature; ignoring".to_string());
+        }
+        let corpus_mix = file.corpus.mix.filter(|&mix| {
+            let valid = (0.0..=1.0).contains(&mix);
+            if !valid {
+                diagnostics.push(format!("invalid [corpus] mix `{mix}` (expected 0 to 1); ignoring"));
+            }
+            valid
+        });
         Self {
             root,
             use_gitignore: section.use_gitignore,
             use_global_gitignore: section.use_global_gitignore,
             combined,
             extra,
-            heuristics,
+            heuristics: file.heuristics,
+            profile,
             cache_dir,
+            generic_names: file.naming.generic_names,
+            naming_conventions: file.naming.conventions,
+            ignored_signals: section.signals,
+            min_lines: file.min_lines.default,
+            analyzer_min_lines: file.min_lines.analyzers,
+            size_buckets,
+            generated_markers: file.generated.markers,
+            family_colors,
+            parse_timeout_ms: file.limits.parse_timeout_ms,
+            parallel_min_lines: file.limits.parallel_min_lines,
+            symbol_smoothing,
+            confidence_bounds,
+            own_dirs,
+            family_assertions,
+            corpus_store,
+            corpus_mix,
+            diagnostics,
         }
     }
 }
@@ -272,6 +740,9 @@ impl IgnoreRules for IgnoreConfig {
     /// directory (e.g. `vendor/lib.rs` when `vendor/` is in the pattern list)
     /// is correctly reported as ignored.
     fn is_ignored(&self, path: &Path) -> bool {
+        if self.is_own_state(path) {
+            return true;
+        }
         let rel = path.strip_prefix(&self.root).unwrap_or(path);
         self.combined
             .matched_path_or_any_parents(rel, path.is_dir())
@@ -279,10 +750,140 @@ impl IgnoreRules for IgnoreConfig {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Diagnostics
+// ---------------------------------------------------------------------------
+
+/// Problems in the text of a `.vibecheck` file that loading would silently
+/// paper over: invalid TOML (the whole file is then ignored), unknown or
+/// malformed sections, unknown signal IDs in `[heuristics]`, and invalid
+/// `profile`, `[size_buckets]`, `[colors]`, `[symbols]`, `[corpus]` and
+/// `[confidence_bounds]` values.  Empty when the file is clean.
+pub fn config_problems(text: &str) -> Vec<String> {
+    let table: toml::Table = match toml::from_str(text) {
+        Ok(table) => table,
+        Err(e) => {
+            return vec![format!("not valid TOML, so the whole file is ignored: {}", e.message().trim())]
+        }
+    };
+    let mut problems = Vec::new();
+    fn section<T: serde::de::DeserializeOwned>(
+        table: &toml::Table,
+        key: &str,
+        problems: &mut Vec<String>,
+    ) -> Option<T> {
+        let value = table.get(key)?.clone();
+        match value.try_into() {
+            Ok(parsed) => Some(parsed),
+            Err(e) => {
+                let message = e.message().trim().to_string();
+                problems.push(format!("[{key}] is malformed, so the whole file is ignored: {message}"));
+                None
+            }
+        }
+    }
+
+    const KNOWN: &[&str] = &[
+        "profile", "ignore", "heuristics", "cache", "naming", "min_lines", "size_buckets",
+        "generated", "colors", "limits", "symbols", "confidence_bounds", "assert", "corpus",
+    ];
+    let mut unknown: Vec<_> = table.keys().filter(|k| !KNOWN.contains(&k.as_str())).collect();
+    unknown.sort();
+    for key in unknown {
+        problems.push(format!("unknown section or key `{key}` is ignored"));
+    }
+
+    if let Some(name) = section::<String>(&table, "profile", &mut problems) {
+        if !crate::heuristics::PROFILES.iter().any(|p| p.name == name) {
+            problems.push(format!("unknown profile `{name}`; default weights are used"));
+        }
+    }
+    if let Some(weights) = section::<std::collections::BTreeMap<String, f64>>(&table, "heuristics", &mut problems) {
+        let known: std::collections::HashSet<_> =
+            crate::heuristics::all_heuristics().iter().map(|h| h.id).collect();
+        for id in weights.keys().filter(|id| !known.contains(id.as_str())) {
+            problems.push(format!("unknown signal ID `{id}` in [heuristics] has no effect"));
+        }
+    }
+    if let Some(buckets) = section::<std::collections::BTreeMap<String, f64>>(&table, "size_buckets", &mut problems) {
+        for (range, &multiplier) in &buckets {
+            if SizeBucket::parse(range, multiplier).is_none() {
+                problems.push(format!("invalid [size_buckets] entry `{range} = {multiplier}` is ignored"));
+            }
+        }
+    }
+    if let Some(colors) = section::<std::collections::BTreeMap<String, String>>(&table, "colors", &mut problems) {
+        for (name, hex) in &colors {
+            if ModelFamily::from_name(name).is_none() {
+                problems.push(format!("unknown family `{name}` in [colors] is ignored"));
+            } else if crate::colors::parse_hex(hex).is_none() {
+                problems.push(format!("invalid [colors] entry `{name} = \"{hex}\"` (expected #rrggbb) is ignored"));
+            }
+        }
+    }
+    if let Some(symbols) = section::<SymbolsSection>(&table, "symbols", &mut problems) {
+        if let Some(s) = symbols.smoothing.filter(|s| !s.is_finite() || *s < 0.0) {
+            problems.push(format!("invalid [symbols] smoothing `{s}` (expected >= 0) is ignored"));
+        }
+    }
+    if let Some(corpus) = section::<CorpusSection>(&table, "corpus", &mut problems) {
+        if let Some(mix) = corpus.mix.filter(|mix| !(0.0..=1.0).contains(mix)) {
+            problems.push(format!("invalid [corpus] mix `{mix}` (expected 0 to 1) is ignored"));
+        }
+    }
+    type Bounds = std::collections::BTreeMap<String, BoundsSection>;
+    if let Some(bounds) = section::<Bounds>(&table, "confidence_bounds", &mut problems) {
+        for (name, b) in &bounds {
+            if ModelFamily::from_name(name).is_none() {
+                problems.push(format!("unknown family `{name}` in [confidence_bounds] is ignored"));
+            } else if ConfidenceBound::new(b.min, b.max).is_none() {
+                problems.push(format!(
+                    "invalid [confidence_bounds] entry for `{name}` (expected 0 <= min <= max <= 1) is ignored"
+                ));
+            }
+        }
+    }
+    if let Some(assert) = section::<AssertSection>(&table, "assert", &mut problems) {
+        let names = assert.allowed_families.iter().flatten();
+        for name in names.chain(assert.overrides.iter().flat_map(|o| &o.allowed_families)) {
+            if ModelFamily::from_name(name).is_none() {
+                problems.push(format!("unknown family `{name}` in [assert] is ignored"));
+            }
+        }
+        for pattern in assert.overrides.iter().flat_map(|o| &o.paths) {
+            if GitignoreBuilder::new("").add_line(None, pattern).is_err() {
+                problems.push(format!("invalid [assert] override path `{pattern}` is ignored"));
+            }
+        }
+    }
+    section::<IgnoreSection>(&table, "ignore", &mut problems);
+    section::<CacheSection>(&table, "cache", &mut problems);
+    section::<NamingSection>(&table, "naming", &mut problems);
+    section::<MinLinesSection>(&table, "min_lines", &mut problems);
+    section::<GeneratedSection>(&table, "generated", &mut problems);
+    section::<LimitsSection>(&table, "limits", &mut problems);
+    problems
+}
+
 // ---------------------------------------------------------------------------
 // Discovery
 // ---------------------------------------------------------------------------
 
+/// The `.vibecheck` file [`IgnoreConfig::load`] reads for `start`, or `None`
+/// when discovery stops at a git root (or the filesystem root) without
+/// finding one and defaults apply.
+pub fn find_config_file(start: &Path) -> Option<PathBuf> {
+    let path = find_config_root(start).join(".vibecheck");
+    path.is_file().then_some(path)
+}
+
+/// The nearest directory at or above `start` containing `.git` (a directory,
+/// or a file for worktrees and submodules).
+pub fn find_git_root(start: &Path) -> Option<PathBuf> {
+    let dir = if start.is_file() { start.parent()? } else { start };
+    dir.ancestors().find(|d| d.join(".git").exists()).map(Path::to_path_buf)
+}
+
 /// Walk upward from `start` (normalised to a directory) looking for a
 /// `.vibecheck` file or a `.git` directory.  Returns the first match, or
 /// `start` itself if neither is found before the filesystem root.
@@ -309,8 +910,66 @@ fn find_config_root(start: &Path) -> PathBuf {
 // Matcher builders
 // ---------------------------------------------------------------------------
 
+/// File name of the dedicated vibecheck ignore file (gitignore syntax).
+const VIBECHECKIGNORE: &str = ".vibecheckignore";
+
+/// vibecheck's own config files, never analyzed wherever they appear.
+const OWN_FILES: &[&str] = &[".vibecheck", VIBECHECKIGNORE];
+
+/// The cache directory a run with `configured` (`[cache] dir`) would use,
+/// plus the platform default, as absolute paths.  A directory containing
+/// `root` is dropped: excluding it would hide the whole tree.
+fn own_cache_dirs(root: &Path, configured: Option<&Path>) -> Vec<PathBuf> {
+    let root = std::path::absolute(root).unwrap_or_else(|_| root.to_path_buf());
+    let mut dirs: Vec<PathBuf> = [crate::cache::Cache::resolve_path(configured), crate::cache::Cache::default_path()]
+        .into_iter()
+        .filter_map(|d| std::path::absolute(d).ok())
+        .filter(|d| !root.starts_with(d))
+        .collect();
+    dirs.dedup();
+    dirs
+}
+
+/// Resolve an `[assert]` section against the config `root`, dropping
+/// unknown family names and invalid globs with a diagnostic.
+fn family_assertions(root: &Path, section: AssertSection, diagnostics: &mut Vec<String>) -> FamilyAssertions {
+    let families = |names: &[String], diagnostics: &mut Vec<String>| -> Vec<ModelFamily> {
+        names
+            .iter()
+            .filter_map(|name| {
+                let family = ModelFamily::from_name(name);
+                if family.is_none() {
+                    diagnostics.push(format!("unknown family `{name}` in [assert]; ignoring"));
+                }
+                family
+            })
+            .collect()
+    };
+    let root = std::path::absolute(root).unwrap_or_else(|_| root.to_path_buf());
+    let overrides = section
+        .overrides
+        .iter()
+        .map(|o| {
+            let mut b = GitignoreBuilder::new(&root);
+            for pattern in &o.paths {
+                if b.add_line(None, pattern).is_err() {
+                    diagnostics.push(format!("invalid [assert] override path `{pattern}`; ignoring"));
+                }
+            }
+            (b.build().unwrap_or(Gitignore::empty()), families(&o.allowed_families, diagnostics))
+        })
+        .collect();
+    FamilyAssertions {
+        allowed: section.allowed_families.as_deref().map(|names| families(names, diagnostics)),
+        overrides,
+        root,
+    }
+}
+
 /// Build a `Gitignore` matcher that combines the root `.gitignore` (when
-/// `use_gitignore` is `true`) with the extra patterns from `.vibecheck`.
+/// `use_gitignore` is `true`) with the extra patterns from `.vibecheck` and
+/// the root `.vibecheckignore`.  Later rules win, so each source is added in
+/// increasing order of precedence.
 fn build_combined(root: &Path, patterns: &[String], use_gitignore: bool) -> Gitignore {
     let mut b = GitignoreBuilder::new(root);
     if use_gitignore {
@@ -319,19 +978,27 @@ fn build_combined(root: &Path, patterns: &[String], use_gitignore: bool) -> Giti
             let _ = b.add(gi);
         }
     }
-    for p in patterns {
-        let _ = b.add_line(None, p);
-    }
+    add_extra_rules(&mut b, root, patterns);
     b.build().unwrap_or(Gitignore::empty())
 }
 
-/// Build a `Gitignore` matcher for the extra patterns only.
+/// Build a `Gitignore` matcher for the extra patterns and `.vibecheckignore`.
 fn build_extra(root: &Path, patterns: &[String]) -> Gitignore {
     let mut b = GitignoreBuilder::new(root);
+    add_extra_rules(&mut b, root, patterns);
+    b.build().unwrap_or(Gitignore::empty())
+}
+
+/// Add the `.vibecheck` patterns, then the root `.vibecheckignore` so its
+/// rules (including `!` negations) take precedence.
+fn add_extra_rules(b: &mut GitignoreBuilder, root: &Path, patterns: &[String]) {
     for p in patterns {
         let _ = b.add_line(None, p);
     }
-    b.build().unwrap_or(Gitignore::empty())
+    let vi = root.join(VIBECHECKIGNORE);
+    if vi.is_file() {
+        let _ = b.add(vi);
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -373,6 +1040,28 @@ mod tests {
         assert!(!cfg.is_ignored(&dir.path().join("src/main.rs")));
     }
 
+    #[test]
+    fn own_config_and_cache_are_always_ignored() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = dir.path().join("state/cache");
+        std::fs::write(dir.path().join(".vibecheck"), format!("[cache]\ndir = {:?}\n", cache.display().to_string()))
+            .unwrap();
+        std::fs::write(dir.path().join(".vibecheckignore"), "!state/\n!.vibecheck\n").unwrap();
+        let cfg = IgnoreConfig::load(dir.path());
+        assert!(cfg.is_ignored(&dir.path().join(".vibecheck")));
+        assert!(cfg.is_ignored(&dir.path().join("sub/.vibecheckignore")));
+        assert!(cfg.is_ignored_dir(&cache));
+        assert!(cfg.is_ignored(&cache.join("reports/entry.json")));
+        assert!(cfg.is_extra_ignored(&cache.join("reports/entry.json")));
+        assert!(!cfg.is_ignored(&dir.path().join("state/main.rs")));
+
+        let inside = IgnoreConfig::from_config_file(
+            cache.join("project"),
+            toml::from_str(&format!("[cache]\ndir = {:?}\n", cache.display().to_string())).unwrap(),
+        );
+        assert!(!inside.is_ignored(&cache.join("project/main.rs")), "a cache containing the root is not excluded");
+    }
+
     #[test]
     fn ignore_config_parses_patterns() {
         let dir = tempfile::tempdir().unwrap();
@@ -404,6 +1093,44 @@ mod tests {
         assert!(!cfg.is_ignored(&dir.path().join("src/main.rs")));
     }
 
+    #[test]
+    fn vibecheckignore_supports_gitignore_syntax() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("build")).unwrap();
+        std::fs::write(
+            dir.path().join(".vibecheckignore"),
+            "# generated code\n/build/\n*.gen.rs\n!keep.gen.rs\n",
+        )
+        .unwrap();
+        let cfg = IgnoreConfig::load(dir.path());
+        assert!(cfg.is_ignored(&dir.path().join("build/out.rs")));
+        assert!(cfg.is_ignored(&dir.path().join("src/api.gen.rs")));
+        assert!(!cfg.is_ignored(&dir.path().join("src/keep.gen.rs")));
+        // Anchored: only the root-level build/ directory.
+        assert!(!cfg.is_ignored(&dir.path().join("src/build/lib.rs")));
+        assert!(cfg.is_extra_ignored(&dir.path().join("src/api.gen.rs")));
+    }
+
+    #[test]
+    fn vibecheckignore_outranks_config_patterns_and_gitignore() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join(".gitignore"), "fixtures/\n").unwrap();
+        std::fs::write(
+            dir.path().join(".vibecheck"),
+            "[ignore]\npatterns = [\"vendor/\"]\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join(".vibecheckignore"),
+            "!fixtures/\n!vendor/\nvendor/*.min.js\n",
+        )
+        .unwrap();
+        let cfg = IgnoreConfig::load(dir.path());
+        assert!(!cfg.is_ignored(&dir.path().join("fixtures/sample.rs")));
+        assert!(!cfg.is_ignored(&dir.path().join("vendor/lib.rs")));
+        assert!(cfg.is_ignored(&dir.path().join("vendor/app.min.js")));
+    }
+
     #[test]
     fn find_config_root_stops_at_git() {
         let dir = tempfile::tempdir().unwrap();
@@ -447,6 +1174,229 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ignored_signals_parsed_from_config() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(IgnoreConfig::load(dir.path()).ignored_signals().is_empty());
+        std::fs::write(
+            dir.path().join(".vibecheck"),
+            "[ignore]\nsignals = [\"structure\", \"rust.errors.zero_unwrap\"]\n",
+        )
+        .unwrap();
+        let cfg = IgnoreConfig::load(dir.path());
+        assert_eq!(cfg.ignored_signals(), ["structure", "rust.errors.zero_unwrap"]);
+    }
+
+    #[test]
+    fn min_lines_parsed_from_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let cfg = IgnoreConfig::load(dir.path());
+        assert!(cfg.min_lines().is_none());
+        assert!(cfg.analyzer_min_lines().is_empty());
+        std::fs::write(
+            dir.path().join(".vibecheck"),
+            "[min_lines]\ndefault = 3\nnaming = 20\n",
+        )
+        .unwrap();
+        let cfg = IgnoreConfig::load(dir.path());
+        assert_eq!(cfg.min_lines(), Some(3));
+        assert_eq!(cfg.analyzer_min_lines().get("naming"), Some(&20));
+        assert!(!cfg.analyzer_min_lines().contains_key("default"));
+    }
+
+    #[test]
+    fn size_buckets_parsed_sorted_and_validated() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(IgnoreConfig::load(dir.path()).size_buckets().is_empty());
+        std::fs::write(
+            dir.path().join(".vibecheck"),
+            "[size_buckets]\n2000- = 0.9\n0-19 = 0.5\n50-10 = 0.7\n",
+        )
+        .unwrap();
+        let cfg = IgnoreConfig::load(dir.path());
+        assert_eq!(
+            cfg.size_buckets(),
+            [
+                SizeBucket { min_lines: 0, max_lines: Some(19), multiplier: 0.5 },
+                SizeBucket { min_lines: 2000, max_lines: None, multiplier: 0.9 },
+            ]
+        );
+    }
+
+    #[test]
+    fn profile_parsed_from_config() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(IgnoreConfig::load(dir.path()).profile().is_none());
+        let f = dir.path().join(".vibecheck");
+        std::fs::write(&f, "profile = \"strict\"\n\n[ignore]\npatterns = []\n").unwrap();
+        assert_eq!(IgnoreConfig::load(dir.path()).profile(), Some("strict"));
+        std::fs::write(&f, "profile = \"paranoid\"\n").unwrap();
+        assert!(IgnoreConfig::load(dir.path()).profile().is_none());
+    }
+
+    #[test]
+    fn with_profile_overrides_known_names_only() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join(".vibecheck"), "profile = \"strict\"\n").unwrap();
+        let config = IgnoreConfig::load(dir.path());
+        assert_eq!(config.with_profile("lenient").profile(), Some("lenient"));
+        let config = IgnoreConfig::load(dir.path());
+        assert_eq!(config.with_profile("paranoid").profile(), Some("strict"));
+    }
+
+    #[test]
+    fn generated_markers_parsed_from_config() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(IgnoreConfig::load(dir.path()).generated_markers().is_none());
+        std::fs::write(dir.path().join(".vibecheck"), "[generated]\nmarkers = [\"AUTOGEN\"]\n").unwrap();
+        let cfg = IgnoreConfig::load(dir.path());
+        assert_eq!(cfg.generated_markers(), Some(&["AUTOGEN".to_string()][..]));
+    }
+
+    #[test]
+    fn family_colors_parsed_and_validated() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(IgnoreConfig::load(dir.path()).family_colors().is_empty());
+        std::fs::write(
+            dir.path().join(".vibecheck"),
+            "[colors]\nGPT = \"#00ff00\"\nclaude = \"#FF79C6\"\nllama = \"#000000\"\nhuman = \"gold\"\n",
+        )
+        .unwrap();
+        let cfg = IgnoreConfig::load(dir.path());
+        assert_eq!(
+            cfg.family_colors(),
+            [(ModelFamily::Claude, (255, 121, 198)), (ModelFamily::Gpt, (0, 255, 0))]
+        );
+        let mut diagnostics = cfg.diagnostics().to_vec();
+        diagnostics.sort();
+        assert_eq!(
+            diagnostics,
+            [
+                "invalid [colors] entry `human = \"gold\"` (expected #rrggbb); ignoring",
+                "unknown family `llama` in [colors]; ignoring",
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_timeout_parsed_from_config() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(IgnoreConfig::load(dir.path()).parse_timeout_ms().is_none());
+        std::fs::write(dir.path().join(".vibecheck"), "[limits]\nparse_timeout_ms = 250\n").unwrap();
+        assert_eq!(IgnoreConfig::load(dir.path()).parse_timeout_ms(), Some(250));
+        assert!(IgnoreConfig::load(dir.path()).parallel_min_lines().is_none());
+        std::fs::write(dir.path().join(".vibecheck"), "[limits]\nparallel_min_lines = 0\n").unwrap();
+        assert_eq!(IgnoreConfig::load(dir.path()).parallel_min_lines(), Some(0));
+    }
+
+    #[test]
+    fn symbol_smoothing_parsed_and_validated() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(IgnoreConfig::load(dir.path()).symbol_smoothing().is_none());
+        std::fs::write(dir.path().join(".vibecheck"), "[symbols]\nsmoothing = 5\n").unwrap();
+        assert_eq!(IgnoreConfig::load(dir.path()).symbol_smoothing(), Some(5.0));
+        std::fs::write(dir.path().join(".vibecheck"), "[symbols]\nsmoothing = -1.0\n").unwrap();
+        assert!(IgnoreConfig::load(dir.path()).symbol_smoothing().is_none());
+    }
+
+    #[test]
+    fn corpus_priors_parsed_and_validated() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(IgnoreConfig::load(dir.path()).corpus_priors().is_none());
+        std::fs::write(dir.path().join(".vibecheck"), "[corpus]\nstore = \"corpus.db\"\n").unwrap();
+        let config = IgnoreConfig::load(dir.path());
+        assert_eq!(config.corpus_priors(), Some((dir.path().join("corpus.db").as_path(), DEFAULT_CORPUS_MIX)));
+        std::fs::write(dir.path().join(".vibecheck"), "[corpus]\nstore = \"c.db\"\nmix = 1.5\n").unwrap();
+        assert_eq!(IgnoreConfig::load(dir.path()).corpus_priors().unwrap().1, DEFAULT_CORPUS_MIX);
+        std::fs::write(dir.path().join(".vibecheck"), "[corpus]\nmix = 0.2\n").unwrap();
+        assert!(IgnoreConfig::load(dir.path()).corpus_priors().is_none(), "mix alone does nothing");
+        assert_eq!(config_problems("[corpus]\nmix = -1.0\n"), ["invalid [corpus] mix `-1` (expected 0 to 1) is ignored"]);
+    }
+
+    #[test]
+    fn confidence_bounds_parsed_and_validated() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(IgnoreConfig::load(dir.path()).confidence_bounds().is_empty());
+        std::fs::write(
+            dir.path().join(".vibecheck"),
+            "[confidence_bounds]\ncopilot = { max = 0.7 }\nclaude = { min = 0.2, max = 0.9 }\n\
+             gpt = { min = 0.8, max = 0.3 }\nnobody = { max = 0.5 }\n",
+        )
+        .unwrap();
+        assert_eq!(
+            IgnoreConfig::load(dir.path()).confidence_bounds(),
+            [
+                (ModelFamily::Claude, ConfidenceBound { min: 0.2, max: 0.9 }),
+                (ModelFamily::Copilot, ConfidenceBound { min: 0.0, max: 0.7 }),
+            ]
+        );
+
+        let problems = config_problems("[confidence_bounds]\ngpt = { min = 1.5 }\nnobody = { max = 0.5 }\n");
+        assert_eq!(problems.len(), 2, "{problems:?}");
+        assert!(problems[0].contains("invalid [confidence_bounds] entry for `gpt`"));
+        assert!(problems[1].contains("unknown family `nobody`"));
+    }
+
+    #[test]
+    fn assert_section_resolves_families_per_path() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(IgnoreConfig::load(dir.path()).family_assertions().is_none());
+        std::fs::write(
+            dir.path().join(".vibecheck"),
+            "[assert]\nallowed_families = [\"human\", \"nobody\"]\n\n\
+             [[assert.override]]\npaths = [\"examples/\"]\nallowed_families = [\"human\", \"claude\"]\n\n\
+             [[assert.override]]\npaths = [\"examples/gen_*.rs\"]\nallowed_families = [\"gpt\"]\n",
+        )
+        .unwrap();
+        let config = IgnoreConfig::load(dir.path());
+        let assertions = config.family_assertions().unwrap();
+        let allowed = |rel: &str| assertions.allowed_for(&dir.path().join(rel)).map(<[_]>::to_vec);
+        assert_eq!(allowed("src/lib.rs"), Some(vec![ModelFamily::Human]));
+        assert_eq!(allowed("examples/demo.rs"), Some(vec![ModelFamily::Human, ModelFamily::Claude]));
+        assert_eq!(allowed("examples/gen_a.rs"), Some(vec![ModelFamily::Gpt]), "the last match wins");
+        assert_eq!(assertions.allowed_for(Path::new("/elsewhere/x.rs")), Some(&[ModelFamily::Human][..]));
+
+        std::fs::write(dir.path().join(".vibecheck"), "[[assert.override]]\npaths = [\"a/\"]\nallowed_families = []\n")
+            .unwrap();
+        let config = IgnoreConfig::load(dir.path());
+        let assertions = config.family_assertions().unwrap();
+        assert_eq!(assertions.allowed_for(&dir.path().join("b/x.rs")), None, "unmatched files are not checked");
+        assert_eq!(assertions.allowed_for(&dir.path().join("a/x.rs")), Some(&[][..]));
+
+        let problems = config_problems("[assert]\nallowed_families = [\"human\", \"robot\"]\n");
+        assert_eq!(problems, ["unknown family `robot` in [assert] is ignored"]);
+    }
+
+    #[test]
+    fn generic_names_none_when_not_configured() {
+        let dir = tempfile::tempdir().unwrap();
+        let cfg = IgnoreConfig::load(dir.path());
+        assert!(cfg.generic_names().is_none());
+    }
+
+    #[test]
+    fn generic_names_parsed_from_config() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join(".vibecheck"),
+            "[naming]\ngeneric_names = [\"foo\", \"bar\"]\n",
+        )
+        .unwrap();
+        let cfg = IgnoreConfig::load(dir.path());
+        assert_eq!(
+            cfg.generic_names(),
+            Some(&["foo".to_string(), "bar".to_string()][..]),
+        );
+    }
+
+    #[test]
+    fn naming_conventions_parsed_from_config() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(IgnoreConfig::load(dir.path()).naming_conventions(), None);
+        std::fs::write(dir.path().join(".vibecheck"), "[naming]\nconventions = false\n").unwrap();
+        assert_eq!(IgnoreConfig::load(dir.path()).naming_conventions(), Some(false));
+    }
+
     #[test]
     fn cache_dir_absent_section_is_none() {
         let dir = tempfile::tempdir().unwrap();
@@ -458,4 +1408,32 @@ mod tests {
         let cfg = IgnoreConfig::load(dir.path());
         assert!(cfg.cache_dir().is_none());
     }
+
+    #[test]
+    fn config_problems_flags_what_loading_ignores() {
+        assert!(config_problems("profile = \"strict\"\n[heuristics]\n\"rust.errors.zero_unwrap\" = 0.5\n").is_empty());
+        assert!(config_problems("[ignore\n")[0].starts_with("not valid TOML"));
+        assert!(config_problems("[ignore]\npatterns = 3\n")[0].starts_with("[ignore] is malformed"));
+
+        let problems = config_problems(
+            "profile = \"nope\"\n[heuristics]\n\"rust.made_up\" = 1.0\n[colors]\nclaude = \"red\"\n[extra]\n",
+        );
+        assert_eq!(problems.len(), 4, "{problems:?}");
+        assert!(problems[0].contains("`extra`"));
+        assert!(problems[1].contains("unknown profile `nope`"));
+        assert!(problems[2].contains("unknown signal ID `rust.made_up`"));
+        assert!(problems[3].contains("invalid [colors] entry"));
+    }
+
+    #[test]
+    fn config_and_git_roots_are_discovered() {
+        let dir = tempfile::tempdir().unwrap();
+        let sub = dir.path().join("a/b");
+        std::fs::create_dir_all(&sub).unwrap();
+        std::fs::create_dir(dir.path().join(".git")).unwrap();
+        assert_eq!(find_git_root(&sub).as_deref(), Some(dir.path()));
+        assert!(find_config_file(&sub).is_none());
+        std::fs::write(dir.path().join("a/.vibecheck"), "").unwrap();
+        assert_eq!(find_config_file(&sub), Some(dir.path().join("a/.vibecheck")));
+    }
 }
@@ -1,12 +1,59 @@
 use std::path::Path;
 
-/// Source languages supported by CST analysis.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Source languages vibecheck analyzes.
+///
+/// Every language except [`Language::Hcl`] also has a tree-sitter grammar
+/// and CST analysis; HCL is covered by the text analyzers only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Language {
     Rust,
     Python,
     JavaScript,
     Go,
+    CSharp,
+    /// Terraform / HashiCorp Configuration Language.
+    Hcl,
+}
+
+impl Language {
+    /// Stable lowercase identifier — the serialized form (`"rust"`,
+    /// `"javascript"`, `"csharp"`, …).
+    pub fn name(self) -> &'static str {
+        match self {
+            Language::Rust => "rust",
+            Language::Python => "python",
+            Language::JavaScript => "javascript",
+            Language::Go => "go",
+            Language::CSharp => "csharp",
+            Language::Hcl => "hcl",
+        }
+    }
+
+    /// Canonical file extension for this language (without the dot).
+    pub fn extension(self) -> &'static str {
+        match self {
+            Language::Rust => "rs",
+            Language::Python => "py",
+            Language::JavaScript => "js",
+            Language::Go => "go",
+            Language::CSharp => "cs",
+            Language::Hcl => "tf",
+        }
+    }
+}
+
+impl std::fmt::Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Language::Rust => "Rust",
+            Language::Python => "Python",
+            Language::JavaScript => "JavaScript",
+            Language::Go => "Go",
+            Language::CSharp => "C#",
+            Language::Hcl => "Terraform/HCL",
+        })
+    }
 }
 
 /// Detect the language of a file from its extension.
@@ -16,16 +63,96 @@ pub fn detect_language(path: &Path) -> Option<Language> {
         "py" => Some(Language::Python),
         "js" | "ts" | "jsx" | "tsx" => Some(Language::JavaScript),
         "go" => Some(Language::Go),
+        "cs" => Some(Language::CSharp),
+        "tf" | "hcl" => Some(Language::Hcl),
         _ => None,
     }
 }
 
-/// Get the tree-sitter grammar for a given language.
-pub fn get_ts_language(lang: Language) -> tree_sitter::Language {
+/// Whether a path is a JSX / TSX file.
+///
+/// These parse as [`Language::JavaScript`] but additionally receive the
+/// React component heuristics.
+pub fn is_jsx_path(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|e| e.to_str()),
+        Some("jsx" | "tsx")
+    )
+}
+
+/// Whether a file holds test code, judged by path convention.
+///
+/// Conventions: a `tests/`, `test/`, or `__tests__/` directory,
+/// `*_test.rs`, `*_test.go`, `test_*.py` / `*_test.py`, and `*.test.*` /
+/// `*.spec.*` JavaScript and TypeScript files.  A Rust module with an
+/// inline `#[cfg(test)]` block is not a test file: most of it is
+/// production code.
+pub fn is_test_file(path: &Path) -> bool {
+    let in_test_dir = path.parent().is_some_and(|dir| {
+        dir.components().any(|c| {
+            matches!(c.as_os_str().to_str(), Some("tests" | "test" | "__tests__"))
+        })
+    });
+    if in_test_dir {
+        return true;
+    }
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    match detect_language(path) {
+        Some(Language::Go) => name.ends_with("_test.go"),
+        Some(Language::Python) => name.starts_with("test_") || name.ends_with("_test.py"),
+        Some(Language::JavaScript) => name.contains(".test.") || name.contains(".spec."),
+        Some(Language::Rust) => name.ends_with("_test.rs"),
+        _ => false,
+    }
+}
+
+/// Get the tree-sitter grammar for a given language, or `None` when no
+/// grammar is bundled for it (HCL).
+pub fn get_ts_language(lang: Language) -> Option<tree_sitter::Language> {
     match lang {
-        Language::Rust => tree_sitter_rust::LANGUAGE.into(),
-        Language::Python => tree_sitter_python::LANGUAGE.into(),
-        Language::JavaScript => tree_sitter_javascript::LANGUAGE.into(),
-        Language::Go => tree_sitter_go::LANGUAGE.into(),
+        Language::Rust => Some(tree_sitter_rust::LANGUAGE.into()),
+        Language::Python => Some(tree_sitter_python::LANGUAGE.into()),
+        Language::JavaScript => Some(tree_sitter_javascript::LANGUAGE.into()),
+        Language::Go => Some(tree_sitter_go::LANGUAGE.into()),
+        Language::CSharp => Some(tree_sitter_c_sharp::LANGUAGE.into()),
+        Language::Hcl => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_by_path_convention() {
+        for path in [
+            "tests/integration.rs",
+            "src/__tests__/app.js",
+            "pkg/parse_test.go",
+            "test_parse.py",
+            "parse_test.py",
+            "src/App.test.tsx",
+            "src/api.spec.ts",
+        ] {
+            assert!(is_test_file(Path::new(path)), "{path}");
+        }
+        for path in ["src/lib.rs", "pkg/parse.go", "testing.py", "src/contest.js"] {
+            assert!(!is_test_file(Path::new(path)), "{path}");
+        }
+    }
+
+    #[test]
+    fn rust_test_files_are_judged_by_path_only() {
+        assert!(is_test_file(Path::new("src/parser_test.rs")));
+        assert!(is_test_file(Path::new("tests/cli.rs")));
+        // Inline `#[cfg(test)]` modules sit alongside production code.
+        assert!(!is_test_file(Path::new("src/lib.rs")));
+    }
+
+    #[test]
+    fn terraform_files_are_hcl_without_grammar() {
+        assert_eq!(detect_language(Path::new("infra/main.tf")), Some(Language::Hcl));
+        assert_eq!(detect_language(Path::new("config.hcl")), Some(Language::Hcl));
+        assert!(get_ts_language(Language::Hcl).is_none());
     }
 }
@@ -3,6 +3,8 @@
 pub mod analyzers;
 pub mod cache;
 pub mod colors;
+pub mod editorconfig;
+pub mod eval;
 pub mod heuristics;
 pub mod ignore_rules;
 pub mod language;
@@ -24,16 +26,174 @@ use merkle::walk_and_hash_with;
 use pipeline::Pipeline;
 use report::Report;
 
-fn load_config(dir: &std::path::Path) -> IgnoreConfig {
-    IgnoreConfig::load(dir)
+/// Settings that take priority over the discovered `.vibecheck` config,
+/// such as the CLI's `--config` and `--profile` flags.  The default changes
+/// nothing.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigOverrides {
+    /// Config file used instead of discovering `.vibecheck` upward from each
+    /// analyzed path.
+    pub config_file: Option<PathBuf>,
+    /// Built-in weight profile used instead of the config's `profile` key.
+    /// Unknown names are ignored.
+    pub profile: Option<String>,
+}
+
+impl ConfigOverrides {
+    /// The config that applies to files in `dir`, with these overrides
+    /// applied.  An unreadable `config_file` falls back to discovery with a
+    /// warning.
+    pub fn load_config(&self, dir: &Path) -> IgnoreConfig {
+        let config = match &self.config_file {
+            Some(path) => IgnoreConfig::from_file(path).unwrap_or_else(|e| {
+                eprintln!("vibecheck: warning: {e}; falling back to .vibecheck discovery");
+                IgnoreConfig::load(dir)
+            }),
+            None => IgnoreConfig::load(dir),
+        };
+        self.apply(config)
+    }
+
+    /// `config` with these overrides applied.
+    pub fn apply(&self, config: IgnoreConfig) -> IgnoreConfig {
+        match &self.profile {
+            Some(profile) => config.with_profile(profile),
+            None => config,
+        }
+    }
 }
 
 fn heuristics_from_config(config: &IgnoreConfig) -> Box<dyn HeuristicsProvider> {
-    Box::new(ConfiguredHeuristics::from_config(config.heuristics_map()))
+    let profile = config.profile().and_then(heuristics::profile_overrides).unwrap_or_default();
+    Box::new(
+        ConfiguredHeuristics::from_config(config.heuristics_map())
+            .with_profile(profile)
+            .with_suppressed(config.ignored_signals().to_vec()),
+    )
+}
+
+fn analyzers_from_config(
+    config: &IgnoreConfig,
+    max_line_length: Option<usize>,
+) -> Vec<Box<dyn analyzers::Analyzer>> {
+    let naming = match config.generic_names() {
+        Some(names) => analyzers::text::naming::NamingAnalyzer::with_generic_names(names.to_vec()),
+        None => analyzers::text::naming::NamingAnalyzer::default(),
+    }
+    .with_conventions(config.naming_conventions().unwrap_or(true));
+    let structure =
+        analyzers::text::code_structure::CodeStructureAnalyzer::with_max_line_length(max_line_length);
+    analyzers::analyzers_with(naming, structure)
+}
+
+/// Serialized form of every config setting that changes analysis output:
+/// heuristic weights, the profile, suppressed signals, naming overrides,
+/// minimum line counts, size buckets, generated-code markers, the parse
+/// timeout, symbol smoothing, confidence bounds and the corpus priors
+/// (including the store's current tallies, so rebuilding the corpus
+/// invalidates cached reports).
+///
+/// Ignore patterns and the cache location are deliberately left out: they
+/// decide *which* files are analyzed, not how.
+fn config_fingerprint(config: &IgnoreConfig) -> Vec<u8> {
+    let mut heuristics: Vec<_> = config.heuristics_map().into_iter().collect();
+    heuristics.sort_by(|a, b| a.0.cmp(&b.0));
+    let mut analyzer_min_lines: Vec<_> = config.analyzer_min_lines().iter().collect();
+    analyzer_min_lines.sort();
+    let profile = config.profile();
+    format!(
+        "{heuristics:?}|{profile:?}|{:?}|{:?}|{:?}|{:?}|{analyzer_min_lines:?}|{:?}|{:?}|{:?}|{:?}|{:?}|{}",
+        config.ignored_signals(),
+        config.generic_names(),
+        config.naming_conventions(),
+        config.min_lines(),
+        config.size_buckets(),
+        config.generated_markers(),
+        config.parse_timeout_ms(),
+        config.symbol_smoothing(),
+        config.confidence_bounds(),
+        corpus_fingerprint(config),
+    )
+    .into_bytes()
+}
+
+/// The `[corpus]` blend and store tallies, sorted; empty without priors.
+#[cfg(feature = "corpus")]
+fn corpus_fingerprint(config: &IgnoreConfig) -> String {
+    let Some((path, mix)) = config.corpus_priors() else {
+        return String::new();
+    };
+    let Ok(counts) = corpus_counts(path) else {
+        return format!("{mix}|unreadable");
+    };
+    let mut families: Vec<_> = counts.families.iter().map(|(f, n)| (f.name(), *n)).collect();
+    families.sort();
+    let mut signals: Vec<_> = counts
+        .signals
+        .iter()
+        .flat_map(|(id, by_family)| by_family.iter().map(move |(f, n)| (id.as_str(), f.name(), *n)))
+        .collect();
+    signals.sort();
+    format!("{mix}|{families:?}|{signals:?}")
+}
+
+#[cfg(not(feature = "corpus"))]
+fn corpus_fingerprint(_config: &IgnoreConfig) -> String {
+    String::new()
+}
+
+/// Read the tallies of the corpus store at `path`, which must exist.
+#[cfg(feature = "corpus")]
+fn read_corpus(path: &Path) -> anyhow::Result<store::CorpusCounts> {
+    anyhow::ensure!(path.is_file(), "no such file");
+    Ok(store::Store::open(path)?.signal_family_counts()?)
+}
+
+/// [`read_corpus`], memoized per store path until the file's modification
+/// time changes.  The cache key and the pipeline both need the tallies for
+/// every analyzed file, so the store is read (and a failure reported) once
+/// per run rather than twice per file.
+#[cfg(feature = "corpus")]
+fn corpus_counts(path: &Path) -> Result<std::sync::Arc<store::CorpusCounts>, String> {
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex, OnceLock};
+    type Read = (Option<std::time::SystemTime>, Result<Arc<store::CorpusCounts>, String>);
+    static READS: OnceLock<Mutex<HashMap<PathBuf, Read>>> = OnceLock::new();
+
+    let modified = std::fs::metadata(path).and_then(|m| m.modified()).ok();
+    let mut reads = READS.get_or_init(Default::default).lock().unwrap_or_else(|e| e.into_inner());
+    if let Some((_, counts)) = reads.get(path).filter(|(seen, _)| *seen == modified) {
+        return counts.clone();
+    }
+    let counts = read_corpus(path).map(Arc::new).map_err(|e| {
+        eprintln!("vibecheck: warning: cannot read corpus store {}: {e}; ignoring [corpus]", path.display());
+        e.to_string()
+    });
+    reads.insert(path.to_path_buf(), (modified, counts.clone()));
+    counts
 }
 
-fn open_cache(config: &IgnoreConfig) -> Option<Cache> {
-    let path = Cache::resolve_path(config.cache_dir());
+/// Cache key for `bytes` analyzed under `config` with the `.editorconfig`
+/// line-length limit `max_line_length`.
+fn cache_key_with(bytes: &[u8], config: &IgnoreConfig, max_line_length: Option<usize>) -> [u8; 32] {
+    let mut fingerprint = config_fingerprint(config);
+    fingerprint.extend(format!("|{max_line_length:?}").into_bytes());
+    Cache::hash_content_with(bytes, &fingerprint)
+}
+
+/// Cache key for `bytes` analyzed as the file at `path`: the content hash
+/// mixed with a fingerprint of the `.vibecheck` config and `.editorconfig`
+/// line-length limit that apply to `path`, so editing weights or profiles
+/// invalidates cached reports.
+pub fn cache_key(bytes: &[u8], path: &Path, overrides: &ConfigOverrides) -> [u8; 32] {
+    let config = overrides.load_config(path.parent().unwrap_or(path));
+    cache_key_with(bytes, &config, editorconfig::max_line_length(path))
+}
+
+/// Open the cache, preferring an explicit `cache_dir` (e.g. `--cache-dir`)
+/// over the config's `[cache] dir` and the environment/platform defaults.
+fn open_cache(config: &IgnoreConfig, cache_dir: Option<&Path>) -> Option<Cache> {
+    let path = Cache::resolve_path(cache_dir.or(config.cache_dir()));
     Cache::open(&path).ok()
 }
 
@@ -48,31 +208,100 @@ pub fn analyze(source: &str) -> Report {
 /// Cache location is resolved from (in priority order):
 /// 1. `[cache] dir` in the nearest `.vibecheck` config
 /// 2. `VIBECHECK_CACHE_DIR` environment variable
-/// 3. Platform default (`~/.cache/vibecheck/`)
+/// 3. Platform default (`$XDG_CACHE_HOME/vibecheck/` on Linux)
 pub fn analyze_file(path: &Path) -> std::io::Result<Report> {
+    analyze_file_with_cache_dir(path, None, &ConfigOverrides::default())
+}
+
+/// Like [`analyze_file`], but stores the cache in `cache_dir` when given,
+/// taking priority over `[cache] dir` and `VIBECHECK_CACHE_DIR`, and applies
+/// `overrides` on top of the discovered config.
+pub fn analyze_file_with_cache_dir(
+    path: &Path,
+    cache_dir: Option<&Path>,
+    overrides: &ConfigOverrides,
+) -> std::io::Result<Report> {
+    let bytes = std::fs::read(path)?;
+    analyze_bytes_with_cache_dir(bytes, path, cache_dir, false, overrides)
+}
+
+/// Like [`analyze_file_with_cache_dir`], but a file that is not valid UTF-8
+/// is analyzed anyway, with each invalid sequence replaced by U+FFFD, and
+/// the report is marked [`lossy`](report::ReportMetadata::lossy).  Meant
+/// for legacy trees where a stray byte would otherwise fail the file.
+pub fn analyze_file_lossy(
+    path: &Path,
+    cache_dir: Option<&Path>,
+    overrides: &ConfigOverrides,
+) -> std::io::Result<Report> {
     let bytes = std::fs::read(path)?;
-    let hash = Cache::hash_content(&bytes);
+    analyze_bytes_with_cache_dir(bytes, path, cache_dir, true, overrides)
+}
+
+/// [`analyze_file_lossy`] without consulting or updating the cache.
+pub fn analyze_file_lossy_no_cache(path: &Path, overrides: &ConfigOverrides) -> std::io::Result<Report> {
+    let (source, lossy) = decode_source(std::fs::read(path)?, true)?;
+    let mut report = analyze_source(&source, path, overrides);
+    report.metadata.lossy = lossy;
+    Ok(report)
+}
+
+/// `bytes` as a UTF-8 string.  Invalid UTF-8 is an error unless `lossy`,
+/// in which case it is replaced with U+FFFD; the flag says whether any was.
+fn decode_source(bytes: Vec<u8>, lossy: bool) -> std::io::Result<(String, bool)> {
+    match String::from_utf8(bytes) {
+        Ok(source) => Ok((source, false)),
+        Err(e) if lossy => Ok((String::from_utf8_lossy(e.as_bytes()).into_owned(), true)),
+        Err(e) => Err(std::io::Error::new(std::io::ErrorKind::InvalidData, e)),
+    }
+}
+
+/// Async [`analyze_file`] for embedding in async services: the read goes
+/// through `tokio::fs` and the CPU-bound pipeline (plus config and cache
+/// lookups) runs on tokio's blocking pool, so the executor is never stalled.
+///
+/// Must be called from within a tokio runtime.  Requires the `async` feature.
+#[cfg(feature = "async")]
+pub async fn analyze_file_async(path: &Path) -> std::io::Result<Report> {
+    let bytes = tokio::fs::read(path).await?;
+    let path = path.to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        analyze_bytes_with_cache_dir(bytes, &path, None, false, &ConfigOverrides::default())
+    })
+        .await
+        .map_err(std::io::Error::other)?
+}
+
+/// Cached analysis of `bytes` already read from `path`, decoded lossily
+/// when `lossy` (see [`analyze_file_lossy`]).
+fn analyze_bytes_with_cache_dir(
+    bytes: Vec<u8>,
+    path: &Path,
+    cache_dir: Option<&Path>,
+    lossy: bool,
+    overrides: &ConfigOverrides,
+) -> std::io::Result<Report> {
     let dir = path.parent().unwrap_or(path);
-    let config = load_config(dir);
-    let cache = open_cache(&config);
+    let config = overrides.load_config(dir);
+    let max_line_length = editorconfig::max_line_length(path);
+    let hash = cache_key_with(&bytes, &config, max_line_length);
+    let cache = open_cache(&config, cache_dir);
 
     if let Some(ref c) = cache {
-        if let Some(mut cached) = c.get(&hash) {
+        // A lossy report must not stand in for a strict read of the same bytes.
+        if let Some(mut cached) = c.get(&hash).filter(|r| lossy || !r.metadata.lossy) {
             cached.metadata.file_path = Some(path.to_path_buf());
             return Ok(cached);
         }
     }
 
-    let source = String::from_utf8(bytes)
-        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
-    let pipeline = Pipeline::with_heuristics(
-        crate::analyzers::default_analyzers(),
-        crate::analyzers::default_cst_analyzers(),
-        heuristics_from_config(&config),
-    );
-    let report = pipeline.run(&source, Some(path.to_path_buf()));
+    let (source, replaced) = decode_source(bytes, lossy)?;
+    let pipeline = build_pipeline(&config, max_line_length);
+    let mut report = pipeline.run(&source, Some(path.to_path_buf()));
+    report.metadata.lossy = replaced;
 
-    if let Some(ref c) = cache {
+    // A timed-out parse is retried next run instead of being served from cache.
+    if let Some(ref c) = cache.filter(|_| !report.metadata.timed_out) {
         let _ = c.put(&hash, &report);
     }
 
@@ -81,15 +310,131 @@ pub fn analyze_file(path: &Path) -> std::io::Result<Report> {
 
 /// Analyze a file without consulting or updating the cache.
 pub fn analyze_file_no_cache(path: &Path) -> std::io::Result<Report> {
+    analyze_file_no_cache_with_overrides(path, &ConfigOverrides::default())
+}
+
+/// Like [`analyze_file_no_cache`], but applies `overrides` on top of the
+/// discovered config.
+pub fn analyze_file_no_cache_with_overrides(
+    path: &Path,
+    overrides: &ConfigOverrides,
+) -> std::io::Result<Report> {
+    let source = std::fs::read_to_string(path)?;
+    let dir = path.parent().unwrap_or(path);
+    let pipeline = pipeline_for_file(&overrides.load_config(dir), path);
+    Ok(pipeline.run(&source, Some(path.to_path_buf())))
+}
+
+/// Attribute each line of the file at `path` under its `.vibecheck` config;
+/// see [`Pipeline::run_heatmap`].  `window` is the number of lines analyzed
+/// together ([`pipeline::DEFAULT_HEATMAP_WINDOW`] is a good default).  The
+/// cache is not consulted.
+pub fn analyze_file_heatmap(
+    path: &Path,
+    window: usize,
+    overrides: &ConfigOverrides,
+) -> std::io::Result<Vec<report::LineAttribution>> {
     let source = std::fs::read_to_string(path)?;
     let dir = path.parent().unwrap_or(path);
-    let config = load_config(dir);
+    let pipeline = pipeline_for_file(&overrides.load_config(dir), path);
+    Ok(pipeline.run_heatmap(&source, Some(path.to_path_buf()), window))
+}
+
+/// Analyze in-memory `source` as though it were the file at `path`.
+///
+/// The path selects the language and the `.vibecheck` config, but is never
+/// read — useful for content that is not in the working tree, such as staged
+/// git blobs.  The cache is not consulted.
+pub fn analyze_source(source: &str, path: &Path, overrides: &ConfigOverrides) -> Report {
+    let dir = path.parent().unwrap_or(path);
+    pipeline_for_file(&overrides.load_config(dir), path).run(source, Some(path.to_path_buf()))
+}
+
+/// Analyze in-memory `source` as `language`, including per-symbol reports.
+///
+/// Like [`analyze_source`], `path` selects the `.vibecheck` config and
+/// test-file conventions but is never read.  When its extension does not
+/// already map to `language` (e.g. an unsaved editor buffer), the language's
+/// canonical extension is substituted for analysis; the report still carries
+/// the original `path`.  The cache is not consulted.
+pub fn analyze_with_language(
+    source: &str,
+    path: &Path,
+    language: language::Language,
+    overrides: &ConfigOverrides,
+) -> anyhow::Result<Report> {
+    let dir = path.parent().unwrap_or(path);
+    let pipeline = pipeline_for_file(&overrides.load_config(dir), path);
+    let analysis_path = if language::detect_language(path) == Some(language) {
+        path.to_path_buf()
+    } else {
+        path.with_extension(language.extension())
+    };
+    let mut report = pipeline.run(source, Some(analysis_path.clone()));
+    report.symbol_reports = Some(pipeline.run_symbols(source.as_bytes(), &analysis_path)?);
+    report.metadata.file_path = Some(path.to_path_buf());
+    Ok(report)
+}
+
+/// Build a [`Pipeline`] honouring a loaded `.vibecheck` config (heuristic
+/// weights, suppressed signals, naming overrides, minimum line counts, size
+/// buckets, generated-code markers, parse timeout, symbol smoothing).
+///
+/// Useful when analyzing many in-memory sources under one config, e.g. a
+/// batch of snippets, without re-discovering the config for each.
+///
+/// No `.editorconfig` is consulted, since there is no file path to look it
+/// up for; line-length signals use their per-language defaults.
+pub fn pipeline_from_config(config: &IgnoreConfig) -> Pipeline {
+    build_pipeline(config, None)
+}
+
+/// [`pipeline_from_config`] plus the `.editorconfig` `max_line_length` that
+/// applies to `path`, if any.
+fn pipeline_for_file(config: &IgnoreConfig, path: &Path) -> Pipeline {
+    build_pipeline(config, editorconfig::max_line_length(path))
+}
+
+fn build_pipeline(config: &IgnoreConfig, max_line_length: Option<usize>) -> Pipeline {
     let pipeline = Pipeline::with_heuristics(
-        crate::analyzers::default_analyzers(),
+        analyzers_from_config(config, max_line_length),
         crate::analyzers::default_cst_analyzers(),
-        heuristics_from_config(&config),
-    );
-    Ok(pipeline.run(&source, Some(path.to_path_buf())))
+        heuristics_from_config(config),
+    )
+    .with_analyzer_min_lines(config.analyzer_min_lines().clone())
+    .with_size_buckets(config.size_buckets().to_vec())
+    .with_confidence_bounds(config.confidence_bounds().to_vec());
+    let pipeline = match config.generated_markers() {
+        Some(markers) => pipeline.with_generated_markers(markers.to_vec()),
+        None => pipeline,
+    };
+    let pipeline = match config.parse_timeout_ms() {
+        Some(0) => pipeline.with_parse_timeout(None),
+        Some(ms) => pipeline.with_parse_timeout(Some(std::time::Duration::from_millis(ms))),
+        None => pipeline,
+    };
+    let pipeline = match config.parallel_min_lines() {
+        Some(0) => pipeline.with_parallel_min_lines(None),
+        Some(lines) => pipeline.with_parallel_min_lines(Some(lines)),
+        None => pipeline,
+    };
+    let pipeline = match config.symbol_smoothing() {
+        Some(strength) => pipeline.with_symbol_smoothing(strength),
+        None => pipeline,
+    };
+    #[cfg(feature = "corpus")]
+    let pipeline = match config.corpus_priors() {
+        // An unreadable store was already reported by `corpus_counts`.
+        Some((path, mix)) => match corpus_counts(path) {
+            Ok(counts) => pipeline.with_corpus_counts(&counts, mix),
+            Err(_) => pipeline,
+        },
+        None => pipeline,
+    };
+    match config.min_lines() {
+        Some(lines) => pipeline.with_min_lines(lines),
+        None => pipeline,
+    }
 }
 
 /// Analyze every supported source file under `dir`, using a Merkle hash tree
@@ -107,9 +452,9 @@ pub fn analyze_directory(
     dir: &Path,
     use_cache: bool,
 ) -> anyhow::Result<Vec<(PathBuf, Report)>> {
-    let config = load_config(dir);
-    let cache_path = Cache::resolve_path(config.cache_dir());
-    analyze_directory_inner(dir, use_cache, &config, &cache_path)
+    let config = IgnoreConfig::load(dir);
+    let options = DirOptions { cache_dir: config.cache_dir().map(Path::to_path_buf), ..DirOptions::default() };
+    analyze_directory_with_options(dir, use_cache, &config, &options)
 }
 
 /// Like [`analyze_directory`], but accepts any [`IgnoreRules`] implementation.
@@ -120,15 +465,113 @@ pub fn analyze_directory(
 /// other [`IgnoreRules`] implementation.
 ///
 /// Cache location is resolved from `VIBECHECK_CACHE_DIR` env var, falling back
-/// to the platform default (`~/.cache/vibecheck/`).  For config-file overrides,
-/// use [`analyze_directory`] which reads `[cache] dir` from `.vibecheck`.
+/// to the platform default (`$XDG_CACHE_HOME/vibecheck/` on Linux).  For
+/// config-file overrides, use [`analyze_directory`] which reads `[cache] dir`
+/// from `.vibecheck`; for an explicit location use
+/// [`analyze_directory_with_cache_dir`].
 pub fn analyze_directory_with(
     dir: &Path,
     use_cache: bool,
     ignore: &dyn IgnoreRules,
 ) -> anyhow::Result<Vec<(PathBuf, Report)>> {
-    let cache_path = Cache::resolve_path(None);
-    analyze_directory_inner(dir, use_cache, ignore, &cache_path)
+    analyze_directory_with_cache_dir(dir, use_cache, ignore, None)
+}
+
+/// Like [`analyze_directory_with`], but stores the cache in `cache_dir` when
+/// given (e.g. a shared CI cache or a per-project cache directory).
+pub fn analyze_directory_with_cache_dir(
+    dir: &Path,
+    use_cache: bool,
+    ignore: &dyn IgnoreRules,
+    cache_dir: Option<&Path>,
+) -> anyhow::Result<Vec<(PathBuf, Report)>> {
+    let options = DirOptions { cache_dir: cache_dir.map(Path::to_path_buf), ..DirOptions::default() };
+    analyze_directory_with_options(dir, use_cache, ignore, &options)
+}
+
+/// How [`analyze_directory_with_options`] walks a directory.
+#[derive(Debug, Clone)]
+pub struct DirOptions {
+    /// Descend into subdirectories.  Defaults to `true`.
+    pub recursive: bool,
+    /// Which extensions to pick up (e.g. restrict a polyglot repo to `rs`
+    /// files).  Defaults to every supported extension.
+    pub filter: ExtensionFilter,
+    /// Cache location, taking priority over `VIBECHECK_CACHE_DIR` and the
+    /// platform default.
+    pub cache_dir: Option<PathBuf>,
+    /// Applied on top of each file's discovered config.
+    pub overrides: ConfigOverrides,
+}
+
+impl Default for DirOptions {
+    fn default() -> Self {
+        Self {
+            recursive: true,
+            filter: ExtensionFilter::default(),
+            cache_dir: None,
+            overrides: ConfigOverrides::default(),
+        }
+    }
+}
+
+/// Like [`analyze_directory_with`], with every walk setting spelled out in
+/// `options`.
+///
+/// A non-recursive walk does not consult the Merkle directory cache (there
+/// is no subtree to skip); unchanged files are still served from the
+/// per-file cache when `use_cache` is `true`.
+pub fn analyze_directory_with_options(
+    dir: &Path,
+    use_cache: bool,
+    ignore: &dyn IgnoreRules,
+    options: &DirOptions,
+) -> anyhow::Result<Vec<(PathBuf, Report)>> {
+    let cache_path = Cache::resolve_path(options.cache_dir.as_deref());
+    if options.recursive {
+        return analyze_directory_inner(dir, use_cache, ignore, &cache_path, options);
+    }
+    let mut results = Vec::new();
+    let cache_path = use_cache.then_some(cache_path.as_path());
+    walk_and_analyze(dir, options, cache_path, &mut results, ignore)?;
+    Ok(results)
+}
+
+/// File extensions picked up by directory analysis.
+pub const SUPPORTED_EXTS: [&str; 10] = ["rs", "py", "js", "ts", "jsx", "tsx", "go", "cs", "tf", "hcl"];
+
+/// Narrows [`SUPPORTED_EXTS`] for directory walks.
+///
+/// The default admits every supported extension. `include` restricts the set
+/// to the listed extensions; `exclude` removes extensions from it. Leading
+/// dots are ignored, so `.rs` and `rs` are equivalent.
+#[derive(Debug, Clone, Default)]
+pub struct ExtensionFilter {
+    include: Option<Vec<String>>,
+    exclude: Vec<String>,
+}
+
+impl ExtensionFilter {
+    pub fn new(include: Option<Vec<String>>, exclude: Vec<String>) -> Self {
+        let normalize = |v: Vec<String>| -> Vec<String> {
+            v.into_iter().map(|e| e.trim_start_matches('.').to_string()).collect()
+        };
+        Self { include: include.map(normalize), exclude: normalize(exclude) }
+    }
+
+    /// Whether a file with extension `ext` should be analyzed.
+    pub fn allows(&self, ext: &str) -> bool {
+        SUPPORTED_EXTS.contains(&ext)
+            && self.include.as_ref().is_none_or(|inc| inc.iter().any(|e| e == ext))
+            && !self.exclude.iter().any(|e| e == ext)
+    }
+
+    /// Whether `path` has an extension this filter admits.
+    pub fn allows_path(&self, path: &Path) -> bool {
+        path.extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| self.allows(e))
+    }
 }
 
 fn analyze_directory_inner(
@@ -136,8 +579,8 @@ fn analyze_directory_inner(
     use_cache: bool,
     ignore: &dyn IgnoreRules,
     cache_path: &Path,
+    options: &DirOptions,
 ) -> anyhow::Result<Vec<(PathBuf, Report)>> {
-    let supported_exts = ["rs", "py", "js", "ts", "jsx", "tsx", "go"];
     let cache = if use_cache {
         Cache::open(cache_path).ok()
     } else {
@@ -164,11 +607,11 @@ fn analyze_directory_inner(
 
     if unchanged {
         // Collect reports from the file cache — no pipeline work needed.
-        collect_cached_reports(dir, &supported_exts, cache.as_ref(), &mut results, ignore);
+        collect_cached_reports(dir, options, cache.as_ref(), cache_path, &mut results, ignore);
     } else {
         // Walk and analyze, relying on the per-file cache to avoid re-parsing
         // individual unchanged files (analyze_file handles per-file caching).
-        walk_and_analyze(dir, &supported_exts, &mut results, ignore)?;
+        walk_and_analyze(dir, options, Some(cache_path), &mut results, ignore)?;
 
         // Persist the updated directory node.
         if let Some(ref c) = cache {
@@ -181,8 +624,9 @@ fn analyze_directory_inner(
 
 fn collect_cached_reports(
     dir: &Path,
-    supported_exts: &[&str],
+    options: &DirOptions,
     cache: Option<&Cache>,
+    cache_path: &Path,
     results: &mut Vec<(PathBuf, Report)>,
     ignore: &dyn IgnoreRules,
 ) {
@@ -198,25 +642,22 @@ fn collect_cached_reports(
             if ignore.is_ignored_dir(&path) {
                 continue;
             }
-            collect_cached_reports(&path, supported_exts, cache, results, ignore);
+            collect_cached_reports(&path, options, cache, cache_path, results, ignore);
         } else if path.is_file() {
             if ignore.is_ignored(&path) {
                 continue;
             }
-            let ext = path
-                .extension()
-                .and_then(|e| e.to_str())
-                .unwrap_or("");
-            if !supported_exts.contains(&ext) {
+            if !options.filter.allows_path(&path) {
                 continue;
             }
             if let Ok(bytes) = std::fs::read(&path) {
-                let hash = Cache::hash_content(&bytes);
+                let config = options.overrides.load_config(dir);
+                let hash = cache_key_with(&bytes, &config, editorconfig::max_line_length(&path));
                 let cached = cache.and_then(|c| c.get(&hash));
                 if let Some(mut report) = cached {
                     report.metadata.file_path = Some(path.clone());
                     results.push((path, report));
-                } else if let Ok(report) = analyze_file(&path) {
+                } else if let Ok(report) = analyze_bytes_with_cache_dir(bytes, &path, Some(cache_path), false, &options.overrides) {
                     results.push((path, report));
                 }
             }
@@ -224,9 +665,12 @@ fn collect_cached_reports(
     }
 }
 
+/// Analyze supported files under `dir`, descending into subdirectories only
+/// when `options.recursive` is set.  `cache_path: None` bypasses the file cache.
 fn walk_and_analyze(
     dir: &Path,
-    supported_exts: &[&str],
+    options: &DirOptions,
+    cache_path: Option<&Path>,
     results: &mut Vec<(PathBuf, Report)>,
     ignore: &dyn IgnoreRules,
 ) -> anyhow::Result<()> {
@@ -238,22 +682,24 @@ fn walk_and_analyze(
 
     for path in entries {
         if path.is_dir() {
-            if ignore.is_ignored_dir(&path) {
+            if !options.recursive || ignore.is_ignored_dir(&path) {
                 continue;
             }
-            walk_and_analyze(&path, supported_exts, results, ignore)?;
+            walk_and_analyze(&path, options, cache_path, results, ignore)?;
         } else if path.is_file() {
             if ignore.is_ignored(&path) {
                 continue;
             }
-            let ext = path
-                .extension()
-                .and_then(|e| e.to_str())
-                .unwrap_or("");
-            if !supported_exts.contains(&ext) {
+            if !options.filter.allows_path(&path) {
                 continue;
             }
-            let report = analyze_file(&path)
+            let report = match cache_path {
+                Some(cache_path) => {
+                    analyze_file_with_cache_dir(&path, Some(cache_path), &options.overrides)
+                }
+                None => analyze_file_no_cache_with_overrides(&path, &options.overrides),
+            };
+            let report = report
                 .map_err(|e| anyhow::anyhow!("failed to analyze {}: {}", path.display(), e))?;
             results.push((path, report));
         }
@@ -266,12 +712,24 @@ fn walk_and_analyze(
 /// Both the base report and the symbol list are served from the
 /// content-addressed cache when available, and written back on a miss.
 pub fn analyze_file_symbols(file_path: &Path) -> anyhow::Result<Report> {
+    analyze_file_symbols_with_cache_dir(file_path, None, &ConfigOverrides::default())
+}
+
+/// Like [`analyze_file_symbols`], but stores the cache in `cache_dir` when
+/// given, taking priority over `[cache] dir` and `VIBECHECK_CACHE_DIR`, and
+/// applies `overrides` on top of the discovered config.
+pub fn analyze_file_symbols_with_cache_dir(
+    file_path: &Path,
+    cache_dir: Option<&Path>,
+    overrides: &ConfigOverrides,
+) -> anyhow::Result<Report> {
     let bytes = std::fs::read(file_path)
         .map_err(|e| anyhow::anyhow!("cannot read {}: {}", file_path.display(), e))?;
-    let hash = Cache::hash_content(&bytes);
     let dir = file_path.parent().unwrap_or(file_path);
-    let config = load_config(dir);
-    let cache = open_cache(&config);
+    let config = overrides.load_config(dir);
+    let max_line_length = editorconfig::max_line_length(file_path);
+    let hash = cache_key_with(&bytes, &config, max_line_length);
+    let cache = open_cache(&config, cache_dir);
 
     // Fast path: both layers cached.
     if let Some(ref c) = cache {
@@ -284,12 +742,14 @@ pub fn analyze_file_symbols(file_path: &Path) -> anyhow::Result<Report> {
 
     let source_str = std::str::from_utf8(&bytes)
         .map_err(|e| anyhow::anyhow!("non-UTF-8 file: {e}"))?;
-    let pipeline = Pipeline::with_defaults();
+    // The base report is cached under the same config-aware key as
+    // `analyze_file`, so it must come from the same configured pipeline.
+    let pipeline = build_pipeline(&config, max_line_length);
     let mut report = pipeline.run(source_str, Some(file_path.to_path_buf()));
     let symbol_reports = pipeline.run_symbols(&bytes, file_path)?;
     report.symbol_reports = Some(symbol_reports.clone());
 
-    if let Some(ref c) = cache {
+    if let Some(ref c) = cache.filter(|_| !report.metadata.timed_out) {
         let _ = c.put(&hash, &report);
         let _ = c.put_symbols(&hash, &symbol_reports);
     }
@@ -299,11 +759,21 @@ pub fn analyze_file_symbols(file_path: &Path) -> anyhow::Result<Report> {
 
 /// Analyze a source file at symbol level, bypassing the cache entirely.
 pub fn analyze_file_symbols_no_cache(file_path: &Path) -> anyhow::Result<Report> {
+    analyze_file_symbols_no_cache_with_overrides(file_path, &ConfigOverrides::default())
+}
+
+/// Like [`analyze_file_symbols_no_cache`], but applies `overrides` on top
+/// of the discovered config.
+pub fn analyze_file_symbols_no_cache_with_overrides(
+    file_path: &Path,
+    overrides: &ConfigOverrides,
+) -> anyhow::Result<Report> {
     let bytes = std::fs::read(file_path)
         .map_err(|e| anyhow::anyhow!("cannot read {}: {}", file_path.display(), e))?;
     let source_str = std::str::from_utf8(&bytes)
         .map_err(|e| anyhow::anyhow!("non-UTF-8 file: {e}"))?;
-    let pipeline = Pipeline::with_defaults();
+    let dir = file_path.parent().unwrap_or(file_path);
+    let pipeline = pipeline_for_file(&overrides.load_config(dir), file_path);
     let mut report = pipeline.run(source_str, Some(file_path.to_path_buf()));
     let symbol_reports = pipeline.run_symbols(&bytes, file_path)?;
     report.symbol_reports = Some(symbol_reports);
@@ -316,6 +786,8 @@ mod tests {
     use crate::ignore_rules::{AllowAll, PatternIgnore};
     use std::io::Write;
 
+    const NO_OVERRIDES: &ConfigOverrides = &ConfigOverrides { config_file: None, profile: None };
+
     fn sample_rust_source(n_lines: usize) -> String {
         (0..n_lines).map(|i| format!("let x{i} = {i};")).collect::<Vec<_>>().join("\n")
     }
@@ -346,6 +818,155 @@ mod tests {
         assert!(report.metadata.lines_of_code > 0);
     }
 
+    #[test]
+    fn lossy_reads_analyze_invalid_utf8_and_flag_it() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("legacy.rs");
+        let mut bytes = sample_rust_source(40).into_bytes();
+        bytes.extend(b"\n// caf\xe9\n");
+        std::fs::write(&path, &bytes).unwrap();
+        let cache = dir.path().join("cache");
+
+        let strict = analyze_file_with_cache_dir(&path, Some(&cache), NO_OVERRIDES).unwrap_err();
+        assert_eq!(strict.kind(), std::io::ErrorKind::InvalidData);
+        let report = analyze_file_lossy(&path, Some(&cache), NO_OVERRIDES).unwrap();
+        assert!(report.metadata.lossy);
+        assert!(report.metadata.lines_of_code > 0);
+        assert!(analyze_file_with_cache_dir(&path, Some(&cache), NO_OVERRIDES).is_err(), "no lossy cache hit for strict reads");
+        assert!(analyze_file_lossy(&path, Some(&cache), NO_OVERRIDES).unwrap().metadata.lossy, "from the cache");
+        assert!(analyze_file_lossy_no_cache(&path, NO_OVERRIDES).unwrap().metadata.lossy);
+
+        std::fs::write(&path, sample_rust_source(40)).unwrap();
+        assert!(!analyze_file_lossy_no_cache(&path, NO_OVERRIDES).unwrap().metadata.lossy, "valid files are not flagged");
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn analyze_file_async_matches_sync() {
+        let mut f = tempfile::NamedTempFile::with_suffix(".rs").unwrap();
+        writeln!(f, "{}", sample_rust_source(40)).unwrap();
+        let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        let report = runtime.block_on(analyze_file_async(f.path())).unwrap();
+        let sync = analyze_file_no_cache(f.path()).unwrap();
+        assert_eq!(report.attribution.primary, sync.attribution.primary);
+        assert_eq!(report.metadata.file_path.as_deref(), Some(f.path()));
+        assert!(runtime.block_on(analyze_file_async(Path::new("/nonexistent.rs"))).is_err());
+    }
+
+    #[test]
+    fn analyze_file_with_cache_dir_writes_to_given_dir() {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let mut f = tempfile::NamedTempFile::new().unwrap();
+        writeln!(f, "{}", sample_rust_source(40)).unwrap();
+        let report = analyze_file_with_cache_dir(f.path(), Some(cache_dir.path()), NO_OVERRIDES).unwrap();
+        assert!(cache_dir.path().join("cache.redb").is_file());
+        let hash = cache_key(&std::fs::read(f.path()).unwrap(), f.path(), NO_OVERRIDES);
+        let cached = Cache::open(cache_dir.path()).unwrap().get(&hash).unwrap();
+        assert_eq!(cached.attribution.primary, report.attribution.primary);
+    }
+
+    #[test]
+    fn timed_out_reports_are_not_cached() {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join(".vibecheck"), "[limits]\nparse_timeout_ms = 1\n").unwrap();
+        let path = dir.path().join("main.rs");
+        std::fs::write(&path, sample_rust_source(20_000)).unwrap();
+        let report = analyze_file_with_cache_dir(&path, Some(cache_dir.path()), NO_OVERRIDES).unwrap();
+        assert!(report.metadata.timed_out);
+        let hash = cache_key(&std::fs::read(&path).unwrap(), &path, NO_OVERRIDES);
+        assert!(Cache::open(cache_dir.path()).unwrap().get(&hash).is_none());
+    }
+
+    #[test]
+    fn analyze_directory_with_cache_dir_uses_given_dir() {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("main.rs"), sample_rust_source(40)).unwrap();
+        let results =
+            analyze_directory_with_cache_dir(dir.path(), true, &AllowAll, Some(cache_dir.path()))
+                .unwrap();
+        assert_eq!(results.len(), 1);
+        assert!(cache_dir.path().join("cache.redb").is_file());
+    }
+
+    #[test]
+    fn editing_config_weights_invalidates_cached_reports() {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("main.rs");
+        std::fs::write(&path, sample_rust_source(40)).unwrap();
+        let before =
+            analyze_directory_with_cache_dir(dir.path(), true, &AllowAll, Some(cache_dir.path()))
+                .unwrap();
+        let signal = before[0].1.signals.first().expect("sample emits signals").id.clone();
+
+        let key_before = cache_key(&std::fs::read(&path).unwrap(), &path, NO_OVERRIDES);
+        std::fs::write(dir.path().join(".vibecheck"), format!("[heuristics]\n\"{signal}\" = 0.0\n"))
+            .unwrap();
+        assert_ne!(cache_key(&std::fs::read(&path).unwrap(), &path, NO_OVERRIDES), key_before);
+
+        // The directory hash is unchanged, but the file must be re-analyzed.
+        let after =
+            analyze_directory_with_cache_dir(dir.path(), true, &AllowAll, Some(cache_dir.path()))
+                .unwrap();
+        let weight = |r: &Report| r.signals.iter().find(|s| s.id == signal).map(|s| s.weight);
+        assert_ne!(weight(&after[0].1), weight(&before[0].1));
+    }
+
+    #[test]
+    fn profile_override_changes_weights_and_cache_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("main.rs");
+        std::fs::write(&path, sample_rust_source(40)).unwrap();
+        let strict = ConfigOverrides { profile: Some("strict".into()), ..Default::default() };
+        let bytes = std::fs::read(&path).unwrap();
+        assert_ne!(cache_key(&bytes, &path, &strict), cache_key(&bytes, &path, NO_OVERRIDES));
+
+        let weights = |overrides: &ConfigOverrides| -> Vec<f64> {
+            analyze_file_no_cache_with_overrides(&path, overrides).unwrap().signals.iter().map(|s| s.weight).collect()
+        };
+        assert_ne!(weights(&strict), weights(NO_OVERRIDES));
+    }
+
+    #[test]
+    fn config_file_override_replaces_discovery() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("main.rs");
+        std::fs::write(&path, sample_rust_source(40)).unwrap();
+        std::fs::write(dir.path().join(".vibecheck"), "profile = \"strict\"\n").unwrap();
+        let explicit = dir.path().join("ci.toml");
+        std::fs::write(&explicit, "profile = \"lenient\"\n").unwrap();
+
+        let overrides = ConfigOverrides { config_file: Some(explicit), ..Default::default() };
+        assert_eq!(overrides.load_config(dir.path()).profile(), Some("lenient"));
+        assert_eq!(NO_OVERRIDES.load_config(dir.path()).profile(), Some("strict"));
+        let missing = ConfigOverrides { config_file: Some(dir.path().join("missing.toml")), ..Default::default() };
+        assert_eq!(missing.load_config(dir.path()).profile(), Some("strict"));
+    }
+
+    #[test]
+    fn editorconfig_max_line_length_sets_the_line_length_limit() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app.py");
+        let long = format!("x = \"{}\"\n", "a".repeat(100));
+        std::fs::write(&path, format!("{long}{}", "y = 1\n".repeat(12))).unwrap();
+        let fires = |path: &Path| {
+            analyze_file_no_cache(path)
+                .unwrap()
+                .signals
+                .iter()
+                .any(|s| s.id == heuristics::signal_ids::PYTHON_STRUCTURE_LINES_UNDER_88)
+        };
+        assert!(!fires(&path), "over the default 88");
+
+        let key_before = cache_key(&std::fs::read(&path).unwrap(), &path, NO_OVERRIDES);
+        std::fs::write(dir.path().join(".editorconfig"), "root = true\n[*.py]\nmax_line_length = 120\n")
+            .unwrap();
+        assert!(fires(&path), "within the project's 120");
+        assert_ne!(cache_key(&std::fs::read(&path).unwrap(), &path, NO_OVERRIDES), key_before);
+    }
+
     #[test]
     fn analyze_directory_with_empty_dir_returns_empty() {
         let dir = tempfile::tempdir().unwrap();
@@ -381,6 +1002,69 @@ mod tests {
         assert_eq!(results.len(), 1);
     }
 
+    #[test]
+    fn analyze_directory_non_recursive_skips_subdirs() {
+        let dir = tempfile::tempdir().unwrap();
+        let sub = dir.path().join("sub");
+        std::fs::create_dir(&sub).unwrap();
+        std::fs::write(dir.path().join("top.rs"), sample_rust_source(40)).unwrap();
+        std::fs::write(sub.join("nested.rs"), sample_rust_source(40)).unwrap();
+        let options = DirOptions { recursive: false, ..DirOptions::default() };
+        let results = analyze_directory_with_options(dir.path(), false, &AllowAll, &options).unwrap();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].0.ends_with("top.rs"));
+    }
+
+    #[test]
+    fn analyze_directory_with_options_combines_depth_and_filter() {
+        let dir = tempfile::tempdir().unwrap();
+        let sub = dir.path().join("sub");
+        std::fs::create_dir(&sub).unwrap();
+        std::fs::write(dir.path().join("top.rs"), sample_rust_source(40)).unwrap();
+        std::fs::write(dir.path().join("top.py"), "def f():\n    return 1\n").unwrap();
+        std::fs::write(sub.join("nested.rs"), sample_rust_source(40)).unwrap();
+        let options = DirOptions {
+            recursive: false,
+            filter: ExtensionFilter::new(None, vec!["py".into()]),
+            ..DirOptions::default()
+        };
+        let results = analyze_directory_with_options(dir.path(), false, &AllowAll, &options).unwrap();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].0.ends_with("top.rs"));
+    }
+
+    #[test]
+    fn extension_filter_include_and_exclude() {
+        let all = ExtensionFilter::default();
+        assert!(all.allows("rs") && all.allows("go"));
+        assert!(!all.allows("md"));
+
+        let only_rs = ExtensionFilter::new(Some(vec![".rs".into(), "md".into()]), vec![]);
+        assert!(only_rs.allows("rs"));
+        assert!(!only_rs.allows("py"));
+        assert!(!only_rs.allows("md"), "include cannot add unsupported extensions");
+
+        let no_go = ExtensionFilter::new(None, vec!["go".into()]);
+        assert!(no_go.allows("rs"));
+        assert!(!no_go.allows("go"));
+    }
+
+    #[test]
+    fn analyze_directory_with_options_skips_excluded_exts() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("a.rs"), sample_rust_source(40)).unwrap();
+        std::fs::write(dir.path().join("b.py"), "def f():\n    return 1\n").unwrap();
+        let options = DirOptions {
+            filter: ExtensionFilter::new(Some(vec!["rs".into()]), vec![]),
+            cache_dir: Some(cache_dir.path().to_path_buf()),
+            ..DirOptions::default()
+        };
+        let results = analyze_directory_with_options(dir.path(), true, &AllowAll, &options).unwrap();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].0.ends_with("a.rs"));
+    }
+
     #[test]
     fn analyze_file_symbols_no_cache_works() {
         let mut f = tempfile::NamedTempFile::with_suffix(".rs").unwrap();
@@ -465,4 +1149,31 @@ mod tests {
             analyze_directory_with(dir.path(), false, &PatternIgnore(vec!["generated".into()])).unwrap();
         assert!(results.is_empty());
     }
+
+    #[test]
+    fn analyze_with_language_overrides_extension_and_keeps_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("Untitled-1");
+        let source = "def greet(name):\n    return name\n\ndef wave():\n    pass\n";
+        let report = analyze_with_language(source, &path, language::Language::Python, NO_OVERRIDES).unwrap();
+        assert_eq!(report.metadata.file_path.as_deref(), Some(path.as_path()));
+        let names: Vec<_> = report
+            .symbol_reports
+            .unwrap()
+            .into_iter()
+            .map(|s| s.metadata.name)
+            .collect();
+        assert_eq!(names.len(), 2, "{names:?}");
+        assert!(names.contains(&"greet".to_string()));
+        assert!(!path.exists(), "the path must not be read or created");
+    }
+
+    #[test]
+    fn analyze_source_uses_path_for_language_without_reading_it() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("missing.py");
+        let report = analyze_source("def f():\n    return 1\n", &path, NO_OVERRIDES);
+        assert_eq!(report.metadata.file_path, Some(path));
+        assert!(report.signals.iter().all(|s| !s.id.starts_with("rust")));
+    }
 }
@@ -1,3 +1,6 @@
+use std::io::Write;
+
+use crate::pipeline::raw_scores;
 use crate::report::Report;
 
 /// Output format for CLI.
@@ -6,6 +9,31 @@ pub enum OutputFormat {
     Pretty,
     Text,
     Json,
+    /// Compact single-line JSON, one report per line (JSON Lines).
+    Jsonl,
+    Yaml,
+}
+
+impl OutputFormat {
+    /// Every format, in the order `--format` lists them.  Front ends parse
+    /// and list formats from here so a new one shows up everywhere at once.
+    pub const ALL: &[OutputFormat] = &[Self::Pretty, Self::Text, Self::Json, Self::Jsonl, Self::Yaml];
+
+    /// The `--format` name, e.g. `"jsonl"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Pretty => "pretty",
+            Self::Text => "text",
+            Self::Json => "json",
+            Self::Jsonl => "jsonl",
+            Self::Yaml => "yaml",
+        }
+    }
+
+    /// Inverse of [`name`](Self::name).
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|f| f.name() == name)
+    }
 }
 
 /// Format a report as JSON.
@@ -13,6 +41,78 @@ pub fn format_json(report: &Report) -> String {
     serde_json::to_string_pretty(report).expect("report should be serializable")
 }
 
+/// Format a report as compact single-line JSON, for log ingestion and
+/// JSON Lines streams.
+pub fn format_json_compact(report: &Report) -> String {
+    serde_json::to_string(report).expect("report should be serializable")
+}
+
+/// Format a report as YAML, with the same structure as [`format_json`].
+pub fn format_yaml(report: &Report) -> String {
+    serde_yaml::to_string(report).expect("report should be serializable")
+}
+
+/// Format several reports as a single YAML sequence (multi-file runs).
+pub fn format_yaml_sequence(reports: &[Report]) -> String {
+    serde_yaml::to_string(reports).expect("report should be serializable")
+}
+
+/// Format reports as a JSON array of [`MinimalReport`](crate::report::MinimalReport)s.
+pub fn format_minimal_json(reports: &[Report]) -> String {
+    let minimal: Vec<_> = reports.iter().map(Report::to_minimal).collect();
+    serde_json::to_string_pretty(&minimal).expect("report should be serializable")
+}
+
+/// Format the symbols of every report as one flat JSON array of
+/// [`SymbolAnnotation`](crate::report::SymbolAnnotation)s.
+pub fn format_symbols_json(reports: &[Report]) -> String {
+    let symbols: Vec<_> = reports.iter().flat_map(Report::symbol_annotations).collect();
+    serde_json::to_string_pretty(&symbols).expect("report should be serializable")
+}
+
+/// Writes reports as one pretty-printed JSON array as they arrive, flushing
+/// after each, so consumers can start reading before a long scan finishes
+/// and nothing is held back in memory.
+///
+/// The finished output is exactly `serde_json::to_string_pretty` of all the
+/// reports plus a newline.  A run that dies before
+/// [`finish`](Self::finish) leaves the array unterminated: every element
+/// written is complete, only the closing `]` is missing.
+pub struct JsonArrayWriter<W: Write> {
+    out: W,
+    written: usize,
+}
+
+impl<W: Write> JsonArrayWriter<W> {
+    pub fn new(out: W) -> Self {
+        Self { out, written: 0 }
+    }
+
+    /// Write `report` as the next element and flush.
+    pub fn push(&mut self, report: &Report) -> std::io::Result<()> {
+        let json = format_json(report);
+        let mut element = String::with_capacity(json.len() + json.len() / 8);
+        element.push_str(if self.written == 0 { "[\n" } else { ",\n" });
+        for (i, line) in json.lines().enumerate() {
+            if i > 0 {
+                element.push('\n');
+            }
+            element.push_str("  ");
+            element.push_str(line);
+        }
+        self.out.write_all(element.as_bytes())?;
+        self.written += 1;
+        self.out.flush()
+    }
+
+    /// Close the array (`[]` if nothing was pushed) and flush.
+    pub fn finish(mut self) -> std::io::Result<W> {
+        self.out.write_all(if self.written == 0 { b"[]\n" } else { b"\n]\n" })?;
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
 /// Format a report as plain text (no colors).
 pub fn format_text(report: &Report) -> String {
     let mut out = String::new();
@@ -20,7 +120,9 @@ pub fn format_text(report: &Report) -> String {
     if let Some(ref path) = report.metadata.file_path {
         out.push_str(&format!("File: {}\n", path.display()));
     }
-    if report.attribution.has_sufficient_data() {
+    if report.metadata.generated {
+        out.push_str("Verdict: Generated code (not analyzed)\n");
+    } else if report.attribution.has_sufficient_data() {
         out.push_str(&format!(
             "Verdict: {} ({:.0}% confidence)\n",
             report.attribution.primary,
@@ -29,10 +131,24 @@ pub fn format_text(report: &Report) -> String {
     } else {
         out.push_str("Verdict: Insufficient data\n");
     }
+    if let Some(language) = report.metadata.language {
+        out.push_str(&format!("Language: {language} | "));
+    }
     out.push_str(&format!(
         "Lines: {} | Signals: {}\n",
         report.metadata.lines_of_code, report.metadata.signal_count
     ));
+    if report.metadata.timed_out {
+        out.push_str("Parsing timed out; syntax-tree signals skipped\n");
+    } else if report.metadata.parse_errors > 0 {
+        out.push_str(&format!(
+            "{} parse errors; syntax-tree signals down-weighted\n",
+            report.metadata.parse_errors
+        ));
+    }
+    if report.metadata.lossy {
+        out.push_str("Invalid UTF-8 replaced before analysis\n");
+    }
 
     out.push_str("\nScores:\n");
     let mut sorted_scores: Vec<_> = report.attribution.scores.iter().collect();
@@ -55,10 +171,57 @@ pub fn format_text(report: &Report) -> String {
     out
 }
 
+/// Where each line-specific signal fired, as `line:column  id` followed by
+/// the offending source line.  Empty when no signal carries a location.
+pub fn format_signal_locations(report: &Report) -> String {
+    let mut out = String::new();
+    for signal in &report.signals {
+        let Some((line, column)) = signal.location else { continue };
+        if out.is_empty() {
+            out.push_str("Locations:\n");
+        }
+        let label = if signal.id.is_empty() { &signal.description } else { &signal.id };
+        out.push_str(&format!("  {:>9}  {label}\n", format!("{line}:{column}")));
+        if let Some(snippet) = &signal.snippet {
+            out.push_str(&format!("             {snippet}\n"));
+        }
+    }
+    out
+}
+
+/// Per-family raw weight sums (before normalization) and the signals that
+/// contributed to each, for explaining a verdict.  Families are listed by
+/// raw sum, highest first; families with no signals are omitted.
+pub fn format_debug_scores(report: &Report) -> String {
+    let mut out = String::new();
+    let path = report
+        .metadata
+        .file_path
+        .as_ref()
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|| "<stdin>".into());
+    out.push_str(&format!("Raw scores for {path}:\n"));
+
+    let mut families: Vec<_> = raw_scores(&report.signals).into_iter().collect();
+    families.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.to_string().cmp(&b.0.to_string())));
+    for (family, raw) in families {
+        let contributing: Vec<_> = report.signals.iter().filter(|s| s.family == family).collect();
+        if contributing.is_empty() {
+            continue;
+        }
+        out.push_str(&format!("  {:<10} {raw:+.2}\n", family.to_string()));
+        for signal in contributing {
+            let label = if signal.id.is_empty() { &signal.description } else { &signal.id };
+            out.push_str(&format!("      {:+.2}  {label}\n", signal.weight));
+        }
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::report::{Attribution, ModelFamily, ReportMetadata, Signal};
+    use crate::report::{Attribution, ModelFamily, ReportMetadata, Signal, SymbolMetadata, SymbolReport};
     use std::collections::HashMap;
     use std::path::PathBuf;
 
@@ -82,11 +245,49 @@ mod tests {
                 file_path: if with_path { Some(PathBuf::from("src/main.rs")) } else { None },
                 lines_of_code: 42,
                 signal_count: if with_signals { 1 } else { 0 },
+                analyzer_version: String::new(),
+                generated: false,
+                timed_out: false,
+                parse_errors: 0,
+                language: None,
+                lossy: false,
             },
             symbol_reports: None,
         }
     }
 
+    #[test]
+    fn signal_locations_list_only_located_signals() {
+        let mut report = make_report(true, true);
+        assert_eq!(format_signal_locations(&report), "");
+        report.signals = vec![
+            Signal::new("rust.ai_signals.commented_out_code", "ai_signals", "2 lines", ModelFamily::Human, 2.0)
+                .with_location(12, 5, "// let x = 1;"),
+            Signal::new("rust.comments.high_density", "comments", "High density", ModelFamily::Claude, 1.5),
+        ];
+        assert_eq!(
+            format_signal_locations(&report),
+            "Locations:\n       12:5  rust.ai_signals.commented_out_code\n             // let x = 1;\n"
+        );
+        let json = format_json(&report);
+        assert!(json.contains("\"location\": [\n        12,\n        5\n      ]"), "{json}");
+        assert_eq!(json.matches("\"snippet\"").count(), 1, "unlocated signals omit the fields");
+    }
+
+    #[test]
+    fn debug_scores_list_raw_sums_and_contributors() {
+        let mut report = make_report(true, true);
+        report.signals.push(Signal::new("rust.comments.terse_markers", "comments", "TODOs", ModelFamily::Human, 2.0));
+        report.signals.push(Signal::new("rust.naming.short_vars", "naming", "Short", ModelFamily::Human, 0.5));
+        let out = format_debug_scores(&report);
+        assert!(out.starts_with("Raw scores for src/main.rs:"));
+        let human = out.find("Human      +2.50").expect(&out);
+        let claude = out.find("Claude     +1.50").expect(&out);
+        assert!(human < claude, "highest raw sum first");
+        assert!(out.contains("      +1.50  rust.errors.zero_unwrap"));
+        assert!(!out.contains("GPT"), "families without signals are omitted");
+    }
+
     #[test]
     fn format_text_contains_verdict() {
         let report = make_report(false, false);
@@ -102,6 +303,42 @@ mod tests {
         assert!(out.contains("File: src/main.rs"));
     }
 
+    #[test]
+    fn format_text_and_json_show_language() {
+        let mut report = make_report(true, false);
+        assert!(!format_text(&report).contains("Language:"));
+        report.metadata.language = Some(crate::language::Language::CSharp);
+        assert!(format_text(&report).contains("Language: C# | Lines: 42"));
+        assert!(format_json(&report).contains("\"language\": \"csharp\""));
+    }
+
+    #[test]
+    fn format_json_compact_is_one_line() {
+        let report = make_report(true, true);
+        let line = format_json_compact(&report);
+        assert!(!line.contains('\n'));
+        let parsed: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(parsed, serde_json::from_str::<serde_json::Value>(&format_json(&report)).unwrap());
+    }
+
+    #[test]
+    fn format_text_notes_parse_timeouts() {
+        let mut report = make_report(true, false);
+        assert!(!format_text(&report).contains("timed out"));
+        report.metadata.timed_out = true;
+        assert!(format_text(&report).contains("Parsing timed out"));
+        assert!(format_json(&report).contains("\"timed_out\": true"));
+    }
+
+    #[test]
+    fn format_text_notes_lossy_reads() {
+        let mut report = make_report(true, false);
+        assert!(!format_text(&report).contains("UTF-8"));
+        report.metadata.lossy = true;
+        assert!(format_text(&report).contains("Invalid UTF-8 replaced before analysis"));
+        assert!(format_json(&report).contains("\"lossy\": true"));
+    }
+
     #[test]
     fn format_text_with_signals() {
         let report = make_report(false, true);
@@ -119,6 +356,78 @@ mod tests {
         assert!(json.contains("claude"));
     }
 
+    #[test]
+    fn format_yaml_mirrors_json_structure() {
+        let report = make_report(true, true);
+        let yaml = format_yaml(&report);
+        let from_yaml: serde_json::Value = serde_yaml::from_str(&yaml).unwrap();
+        let from_json: serde_json::Value = serde_json::from_str(&format_json(&report)).unwrap();
+        assert_eq!(from_yaml, from_json);
+        assert!(yaml.contains("primary: claude"));
+    }
+
+    #[test]
+    fn format_yaml_sequence_emits_one_item_per_report() {
+        let reports = [make_report(true, false), make_report(false, true)];
+        let yaml = format_yaml_sequence(&reports);
+        let parsed: Vec<serde_json::Value> = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0]["metadata"]["file_path"], "src/main.rs");
+    }
+
+    #[test]
+    fn format_minimal_json_keeps_only_the_verdict() {
+        let reports = [make_report(true, true), make_report(false, false)];
+        let parsed: serde_json::Value = serde_json::from_str(&format_minimal_json(&reports)).unwrap();
+        assert_eq!(
+            parsed,
+            serde_json::json!([
+                {"path": "src/main.rs", "family": "claude", "confidence": 0.8},
+                {"path": null, "family": "claude", "confidence": 0.8},
+            ])
+        );
+    }
+
+    #[test]
+    fn output_format_names_round_trip() {
+        for &format in OutputFormat::ALL {
+            assert_eq!(OutputFormat::from_name(format.name()), Some(format));
+        }
+        assert_eq!(OutputFormat::from_name("csv"), None);
+    }
+
+    #[test]
+    fn format_symbols_json_is_a_flat_array_across_reports() {
+        let mut report = make_report(true, true);
+        let mut symbol = make_report(false, true);
+        symbol.attribution.primary = ModelFamily::Human;
+        report.symbol_reports = Some(vec![SymbolReport {
+            metadata: SymbolMetadata {
+                name: "main".into(),
+                kind: "function".into(),
+                start_line: 3,
+                end_line: 9,
+                ..Default::default()
+            },
+            attribution: symbol.attribution,
+            signals: symbol.signals,
+        }]);
+        let reports = [report, make_report(true, false)];
+        let parsed: serde_json::Value = serde_json::from_str(&format_symbols_json(&reports)).unwrap();
+        assert_eq!(
+            parsed,
+            serde_json::json!([{
+                "path": "src/main.rs",
+                "name": "main",
+                "kind": "function",
+                "start_line": 3,
+                "end_line": 9,
+                "family": "human",
+                "confidence": 0.8,
+            }])
+        );
+    }
+
     #[test]
     fn format_text_insufficient_data() {
         let scores = HashMap::new();
@@ -133,6 +442,12 @@ mod tests {
                 file_path: Some(PathBuf::from("config.toml")),
                 lines_of_code: 10,
                 signal_count: 0,
+                analyzer_version: String::new(),
+                generated: false,
+                timed_out: false,
+                parse_errors: 0,
+                language: None,
+                lossy: false,
             },
             symbol_reports: None,
         };
@@ -146,4 +461,19 @@ mod tests {
         assert_eq!(OutputFormat::Pretty, OutputFormat::Pretty);
         assert_ne!(OutputFormat::Json, OutputFormat::Text);
     }
+
+    #[test]
+    fn json_array_writer_matches_pretty_array() {
+        let reports = vec![make_report(true, true), make_report(false, false)];
+        let mut writer = JsonArrayWriter::new(Vec::new());
+        writer.push(&reports[0]).unwrap();
+        let partial = String::from_utf8(writer.out.clone()).unwrap();
+        assert!(partial.starts_with("[\n  {") && partial.ends_with("  }"), "{partial}");
+        writer.push(&reports[1]).unwrap();
+        let out = String::from_utf8(writer.finish().unwrap()).unwrap();
+        assert_eq!(out, serde_json::to_string_pretty(&reports).unwrap() + "\n");
+
+        let empty = JsonArrayWriter::new(Vec::new()).finish().unwrap();
+        assert_eq!(empty, b"[]\n");
+    }
 }
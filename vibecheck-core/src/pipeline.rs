@@ -1,10 +1,20 @@
 use std::collections::HashMap;
+use std::ops::Range;
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
-use crate::analyzers::{default_analyzers, default_cst_analyzers, Analyzer, CstAnalyzer};
+use rayon::prelude::*;
+
+use crate::analyzers::text::test_style::TestStyleAnalyzer;
+use crate::analyzers::{
+    default_analyzers, default_cst_analyzers, Analyzer, AnalyzerRegistry, CstAnalyzer,
+};
 use crate::heuristics::{all_heuristics, DefaultHeuristics, HeuristicLanguage, HeuristicsProvider};
-use crate::language::{detect_language, get_ts_language, Language};
-use crate::report::{Attribution, ModelFamily, Report, ReportMetadata, Signal, SymbolReport};
+use crate::language::{detect_language, get_ts_language, is_jsx_path, is_test_file, Language};
+use crate::report::{
+    analyzer_version, Attribution, LineAttribution, ModelFamily, Report, ReportMetadata, Signal,
+    SymbolReport,
+};
 
 /// Match extracted CST metrics against TOML-defined threshold rules to produce signals.
 pub(crate) fn match_metric_signals(
@@ -100,15 +110,7 @@ fn blend_attributions(heuristic: &Attribution, ml: &Attribution, blend: f64) ->
         scores.insert(*family, (1.0 - blend) * h + blend * m);
     }
 
-    let (primary, confidence) = scores
-        .iter()
-        .max_by(|a, b| {
-            a.1.partial_cmp(b.1)
-                .unwrap()
-                .then_with(|| a.0.to_string().cmp(&b.0.to_string()))
-        })
-        .map(|(&k, &v)| (k, v))
-        .unwrap();
+    let (primary, confidence) = top_family(&scores).unwrap_or((ModelFamily::Human, 0.0));
 
     Attribution {
         primary,
@@ -117,6 +119,110 @@ fn blend_attributions(heuristic: &Attribution, ml: &Attribution, blend: f64) ->
     }
 }
 
+/// A range of file lengths and the confidence multiplier applied to files in
+/// it, from the `.vibecheck` `[size_buckets]` table.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SizeBucket {
+    pub min_lines: usize,
+    /// Inclusive upper bound; `None` is open-ended.
+    pub max_lines: Option<usize>,
+    pub multiplier: f64,
+}
+
+impl SizeBucket {
+    /// Parse a `LO-HI` (inclusive) or open-ended `LO-` range key.
+    pub fn parse(range: &str, multiplier: f64) -> Option<Self> {
+        let (lo, hi) = range.split_once('-')?;
+        let min_lines = lo.trim().parse().ok()?;
+        let max_lines = match hi.trim() {
+            "" => None,
+            hi => Some(hi.parse().ok()?),
+        };
+        if max_lines.is_some_and(|max| max < min_lines) || multiplier.is_nan() || multiplier < 0.0 {
+            return None;
+        }
+        Some(Self { min_lines, max_lines, multiplier })
+    }
+
+    pub fn contains(&self, lines: usize) -> bool {
+        lines >= self.min_lines && self.max_lines.is_none_or(|max| lines <= max)
+    }
+}
+
+/// Floor and ceiling on one family's final score, from the `.vibecheck`
+/// `[confidence_bounds]` table.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConfidenceBound {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl ConfidenceBound {
+    /// Build from optional bounds (a missing one is `0` or `1`); `None` when
+    /// either lies outside `[0, 1]` or `min > max`.
+    pub fn new(min: Option<f64>, max: Option<f64>) -> Option<Self> {
+        let (min, max) = (min.unwrap_or(0.0), max.unwrap_or(1.0));
+        ((0.0..=1.0).contains(&min) && (0.0..=1.0).contains(&max) && min <= max).then_some(Self { min, max })
+    }
+
+    pub fn apply(&self, score: f64) -> f64 {
+        score.clamp(self.min, self.max)
+    }
+}
+
+/// Header substrings that mark a source as generated code: the Go
+/// `// Code generated ... DO NOT EDIT.` convention (also used by protoc and
+/// many other generators), `@generated`, and .NET's `<auto-generated>`.
+pub const DEFAULT_GENERATED_MARKERS: &[&str] = &["DO NOT EDIT", "@generated", "<auto-generated"];
+
+/// How many leading lines are searched for a generated-code marker.
+const GENERATED_HEADER_LINES: usize = 10;
+
+/// Default limit on tree-sitter parsing of one source; see
+/// [`Pipeline::with_parse_timeout`].
+pub const DEFAULT_PARSE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Default line count from which one file's analyzers run in parallel; see
+/// [`Pipeline::with_parallel_min_lines`].
+pub const DEFAULT_PARALLEL_MIN_LINES: usize = 2000;
+
+/// Default window size, in lines, for [`Pipeline::run_heatmap`].
+pub const DEFAULT_HEATMAP_WINDOW: usize = 20;
+
+/// Parse `source`, cancelling once `timeout` has elapsed.  Returns the tree
+/// (if any) and whether the timeout cut parsing short.
+fn parse_with_timeout(
+    parser: &mut tree_sitter::Parser,
+    source: &[u8],
+    timeout: Option<Duration>,
+) -> (Option<tree_sitter::Tree>, bool) {
+    let Some(timeout) = timeout else {
+        return (parser.parse(source, None), false);
+    };
+    let deadline = Instant::now() + timeout;
+    let mut timed_out = false;
+    let mut progress = |_: &tree_sitter::ParseState| {
+        timed_out = Instant::now() >= deadline;
+        timed_out
+    };
+    let tree = parser.parse_with_options(
+        &mut |offset, _| source.get(offset..).unwrap_or_default(),
+        None,
+        Some(tree_sitter::ParseOptions::new().progress_callback(&mut progress)),
+    );
+    (tree, timed_out)
+}
+
+/// `items.iter().map(f)`, spread over the rayon pool when `parallel` is set.
+/// Results keep the order of `items` either way.
+fn ordered_map<T: Sync, R: Send>(items: &[T], parallel: bool, f: impl Fn(&T) -> R + Sync + Send) -> Vec<R> {
+    if parallel {
+        items.par_iter().map(f).collect()
+    } else {
+        items.iter().map(f).collect()
+    }
+}
+
 /// Orchestrates analyzers and aggregates their signals into a report.
 pub struct Pipeline {
     analyzers: Vec<Box<dyn Analyzer>>,
@@ -124,6 +230,24 @@ pub struct Pipeline {
     heuristics: Box<dyn HeuristicsProvider>,
     scorer: Option<Box<dyn PostScorer>>,
     ml_blend: f64,
+    parse_scaling: bool,
+    /// Global override of every analyzer's [`Analyzer::min_lines`].
+    min_lines: Option<usize>,
+    /// Per-analyzer overrides keyed by [`Analyzer::name`].
+    analyzer_min_lines: HashMap<String, usize>,
+    /// Confidence multipliers by file length; the first match applies.
+    size_buckets: Vec<SizeBucket>,
+    /// Header markers that make [`run`](Self::run) skip a source as generated.
+    generated_markers: Vec<String>,
+    /// Cap on tree-sitter parsing per source; `None` is unlimited.
+    parse_timeout: Option<Duration>,
+    /// Line count from which analyzers run in parallel; `None` never does.
+    parallel_min_lines: Option<usize>,
+    /// Pseudo-signal count pulling symbol attributions toward the file's;
+    /// `0.0` disables smoothing.
+    symbol_smoothing: f64,
+    /// Per-family floors and ceilings applied to the final scores.
+    confidence_bounds: Vec<(ModelFamily, ConfidenceBound)>,
 }
 
 impl Pipeline {
@@ -143,9 +267,28 @@ impl Pipeline {
             heuristics,
             scorer: None,
             ml_blend: 0.0,
+            parse_scaling: true,
+            min_lines: None,
+            analyzer_min_lines: HashMap::new(),
+            size_buckets: Vec::new(),
+            generated_markers: DEFAULT_GENERATED_MARKERS.iter().map(|m| m.to_string()).collect(),
+            parse_timeout: Some(DEFAULT_PARSE_TIMEOUT),
+            parallel_min_lines: Some(DEFAULT_PARALLEL_MIN_LINES),
+            symbol_smoothing: 0.0,
+            confidence_bounds: Vec::new(),
         }
     }
 
+    /// Construct from an [`AnalyzerRegistry`], e.g. one with custom analyzers
+    /// registered alongside (or instead of) the defaults.
+    pub fn from_registry(
+        registry: AnalyzerRegistry,
+        heuristics: Box<dyn HeuristicsProvider>,
+    ) -> Self {
+        let (analyzers, cst_analyzers) = registry.into_parts();
+        Self::with_heuristics(analyzers, cst_analyzers, heuristics)
+    }
+
     /// Construct with default heuristics and the standard analyzer set.
     pub fn with_defaults() -> Self {
         Self::with_heuristics(
@@ -168,51 +311,232 @@ impl Pipeline {
         blend: f64,
     ) -> Self {
         Self {
-            analyzers,
-            cst_analyzers,
-            heuristics,
             scorer: Some(scorer),
             ml_blend: blend.clamp(0.0, 1.0),
+            ..Self::with_heuristics(analyzers, cst_analyzers, heuristics)
         }
     }
 
+    /// Scale CST signal weights by the fraction of the source that parsed
+    /// cleanly (`1 - error coverage`).  On by default; a clean parse is
+    /// unaffected either way.
+    pub fn with_parse_scaling(mut self, enabled: bool) -> Self {
+        self.parse_scaling = enabled;
+        self
+    }
+
+    /// Blend the current heuristic weights with empirical priors from the
+    /// corpus in `store`, weighted by `mix` (`0.0` keeps the weights as
+    /// they are); see [`CorpusHeuristics`](crate::store::CorpusHeuristics).
+    /// The store is only read, once, here.
+    #[cfg(feature = "corpus")]
+    pub fn with_corpus_priors(self, store: &crate::store::Store, mix: f64) -> rusqlite::Result<Self> {
+        Ok(self.with_corpus_counts(&store.signal_family_counts()?, mix))
+    }
+
+    /// Like [`with_corpus_priors`](Self::with_corpus_priors), from corpus
+    /// tallies already read.
+    #[cfg(feature = "corpus")]
+    pub fn with_corpus_counts(mut self, counts: &crate::store::CorpusCounts, mix: f64) -> Self {
+        let inner = std::mem::replace(&mut self.heuristics, Box::new(DefaultHeuristics));
+        self.heuristics = Box::new(crate::store::CorpusHeuristics::new(inner, counts, mix));
+        self
+    }
+
+    /// Skip text analyzers on sources shorter than `lines`, replacing each
+    /// analyzer's own [`Analyzer::min_lines`].
+    pub fn with_min_lines(mut self, lines: usize) -> Self {
+        self.min_lines = Some(lines);
+        self
+    }
+
+    /// Per-analyzer minimum line counts keyed by [`Analyzer::name`]; these
+    /// win over [`with_min_lines`](Self::with_min_lines).
+    pub fn with_analyzer_min_lines(mut self, overrides: HashMap<String, usize>) -> Self {
+        self.analyzer_min_lines = overrides;
+        self
+    }
+
+    /// Scale the final confidence of sources whose line count falls in a
+    /// bucket by that bucket's multiplier (clamped to `[0, 1]`); the first
+    /// matching bucket wins.  The adjustment is made on the confidence, like
+    /// the entropy penalty, since a uniform multiplier on raw signal weights
+    /// would cancel out in normalization.  Empty (the default) is no
+    /// adjustment.
+    pub fn with_size_buckets(mut self, buckets: Vec<SizeBucket>) -> Self {
+        self.size_buckets = buckets;
+        self
+    }
+
+    /// Clamp each listed family's final score into its bound, and the
+    /// confidence too when that family is the primary.  Applied last, after
+    /// normalization, ML blending and size buckets; the primary is not
+    /// re-picked, and sources without enough evidence are left alone.
+    /// Empty (the default) is no clamping.
+    pub fn with_confidence_bounds(mut self, bounds: Vec<(ModelFamily, ConfidenceBound)>) -> Self {
+        self.confidence_bounds = bounds;
+        self
+    }
+
+    /// Replace [`DEFAULT_GENERATED_MARKERS`]; an empty list analyzes
+    /// generated files like any other.
+    pub fn with_generated_markers(mut self, markers: Vec<String>) -> Self {
+        self.generated_markers = markers;
+        self
+    }
+
+    /// Give up on tree-sitter parsing of a source after `timeout` (default
+    /// [`DEFAULT_PARSE_TIMEOUT`]; `None` never gives up), so a pathological
+    /// or hostile file cannot stall a scan.  [`run`](Self::run) then returns
+    /// a degraded report from the text analyzers alone, marked
+    /// [`ReportMetadata::timed_out`]; [`run_symbols`](Self::run_symbols)
+    /// returns an error.
+    pub fn with_parse_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.parse_timeout = timeout;
+        self
+    }
+
+    /// Run a file's text analyzers, and its CST analyzers, in parallel once
+    /// it has at least `lines` lines (default [`DEFAULT_PARALLEL_MIN_LINES`];
+    /// `None` always runs them one after another).  Below that, spreading
+    /// the work over threads costs more than it saves.  Signals come out in
+    /// the same order either way.
+    pub fn with_parallel_min_lines(mut self, lines: Option<usize>) -> Self {
+        self.parallel_min_lines = lines;
+        self
+    }
+
+    /// Smooth each symbol's attribution in [`run_symbols`](Self::run_symbols)
+    /// toward the whole file's, so tiny functions with a signal or two do
+    /// not swing wildly.  `strength` acts as a pseudo-count: a symbol with
+    /// `n` signals keeps `n / (n + strength)` of its own scores and takes
+    /// the rest from the file.  `0.0` (the default) disables smoothing.
+    pub fn with_symbol_smoothing(mut self, strength: f64) -> Self {
+        self.symbol_smoothing = if strength.is_finite() { strength.max(0.0) } else { 0.0 };
+        self
+    }
+
+    /// Whether one of the first few lines of `source` carries a
+    /// generated-code marker.
+    pub fn is_generated(&self, source: &str) -> bool {
+        source
+            .lines()
+            .take(GENERATED_HEADER_LINES)
+            .any(|line| self.generated_markers.iter().any(|m| line.contains(m.as_str())))
+    }
+
+    /// Whether `analyzer` should run on a source of `line_count` lines.
+    fn meets_min_lines(&self, analyzer: &dyn Analyzer, line_count: usize) -> bool {
+        let min = self
+            .analyzer_min_lines
+            .get(analyzer.name())
+            .copied()
+            .or(self.min_lines)
+            .unwrap_or_else(|| analyzer.min_lines());
+        line_count > 0 && line_count >= min
+    }
+
     pub fn run(&self, source: &str, file_path: Option<PathBuf>) -> Report {
         let lang = file_path.as_ref().and_then(|p| detect_language(p));
 
-        let mut signals: Vec<Signal> = self
+        let lines_of_code = source.lines().count();
+        // Generated code says nothing about who wrote the generator's input;
+        // report it unattributed so it never sways a verdict or aggregate.
+        if self.is_generated(source) {
+            return Report {
+                attribution: aggregate_signals(&[]),
+                signals: Vec::new(),
+                metadata: ReportMetadata {
+                    file_path,
+                    lines_of_code,
+                    signal_count: 0,
+                    analyzer_version: analyzer_version().to_string(),
+                    generated: true,
+                    timed_out: false,
+                    parse_errors: 0,
+                    language: lang,
+                    lossy: false,
+                },
+                symbol_reports: None,
+            };
+        }
+        let parallel = self.parallel_min_lines.is_some_and(|min| lines_of_code >= min);
+        let analyzers: Vec<&dyn Analyzer> = self
             .analyzers
             .iter()
-            .flat_map(|a| a.analyze_with_language(source, lang))
+            .map(|a| a.as_ref())
+            .filter(|&a| self.meets_min_lines(a, lines_of_code))
             .collect();
+        // Parse once, up front: text analyzers that read declarations get
+        // the same tree as the CST layer below.  Languages without a bundled
+        // grammar (HCL) skip both.
+        let grammar = lang.and_then(|l| Some((l, get_ts_language(l)?, HeuristicLanguage::cst_from(l)?)));
+        let (tree, timed_out) = match &grammar {
+            Some((_, ts_lang, _)) => {
+                let mut parser = tree_sitter::Parser::new();
+                match parser.set_language(ts_lang) {
+                    Ok(()) => parse_with_timeout(&mut parser, source.as_bytes(), self.parse_timeout),
+                    Err(_) => (None, false),
+                }
+            }
+            None => (None, false),
+        };
+        let mut signals: Vec<Signal> =
+            ordered_map(&analyzers, parallel, |a| a.analyze_with_tree(source, lang, tree.as_ref()))
+                .into_iter()
+                .flatten()
+                .collect();
+
+        if file_path.as_deref().is_some_and(is_test_file)
+            && self.meets_min_lines(&TestStyleAnalyzer, lines_of_code)
+        {
+            signals.extend(TestStyleAnalyzer.analyze_with_language(source, lang));
+        }
 
         // CST analysis — extract metrics, match against TOML rules, and
         // accumulate raw metrics for the PostScorer (if configured).
         let mut collected_metrics = HashMap::new();
+        let cst_start = signals.len();
+        let mut parse_factor = 1.0;
+        let mut parse_errors = 0;
 
-        if let Some(ref path) = file_path {
-            if let Some(cst_lang) = detect_language(path) {
-                let ts_lang = crate::language::get_ts_language(cst_lang);
-                let mut parser = tree_sitter::Parser::new();
-                if parser.set_language(&ts_lang).is_ok() {
-                    if let Some(tree) = parser.parse(source.as_bytes(), None) {
-                        let cst_heur_lang = HeuristicLanguage::cst_from(cst_lang);
-                        for cst_analyzer in &self.cst_analyzers {
-                            if cst_analyzer.target_language() == cst_lang {
-                                let metrics = cst_analyzer.extract_metrics(&tree, source);
-                                if metrics.is_empty() {
-                                    signals.extend(cst_analyzer.analyze_tree(&tree, source));
-                                } else {
-                                    collected_metrics.extend(
-                                        metrics.iter().map(|(k, &v)| (k.clone(), v)),
-                                    );
-                                    signals.extend(match_metric_signals(
-                                        &metrics,
-                                        cst_heur_lang,
-                                        &*self.heuristics,
-                                    ));
-                                }
-                            }
-                        }
+        if let (Some((cst_lang, _, cst_heur_lang)), Some(tree)) = (grammar, &tree) {
+            parse_errors = parse_error_count(tree);
+            if self.parse_scaling {
+                parse_factor = 1.0 - error_coverage(tree, source.len());
+            }
+            let cst_analyzers: Vec<&dyn CstAnalyzer> = self
+                .cst_analyzers
+                .iter()
+                .map(|a| a.as_ref())
+                .filter(|a| a.target_language() == cst_lang)
+                .collect();
+            // Tree walks run in parallel; matching against the
+            // heuristics table stays in analyzer order.
+            let extracted = ordered_map(&cst_analyzers, parallel, |a| {
+                let metrics = a.extract_metrics(tree, source);
+                let tree_signals =
+                    if metrics.is_empty() { a.analyze_tree(tree, source) } else { Vec::new() };
+                (metrics, tree_signals)
+            });
+            for (metrics, tree_signals) in extracted {
+                if metrics.is_empty() {
+                    signals.extend(tree_signals);
+                } else {
+                    collected_metrics.extend(
+                        metrics.iter().map(|(k, &v)| (k.clone(), v)),
+                    );
+                    signals.extend(match_metric_signals(
+                        &metrics,
+                        cst_heur_lang,
+                        &*self.heuristics,
+                    ));
+                    if file_path.as_deref().is_some_and(is_jsx_path) {
+                        signals.extend(match_metric_signals(
+                            &metrics,
+                            HeuristicLanguage::Jsx,
+                            &*self.heuristics,
+                        ));
                     }
                 }
             }
@@ -223,9 +547,20 @@ impl Pipeline {
                 s.weight = self.heuristics.weight(&s.id);
             }
         }
-        signals.retain(|s| s.id.is_empty() || self.heuristics.is_enabled(&s.id));
+        // A partial parse makes CST signals unreliable — let them count only
+        // in proportion to how much of the file the tree actually covers.
+        if parse_factor < 1.0 {
+            for s in &mut signals[cst_start..] {
+                s.weight *= parse_factor;
+            }
+        }
+        signals.retain(|s| {
+            (s.id.is_empty() || self.heuristics.is_enabled(&s.id))
+                && self.heuristics.is_source_enabled(&s.source)
+        });
+        sort_signals(&mut signals);
 
-        let attribution = if let Some(ref scorer) = self.scorer {
+        let mut attribution = if let Some(ref scorer) = self.scorer {
             let heuristic_attr = self.aggregate(&signals);
             let ml_attr = scorer.rescore(
                 &signals,
@@ -238,8 +573,20 @@ impl Pipeline {
         } else {
             self.aggregate(&signals)
         };
+        if let Some(bucket) = self.size_buckets.iter().find(|b| b.contains(lines_of_code)) {
+            attribution.confidence = (attribution.confidence * bucket.multiplier).clamp(0.0, 1.0);
+        }
+        if attribution.has_sufficient_data() {
+            for (family, bound) in &self.confidence_bounds {
+                if let Some(score) = attribution.scores.get_mut(family) {
+                    *score = bound.apply(*score);
+                }
+                if attribution.primary == *family {
+                    attribution.confidence = bound.apply(attribution.confidence);
+                }
+            }
+        }
 
-        let lines_of_code = source.lines().count();
         let signal_count = signals.len();
 
         Report {
@@ -249,17 +596,100 @@ impl Pipeline {
                 file_path,
                 lines_of_code,
                 signal_count,
+                analyzer_version: analyzer_version().to_string(),
+                generated: false,
+                timed_out,
+                parse_errors,
+                language: lang,
+                lossy: false,
             },
             symbol_reports: None,
         }
     }
 
+    /// Re-analyze an edited file, reusing `old` where the edit provably
+    /// leaves it unchanged.
+    ///
+    /// `changed_range` is the range of lines in `new_source` that differ from
+    /// the source `old` was produced from.  Nearly every signal is a
+    /// file-wide ratio or count, so any real edit can move any of them; this
+    /// therefore only short-circuits when nothing changed (an empty range
+    /// with the same line count) and `old` came from this analyzer version,
+    /// and otherwise falls back to a full [`run`](Self::run).  Cheap for
+    /// watch/LSP loops that re-trigger on saves without edits.
+    pub fn run_incremental(&self, old: &Report, new_source: &str, changed_range: Range<usize>) -> Report {
+        let unchanged = changed_range.is_empty()
+            && old.metadata.lines_of_code == new_source.lines().count()
+            && old.metadata.analyzer_version == analyzer_version();
+        if unchanged {
+            return old.clone();
+        }
+        self.run(new_source, old.metadata.file_path.clone())
+    }
+
+    /// Attribute each line of `source` by analyzing overlapping windows of
+    /// `window` lines (a quarter window apart) and averaging, per line, the
+    /// family scores of every window covering it.  Finer-grained than
+    /// [`run_symbols`](Self::run_symbols) and independent of the grammar,
+    /// so it can single out an inserted block inside an otherwise uniform
+    /// file.
+    ///
+    /// Lines covered only by windows with too little data to attribute are
+    /// left out, as is everything in a generated file.  `window` is clamped
+    /// to the file's length.  Ties between families are broken as for a
+    /// file's primary family.
+    pub fn run_heatmap(
+        &self,
+        source: &str,
+        file_path: Option<PathBuf>,
+        window: usize,
+    ) -> Vec<LineAttribution> {
+        let lines: Vec<&str> = source.lines().collect();
+        if lines.is_empty() || self.is_generated(source) {
+            return Vec::new();
+        }
+        let window = window.clamp(1, lines.len());
+        let stride = (window / 4).max(1);
+        let mut starts: Vec<usize> = (0..=lines.len() - window).step_by(stride).collect();
+        if starts.last() != Some(&(lines.len() - window)) {
+            starts.push(lines.len() - window);
+        }
+
+        let parallel = self.parallel_min_lines.is_some_and(|min| lines.len() >= min);
+        let windows = ordered_map(&starts, parallel, |&start| {
+            let text = lines[start..start + window].join("\n");
+            (start, self.run(&text, file_path.clone()).attribution)
+        });
+        let mut totals: Vec<(HashMap<ModelFamily, f64>, usize)> = vec![(HashMap::new(), 0); lines.len()];
+        for (start, attribution) in windows.iter().filter(|(_, a)| a.has_sufficient_data()) {
+            for (scores, count) in &mut totals[*start..start + window] {
+                for (&family, &score) in &attribution.scores {
+                    *scores.entry(family).or_default() += score;
+                }
+                *count += 1;
+            }
+        }
+
+        totals
+            .into_iter()
+            .enumerate()
+            .filter(|(_, (_, count))| *count > 0)
+            .filter_map(|(i, (scores, count))| {
+                let (family, score) = top_family(&scores)?;
+                Some(LineAttribution { line: i + 1, family, score: score / count as f64 })
+            })
+            .collect()
+    }
+
     /// Analyze a file at the symbol level, returning one `SymbolReport` per
     /// extracted named symbol (function, method, class, …).
     ///
-    /// Returns an empty `Vec` if the file language has no symbol analyzer or
-    /// if the file cannot be parsed.
+    /// Returns an empty `Vec` if the file language has no grammar or symbol
+    /// analyzer, if the file cannot be parsed, or if it is generated code.
     pub fn run_symbols(&self, source: &[u8], file_path: &Path) -> anyhow::Result<Vec<SymbolReport>> {
+        if std::str::from_utf8(source).is_ok_and(|s| self.is_generated(s)) {
+            return Ok(vec![]);
+        }
         let lang = match detect_language(file_path) {
             Some(l) => l,
             None => return Ok(vec![]),
@@ -267,15 +697,22 @@ impl Pipeline {
 
         // Parse once and share the tree with both symbol extraction and
         // per-symbol signal collection.
-        let ts_lang = get_ts_language(lang);
+        let Some(ts_lang) = get_ts_language(lang) else {
+            return Ok(vec![]);
+        };
         let mut parser = tree_sitter::Parser::new();
         parser
             .set_language(&ts_lang)
             .map_err(|e| anyhow::anyhow!("tree-sitter language error: {e}"))?;
 
-        let tree = parser
-            .parse(source, None)
-            .ok_or_else(|| anyhow::anyhow!("failed to parse file"))?;
+        let tree = match parse_with_timeout(&mut parser, source, self.parse_timeout) {
+            (Some(tree), _) => tree,
+            (None, true) => anyhow::bail!(
+                "parsing timed out after {:?}",
+                self.parse_timeout.unwrap_or_default()
+            ),
+            (None, false) => anyhow::bail!("failed to parse file"),
+        };
 
         // Use the matching CstAnalyzer — it already knows the node kinds for
         // its language; no separate SymbolAnalyzer needed.
@@ -286,15 +723,26 @@ impl Pipeline {
             .map(|a| a.extract_symbols(&tree, source))
             .unwrap_or_default();
 
+        let file_attribution = (self.symbol_smoothing > 0.0 && !symbols.is_empty())
+            .then(|| self.run(&String::from_utf8_lossy(source), Some(file_path.to_path_buf())).attribution);
+
         let mut reports = Vec::new();
         for (metadata, node) in symbols {
             let range = node.byte_range();
             let symbol_bytes = source.get(range).unwrap_or(b"");
             let symbol_str = std::str::from_utf8(symbol_bytes).unwrap_or("");
             let sub_report = self.run(symbol_str, Some(file_path.to_path_buf()));
+            let attribution = match &file_attribution {
+                Some(file) => {
+                    let signals = sub_report.signals.len() as f64;
+                    let pull = self.symbol_smoothing / (signals + self.symbol_smoothing);
+                    blend_attributions(&sub_report.attribution, file, pull)
+                }
+                None => sub_report.attribution,
+            };
             reports.push(SymbolReport {
                 metadata,
-                attribution: sub_report.attribution,
+                attribution,
                 signals: sub_report.signals,
             });
         }
@@ -303,48 +751,168 @@ impl Pipeline {
     }
 
     fn aggregate(&self, signals: &[Signal]) -> Attribution {
-        let mut raw_scores: HashMap<ModelFamily, f64> = HashMap::new();
-        for family in ModelFamily::all() {
-            raw_scores.insert(*family, 0.0);
+        aggregate_signals(signals)
+    }
+}
+
+/// Fraction of `source_len` bytes covered by ERROR nodes in `tree`, in
+/// `[0, 1]`.  Nested errors are counted once, via their outermost node.
+fn error_coverage(tree: &tree_sitter::Tree, source_len: usize) -> f64 {
+    let root = tree.root_node();
+    if source_len == 0 || !root.has_error() {
+        return 0.0;
+    }
+    let mut error_bytes = 0;
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if node.is_error() {
+            error_bytes += node.end_byte() - node.start_byte();
+        } else if node.has_error() {
+            let mut cursor = node.walk();
+            stack.extend(node.children(&mut cursor));
         }
+    }
+    (error_bytes as f64 / source_len as f64).clamp(0.0, 1.0)
+}
 
-        for signal in signals {
-            *raw_scores.entry(signal.family).or_insert(0.0) += signal.weight;
+/// Syntax errors in `tree`: outermost ERROR nodes plus MISSING tokens the
+/// parser inserted to recover.
+fn parse_error_count(tree: &tree_sitter::Tree) -> usize {
+    let root = tree.root_node();
+    if !root.has_error() {
+        return 0;
+    }
+    let mut count = 0;
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if node.is_error() || node.is_missing() {
+            count += 1;
+        } else if node.has_error() {
+            let mut cursor = node.walk();
+            stack.extend(node.children(&mut cursor));
         }
+    }
+    count
+}
 
-        // Shift all scores so the minimum is 0
-        let min_score = raw_scores.values().cloned().fold(f64::INFINITY, f64::min);
-        let mut shifted: HashMap<ModelFamily, f64> = raw_scores
-            .iter()
-            .map(|(&k, &v)| (k, (v - min_score).max(0.0)))
-            .collect();
+/// Order signals by source, then id, so reports are reproducible regardless
+/// of analyzer order. Ties (id-less signals) fall back to the description.
+pub(crate) fn sort_signals(signals: &mut [Signal]) {
+    signals.sort_by(|a, b| {
+        a.source
+            .cmp(&b.source)
+            .then_with(|| a.id.cmp(&b.id))
+            .then_with(|| a.description.cmp(&b.description))
+    });
+}
 
-        // Normalize to a distribution summing to 1.0
-        let total: f64 = shifted.values().sum();
-        if total > 0.0 {
-            for v in shifted.values_mut() {
-                *v /= total;
-            }
-        } else {
-            // No signal data — leave all scores at 0.0, confidence 0.0
-            return Attribution {
-                primary: ModelFamily::Human,
-                confidence: 0.0,
-                scores: shifted,
-            };
-        }
+/// Fraction of the winner's score removed when the score distribution is
+/// maximally spread (uniform across every family).  Kept small so a clear
+/// winner is barely touched; only genuinely conflicting files lose much.
+const ENTROPY_PENALTY: f64 = 0.25;
 
-        let (primary, confidence) = shifted
-            .iter()
-            .max_by(|a, b| a.1.partial_cmp(b.1).unwrap().then_with(|| a.0.to_string().cmp(&b.0.to_string())))
-            .map(|(&k, &v)| (k, v))
-            .unwrap();
+/// Confidence multiplier in `[1 - ENTROPY_PENALTY, 1]` for a normalized score
+/// distribution, falling linearly with its Shannon entropy (relative to the
+/// maximum for that many families).
+fn entropy_factor(scores: &HashMap<ModelFamily, f64>) -> f64 {
+    if scores.len() < 2 {
+        return 1.0;
+    }
+    let entropy: f64 = sorted_values(scores)
+        .iter()
+        .filter(|&&p| p > 0.0)
+        .map(|&p| -p * p.ln())
+        .sum();
+    let normalized = (entropy / (scores.len() as f64).ln()).clamp(0.0, 1.0);
+    1.0 - ENTROPY_PENALTY * normalized
+}
 
-        Attribution {
-            primary,
-            confidence,
-            scores: shifted,
+/// Score values in ascending order, so sums over them don't depend on
+/// `HashMap` iteration order.
+fn sorted_values(scores: &HashMap<ModelFamily, f64>) -> Vec<f64> {
+    let mut values: Vec<f64> = scores.values().copied().collect();
+    values.sort_by(f64::total_cmp);
+    values
+}
+
+/// Raw accumulated signal weight per family, before the shift and
+/// normalization in [`aggregate_signals`].  Every family is present, at
+/// `0.0` when no signal points to it.
+///
+/// Each family's weights are summed in ascending order, so the result is
+/// bit-for-bit the same however the signals are ordered.
+pub fn raw_scores(signals: &[Signal]) -> HashMap<ModelFamily, f64> {
+    let mut contributions: HashMap<ModelFamily, Vec<f64>> =
+        ModelFamily::all().iter().map(|&family| (family, Vec::new())).collect();
+    for signal in signals {
+        contributions.entry(signal.family).or_default().push(signal.weight);
+    }
+    contributions
+        .into_iter()
+        .map(|(family, mut weights)| {
+            weights.sort_by(f64::total_cmp);
+            (family, weights.iter().sum())
+        })
+        .collect()
+}
+
+/// The highest-scoring family and its score, or `None` for empty `scores`.
+///
+/// Ties are broken deterministically: [`ModelFamily::Human`] wins any tie
+/// (an even split is not evidence of AI authorship); remaining ties go to
+/// the family whose [`ModelFamily::name`] sorts first.  NaN scores rank
+/// below every real score, so a corrupt value can never win or panic.
+pub(crate) fn top_family(scores: &HashMap<ModelFamily, f64>) -> Option<(ModelFamily, f64)> {
+    let rank = |v: f64| if v.is_nan() { f64::NEG_INFINITY } else { v };
+    scores
+        .iter()
+        .map(|(&family, &score)| (family, score))
+        .max_by(|(fa, a), (fb, b)| {
+            rank(*a)
+                .total_cmp(&rank(*b))
+                .then_with(|| (*fa == ModelFamily::Human).cmp(&(*fb == ModelFamily::Human)))
+                .then_with(|| fb.name().cmp(fa.name()))
+        })
+}
+
+/// Sum signal weights per family, shift so the minimum is 0, and normalize
+/// into an [`Attribution`].
+///
+/// The winner's confidence is its normalized score, scaled down by
+/// [`entropy_factor`] when the evidence is split across families.
+pub(crate) fn aggregate_signals(signals: &[Signal]) -> Attribution {
+    let raw_scores = raw_scores(signals);
+
+    // Shift all scores so the minimum is 0
+    let min_score = raw_scores.values().cloned().fold(f64::INFINITY, f64::min);
+    let mut shifted: HashMap<ModelFamily, f64> = raw_scores
+        .iter()
+        .map(|(&k, &v)| (k, (v - min_score).max(0.0)))
+        .collect();
+
+    // Normalize to a distribution summing to 1.0 (summed in a fixed order so
+    // scores are bit-for-bit reproducible across runs)
+    let total: f64 = sorted_values(&shifted).iter().sum();
+    if total > 0.0 {
+        for v in shifted.values_mut() {
+            *v /= total;
         }
+    } else {
+        // No signal data — leave all scores at 0.0, confidence 0.0
+        return Attribution {
+            primary: ModelFamily::Human,
+            confidence: 0.0,
+            scores: shifted,
+        };
+    }
+
+    let (primary, confidence) = top_family(&shifted).unwrap_or((ModelFamily::Human, 0.0));
+    let confidence = confidence * entropy_factor(&shifted);
+
+    Attribution {
+        primary,
+        confidence,
+        scores: shifted,
     }
 }
 
@@ -368,6 +936,78 @@ mod tests {
         assert!(reports.iter().any(|r| r.metadata.name == "sub"));
     }
 
+    #[test]
+    fn heatmap_singles_out_an_inserted_block() {
+        let human: String = (0..6)
+            .map(|i| {
+                format!("fn f{i}(x: &str) -> u32 {{\n    // TODO: fix this hack\n    x.parse::<u32>().unwrap() + {i}\n}}\n")
+            })
+            .collect();
+        let inserted: String = (0..6)
+            .map(|i| {
+                format!(
+                    "/// Computes the value for input {i}.\n///\n/// # Arguments\n///\n\
+                     /// * `x` - The input value.\n\
+                     pub fn g{i}(x: u32) -> Result<u32, String> {{\n    // Step 1: Validate the input\n    \
+                     if x == 0 {{\n        return Err(\"x must be non-zero\".to_string());\n    }}\n    \
+                     // Step 2: Compute the result\n    Ok(x * {i})\n}}\n"
+                )
+            })
+            .collect();
+        let source = format!("{human}\n{inserted}\n{human}");
+        let total = source.lines().count();
+        let heatmap = Pipeline::with_defaults().run_heatmap(&source, Some(PathBuf::from("lib.rs")), 20);
+
+        assert_eq!(heatmap.len(), total, "every line is covered by an attributable window");
+        assert!(heatmap.windows(2).all(|w| w[0].line + 1 == w[1].line));
+        let family_at = |line: usize| heatmap[line - 1].family;
+        assert_eq!(family_at(1), ModelFamily::Human);
+        assert_ne!(family_at(total / 2), ModelFamily::Human);
+        assert_eq!(family_at(total), ModelFamily::Human);
+    }
+
+    #[test]
+    fn heatmap_window_covering_the_file_matches_run() {
+        let source = "/// Adds one.\npub fn inc(x: u32) -> u32 {\n    x + 1\n}\n".repeat(5);
+        let pipeline = Pipeline::with_defaults();
+        let path = Some(PathBuf::from("lib.rs"));
+        let file = pipeline.run(&source, path.clone()).attribution;
+        let heatmap = pipeline.run_heatmap(&source, path.clone(), 1000);
+        assert_eq!(heatmap.len(), 20);
+        assert!(heatmap.iter().all(|l| l.family == file.primary && l.score == file.scores[&file.primary]));
+
+        assert!(pipeline.run_heatmap("", path.clone(), 20).is_empty());
+        assert!(pipeline.run_heatmap(&format!("// @generated\n{source}"), path, 20).is_empty());
+    }
+
+    #[test]
+    fn symbol_smoothing_pulls_symbols_toward_the_file() {
+        let mut source = String::from("fn add(a: i32, b: i32) -> i32 { a + b }\n");
+        for i in 0..15 {
+            source.push_str(&format!("/// Doubles {i}.\npub fn double_{i}(x: u32) -> u32 {{\n    x * 2 + {i}\n}}\n"));
+        }
+        let path = PathBuf::from("lib.rs");
+        let file = Pipeline::with_defaults().run(&source, Some(path.clone())).attribution;
+        let distance = |pipeline: Pipeline| -> f64 {
+            pipeline
+                .run_symbols(source.as_bytes(), &path)
+                .unwrap()
+                .iter()
+                .flat_map(|r| {
+                    ModelFamily::all().iter().map(|f| {
+                        let score = |a: &Attribution| a.scores.get(f).copied().unwrap_or(0.0);
+                        (score(&r.attribution) - score(&file)).abs()
+                    })
+                })
+                .sum()
+        };
+        let raw = distance(Pipeline::with_defaults());
+        assert_eq!(distance(Pipeline::with_defaults().with_symbol_smoothing(0.0)), raw);
+        let smoothed = distance(Pipeline::with_defaults().with_symbol_smoothing(5.0));
+        assert!(smoothed < raw, "smoothed {smoothed} vs raw {raw}");
+        assert!(distance(Pipeline::with_defaults().with_symbol_smoothing(1e9)) < 1e-6);
+    }
+
     #[test]
     fn run_symbols_symbol_reports_have_attribution() {
         let source = b"fn documented() -> i32 { 42 }\n";
@@ -424,6 +1064,468 @@ mod tests {
         assert_eq!(total, 0.0, "scores should all be 0.0 when no signals");
     }
 
+    const REACT_SOURCE: &str = r#"
+import { useState, useEffect, useMemo } from 'react';
+
+function Counter({ initial }) {
+    const [count, setCount] = useState(initial);
+    useEffect(() => { document.title = `${count}`; }, [count]);
+    const doubled = useMemo(() => count * 2, [count]);
+    return <button onClick={() => setCount(count + 1)}>{doubled}</button>;
+}
+
+const Label = ({ text }) => <span>{text}</span>;
+"#;
+
+    #[test]
+    fn jsx_signals_fire_for_jsx_and_tsx_paths() {
+        let pipeline = Pipeline::with_defaults();
+        for name in ["App.jsx", "App.tsx"] {
+            let report = pipeline.run(REACT_SOURCE, Some(PathBuf::from(name)));
+            let ids: Vec<_> = report.signals.iter().map(|s| s.id.as_str()).collect();
+            assert!(ids.contains(&crate::heuristics::signal_ids::JSX_IDIOMS_HOOKS), "{name}: {ids:?}");
+            assert!(ids.contains(&crate::heuristics::signal_ids::JSX_IDIOMS_FUNCTIONAL_COMPONENT), "{name}: {ids:?}");
+            assert!(ids.contains(&crate::heuristics::signal_ids::JSX_STRUCTURE_PROP_DESTRUCTURING), "{name}: {ids:?}");
+        }
+    }
+
+    #[test]
+    fn jsx_signals_skipped_for_plain_js_paths() {
+        let pipeline = Pipeline::with_defaults();
+        let report = pipeline.run(REACT_SOURCE, Some(PathBuf::from("app.js")));
+        assert!(report.signals.iter().all(|s| !s.id.starts_with("jsx.")));
+    }
+
+    const INCREMENTAL_SOURCE: &str = "fn main() {\n    let x = 1;\n    println!(\"{x}\");\n}\n";
+
+    fn marked_report(pipeline: &Pipeline) -> Report {
+        let mut old = pipeline.run(INCREMENTAL_SOURCE, Some(PathBuf::from("main.rs")));
+        old.attribution.confidence = 0.123;
+        old
+    }
+
+    #[test]
+    fn run_incremental_reuses_report_when_nothing_changed() {
+        let pipeline = Pipeline::with_defaults();
+        let old = marked_report(&pipeline);
+        let report = pipeline.run_incremental(&old, INCREMENTAL_SOURCE, 2..2);
+        assert_eq!(report.attribution.confidence, 0.123);
+    }
+
+    #[test]
+    fn run_incremental_reruns_after_an_edit() {
+        let pipeline = Pipeline::with_defaults();
+        let old = marked_report(&pipeline);
+        let edited = INCREMENTAL_SOURCE.replace("let x = 1;", "let x = 2;");
+        let report = pipeline.run_incremental(&old, &edited, 1..2);
+        let fresh = pipeline.run(&edited, Some(PathBuf::from("main.rs")));
+        assert_eq!(report.attribution.confidence, fresh.attribution.confidence);
+        assert_eq!(report.metadata.file_path, old.metadata.file_path);
+    }
+
+    #[test]
+    fn run_incremental_reruns_stale_analyzer_version() {
+        let pipeline = Pipeline::with_defaults();
+        let mut old = marked_report(&pipeline);
+        old.metadata.analyzer_version = "stale".into();
+        let report = pipeline.run_incremental(&old, INCREMENTAL_SOURCE, 0..0);
+        assert_ne!(report.attribution.confidence, 0.123);
+        assert_eq!(report.metadata.analyzer_version, analyzer_version());
+    }
+
+    fn parse(source: &str) -> tree_sitter::Tree {
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&get_ts_language(Language::Rust).unwrap()).unwrap();
+        parser.parse(source, None).unwrap()
+    }
+
+    #[test]
+    fn error_coverage_is_zero_for_clean_parse() {
+        let source = "fn main() {\n    let x = 1;\n}\n";
+        assert_eq!(error_coverage(&parse(source), source.len()), 0.0);
+    }
+
+    #[test]
+    fn error_coverage_grows_with_broken_source() {
+        let source = "fn main() { let x = 1; }\n@@@ ### $$$ %%% ^^^ &&& *** !!!\n";
+        let coverage = error_coverage(&parse(source), source.len());
+        assert!(coverage > 0.2 && coverage < 1.0, "coverage = {coverage}");
+    }
+
+    #[test]
+    fn parse_errors_are_counted_in_metadata() {
+        let path = PathBuf::from("lib.rs");
+        let clean = Pipeline::with_defaults().run("fn main() {\n    let x = 1;\n}\n", Some(path.clone()));
+        assert_eq!(clean.metadata.parse_errors, 0);
+        assert!(!clean.metadata.is_degraded());
+
+        let broken = Pipeline::with_defaults().run("fn main() {\n    let x = ;\n}\n@@@ ###\n", Some(path));
+        assert!(broken.metadata.parse_errors > 0);
+        assert!(broken.metadata.is_degraded());
+        assert!(parse_error_count(&parse("fn main() { let x = 1 }")) > 0, "MISSING `;` counts");
+    }
+
+    #[test]
+    fn parse_timeout_degrades_to_text_signals() {
+        let source: String = (0..2000)
+            .map(|i| format!("/// Doc {i}.\npub fn f{i}(x: u32) -> u32 {{\n    x + {i}\n}}\n"))
+            .collect();
+        let path = PathBuf::from("lib.rs");
+        let is_cst = |s: &Signal| s.id.contains("_cst.");
+
+        let full = Pipeline::with_defaults().run(&source, Some(path.clone()));
+        assert!(!full.metadata.timed_out);
+        assert!(full.signals.iter().any(is_cst));
+
+        let pipeline = Pipeline::with_defaults().with_parse_timeout(Some(Duration::ZERO));
+        let degraded = pipeline.run(&source, Some(path.clone()));
+        assert!(degraded.metadata.timed_out);
+        assert!(!degraded.signals.iter().any(is_cst));
+        assert!(!degraded.signals.is_empty(), "text analyzers still run");
+        let err = pipeline.run_symbols(source.as_bytes(), &path).unwrap_err();
+        assert!(err.to_string().contains("timed out"), "{err}");
+    }
+
+    #[test]
+    fn parallel_analysis_matches_sequential() {
+        let source: String = (0..60)
+            .map(|i| format!("/// Doc {i}.\npub fn f{i}(x: u32) -> u32 {{\n    // TODO: tune\n    x + {i}\n}}\n"))
+            .collect();
+        let path = PathBuf::from("lib.rs");
+        let sequential = Pipeline::with_defaults().with_parallel_min_lines(None).run(&source, Some(path.clone()));
+        let parallel = Pipeline::with_defaults().with_parallel_min_lines(Some(1)).run(&source, Some(path));
+        let ids = |r: &Report| r.signals.iter().map(|s| s.id.clone()).collect::<Vec<_>>();
+        assert!(parallel.signals.iter().any(|s| s.id.contains("_cst.")));
+        assert_eq!(ids(&parallel), ids(&sequential));
+        assert_eq!(parallel.attribution.confidence, sequential.attribution.confidence);
+    }
+
+    #[test]
+    fn broken_parse_scales_down_cst_signals() {
+        let mut source = String::from("/// Adds.\nfn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n");
+        for i in 0..20 {
+            source.push_str(&format!("/// Doc {i}.\npub fn f{i}(x: u32) -> u32 {{\n    x + {i}\n}}\n"));
+        }
+        let broken = format!("{source}{}", "@@@ ### $$$ %%% ^^^ &&& *** !!!\n".repeat(20));
+        let path = Some(PathBuf::from("lib.rs"));
+        let total_weight = |pipeline: Pipeline| -> f64 {
+            pipeline
+                .run(&broken, path.clone())
+                .signals
+                .iter()
+                .map(|s| s.weight.abs())
+                .sum()
+        };
+        let scaled = total_weight(Pipeline::with_defaults());
+        let unscaled = total_weight(Pipeline::with_defaults().with_parse_scaling(false));
+        assert!(scaled < unscaled, "scaled {scaled} vs unscaled {unscaled}");
+
+        let clean = Pipeline::with_defaults().run(&source, path.clone());
+        let clean_unscaled = Pipeline::with_defaults()
+            .with_parse_scaling(false)
+            .run(&source, path);
+        assert_eq!(
+            serde_json::to_string(&clean.signals).unwrap(),
+            serde_json::to_string(&clean_unscaled.signals).unwrap(),
+        );
+    }
+
+    #[test]
+    fn run_sorts_signals_by_source_then_id() {
+        let report = Pipeline::with_defaults().run(REACT_SOURCE, Some(PathBuf::from("App.jsx")));
+        let keys: Vec<_> = report.signals.iter().map(|s| (&s.source, &s.id)).collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        assert_eq!(keys, sorted);
+    }
+
+    #[test]
+    fn run_records_detected_language() {
+        let pipeline = Pipeline::with_defaults();
+        let tsx = pipeline.run("const x = 1;\n", Some(PathBuf::from("App.tsx")));
+        assert_eq!(tsx.metadata.language, Some(Language::JavaScript));
+        assert_eq!(pipeline.run("x\n", Some(PathBuf::from("notes.txt"))).metadata.language, None);
+        assert_eq!(pipeline.run("x\n", None).metadata.language, None);
+    }
+
+    #[test]
+    fn run_twice_produces_identical_json() {
+        let source = include_str!("pipeline.rs");
+        let path = Some(PathBuf::from("pipeline.rs"));
+        let first = serde_json::to_string(&Pipeline::with_defaults().run(source, path.clone())).unwrap();
+        let second = serde_json::to_string(&Pipeline::with_defaults().run(source, path)).unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn suppressed_signals_do_not_reach_report_or_attribution() {
+        use crate::heuristics::ConfiguredHeuristics;
+
+        let source = include_str!("pipeline.rs");
+        let path = Some(PathBuf::from("pipeline.rs"));
+        let baseline = Pipeline::with_defaults().run(source, path.clone());
+        let victim = baseline
+            .signals
+            .iter()
+            .find(|s| !s.id.is_empty() && s.weight != 0.0)
+            .expect("pipeline.rs should trigger at least one signal")
+            .clone();
+
+        let heuristics = ConfiguredHeuristics::from_config(HashMap::new())
+            .with_suppressed(vec![victim.id.clone()]);
+        let pipeline = Pipeline::with_heuristics(
+            default_analyzers(),
+            default_cst_analyzers(),
+            Box::new(heuristics),
+        );
+        let report = pipeline.run(source, path.clone());
+        assert!(report.signals.iter().all(|s| s.id != victim.id));
+
+        let expected: Vec<Signal> = baseline
+            .signals
+            .iter()
+            .filter(|s| s.id != victim.id)
+            .cloned()
+            .collect();
+        assert_eq!(report.attribution.scores, aggregate_signals(&expected).scores);
+
+        let heuristics = ConfiguredHeuristics::from_config(HashMap::new())
+            .with_suppressed(vec![victim.source.clone()]);
+        let pipeline = Pipeline::with_heuristics(
+            default_analyzers(),
+            default_cst_analyzers(),
+            Box::new(heuristics),
+        );
+        let report = pipeline.run(source, path);
+        assert!(report.signals.iter().all(|s| s.source != victim.source));
+    }
+
+    #[test]
+    fn test_style_signals_only_for_test_files() {
+        let source: String = ["empty", "zero", "negative", "unicode", "single", "large", "max", "plain"]
+            .iter()
+            .map(|name| format!("def test_{name}():\n    assert parse('{name}') == 1\n\n"))
+            .collect();
+        let fired = |path: &str| {
+            Pipeline::with_defaults()
+                .run(&source, Some(PathBuf::from(path)))
+                .signals
+                .iter()
+                .any(|s| s.id == crate::heuristics::signal_ids::PYTHON_TESTS_EDGE_CASE_NAMES)
+        };
+        assert!(fired("tests/test_parse.py"));
+        assert!(!fired("src/parse.py"));
+    }
+
+    // -- AnalyzerRegistry tests --------------------------------------------
+
+    /// Custom analyzer as an embedder would write it: flags `unsafe` blocks.
+    struct UnsafeBlockAnalyzer;
+
+    impl Analyzer for UnsafeBlockAnalyzer {
+        fn name(&self) -> &str {
+            "unsafe_blocks"
+        }
+
+        fn analyze(&self, source: &str) -> Vec<Signal> {
+            if source.contains("unsafe {") {
+                vec![Signal::new(
+                    "custom.unsafe_blocks.present",
+                    self.name(),
+                    "Contains an unsafe block",
+                    ModelFamily::Human,
+                    1.0,
+                )]
+            } else {
+                vec![]
+            }
+        }
+    }
+
+    #[test]
+    fn from_registry_runs_custom_analyzer() {
+        let mut registry = AnalyzerRegistry::with_defaults();
+        registry.register(UnsafeBlockAnalyzer);
+        let pipeline = Pipeline::from_registry(registry, Box::new(DefaultHeuristics));
+        let report = pipeline.run("fn main() { unsafe { std::hint::unreachable_unchecked() } }", None);
+        assert!(report
+            .signals
+            .iter()
+            .any(|s| s.id == "custom.unsafe_blocks.present" && s.source == "unsafe_blocks"));
+    }
+
+    #[test]
+    fn from_registry_only_registered_analyzers_run() {
+        let mut registry = AnalyzerRegistry::new();
+        registry.register(UnsafeBlockAnalyzer);
+        let pipeline = Pipeline::from_registry(registry, Box::new(DefaultHeuristics));
+        let report = pipeline.run("unsafe { }\n".repeat(20).as_str(), None);
+        assert_eq!(report.signals.len(), 1);
+        assert_eq!(report.attribution.primary, ModelFamily::Human);
+    }
+
+    // -- min_lines tests ---------------------------------------------------
+
+    fn custom_only(analyzer: impl Analyzer + 'static) -> Pipeline {
+        let mut registry = AnalyzerRegistry::new();
+        registry.register(analyzer);
+        Pipeline::from_registry(registry, Box::new(DefaultHeuristics))
+    }
+
+    #[test]
+    fn custom_analyzer_runs_on_one_line_by_default() {
+        let report = custom_only(UnsafeBlockAnalyzer).run("unsafe { }", None);
+        assert_eq!(report.signals.len(), 1);
+        assert!(custom_only(UnsafeBlockAnalyzer).run("", None).signals.is_empty());
+    }
+
+    #[test]
+    fn global_min_lines_overrides_analyzer_default() {
+        let source = "let very_descriptive_name = compute_everything();\n".repeat(4);
+        let naming = || custom_only(crate::analyzers::text::naming::NamingAnalyzer::default());
+        assert!(naming().run(&source, None).signals.is_empty());
+        assert!(!naming().with_min_lines(3).run(&source, None).signals.is_empty());
+        assert!(custom_only(UnsafeBlockAnalyzer)
+            .with_min_lines(5)
+            .run("unsafe { }", None)
+            .signals
+            .is_empty());
+    }
+
+    #[test]
+    fn analyzer_min_lines_beat_global() {
+        let pipeline = custom_only(UnsafeBlockAnalyzer)
+            .with_min_lines(5)
+            .with_analyzer_min_lines(HashMap::from([("unsafe_blocks".to_string(), 1)]));
+        assert_eq!(pipeline.run("unsafe { }", None).signals.len(), 1);
+    }
+
+    // -- size bucket tests ---------------------------------------------------
+
+    #[test]
+    fn size_bucket_parse_and_contains() {
+        let short = SizeBucket::parse("0-19", 0.5).unwrap();
+        assert!(short.contains(0) && short.contains(19));
+        assert!(!short.contains(20));
+        let huge = SizeBucket::parse("2000-", 0.9).unwrap();
+        assert!(huge.contains(2000) && huge.contains(usize::MAX));
+        assert!(SizeBucket::parse("20", 0.5).is_none());
+        assert!(SizeBucket::parse("20-10", 0.5).is_none());
+        assert!(SizeBucket::parse("a-10", 0.5).is_none());
+        assert!(SizeBucket::parse("0-10", -1.0).is_none());
+    }
+
+    #[test]
+    fn size_bucket_scales_confidence_for_matching_length() {
+        let source = "unsafe { }\n".repeat(5);
+        let base = custom_only(UnsafeBlockAnalyzer).run(&source, None);
+        let dampened = custom_only(UnsafeBlockAnalyzer)
+            .with_size_buckets(vec![SizeBucket::parse("0-19", 0.5).unwrap()])
+            .run(&source, None);
+        assert!((dampened.attribution.confidence - base.attribution.confidence * 0.5).abs() < 1e-9);
+        assert_eq!(dampened.attribution.primary, base.attribution.primary);
+
+        let untouched = custom_only(UnsafeBlockAnalyzer)
+            .with_size_buckets(vec![SizeBucket::parse("20-", 0.5).unwrap()])
+            .run(&source, None);
+        assert_eq!(untouched.attribution.confidence, base.attribution.confidence);
+    }
+
+    #[test]
+    fn confidence_bounds_clamp_scores_and_primary_confidence() {
+        let source = "unsafe { }\n".repeat(5);
+        let base = custom_only(UnsafeBlockAnalyzer).run(&source, None);
+        let primary = base.attribution.primary;
+        assert!(base.attribution.confidence > 0.3);
+
+        let capped = custom_only(UnsafeBlockAnalyzer)
+            .with_confidence_bounds(vec![(primary, ConfidenceBound::new(None, Some(0.3)).unwrap())])
+            .run(&source, None);
+        assert_eq!(capped.attribution.primary, primary);
+        assert_eq!(capped.attribution.confidence, 0.3);
+        assert!(capped.attribution.scores.get(&primary).is_some_and(|&s| s <= 0.3));
+
+        let other = ModelFamily::all().iter().copied().find(|&f| f != primary).unwrap();
+        let floored = custom_only(UnsafeBlockAnalyzer)
+            .with_confidence_bounds(vec![(other, ConfidenceBound::new(Some(0.9), None).unwrap())])
+            .run(&source, None);
+        assert_eq!(floored.attribution.confidence, base.attribution.confidence);
+        assert_eq!(floored.attribution.primary, primary, "the primary is not re-picked");
+
+        assert!(ConfidenceBound::new(Some(0.8), Some(0.3)).is_none());
+        assert!(ConfidenceBound::new(None, Some(1.2)).is_none());
+    }
+
+    // -- generated code tests ------------------------------------------------
+
+    #[test]
+    fn generated_headers_skip_analysis() {
+        let body = "unsafe { }\n".repeat(5);
+        let go = format!("// Code generated by protoc-gen-go. DO NOT EDIT.\n{body}");
+        let report = custom_only(UnsafeBlockAnalyzer).run(&go, None);
+        assert!(report.metadata.generated);
+        assert!(report.signals.is_empty());
+        assert!(!report.attribution.has_sufficient_data());
+        assert_eq!(report.metadata.lines_of_code, 6);
+
+        let tagged = format!("// @generated\n{body}");
+        assert!(custom_only(UnsafeBlockAnalyzer).run(&tagged, None).metadata.generated);
+        assert!(!custom_only(UnsafeBlockAnalyzer).run(&body, None).metadata.generated);
+    }
+
+    #[test]
+    fn generated_markers_are_configurable() {
+        let source = format!("// Code generated. DO NOT EDIT.\n{}", "unsafe { }\n".repeat(5));
+        let disabled = custom_only(UnsafeBlockAnalyzer).with_generated_markers(vec![]);
+        assert!(!disabled.run(&source, None).metadata.generated);
+        let custom = custom_only(UnsafeBlockAnalyzer).with_generated_markers(vec!["AUTOGEN".into()]);
+        assert!(!custom.is_generated(&source));
+        assert!(custom.is_generated("# AUTOGEN from schema.json\n"));
+        // Markers past the header are ordinary comments.
+        let late = format!("{}// DO NOT EDIT\n", "let x = 1;\n".repeat(20));
+        assert!(!Pipeline::with_defaults().is_generated(&late));
+    }
+
+    // -- tie-break tests ----------------------------------------------------
+
+    #[test]
+    fn equal_scores_prefer_human_then_alphabetical() {
+        let tie = |families: &[ModelFamily]| -> HashMap<ModelFamily, f64> {
+            families.iter().map(|&f| (f, 0.5)).collect()
+        };
+        let all = [ModelFamily::Gpt, ModelFamily::Human, ModelFamily::Claude];
+        assert_eq!(top_family(&tie(&all)), Some((ModelFamily::Human, 0.5)));
+        let no_human = [ModelFamily::Gpt, ModelFamily::Gemini, ModelFamily::Copilot];
+        assert_eq!(top_family(&tie(&no_human)), Some((ModelFamily::Copilot, 0.5)));
+        let pair = [ModelFamily::Claude, ModelFamily::Gpt];
+        assert_eq!(top_family(&tie(&pair)), Some((ModelFamily::Claude, 0.5)));
+        assert_eq!(top_family(&HashMap::new()), None);
+    }
+
+    #[test]
+    fn equal_signal_weights_attribute_to_human() {
+        let attr = aggregate_signals(&[
+            weighted(ModelFamily::Claude, 2.0),
+            weighted(ModelFamily::Human, 2.0),
+        ]);
+        assert_eq!(attr.primary, ModelFamily::Human);
+        let attr = aggregate_signals(&[
+            weighted(ModelFamily::Gpt, 1.0),
+            weighted(ModelFamily::Claude, 1.0),
+        ]);
+        assert_eq!(attr.primary, ModelFamily::Claude);
+    }
+
+    #[test]
+    fn nan_scores_never_win_or_panic() {
+        let scores = HashMap::from([
+            (ModelFamily::Claude, f64::NAN),
+            (ModelFamily::Gpt, 0.2),
+            (ModelFamily::Human, f64::NAN),
+        ]);
+        assert_eq!(top_family(&scores), Some((ModelFamily::Gpt, 0.2)));
+    }
+
     // -- PostScorer / blend tests ------------------------------------------
 
     struct FixedScorer(Attribution);
@@ -441,6 +1543,57 @@ mod tests {
         }
     }
 
+    fn weighted(family: ModelFamily, weight: f64) -> Signal {
+        Signal::new("", "test", "", family, weight)
+    }
+
+    #[test]
+    fn raw_scores_do_not_depend_on_signal_order() {
+        let mut signals: Vec<Signal> = [0.1, 1e16, 0.7, -1e16, 0.3]
+            .iter()
+            .map(|&w| weighted(ModelFamily::Claude, w))
+            .collect();
+        let forward = raw_scores(&signals);
+        signals.reverse();
+        assert_eq!(forward, raw_scores(&signals));
+    }
+
+    #[test]
+    fn single_family_evidence_keeps_full_confidence() {
+        let attr = aggregate_signals(&[weighted(ModelFamily::Claude, 3.0)]);
+        assert_eq!(attr.primary, ModelFamily::Claude);
+        assert!((attr.confidence - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn conflicting_evidence_lowers_confidence() {
+        let one_family = aggregate_signals(&[
+            weighted(ModelFamily::Claude, 3.0),
+            weighted(ModelFamily::Gpt, 0.5),
+        ]);
+        let balanced = aggregate_signals(&[
+            weighted(ModelFamily::Claude, 3.0),
+            weighted(ModelFamily::Human, 2.9),
+        ]);
+        assert_eq!(balanced.primary, ModelFamily::Claude);
+        assert!(balanced.confidence < one_family.confidence);
+        // The penalty lowers confidence below the raw share, never the scores.
+        assert!(balanced.confidence < balanced.scores[&ModelFamily::Claude]);
+        let total: f64 = balanced.scores.values().sum();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn entropy_factor_is_bounded() {
+        let uniform: HashMap<_, _> = ModelFamily::all().iter().map(|f| (*f, 0.2)).collect();
+        assert!((entropy_factor(&uniform) - (1.0 - ENTROPY_PENALTY)).abs() < 1e-9);
+        let certain: HashMap<_, _> = ModelFamily::all()
+            .iter()
+            .map(|f| (*f, if *f == ModelFamily::Gemini { 1.0 } else { 0.0 }))
+            .collect();
+        assert_eq!(entropy_factor(&certain), 1.0);
+    }
+
     fn make_attribution(primary: ModelFamily, confidence: f64) -> Attribution {
         let mut scores = HashMap::new();
         for f in ModelFamily::all() {
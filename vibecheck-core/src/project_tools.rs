@@ -80,7 +80,7 @@ pub fn detect_project_tools(dir: &Path) -> Vec<DetectedTool> {
         }
     }
 
-    detected.sort_by(|a, b| a.tool.to_string().cmp(&b.tool.to_string()));
+    detected.sort_by_key(|a| a.tool.to_string());
     detected
 }
 
@@ -1,19 +1,117 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+
+use crate::language::Language;
 
 /// The model families we can attribute code to.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+///
+/// The five built-in families are fixed variants.  Embedders can add their
+/// own (e.g. an internal fine-tune) with [`ModelFamily::custom`]; custom
+/// families are interned, so the type stays `Copy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ModelFamily {
     Claude,
     Gpt,
     Gemini,
     Copilot,
     Human,
+    /// A family registered at runtime via [`ModelFamily::custom`].
+    Custom(CustomFamily),
+}
+
+/// Handle to an interned custom family name.  Obtain one through
+/// [`ModelFamily::custom`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CustomFamily(&'static str);
+
+impl CustomFamily {
+    /// The normalized (trimmed, lowercase) family name.
+    pub fn name(self) -> &'static str {
+        self.0
+    }
+}
+
+fn custom_registry() -> &'static Mutex<Vec<&'static str>> {
+    static REGISTRY: OnceLock<Mutex<Vec<&'static str>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| Mutex::new(Vec::new()))
 }
 
 impl ModelFamily {
+    /// Register (or look up) a family by name.
+    ///
+    /// Names are case-insensitive.  Built-in names return the built-in
+    /// variant; anything else is interned once and returned as
+    /// [`ModelFamily::Custom`].  Each distinct name is leaked for the life of
+    /// the process, so this is meant for a handful of configured families,
+    /// not arbitrary user input.
+    ///
+    /// # Panics
+    /// Panics if `name` is empty or whitespace.
+    pub fn custom(name: &str) -> ModelFamily {
+        let name = name.trim().to_lowercase();
+        assert!(!name.is_empty(), "model family name must not be empty");
+        if let Some(builtin) = Self::builtin_from_name(&name) {
+            return builtin;
+        }
+        let mut registry = custom_registry().lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(&existing) = registry.iter().find(|&&n| n == name) {
+            return ModelFamily::Custom(CustomFamily(existing));
+        }
+        let interned: &'static str = Box::leak(name.into_boxed_str());
+        registry.push(interned);
+        ModelFamily::Custom(CustomFamily(interned))
+    }
+
+    /// Look up a built-in or already-registered family by name
+    /// (case-insensitive).  Never registers anything.
+    pub fn from_name(name: &str) -> Option<ModelFamily> {
+        let name = name.trim().to_lowercase();
+        Self::builtin_from_name(&name).or_else(|| {
+            let registry = custom_registry().lock().unwrap_or_else(|e| e.into_inner());
+            registry
+                .iter()
+                .find(|&&n| n == name)
+                .map(|&n| ModelFamily::Custom(CustomFamily(n)))
+        })
+    }
+
+    fn builtin_from_name(name: &str) -> Option<ModelFamily> {
+        match name {
+            "claude"  => Some(ModelFamily::Claude),
+            "gpt"     => Some(ModelFamily::Gpt),
+            "gemini"  => Some(ModelFamily::Gemini),
+            "copilot" => Some(ModelFamily::Copilot),
+            "human"   => Some(ModelFamily::Human),
+            _         => None,
+        }
+    }
+
+    /// All custom families registered so far, in registration order.
+    pub fn custom_families() -> Vec<ModelFamily> {
+        let registry = custom_registry().lock().unwrap_or_else(|e| e.into_inner());
+        registry
+            .iter()
+            .map(|&n| ModelFamily::Custom(CustomFamily(n)))
+            .collect()
+    }
+
+    /// Stable lowercase identifier — the serialized form (`"gpt"`,
+    /// `"claude"`, or the custom name).
+    pub fn name(self) -> &'static str {
+        match self {
+            ModelFamily::Claude    => "claude",
+            ModelFamily::Gpt       => "gpt",
+            ModelFamily::Gemini    => "gemini",
+            ModelFamily::Copilot   => "copilot",
+            ModelFamily::Human     => "human",
+            ModelFamily::Custom(c) => c.name(),
+        }
+    }
+
+    /// The built-in families.  Custom families are listed by
+    /// [`ModelFamily::custom_families`].
     pub fn all() -> &'static [ModelFamily] {
         &[
             ModelFamily::Claude,
@@ -32,10 +130,33 @@ impl ModelFamily {
             ModelFamily::Gemini  => "Gemini",
             ModelFamily::Copilot => "Copilot",
             ModelFamily::Human   => "Human",
+            ModelFamily::Custom(c) => c.name(),
         }
     }
 }
 
+impl Serialize for ModelFamily {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for ModelFamily {
+    /// Built-in and already-registered names map to their families.  Unknown
+    /// names are an error rather than a new registration, so deserializing
+    /// untrusted reports or cache entries never grows the registry; register
+    /// custom families with [`ModelFamily::custom`] before loading reports
+    /// that use them.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        if name.trim().is_empty() {
+            return Err(serde::de::Error::custom("model family name must not be empty"));
+        }
+        ModelFamily::from_name(&name)
+            .ok_or_else(|| serde::de::Error::custom(format!("unknown model family `{}`", name.trim())))
+    }
+}
+
 impl std::fmt::Display for ModelFamily {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -44,6 +165,7 @@ impl std::fmt::Display for ModelFamily {
             ModelFamily::Gemini => write!(f, "Gemini"),
             ModelFamily::Copilot => write!(f, "Copilot"),
             ModelFamily::Human => write!(f, "Human"),
+            ModelFamily::Custom(c) => f.write_str(c.name()),
         }
     }
 }
@@ -57,19 +179,13 @@ impl std::fmt::Display for ModelFamily {
 pub struct FamilyId(pub String);
 
 impl FamilyId {
+    /// The matching built-in or registered custom family, if any.
     pub fn to_model_family(&self) -> Option<ModelFamily> {
-        match self.0.to_lowercase().as_str() {
-            "claude"  => Some(ModelFamily::Claude),
-            "gpt"     => Some(ModelFamily::Gpt),
-            "gemini"  => Some(ModelFamily::Gemini),
-            "copilot" => Some(ModelFamily::Copilot),
-            "human"   => Some(ModelFamily::Human),
-            _         => None,
-        }
+        ModelFamily::from_name(&self.0)
     }
 
     pub fn from_model_family(f: ModelFamily) -> Self {
-        Self(f.to_string().to_lowercase())
+        Self(f.name().to_string())
     }
 }
 
@@ -95,6 +211,14 @@ pub struct Signal {
     pub family: ModelFamily,
     /// Weight of this signal (negative = evidence against).
     pub weight: f64,
+    /// 1-based `(line, column)` of the first place the signal fired, for
+    /// signals tied to specific lines.  `None` for whole-file signals such
+    /// as ratios and densities.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<(usize, usize)>,
+    /// The trimmed source line at [`location`](Self::location).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
 }
 
 impl Signal {
@@ -115,8 +239,18 @@ impl Signal {
             description: desc.into(),
             family,
             weight,
+            location: None,
+            snippet: None,
         }
     }
+
+    /// Attach the 1-based `line` and `column` where this signal fired and
+    /// the source `snippet` found there.
+    pub fn with_location(mut self, line: usize, column: usize, snippet: impl Into<String>) -> Self {
+        self.location = Some((line, column));
+        self.snippet = Some(snippet.into());
+        self
+    }
 }
 
 /// The final attribution for a piece of code.
@@ -127,9 +261,29 @@ pub struct Attribution {
     /// Confidence in the primary attribution (0.0–1.0).
     pub confidence: f64,
     /// Score distribution across all families (sums to ~1.0).
+    /// Serialized in [`ModelFamily::all`] order, then custom families by
+    /// name, so output is reproducible.
+    #[serde(serialize_with = "serialize_scores")]
     pub scores: HashMap<ModelFamily, f64>,
 }
 
+fn serialize_scores<S: serde::Serializer>(
+    scores: &HashMap<ModelFamily, f64>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut custom: Vec<_> = scores
+        .iter()
+        .filter(|(f, _)| matches!(f, ModelFamily::Custom(_)))
+        .collect();
+    custom.sort_by_key(|(f, _)| f.name());
+    serializer.collect_map(
+        ModelFamily::all()
+            .iter()
+            .filter_map(|f| scores.get_key_value(f))
+            .chain(custom),
+    )
+}
+
 impl Attribution {
     /// Returns `true` when there was enough signal data to produce a
     /// meaningful attribution. When `false`, consumers should display
@@ -145,15 +299,74 @@ pub struct ReportMetadata {
     pub file_path: Option<PathBuf>,
     pub lines_of_code: usize,
     pub signal_count: usize,
+    /// [`analyzer_version`] of the build that produced this report.  Empty
+    /// for reports written before the field existed.
+    #[serde(default)]
+    pub analyzer_version: String,
+    /// The source carries a generated-code header (`Code generated ... DO
+    /// NOT EDIT.`, `@generated`, ...) and was not analyzed.
+    #[serde(default)]
+    pub generated: bool,
+    /// Parsing hit the pipeline's parse timeout, so the CST layer was
+    /// skipped and the verdict rests on text signals alone.
+    #[serde(default)]
+    pub timed_out: bool,
+    /// Syntax errors tree-sitter recovered from (outermost ERROR nodes plus
+    /// MISSING tokens).  Nonzero means the CST signals were scaled down and
+    /// the verdict rests more on text signals.
+    #[serde(default)]
+    pub parse_errors: usize,
+    /// Language the pipeline analyzed the source as, from the file
+    /// extension.  `None` when there was no path or it was unrecognized.
+    #[serde(default)]
+    pub language: Option<Language>,
+    /// The file was not valid UTF-8 and was analyzed after replacing the
+    /// invalid bytes with U+FFFD (the opt-in lossy read).
+    #[serde(default)]
+    pub lossy: bool,
+}
+
+impl ReportMetadata {
+    /// Parsing timed out or hit syntax errors, so syntax-tree signals were
+    /// skipped or down-weighted and the verdict is less trustworthy.
+    pub fn is_degraded(&self) -> bool {
+        self.timed_out || self.parse_errors > 0
+    }
+}
+
+/// Machine-comparable identifier for the analysis logic in this build: a
+/// short hex hash of the embedded `heuristics.toml` and the crate version.
+///
+/// Changes whenever signal definitions change or vibecheck is upgraded, so
+/// cached reports from an older build can be recognised and discarded.
+pub fn analyzer_version() -> &'static str {
+    static VERSION: OnceLock<String> = OnceLock::new();
+    VERSION.get_or_init(|| {
+        use sha2::{Digest, Sha256};
+        let mut h = Sha256::new();
+        h.update(include_str!("../heuristics.toml").as_bytes());
+        h.update(env!("CARGO_PKG_VERSION").as_bytes());
+        h.finalize()[..6].iter().map(|b| format!("{b:02x}")).collect()
+    })
 }
 
 /// Metadata about a named symbol (function, method, class, etc.) within a file.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SymbolMetadata {
     pub name: String,
     pub kind: String,       // "function", "method", "class", etc.
     pub start_line: usize,
     pub end_line: usize,
+    /// Cyclomatic complexity: 1 + the number of branch points in the body.
+    /// Nested functions and closures are measured separately.
+    #[serde(default)]
+    pub cyclomatic_complexity: usize,
+    /// Deepest nesting of blocks and control-flow constructs.
+    #[serde(default)]
+    pub max_nesting_depth: usize,
+    /// Non-blank lines in the symbol's span.
+    #[serde(default)]
+    pub loc: usize,
 }
 
 impl SymbolMetadata {
@@ -195,6 +408,40 @@ pub struct SymbolReport {
     pub signals: Vec<Signal>,
 }
 
+/// Verdict-only projection of a [`Report`] for consumers that don't need
+/// the signal list, e.g. dashboards fetching results per file over a network.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MinimalReport {
+    pub path: Option<PathBuf>,
+    pub family: ModelFamily,
+    pub confidence: f64,
+}
+
+/// One symbol's span and verdict, flattened out of
+/// [`Report::symbol_reports`] for editors drawing gutter annotations.
+/// Lines are 1-based and inclusive.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SymbolAnnotation {
+    pub path: Option<PathBuf>,
+    pub name: String,
+    pub kind: String,
+    pub start_line: usize,
+    pub end_line: usize,
+    pub family: ModelFamily,
+    pub confidence: f64,
+}
+
+/// One line's verdict in a line-level heatmap (see
+/// [`Pipeline::run_heatmap`](crate::pipeline::Pipeline::run_heatmap)).
+/// `line` is 1-based; `score` is `family`'s averaged score over the windows
+/// covering the line.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LineAttribution {
+    pub line: usize,
+    pub family: ModelFamily,
+    pub score: f64,
+}
+
 /// The full analysis report for a single source input.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Report {
@@ -204,12 +451,209 @@ pub struct Report {
     pub symbol_reports: Option<Vec<SymbolReport>>,
 }
 
+impl Report {
+    /// Project this report down to its path and verdict.  Cheap: copies
+    /// three fields, no re-analysis.
+    pub fn to_minimal(&self) -> MinimalReport {
+        MinimalReport {
+            path: self.metadata.file_path.clone(),
+            family: self.attribution.primary,
+            confidence: self.attribution.confidence,
+        }
+    }
+
+    /// Project each symbol report down to its span and verdict, dropping
+    /// the signals, in source order.  Empty without symbol-level analysis.
+    pub fn symbol_annotations(&self) -> Vec<SymbolAnnotation> {
+        let mut annotations: Vec<_> = self
+            .symbol_reports
+            .iter()
+            .flatten()
+            .map(|sr| SymbolAnnotation {
+                path: self.metadata.file_path.clone(),
+                name: sr.metadata.name.clone(),
+                kind: sr.metadata.kind.clone(),
+                start_line: sr.metadata.start_line,
+                end_line: sr.metadata.end_line,
+                family: sr.attribution.primary,
+                confidence: sr.attribution.confidence,
+            })
+            .collect();
+        annotations.sort_by_key(|a| (a.start_line, a.end_line));
+        annotations
+    }
+
+    /// Combine two partial reports for the same input, e.g. from separate
+    /// analyzer passes in a multi-stage pipeline.
+    ///
+    /// - **Signals** are unioned: signals from `other` are appended unless
+    ///   `self` already has one with the same ID (or, for legacy ID-less
+    ///   signals, the same source and description).
+    /// - **Attribution** is re-aggregated from the merged signal list.
+    /// - **Metadata** takes the larger `lines_of_code`; `file_path` prefers
+    ///   `self` and only falls back to `other` when `self` has none.
+    /// - **Symbol reports** prefer `self`, falling back to `other`.
+    pub fn merge(mut self, other: Report) -> Report {
+        for signal in other.signals {
+            let duplicate = self.signals.iter().any(|s| {
+                if signal.id.is_empty() {
+                    s.id.is_empty()
+                        && s.source == signal.source
+                        && s.description == signal.description
+                } else {
+                    s.id == signal.id
+                }
+            });
+            if !duplicate {
+                self.signals.push(signal);
+            }
+        }
+
+        crate::pipeline::sort_signals(&mut self.signals);
+        let attribution = crate::pipeline::aggregate_signals(&self.signals);
+        let signal_count = self.signals.len();
+        Report {
+            attribution,
+            metadata: ReportMetadata {
+                file_path: self.metadata.file_path.or(other.metadata.file_path),
+                lines_of_code: self.metadata.lines_of_code.max(other.metadata.lines_of_code),
+                signal_count,
+                analyzer_version: self.metadata.analyzer_version,
+                generated: self.metadata.generated || other.metadata.generated,
+                timed_out: self.metadata.timed_out || other.metadata.timed_out,
+                parse_errors: self.metadata.parse_errors.max(other.metadata.parse_errors),
+                language: self.metadata.language.or(other.metadata.language),
+                lossy: self.metadata.lossy || other.metadata.lossy,
+            },
+            symbol_reports: self.symbol_reports.or(other.symbol_reports),
+            signals: self.signals,
+        }
+    }
+}
+
+/// Line-weighted attribution across many reports, with a rough 95% margin.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct AggregateAttribution {
+    /// Family with the largest line-weighted score share.
+    pub family: ModelFamily,
+    /// That family's line-weighted mean score (0.0–1.0).
+    pub share: f64,
+    /// Half-width of the interval: `share ± margin`.
+    pub margin: f64,
+    /// Reports that contributed (those with sufficient data).
+    pub files: usize,
+    pub lines: usize,
+}
+
+/// Aggregate per-file scores into one family share with a `±` range.
+///
+/// Each report is weighted by its line count. The margin is 1.96 standard
+/// errors of the weighted mean, using the LOC-weighted variance of the
+/// per-file scores and Kish's effective sample size, so one huge file does
+/// not count as many independent observations. A single file yields a zero
+/// margin. Returns `None` when no report has sufficient data.
+pub fn aggregate_attribution<'a>(
+    reports: impl IntoIterator<Item = &'a Report>,
+) -> Option<AggregateAttribution> {
+    let reports: Vec<&Report> = reports
+        .into_iter()
+        .filter(|r| r.attribution.has_sufficient_data())
+        .collect();
+    if reports.is_empty() {
+        return None;
+    }
+    let weights: Vec<f64> = reports
+        .iter()
+        .map(|r| (r.metadata.lines_of_code as f64).max(1.0))
+        .collect();
+    let total: f64 = weights.iter().sum();
+    let score = |r: &Report, f: &ModelFamily| r.attribution.scores.get(f).copied().unwrap_or(0.0);
+    let mean = |f: &ModelFamily| -> f64 {
+        reports.iter().zip(&weights).map(|(r, w)| w * score(r, f)).sum::<f64>() / total
+    };
+
+    let means: HashMap<ModelFamily, f64> = ModelFamily::all()
+        .iter()
+        .copied()
+        .chain(ModelFamily::custom_families())
+        .map(|f| (f, mean(&f)))
+        .collect();
+    // Ties and NaN means are settled as for a single file's primary family.
+    let (family, share) = crate::pipeline::top_family(&means)?;
+
+    let variance = reports
+        .iter()
+        .zip(&weights)
+        .map(|(r, w)| w * (score(r, &family) - share).powi(2))
+        .sum::<f64>()
+        / total;
+    let effective_n = total.powi(2) / weights.iter().map(|w| w * w).sum::<f64>();
+    let margin = 1.96 * (variance / effective_n).sqrt();
+
+    Some(AggregateAttribution {
+        family,
+        share,
+        margin,
+        files: reports.len(),
+        lines: reports.iter().map(|r| r.metadata.lines_of_code).sum(),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn scored(lines: usize, claude: f64) -> Report {
+        let mut report = crate::analyze("");
+        report.attribution = Attribution {
+            primary: ModelFamily::Claude,
+            confidence: claude,
+            scores: HashMap::from([(ModelFamily::Claude, claude), (ModelFamily::Human, 1.0 - claude)]),
+        };
+        report.metadata.lines_of_code = lines;
+        report
+    }
+
+    #[test]
+    fn aggregate_attribution_weights_by_lines() {
+        let reports = [scored(300, 0.9), scored(100, 0.5)];
+        let agg = aggregate_attribution(&reports).unwrap();
+        assert_eq!(agg.family, ModelFamily::Claude);
+        assert!((agg.share - 0.8).abs() < 1e-9, "share {}", agg.share);
+        assert_eq!((agg.files, agg.lines), (2, 400));
+        assert!(agg.margin > 0.0 && agg.margin < 0.5, "margin {}", agg.margin);
+    }
+
+    #[test]
+    fn aggregate_attribution_margin_shrinks_with_agreement() {
+        let spread: Vec<Report> = (0..10).map(|i| scored(50, if i % 2 == 0 { 0.9 } else { 0.6 })).collect();
+        let tight: Vec<Report> = (0..10).map(|_| scored(50, 0.75)).collect();
+        let spread = aggregate_attribution(&spread).unwrap();
+        let tight = aggregate_attribution(&tight).unwrap();
+        assert!((spread.share - tight.share).abs() < 1e-9);
+        assert!(tight.margin < 1e-9);
+        assert!(spread.margin > tight.margin);
+    }
+
+    #[test]
+    fn aggregate_attribution_breaks_ties_like_a_single_file() {
+        let agg = aggregate_attribution(&[scored(100, 0.5)]).unwrap();
+        assert_eq!(agg.family, ModelFamily::Human);
+        let mut corrupt = scored(100, 0.9);
+        corrupt.attribution.scores.insert(ModelFamily::Gpt, f64::NAN);
+        assert_eq!(aggregate_attribution(&[corrupt]).unwrap().family, ModelFamily::Claude);
+    }
+
+    #[test]
+    fn aggregate_attribution_skips_insufficient_data() {
+        assert!(aggregate_attribution(&[crate::analyze("")]).is_none());
+        let single = aggregate_attribution(&[scored(10, 0.7), crate::analyze("")]).unwrap();
+        assert_eq!(single.files, 1);
+        assert_eq!(single.margin, 0.0);
+    }
+
     fn make_sym(kind: &str) -> SymbolMetadata {
-        SymbolMetadata { name: "do_thing".into(), kind: kind.into(), start_line: 1, end_line: 5 }
+        SymbolMetadata { name: "do_thing".into(), kind: kind.into(), start_line: 1, end_line: 5, ..Default::default() }
     }
 
     #[test]
@@ -235,7 +679,7 @@ mod tests {
 
     #[test]
     fn display_name_class_no_parens() {
-        let sym = SymbolMetadata { name: "MyClass".into(), kind: "class".into(), start_line: 1, end_line: 5 };
+        let sym = SymbolMetadata { name: "MyClass".into(), kind: "class".into(), start_line: 1, end_line: 5, ..Default::default() };
         assert_eq!(sym.display_name(50), "MyClass");
     }
 
@@ -246,6 +690,7 @@ mod tests {
             kind: "function".into(),
             start_line: 1,
             end_line: 5,
+            ..Default::default()
         };
         let result = sym.display_name(10);
         // "…" is 3 bytes; total byte len = 9 ASCII chars + 3 = 12
@@ -301,6 +746,54 @@ mod tests {
         }
     }
 
+    fn make_report(path: Option<&str>, loc: usize, signals: Vec<Signal>) -> Report {
+        let signal_count = signals.len();
+        Report {
+            attribution: crate::pipeline::aggregate_signals(&signals),
+            signals,
+            metadata: ReportMetadata {
+                file_path: path.map(PathBuf::from),
+                lines_of_code: loc,
+                signal_count,
+                analyzer_version: analyzer_version().to_string(),
+                generated: false,
+                timed_out: false,
+                parse_errors: 0,
+                language: None,
+                lossy: false,
+            },
+            symbol_reports: None,
+        }
+    }
+
+    #[test]
+    fn merge_unions_signals_and_reaggregates() {
+        let a = make_report(Some("a.rs"), 10, vec![
+            Signal::new("x.one", "s", "one", ModelFamily::Claude, 1.0),
+        ]);
+        let b = make_report(None, 20, vec![
+            Signal::new("x.one", "s", "one", ModelFamily::Claude, 1.0),
+            Signal::new("x.two", "s", "two", ModelFamily::Human, 3.0),
+        ]);
+        let merged = a.merge(b);
+        assert_eq!(merged.signals.len(), 2);
+        assert_eq!(merged.metadata.signal_count, 2);
+        assert_eq!(merged.metadata.lines_of_code, 20);
+        assert_eq!(merged.metadata.file_path, Some(PathBuf::from("a.rs")));
+        assert_eq!(merged.attribution.primary, ModelFamily::Human);
+    }
+
+    #[test]
+    fn merge_prefers_self_file_path_on_conflict() {
+        let a = make_report(Some("a.rs"), 1, vec![]);
+        let b = make_report(Some("b.rs"), 1, vec![]);
+        assert_eq!(a.merge(b).metadata.file_path, Some(PathBuf::from("a.rs")));
+
+        let a = make_report(None, 1, vec![]);
+        let b = make_report(Some("b.rs"), 1, vec![]);
+        assert_eq!(a.merge(b).metadata.file_path, Some(PathBuf::from("b.rs")));
+    }
+
     #[test]
     fn family_id_json_roundtrip() {
         let id = FamilyId("claude".into());
@@ -308,4 +801,70 @@ mod tests {
         let back: FamilyId = serde_json::from_str(&json).unwrap();
         assert_eq!(back, id);
     }
+
+    #[test]
+    fn scores_serialize_in_family_order() {
+        let attribution = Attribution {
+            primary: ModelFamily::Human,
+            confidence: 0.5,
+            scores: ModelFamily::all().iter().rev().map(|&f| (f, 0.2)).collect(),
+        };
+        let json = serde_json::to_string(&attribution).unwrap();
+        assert!(json.contains(
+            r#""scores":{"claude":0.2,"gpt":0.2,"gemini":0.2,"copilot":0.2,"human":0.2}"#
+        ), "{json}");
+    }
+
+    #[test]
+    fn custom_family_is_interned_and_case_insensitive() {
+        let a = ModelFamily::custom("Report-Test-Model");
+        let b = ModelFamily::custom("report-test-model");
+        assert_eq!(a, b);
+        assert_eq!(a.to_string(), "report-test-model");
+        assert_eq!(ModelFamily::from_name("REPORT-TEST-MODEL"), Some(a));
+        assert!(ModelFamily::custom_families().contains(&a));
+        assert!(!ModelFamily::all().contains(&a));
+    }
+
+    #[test]
+    fn custom_with_builtin_name_returns_builtin() {
+        assert_eq!(ModelFamily::custom("GPT"), ModelFamily::Gpt);
+        assert_eq!(ModelFamily::from_name("never-registered-family"), None);
+    }
+
+    #[test]
+    fn custom_family_serde_round_trip() {
+        let family = ModelFamily::custom("report-serde-model");
+        let json = serde_json::to_string(&family).unwrap();
+        assert_eq!(json, r#""report-serde-model""#);
+        let back: ModelFamily = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, family);
+        let builtin: ModelFamily = serde_json::from_str(r#""copilot""#).unwrap();
+        assert_eq!(builtin, ModelFamily::Copilot);
+        assert!(serde_json::from_str::<ModelFamily>(r#""  ""#).is_err());
+    }
+
+    #[test]
+    fn deserializing_unknown_family_errors_without_registering() {
+        let err = serde_json::from_str::<ModelFamily>(r#""report-unregistered-model""#).unwrap_err();
+        assert!(err.to_string().contains("unknown model family"), "{err}");
+        assert_eq!(ModelFamily::from_name("report-unregistered-model"), None);
+    }
+
+    #[test]
+    fn custom_scores_serialize_after_builtins() {
+        let zeta = ModelFamily::custom("report-zeta");
+        let alpha = ModelFamily::custom("report-alpha");
+        let attribution = Attribution {
+            primary: zeta,
+            confidence: 0.5,
+            scores: [(zeta, 0.3), (ModelFamily::Human, 0.2), (alpha, 0.5)]
+                .into_iter()
+                .collect(),
+        };
+        let json = serde_json::to_string(&attribution).unwrap();
+        assert!(json.contains(
+            r#""scores":{"human":0.2,"report-alpha":0.5,"report-zeta":0.3}"#
+        ), "{json}");
+    }
 }
@@ -1,8 +1,21 @@
 #![cfg(feature = "corpus")]
 
+use std::collections::HashMap;
 use std::path::Path;
 
 use rusqlite::{Connection, Result, params};
+use sha2::{Digest, Sha256};
+
+use crate::heuristics::{all_heuristics, HeuristicsProvider};
+use crate::report::{ModelFamily, Report};
+
+/// Fewest corpus files a signal must have fired in before its prior is
+/// trusted; rarer signals keep their configured weight.
+pub const MIN_CORPUS_SAMPLES: usize = 10;
+
+/// Cap on how far a corpus prior can scale a weight, so one lopsided
+/// signal cannot dominate a verdict.
+const MAX_LIFT: f64 = 3.0;
 
 /// A persistent corpus and trend store backed by SQLite.
 pub struct Store {
@@ -30,6 +43,12 @@ impl Store {
             CREATE UNIQUE INDEX IF NOT EXISTS corpus_entries_hash
                 ON corpus_entries(file_hash);
 
+            CREATE TABLE IF NOT EXISTS corpus_signals (
+                file_hash    TEXT    NOT NULL,
+                signal_id    TEXT    NOT NULL,
+                PRIMARY KEY (file_hash, signal_id)
+            );
+
             CREATE TABLE IF NOT EXISTS trend_entries (
                 id           INTEGER PRIMARY KEY AUTOINCREMENT,
                 file_hash    TEXT    NOT NULL,
@@ -57,6 +76,60 @@ impl Store {
         Ok(())
     }
 
+    /// Record which signals fired for the corpus entry `file_hash`.
+    /// Silently ignores signals already recorded for it.
+    pub fn insert_corpus_signals(&self, file_hash: &str, signal_ids: &[&str]) -> Result<()> {
+        let mut stmt = self
+            .conn
+            .prepare("INSERT OR IGNORE INTO corpus_signals (file_hash, signal_id) VALUES (?1, ?2)")?;
+        for id in signal_ids {
+            stmt.execute(params![file_hash, id])?;
+        }
+        Ok(())
+    }
+
+    /// Add the file `content`, whose true family is `family`, to the corpus
+    /// with the signals that fired in its `report`, keyed by the content's
+    /// SHA-256.  The entry's confidence is `1.0`: it is a label, not a
+    /// verdict.  Silently ignores content already in the corpus.
+    pub fn insert_labeled(&self, content: &[u8], report: &Report, family: ModelFamily) -> Result<()> {
+        let hash: String = Sha256::digest(content).iter().map(|b| format!("{b:02x}")).collect();
+        let path = report.metadata.file_path.as_ref().map(|p| p.to_string_lossy());
+        self.insert_corpus(&hash, path.as_deref(), family.name(), 1.0)?;
+        let ids: Vec<&str> = report.signals.iter().map(|s| s.id.as_str()).filter(|id| !id.is_empty()).collect();
+        self.insert_corpus_signals(&hash, &ids)
+    }
+
+    /// Number of corpus entries attributed to each family, and for each
+    /// signal the number of entries per family it fired in.  Attributions
+    /// that do not name a known family are skipped.
+    pub fn signal_family_counts(&self) -> Result<CorpusCounts> {
+        let mut counts = CorpusCounts::default();
+        let mut stmt = self.conn.prepare("SELECT attribution, COUNT(*) FROM corpus_entries GROUP BY 1")?;
+        let rows = stmt.query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, usize>(1)?)))?;
+        for row in rows {
+            let (family, n) = row?;
+            if let Some(family) = ModelFamily::from_name(&family) {
+                *counts.families.entry(family).or_default() += n;
+            }
+        }
+        let mut stmt = self.conn.prepare(
+            "SELECT s.signal_id, e.attribution, COUNT(*)
+             FROM corpus_signals s JOIN corpus_entries e ON e.file_hash = s.file_hash
+             GROUP BY 1, 2",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?, row.get::<_, usize>(2)?))
+        })?;
+        for row in rows {
+            let (id, family, n) = row?;
+            if let Some(family) = ModelFamily::from_name(&family) {
+                *counts.signals.entry(id).or_default().entry(family).or_default() += n;
+            }
+        }
+        Ok(counts)
+    }
+
     /// Record a trend entry (always inserts, does not deduplicate).
     pub fn record_trend(
         &self,
@@ -72,3 +145,173 @@ impl Store {
         Ok(())
     }
 }
+
+/// Corpus tallies read by [`Store::signal_family_counts`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CorpusCounts {
+    /// Family → corpus entries attributed to it.
+    pub families: HashMap<ModelFamily, usize>,
+    /// Signal ID → family → corpus entries attributed to it where the
+    /// signal fired.
+    pub signals: HashMap<String, HashMap<ModelFamily, usize>>,
+}
+
+impl CorpusCounts {
+    /// How much more often than its base rate `family` is the attribution
+    /// of corpus entries where `signal_id` fired: `1.0` means the signal
+    /// tells nothing about it, `2.0` twice as likely.  `None` below
+    /// [`MIN_CORPUS_SAMPLES`] entries or when the corpus has no `family`.
+    pub fn lift(&self, signal_id: &str, family: ModelFamily) -> Option<f64> {
+        let by_family = self.signals.get(signal_id)?;
+        let fired: usize = by_family.values().sum();
+        let total: usize = self.families.values().sum();
+        let base = *self.families.get(&family)? as f64 / total as f64;
+        if fired < MIN_CORPUS_SAMPLES || base == 0.0 {
+            return None;
+        }
+        let share = by_family.get(&family).copied().unwrap_or(0) as f64 / fired as f64;
+        Some((share / base).min(MAX_LIFT))
+    }
+}
+
+/// A [`HeuristicsProvider`] whose weights are blended with empirical priors
+/// from a corpus: each catalogued signal's weight is scaled by its
+/// [`lift`](CorpusCounts::lift) toward its target family, mixed with the
+/// wrapped provider's weight by `mix` (`0.0` ignores the corpus, `1.0`
+/// uses the scaled weight alone).
+///
+/// Read-only: the store is consulted once, at construction.  Signals the
+/// corpus has too few samples of, and whether a signal is enabled at all,
+/// are left to the wrapped provider.
+pub struct CorpusHeuristics {
+    inner: Box<dyn HeuristicsProvider>,
+    /// Signal ID → lift toward its catalogued family.
+    lifts: HashMap<String, f64>,
+    mix: f64,
+}
+
+impl CorpusHeuristics {
+    pub fn new(inner: Box<dyn HeuristicsProvider>, counts: &CorpusCounts, mix: f64) -> Self {
+        let lifts = all_heuristics()
+            .iter()
+            .filter_map(|h| Some((h.id.to_string(), counts.lift(h.id, h.family)?)))
+            .collect();
+        let mix = if mix.is_finite() { mix.clamp(0.0, 1.0) } else { 0.0 };
+        Self { inner, lifts, mix }
+    }
+
+    /// Read the priors from `store`.
+    pub fn from_store(store: &Store, inner: Box<dyn HeuristicsProvider>, mix: f64) -> Result<Self> {
+        Ok(Self::new(inner, &store.signal_family_counts()?, mix))
+    }
+}
+
+impl HeuristicsProvider for CorpusHeuristics {
+    fn weight(&self, id: &str) -> f64 {
+        let weight = self.inner.weight(id);
+        match self.lifts.get(id) {
+            Some(lift) => weight * (1.0 - self.mix + self.mix * lift),
+            None => weight,
+        }
+    }
+
+    fn is_enabled(&self, id: &str) -> bool {
+        self.inner.is_enabled(id)
+    }
+
+    fn is_source_enabled(&self, source: &str) -> bool {
+        self.inner.is_source_enabled(source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::heuristics::{signal_ids, DefaultHeuristics};
+    use crate::pipeline::Pipeline;
+
+    /// A store of `n` entries per `(family, fired)` pair, where `fired`
+    /// says whether `signal` fired in them.
+    fn store_with(signal: &str, entries: &[(ModelFamily, bool, usize)]) -> (tempfile::TempDir, Store) {
+        let dir = tempfile::tempdir().unwrap();
+        let store = Store::open(&dir.path().join("corpus.db")).unwrap();
+        let mut i = 0;
+        for &(family, fired, n) in entries {
+            for _ in 0..n {
+                let hash = format!("h{i}");
+                store.insert_corpus(&hash, None, family.name(), 0.9).unwrap();
+                if fired {
+                    store.insert_corpus_signals(&hash, &[signal]).unwrap();
+                }
+                i += 1;
+            }
+        }
+        (dir, store)
+    }
+
+    #[test]
+    fn lift_compares_co_occurrence_with_base_rate() {
+        use ModelFamily::{Claude, Human};
+        let id = signal_ids::RUST_ERRORS_ZERO_UNWRAP;
+        // Half the corpus is Claude, but 8 of the 10 files the signal fired in are.
+        let (_dir, store) =
+            store_with(id, &[(Claude, true, 8), (Human, true, 2), (Claude, false, 2), (Human, false, 8)]);
+        let counts = store.signal_family_counts().unwrap();
+        assert_eq!(counts.families[&Claude], 10);
+        assert_eq!(counts.lift(id, Claude), Some(1.6));
+        assert_eq!(counts.lift("rust.unknown", Claude), None);
+
+        let (_dir, sparse) = store_with(id, &[(Claude, true, 3), (Human, false, 3)]);
+        assert_eq!(sparse.signal_family_counts().unwrap().lift(id, Claude), None, "too few samples");
+    }
+
+    #[test]
+    fn corpus_weights_blend_with_the_defaults() {
+        let id = signal_ids::RUST_ERRORS_ZERO_UNWRAP;
+        let default = DefaultHeuristics.weight(id);
+        let family = all_heuristics().iter().find(|h| h.id == id).unwrap().family;
+        let other = if family == ModelFamily::Human { ModelFamily::Gpt } else { ModelFamily::Human };
+        let (dir, store) =
+            store_with(id, &[(family, true, 8), (other, true, 2), (family, false, 2), (other, false, 8)]);
+
+        let weight = |mix: f64| {
+            let heuristics = CorpusHeuristics::from_store(&store, Box::new(DefaultHeuristics), mix).unwrap();
+            heuristics.weight(id)
+        };
+        assert_eq!(weight(0.0), default);
+        assert!((weight(1.0) - default * 1.6).abs() < 1e-9);
+        assert!((weight(0.5) - default * 1.3).abs() < 1e-9);
+        let other_id = signal_ids::RUST_ERRORS_MANY_UNWRAPS;
+        let heuristics = CorpusHeuristics::from_store(&store, Box::new(DefaultHeuristics), 1.0).unwrap();
+        assert_eq!(heuristics.weight(other_id), DefaultHeuristics.weight(other_id), "no corpus data");
+
+        // The pipeline, directly and through `[corpus]` in `.vibecheck`,
+        // reports the blended weight on the fired signal.
+        let source = "fn main() {}\n".repeat(40);
+        let fired = |report: Report| report.signals.iter().find(|s| s.id == id).map(|s| s.weight);
+        let plain = Pipeline::with_defaults().run(&source, Some("main.rs".into()));
+        assert_eq!(fired(plain), Some(default));
+        let pipeline = Pipeline::with_defaults().with_corpus_priors(&store, 1.0).unwrap();
+        let blended = fired(pipeline.run(&source, Some("main.rs".into()))).unwrap();
+        assert!((blended - default * 1.6).abs() < 1e-9, "{blended}");
+        std::fs::write(dir.path().join(".vibecheck"), "[corpus]\nstore = \"corpus.db\"\nmix = 1.0\n").unwrap();
+        let report = crate::analyze_source(&source, &dir.path().join("main.rs"), &Default::default());
+        assert!((fired(report).unwrap() - default * 1.6).abs() < 1e-9);
+    }
+
+    #[test]
+    fn labeled_reports_record_their_signals() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = Store::open(&dir.path().join("corpus.db")).unwrap();
+        let source = "fn main() {}\n".repeat(40);
+        let report = Pipeline::with_defaults().run(&source, Some("main.rs".into()));
+        store.insert_labeled(source.as_bytes(), &report, ModelFamily::Human).unwrap();
+        store.insert_labeled(source.as_bytes(), &report, ModelFamily::Claude).unwrap();
+
+        let counts = store.signal_family_counts().unwrap();
+        assert_eq!(counts.families, HashMap::from([(ModelFamily::Human, 1)]), "duplicates are ignored");
+        let id = signal_ids::RUST_ERRORS_ZERO_UNWRAP;
+        assert_eq!(counts.signals[id], HashMap::from([(ModelFamily::Human, 1)]));
+        assert_eq!(counts.signals.len(), report.signals.len());
+    }
+}
@@ -225,14 +225,7 @@ impl PostScorer for EnsembleModel {
         language: Option<Language>,
         source: &str,
     ) -> Attribution {
-        let lang_str = language
-            .map(|l| match l {
-                Language::Rust => "rust",
-                Language::Python => "python",
-                Language::JavaScript => "javascript",
-                Language::Go => "go",
-            })
-            .unwrap_or("unknown");
+        let lang_str = language.map(Language::name).unwrap_or("unknown");
 
         let fv = crate::features::extract_features(
             signals,
@@ -556,7 +549,14 @@ mod tests {
             scores: ModelFamily::all().iter().map(|&f| (f, 0.2)).collect(),
         };
 
-        for lang in [Language::Rust, Language::Python, Language::JavaScript, Language::Go] {
+        for lang in [
+            Language::Rust,
+            Language::Python,
+            Language::JavaScript,
+            Language::Go,
+            Language::CSharp,
+            Language::Hcl,
+        ] {
             let result = ensemble.rescore(
                 &[],
                 &HashMap::new(),
@@ -41,9 +41,12 @@ fn lang_name(ext: &str) -> &str {
     }
 }
 
-fn build_classifier() -> (MarkovClassifier, HashMap<String, Vec<(String, Vec<u32>)>>) {
+/// Language → `(family, interned AST sequence)` pairs.
+type LangSequences = HashMap<String, Vec<(String, Vec<u32>)>>;
+
+fn build_classifier() -> (MarkovClassifier, LangSequences) {
     let mut vocab = Vocabulary::new();
-    let mut per_lang_family: HashMap<String, Vec<(String, Vec<u32>)>> = HashMap::new();
+    let mut per_lang_family: LangSequences = HashMap::new();
 
     for &ext in EXTENSIONS {
         let ts_lang = ts_lang_for_ext(ext);
@@ -67,9 +70,9 @@ fn build_classifier() -> (MarkovClassifier, HashMap<String, Vec<(String, Vec<u32
 
     for (language, entries) in &per_lang_family {
         for (family, seq) in entries {
-            clf.add_model(language, family, 1, &[seq.clone()]);
+            clf.add_model(language, family, 1, std::slice::from_ref(seq));
             if seq.len() > 3 {
-                clf.add_model(language, family, 2, &[seq.clone()]);
+                clf.add_model(language, family, 2, std::slice::from_ref(seq));
             }
         }
     }